@@ -164,6 +164,18 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 use rxtui::providers::EffectsProvider;
                 self.__component_effects_impl(ctx)
             }
+
+            // Use method resolution to call inherent lifecycle hooks generated by
+            // #[component] if they exist, otherwise do nothing
+            fn on_mount(&self, ctx: &rxtui::Context) {
+                use rxtui::providers::LifecycleProvider;
+                self.__component_on_mount_impl(ctx)
+            }
+
+            fn on_unmount(&self, ctx: &rxtui::Context) {
+                use rxtui::providers::LifecycleProvider;
+                self.__component_on_unmount_impl(ctx)
+            }
         }
 
     };
@@ -617,6 +629,7 @@ pub fn effect(_args: TokenStream, input: TokenStream) -> TokenStream {
 /// 1. Collects all methods marked with `#[effect]`
 /// 2. Generates helper methods for each effect
 /// 3. Automatically creates the `effects()` method
+/// 4. Wires `on_mount` and `on_unmount` methods, if defined, into the lifecycle hooks
 ///
 /// # Example
 ///
@@ -637,6 +650,10 @@ pub fn effect(_args: TokenStream, input: TokenStream) -> TokenStream {
 ///     async fn timer(&self, ctx: &Context) {
 ///         // async effect logic
 ///     }
+///
+///     fn on_unmount(&self, ctx: &Context) {
+///         // release resources
+///     }
 /// }
 /// ```
 ///
@@ -763,6 +780,27 @@ pub fn component(_args: TokenStream, input: TokenStream) -> TokenStream {
     let effects_item: ImplItem = syn::parse2(effects_method).unwrap();
     impl_block.items.push(effects_item);
 
+    // Forward `on_mount` and `on_unmount` methods to the lifecycle hooks
+    for (hook, helper_name) in [
+        ("on_mount", "__component_on_mount_impl"),
+        ("on_unmount", "__component_on_unmount_impl"),
+    ] {
+        let defined = impl_block
+            .items
+            .iter()
+            .any(|item| matches!(item, ImplItem::Fn(method) if method.sig.ident == hook));
+        if defined {
+            let hook = format_ident!("{}", hook);
+            let helper_name = format_ident!("{}", helper_name);
+            let helper_item: ImplItem = syn::parse_quote! {
+                fn #helper_name(&self, ctx: &rxtui::Context) {
+                    self.#hook(ctx)
+                }
+            };
+            impl_block.items.push(helper_item);
+        }
+    }
+
     // Just return the impl block with the effects method
     TokenStream::from(quote! { #impl_block })
 }
@@ -0,0 +1,221 @@
+use std::sync::{Arc, RwLock};
+use thiserror::Error;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Errors returned by clipboard operations.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// No clipboard is available (feature disabled, headless session, SSH, ...)
+    #[error("clipboard is not available")]
+    Unavailable,
+
+    /// The clipboard is empty or does not contain text
+    #[error("clipboard does not contain text")]
+    Empty,
+
+    /// The clipboard backend reported an error
+    #[error("clipboard error: {0}")]
+    Backend(String),
+}
+
+/// Source and sink for clipboard text.
+///
+/// The `clipboard` feature provides [`SystemClipboard`]; tests can use [`MemoryClipboard`].
+pub trait ClipboardBackend: Send + Sync {
+    /// Returns the current clipboard text.
+    fn get_text(&self) -> Result<String, ClipboardError>;
+
+    /// Replaces the clipboard contents with `text`.
+    fn set_text(&self, text: &str) -> Result<(), ClipboardError>;
+}
+
+/// In-memory clipboard, mainly useful for tests.
+#[derive(Default)]
+pub struct MemoryClipboard {
+    contents: RwLock<Option<String>>,
+}
+
+/// Clipboard of the operating system, backed by `arboard`.
+#[cfg(feature = "clipboard")]
+#[derive(Default)]
+pub struct SystemClipboard;
+
+/// Holds the clipboard backend shared by every context
+#[derive(Clone, Default)]
+pub(crate) struct Clipboard {
+    backend: Arc<RwLock<Option<Arc<dyn ClipboardBackend>>>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl MemoryClipboard {
+    /// Creates an empty in-memory clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl SystemClipboard {
+    /// Creates a handle to the system clipboard.
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn open() -> Result<arboard::Clipboard, ClipboardError> {
+        arboard::Clipboard::new().map_err(|_| ClipboardError::Unavailable)
+    }
+}
+
+impl Clipboard {
+    /// Installs the backend used for copy and paste
+    pub(crate) fn set_backend(&self, backend: Arc<dyn ClipboardBackend>) {
+        *self.backend.write().unwrap() = Some(backend);
+    }
+
+    /// Returns the clipboard text, or `Unavailable` without a backend
+    pub(crate) fn get_text(&self) -> Result<String, ClipboardError> {
+        match self.backend.read().unwrap().as_ref() {
+            Some(backend) => backend.get_text(),
+            None => Err(ClipboardError::Unavailable),
+        }
+    }
+
+    /// Writes the clipboard text, or returns `Unavailable` without a backend
+    pub(crate) fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+        match self.backend.read().unwrap().as_ref() {
+            Some(backend) => backend.set_text(text),
+            None => Err(ClipboardError::Unavailable),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl ClipboardBackend for MemoryClipboard {
+    fn get_text(&self) -> Result<String, ClipboardError> {
+        self.contents
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or(ClipboardError::Empty)
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+        *self.contents.write().unwrap() = Some(text.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl ClipboardBackend for SystemClipboard {
+    fn get_text(&self) -> Result<String, ClipboardError> {
+        Self::open()?.get_text().map_err(|e| match e {
+            arboard::Error::ContentNotAvailable => ClipboardError::Empty,
+            arboard::Error::ClipboardNotSupported => ClipboardError::Unavailable,
+            e => ClipboardError::Backend(e.to_string()),
+        })
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+        Self::open()?.set_text(text).map_err(|e| match e {
+            arboard::Error::ClipboardNotSupported => ClipboardError::Unavailable,
+            e => ClipboardError::Backend(e.to_string()),
+        })
+    }
+}
+
+impl<B: ClipboardBackend + ?Sized> ClipboardBackend for Arc<B> {
+    fn get_text(&self) -> Result<String, ClipboardError> {
+        (**self).get_text()
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+        (**self).set_text(text)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "clipboard"))]
+mod tests {
+    use super::*;
+    use crate::app::Context;
+    use crate::component::{Action, Component};
+    use crate::components::text_input::{TextInput, TextInputMsg, TextInputState};
+
+    fn send(input: &TextInput, ctx: &Context, msg: TextInputMsg) -> TextInputState {
+        if let Action::Update(state) = input.update(ctx, Box::new(msg), None) {
+            ctx.set_state(state);
+        }
+        ctx.get_state::<TextInputState>()
+    }
+
+    fn focused_input(ctx: &Context, content: &str) {
+        ctx.set_state(Box::new(TextInputState {
+            focused: true,
+            content: content.to_string(),
+            cursor_position: content.chars().count(),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_without_backend_is_unavailable() {
+        let ctx = Context::default();
+        assert_eq!(
+            ctx.copy_to_clipboard("hello"),
+            Err(ClipboardError::Unavailable)
+        );
+        assert_eq!(ctx.read_clipboard(), Err(ClipboardError::Unavailable));
+    }
+
+    #[test]
+    fn test_context_round_trip() {
+        let ctx = Context::default();
+        ctx.clipboard.set_backend(Arc::new(MemoryClipboard::new()));
+
+        assert_eq!(ctx.read_clipboard(), Err(ClipboardError::Empty));
+        ctx.copy_to_clipboard("hello").unwrap();
+        assert_eq!(ctx.child(0).read_clipboard().as_deref(), Ok("hello"));
+    }
+
+    #[test]
+    fn test_copy_then_paste_into_input() {
+        let clipboard = Arc::new(MemoryClipboard::new());
+        let input = TextInput::new();
+
+        let source = Context::default();
+        source.clipboard.set_backend(clipboard.clone());
+        focused_input(&source, "hello world");
+        send(&input, &source, TextInputMsg::SelectAll);
+        send(&input, &source, TextInputMsg::Copy);
+        assert_eq!(clipboard.get_text().as_deref(), Ok("hello world"));
+
+        let target = Context::default();
+        target.clipboard.set_backend(clipboard);
+        focused_input(&target, "> ");
+        let state = send(&input, &target, TextInputMsg::PasteFromClipboard);
+        assert_eq!(state.content, "> hello world");
+        assert_eq!(state.cursor_position, 13);
+    }
+
+    #[test]
+    fn test_paste_without_clipboard_leaves_input_unchanged() {
+        let input = TextInput::new();
+        let ctx = Context::default();
+        focused_input(&ctx, "abc");
+
+        let state = send(&input, &ctx, TextInputMsg::PasteFromClipboard);
+        assert_eq!(state.content, "abc");
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Time base shared by every context.
+///
+/// Follows the wall clock from app start, unless switched to stepped mode where
+/// time only moves when advanced by hand. Headless apps use stepped mode so
+/// timeouts are deterministic in tests.
+#[derive(Clone)]
+pub(crate) struct Clock {
+    start: Instant,
+    stepped: Arc<RwLock<Option<Duration>>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Clock {
+    /// Creates a clock following the wall clock from now
+    pub(crate) fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            stepped: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Stops following the wall clock; time stands still until [`Clock::advance`]
+    pub(crate) fn set_stepped(&self) {
+        let mut stepped = self.stepped.write().unwrap();
+        if stepped.is_none() {
+            *stepped = Some(self.start.elapsed());
+        }
+    }
+
+    /// Moves a stepped clock forward; has no effect on the wall clock
+    pub(crate) fn advance(&self, by: Duration) {
+        if let Some(elapsed) = self.stepped.write().unwrap().as_mut() {
+            *elapsed += by;
+        }
+    }
+
+    /// Returns the time since the app started
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.stepped
+            .read()
+            .unwrap()
+            .unwrap_or_else(|| self.start.elapsed())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
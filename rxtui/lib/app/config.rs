@@ -1,7 +1,56 @@
+use crate::app::Context;
+use crate::key::KeyWithModifiers;
+use crate::terminal::{DEFAULT_WRITE_BUFFER_SIZE, FlushStrategy};
+use crossterm::event::KeyEvent;
+use std::cell::RefCell;
+use std::rc::Rc;
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// Hook consulted before the app exits. Returning `false` vetoes the exit and
+/// keeps the event loop running.
+pub type ExitHook = Rc<dyn Fn(&Context) -> bool>;
+
+/// Width class of the terminal, for switching between stacked and
+/// side-by-side layouts. See [`Context::breakpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than [`Breakpoints::medium`]
+    Small,
+
+    /// At least [`Breakpoints::medium`] but narrower than [`Breakpoints::large`]
+    Medium,
+
+    /// At least [`Breakpoints::large`]
+    Large,
+}
+
+/// What the app does when stdout is not a terminal, e.g. when piped to a file.
+///
+/// Either way raw mode, the alternate screen and mouse capture are skipped, and
+/// frames are printed as plain text without escape sequences.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonTtyMode {
+    /// Print the first frame and exit
+    #[default]
+    SingleFrame,
+
+    /// Keep running, printing every frame that changed
+    EveryFrame,
+}
+
+/// Terminal widths, in columns, at which each breakpoint starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakpoints {
+    /// Width from which the terminal counts as medium (default: 80)
+    pub medium: u16,
+
+    /// Width from which the terminal counts as large (default: 120)
+    pub large: u16,
+}
+
 /// Configuration options for debugging and optimization control.
 #[derive(Clone)]
 pub struct RenderConfig {
@@ -17,12 +66,58 @@ pub struct RenderConfig {
     /// Event polling duration in milliseconds (default: 100ms)
     /// Lower values make the app more responsive but use more CPU
     pub poll_duration_ms: u64,
+
+    /// Maximum number of undo steps kept per component (default: 100)
+    pub history_limit: usize,
+
+    /// Keys that make the app exit, just like `Action::Exit` (default: none)
+    pub exit_keys: Vec<KeyWithModifiers>,
+
+    /// Hook that can veto exit requests, e.g. to confirm discarding unsaved changes
+    pub on_exit_request: Option<ExitHook>,
+
+    /// Width thresholds behind [`Context::breakpoint`]
+    pub breakpoints: Breakpoints,
+
+    /// Draw outlines, sizes and tinted padding over every element (default: false)
+    pub debug_layout: bool,
+
+    /// Log nodes that overflow a parent without `overflow` set to the debug log (default: false)
+    pub warn_overflow: bool,
+
+    /// When terminal output is flushed (default: once per frame)
+    pub flush_strategy: FlushStrategy,
+
+    /// Size in bytes of the terminal output buffer (default: 8 KiB)
+    pub write_buffer_size: usize,
+
+    /// What to do when stdout is not a terminal (default: print one frame and exit)
+    pub non_tty: NonTtyMode,
+
+    /// Suspend the app on Ctrl+Z on Unix, unless a component binds Ctrl+Z (default: true)
+    pub suspend_on_ctrl_z: bool,
+
+    /// Lay out sibling subtrees concurrently, given the `parallel` feature (default: false)
+    pub parallel_layout: bool,
 }
 
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
 
+impl Breakpoints {
+    /// Returns the breakpoint a terminal of the given width falls into.
+    pub fn breakpoint_for(&self, width: u16) -> Breakpoint {
+        if width >= self.large {
+            Breakpoint::Large
+        } else if width >= self.medium {
+            Breakpoint::Medium
+        } else {
+            Breakpoint::Small
+        }
+    }
+}
+
 impl RenderConfig {
     /// Creates a debug configuration with all optimizations disabled.
     pub fn debug() -> Self {
@@ -31,7 +126,37 @@ impl RenderConfig {
             terminal_optimizations: false,
             cell_diffing: false,
             poll_duration_ms: 50,
+            history_limit: 100,
+            exit_keys: Vec::new(),
+            on_exit_request: None,
+            breakpoints: Breakpoints::default(),
+            debug_layout: false,
+            warn_overflow: false,
+            flush_strategy: FlushStrategy::default(),
+            write_buffer_size: DEFAULT_WRITE_BUFFER_SIZE,
+            non_tty: NonTtyMode::default(),
+            suspend_on_ctrl_z: true,
+            parallel_layout: false,
+        }
+    }
+
+    /// Returns true if the key event matches one of the configured exit keys.
+    pub(crate) fn is_exit_key(&self, key_event: &KeyEvent) -> bool {
+        KeyWithModifiers::from_key_event(*key_event)
+            .is_some_and(|key| self.exit_keys.contains(&key))
+    }
+
+    /// Handles an exit request: stops the loop through `running` unless the exit
+    /// hook vetoes it. Returns whether the app is exiting.
+    pub(crate) fn request_exit(&self, context: &Context, running: &RefCell<bool>) -> bool {
+        let allowed = self
+            .on_exit_request
+            .as_ref()
+            .is_none_or(|hook| hook(context));
+        if allowed {
+            *running.borrow_mut() = false;
         }
+        allowed
     }
 }
 
@@ -39,6 +164,15 @@ impl RenderConfig {
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
 
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            medium: 80,
+            large: 120,
+        }
+    }
+}
+
 impl Default for RenderConfig {
     fn default() -> Self {
         Self {
@@ -46,6 +180,98 @@ impl Default for RenderConfig {
             terminal_optimizations: true,
             cell_diffing: true,
             poll_duration_ms: 50,
+            history_limit: 100,
+            exit_keys: Vec::new(),
+            on_exit_request: None,
+            breakpoints: Breakpoints::default(),
+            debug_layout: false,
+            warn_overflow: false,
+            flush_strategy: FlushStrategy::default(),
+            write_buffer_size: DEFAULT_WRITE_BUFFER_SIZE,
+            non_tty: NonTtyMode::default(),
+            suspend_on_ctrl_z: true,
+            parallel_layout: false,
         }
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key::Key;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use std::cell::Cell;
+
+    #[test]
+    fn test_default_breakpoints() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.breakpoint_for(40), Breakpoint::Small);
+        assert_eq!(breakpoints.breakpoint_for(79), Breakpoint::Small);
+        assert_eq!(breakpoints.breakpoint_for(80), Breakpoint::Medium);
+        assert_eq!(breakpoints.breakpoint_for(119), Breakpoint::Medium);
+        assert_eq!(breakpoints.breakpoint_for(120), Breakpoint::Large);
+    }
+
+    #[test]
+    fn test_context_uses_custom_breakpoints() {
+        let context = Context::default();
+        context.set_terminal_size(60, 20);
+        assert_eq!(context.breakpoint(), Breakpoint::Small);
+
+        *context.breakpoints.write().unwrap() = Breakpoints {
+            medium: 50,
+            large: 100,
+        };
+        assert_eq!(context.breakpoint(), Breakpoint::Medium);
+        context.set_terminal_size(100, 20);
+        assert_eq!(context.breakpoint(), Breakpoint::Large);
+    }
+
+    #[test]
+    fn test_custom_exit_key_triggers_shutdown() {
+        let config = RenderConfig {
+            exit_keys: vec![KeyWithModifiers::with_ctrl(Key::Char('q'))],
+            ..RenderConfig::default()
+        };
+        let running = RefCell::new(true);
+
+        let plain_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert!(!config.is_exit_key(&plain_q));
+        assert!(config.is_exit_key(&ctrl_q));
+
+        assert!(config.request_exit(&Context::default(), &running));
+        assert!(!*running.borrow());
+    }
+
+    #[test]
+    fn test_vetoing_hook_keeps_loop_running() {
+        let asked = Rc::new(Cell::new(0));
+        let confirmed = Rc::new(Cell::new(false));
+        let config = RenderConfig {
+            on_exit_request: Some(Rc::new({
+                let asked = asked.clone();
+                let confirmed = confirmed.clone();
+                move |_: &Context| {
+                    asked.set(asked.get() + 1);
+                    confirmed.get()
+                }
+            })),
+            ..RenderConfig::default()
+        };
+        let running = RefCell::new(true);
+        let ctx = Context::default();
+
+        assert!(!config.request_exit(&ctx, &running));
+        assert!(*running.borrow());
+
+        confirmed.set(true);
+        assert!(config.request_exit(&ctx, &running));
+        assert!(!*running.borrow());
+        assert_eq!(asked.get(), 2);
+    }
+}
@@ -1,10 +1,31 @@
+use crate::app::clipboard::{Clipboard, ClipboardError};
+use crate::app::clock::Clock;
+use crate::app::config::{Breakpoint, Breakpoints};
+#[cfg(feature = "serde")]
+use crate::app::persist::{Persistence, PersistentState};
+use crate::app::toast::{DEFAULT_TOAST_DURATION, Toast, ToastKind, ToastStore};
 use crate::component::{ComponentId, Message, State};
+use crate::components::router::{Route, RouterMsg};
+#[cfg(feature = "effects")]
+use crate::effect::{TaskHandle, TaskSpawner};
+use crate::theme::Theme;
 use std::any::TypeId;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{
     Arc, RwLock,
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
 };
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Starting value of the FNV-1a hash behind [`Context::component_uid`]
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Multiplier of the FNV-1a hash behind [`Context::component_uid`]
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -21,6 +42,7 @@ type TopicMessageQueueMap = Arc<RwLock<HashMap<String, VecDeque<Box<dyn Message>
 pub struct Dispatcher {
     queues: MessageQueueMap,
     topic_queues: TopicMessageQueueMap,
+    broadcasts: Arc<RwLock<VecDeque<Box<dyn Message>>>>,
 }
 
 /// State storage for components with interior mutability
@@ -29,6 +51,20 @@ pub struct StateMap {
     states: Arc<RwLock<HashMap<ComponentId, Box<dyn State>>>>,
 }
 
+/// Undo and redo stacks for a single component
+#[derive(Default)]
+struct HistoryStack {
+    undo: VecDeque<Box<dyn State>>,
+    redo: Vec<Box<dyn State>>,
+}
+
+/// Bounded undo/redo history for component states
+#[derive(Clone)]
+pub struct StateHistory {
+    stacks: Arc<RwLock<HashMap<ComponentId, HistoryStack>>>,
+    limit: Arc<AtomicUsize>,
+}
+
 /// Target for focus requests emitted during rendering
 #[derive(Clone)]
 pub(crate) enum FocusTarget {
@@ -52,6 +88,12 @@ pub struct TopicStore {
 
     /// Topic owners - first writer becomes owner
     owners: RwLock<HashMap<String, ComponentId>>,
+
+    /// Components subscribed to changes of each topic
+    subscribers: RwLock<HashMap<String, HashSet<ComponentId>>>,
+
+    /// Subscribers whose topics changed since the last render
+    dirty_subscribers: RwLock<HashSet<ComponentId>>,
 }
 
 /// Tracks component instances for effect management
@@ -76,6 +118,9 @@ pub struct Context {
     /// Topic states
     pub(crate) topics: Arc<TopicStore>,
 
+    /// Undo/redo history for component states
+    pub(crate) history: StateHistory,
+
     /// Message queues (shared with dispatcher)
     pub(crate) message_queues: MessageQueueMap,
 
@@ -94,8 +139,43 @@ pub struct Context {
     /// Components that have completed their first render pass
     pub(crate) rendered_components: Arc<RwLock<HashSet<ComponentId>>>,
 
+    /// Tree paths of the lazy divs that were on screen in the last frame
+    pub(crate) visible_lazy: Arc<RwLock<HashSet<ComponentId>>>,
+
     /// Whether the current component invocation is on its first render
     pub(crate) current_is_first_render: Arc<RwLock<bool>>,
+
+    /// Active color theme shared by all components
+    pub(crate) theme: Arc<RwLock<Theme>>,
+
+    /// Set when something outside the message flow needs the tree re-rendered
+    pub(crate) render_requested: Arc<AtomicBool>,
+
+    /// Clipboard backend used for copy and paste
+    pub(crate) clipboard: Clipboard,
+
+    /// Last known terminal size as (columns, rows)
+    pub(crate) terminal_size: Arc<RwLock<(u16, u16)>>,
+
+    /// Width thresholds used to classify the terminal size
+    pub(crate) breakpoints: Arc<RwLock<Breakpoints>>,
+
+    /// Time base for timeouts such as toast expiry
+    pub(crate) clock: Clock,
+
+    /// Number of frames drawn so far
+    pub(crate) frame_count: Arc<AtomicU64>,
+
+    /// Tasks started with `spawn`, cancelled when their component unmounts
+    #[cfg(feature = "effects")]
+    pub(crate) tasks: TaskSpawner,
+
+    /// Toasts shown over the application
+    pub(crate) toasts: Arc<ToastStore>,
+
+    /// Persisted state bindings and backing store
+    #[cfg(feature = "serde")]
+    pub(crate) persistence: Persistence,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -107,15 +187,18 @@ impl Dispatcher {
         Self {
             queues,
             topic_queues,
+            broadcasts: Arc::new(RwLock::new(VecDeque::new())),
         }
     }
 
     pub fn send_to_id(&self, component_id: ComponentId, message: impl Message) {
+        self.send_boxed_to_id(component_id, Box::new(message));
+    }
+
+    /// Queues an already boxed message for a component
+    pub(crate) fn send_boxed_to_id(&self, component_id: ComponentId, message: Box<dyn Message>) {
         let mut queues = self.queues.write().unwrap();
-        queues
-            .entry(component_id)
-            .or_default()
-            .push_back(Box::new(message));
+        queues.entry(component_id).or_default().push_back(message);
     }
 
     pub fn send_to_topic(&self, topic: String, message: impl Message) {
@@ -125,6 +208,38 @@ impl Dispatcher {
             .or_default()
             .push_back(Box::new(message));
     }
+
+    /// Queue a message for every mounted component, delivered on the next update cycle
+    pub fn broadcast(&self, message: impl Message) {
+        self.broadcasts
+            .write()
+            .unwrap()
+            .push_back(Box::new(message));
+    }
+
+    /// Copy pending broadcasts into the queue of each given component
+    pub(crate) fn deliver_broadcasts<'a>(
+        &self,
+        component_ids: impl IntoIterator<Item = &'a ComponentId>,
+    ) {
+        let broadcasts: Vec<_> = self.broadcasts.write().unwrap().drain(..).collect();
+        if broadcasts.is_empty() {
+            return;
+        }
+
+        let mut queues = self.queues.write().unwrap();
+        for component_id in component_ids {
+            let queue = queues.entry(component_id.clone()).or_default();
+            for msg in &broadcasts {
+                queue.push_back(Message::clone_box(msg.as_ref()));
+            }
+        }
+    }
+
+    /// Returns true if any broadcasts are waiting to be delivered
+    pub(crate) fn has_pending_broadcasts(&self) -> bool {
+        !self.broadcasts.read().unwrap().is_empty()
+    }
 }
 
 impl StateMap {
@@ -166,6 +281,119 @@ impl StateMap {
     pub fn remove(&self, component_id: &ComponentId) -> Option<Box<dyn State>> {
         self.states.write().unwrap().remove(component_id)
     }
+
+    /// Keeps only the states of the components matching the predicate
+    pub(crate) fn retain(&self, mut keep: impl FnMut(&ComponentId) -> bool) {
+        self.states.write().unwrap().retain(|id, _| keep(id));
+    }
+
+    /// Returns a clone of the stored state, if the component has one
+    pub(crate) fn get_boxed(&self, component_id: &ComponentId) -> Option<Box<dyn State>> {
+        self.states
+            .read()
+            .unwrap()
+            .get(component_id)
+            .map(|state| State::clone_box(state.as_ref()))
+    }
+
+    /// Returns true if the component has a state of type `T`
+    #[cfg(feature = "serde")]
+    pub(crate) fn contains<T: State + 'static>(&self, component_id: &ComponentId) -> bool {
+        self.states
+            .read()
+            .unwrap()
+            .get(component_id)
+            .is_some_and(|state| State::as_any(state.as_ref()).is::<T>())
+    }
+}
+
+impl StateHistory {
+    /// Default number of undo steps kept per component
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    /// Creates an empty history keeping up to `limit` undo steps per component
+    pub fn new(limit: usize) -> Self {
+        Self {
+            stacks: Arc::new(RwLock::new(HashMap::new())),
+            limit: Arc::new(AtomicUsize::new(limit)),
+        }
+    }
+
+    /// Sets the maximum number of undo steps kept per component
+    pub fn set_limit(&self, limit: usize) {
+        self.limit.store(limit, Ordering::SeqCst);
+        let mut stacks = self.stacks.write().unwrap();
+        for stack in stacks.values_mut() {
+            while stack.undo.len() > limit {
+                stack.undo.pop_front();
+            }
+        }
+    }
+
+    /// Records `previous` as an undo step and discards any redo steps
+    pub fn push(&self, component_id: &ComponentId, previous: Box<dyn State>) {
+        let limit = self.limit.load(Ordering::SeqCst);
+        let mut stacks = self.stacks.write().unwrap();
+        let stack = stacks.entry(component_id.clone()).or_default();
+
+        stack.redo.clear();
+        if limit == 0 {
+            return;
+        }
+        stack.undo.push_back(previous);
+        while stack.undo.len() > limit {
+            stack.undo.pop_front();
+        }
+    }
+
+    /// Pops the latest undo step, storing `current` so it can be redone
+    fn undo(
+        &self,
+        component_id: &ComponentId,
+        current: Option<Box<dyn State>>,
+    ) -> Option<Box<dyn State>> {
+        let mut stacks = self.stacks.write().unwrap();
+        let stack = stacks.get_mut(component_id)?;
+        let previous = stack.undo.pop_back()?;
+        stack.redo.extend(current);
+        Some(previous)
+    }
+
+    /// Pops the latest redo step, storing `current` so it can be undone again
+    fn redo(
+        &self,
+        component_id: &ComponentId,
+        current: Option<Box<dyn State>>,
+    ) -> Option<Box<dyn State>> {
+        let mut stacks = self.stacks.write().unwrap();
+        let stack = stacks.get_mut(component_id)?;
+        let next = stack.redo.pop()?;
+        stack.undo.extend(current);
+        Some(next)
+    }
+
+    /// Number of undo steps available for a component
+    pub fn undo_len(&self, component_id: &ComponentId) -> usize {
+        self.stacks
+            .read()
+            .unwrap()
+            .get(component_id)
+            .map_or(0, |stack| stack.undo.len())
+    }
+
+    /// Number of redo steps available for a component
+    pub fn redo_len(&self, component_id: &ComponentId) -> usize {
+        self.stacks
+            .read()
+            .unwrap()
+            .get(component_id)
+            .map_or(0, |stack| stack.redo.len())
+    }
+
+    /// Drops the history of a component
+    pub fn remove(&self, component_id: &ComponentId) {
+        self.stacks.write().unwrap().remove(component_id);
+    }
 }
 
 impl TopicStore {
@@ -173,6 +401,8 @@ impl TopicStore {
         Self {
             states: RwLock::new(HashMap::new()),
             owners: RwLock::new(HashMap::new()),
+            subscribers: RwLock::new(HashMap::new()),
+            dirty_subscribers: RwLock::new(HashSet::new()),
         }
     }
 
@@ -188,20 +418,53 @@ impl TopicStore {
         // Check if topic has an owner
         if let Some(owner) = owners.get(&topic) {
             // Only the owner can update the topic
-            if owner == &component_id {
-                states.insert(topic, state);
-                true
-            } else {
-                false
+            if owner != &component_id {
+                return false;
             }
         } else {
             // First writer becomes the owner
             owners.insert(topic.clone(), component_id);
-            states.insert(topic, state);
-            true
+        }
+
+        self.mark_subscribers_dirty(&topic);
+        states.insert(topic, state);
+        true
+    }
+
+    /// Register a component to be re-rendered whenever `topic` changes
+    pub(crate) fn subscribe(&self, topic: &str, component_id: ComponentId) {
+        self.subscribers
+            .write()
+            .unwrap()
+            .entry(topic.to_string())
+            .or_default()
+            .insert(component_id);
+    }
+
+    /// Remove a component from every topic it subscribed to
+    pub(crate) fn unsubscribe(&self, component_id: &ComponentId) {
+        self.subscribers.write().unwrap().retain(|_, subscribers| {
+            subscribers.remove(component_id);
+            !subscribers.is_empty()
+        });
+        self.dirty_subscribers.write().unwrap().remove(component_id);
+    }
+
+    /// Flag every subscriber of `topic` for re-rendering
+    fn mark_subscribers_dirty(&self, topic: &str) {
+        if let Some(subscribers) = self.subscribers.read().unwrap().get(topic) {
+            self.dirty_subscribers
+                .write()
+                .unwrap()
+                .extend(subscribers.iter().cloned());
         }
     }
 
+    /// Take the set of subscribers whose topics changed since the last call
+    pub(crate) fn take_dirty_subscribers(&self) -> HashSet<ComponentId> {
+        std::mem::take(&mut *self.dirty_subscribers.write().unwrap())
+    }
+
     /// Claim ownership of an unassigned topic
     pub(crate) fn claim_topic(&self, topic: String, component_id: ComponentId) -> bool {
         let mut owners = self.owners.write().unwrap();
@@ -296,13 +559,27 @@ impl Context {
             dispatch: Dispatcher::new(queues.clone(), topic_queues.clone()),
             states: StateMap::new(),
             topics: Arc::new(TopicStore::new()),
+            history: StateHistory::new(StateHistory::DEFAULT_LIMIT),
             message_queues: queues,
             topic_message_queues: topic_queues,
             effect_tracker: ComponentInstanceTracker::new(),
             pending_focus_requests: Arc::new(RwLock::new(Vec::new())),
             pending_focus_clear,
             rendered_components: Arc::new(RwLock::new(HashSet::new())),
+            visible_lazy: Arc::new(RwLock::new(HashSet::new())),
             current_is_first_render: Arc::new(RwLock::new(false)),
+            theme: Arc::new(RwLock::new(Theme::default())),
+            render_requested: Arc::new(AtomicBool::new(false)),
+            clipboard: Clipboard::default(),
+            terminal_size: Arc::new(RwLock::new((0, 0))),
+            breakpoints: Arc::new(RwLock::new(Breakpoints::default())),
+            clock: Clock::new(),
+            frame_count: Arc::new(AtomicU64::new(0)),
+            #[cfg(feature = "effects")]
+            tasks: TaskSpawner::default(),
+            toasts: Arc::new(ToastStore::default()),
+            #[cfg(feature = "serde")]
+            persistence: Persistence::default(),
         }
     }
 
@@ -311,6 +588,27 @@ impl Context {
         &self.current_component_id
     }
 
+    /// Returns a number unique to the current component, stable across renders
+    /// and runs as long as the component keeps its position in the tree.
+    pub fn component_uid(&self) -> u64 {
+        // FNV-1a, which unlike the std hasher is fixed across Rust releases
+        self.current_component_id
+            .0
+            .bytes()
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Returns a topic name scoped to the current component.
+    ///
+    /// Reusable components can use it so that several instances don't share
+    /// topics. The name embeds the component's position, so it never collides
+    /// with another component's.
+    pub fn unique_topic(&self, suffix: &str) -> String {
+        format!("{}@{}", suffix, self.current_component_id.0)
+    }
+
     /// Creates a message handler that captures the current component ID
     pub fn handler<T: Message + Clone + 'static>(&self, msg: T) -> Box<dyn Fn() + 'static> {
         let id = self.current_component_id.clone();
@@ -344,6 +642,68 @@ impl Context {
         self.states.insert(self.current_component_id.clone(), state);
     }
 
+    /// Get the state for the current component, restoring it from the app's state store.
+    ///
+    /// The first call binds the component to `key`: the saved value (or `Default`) seeds the
+    /// state, and later updates are written back under the same key.
+    #[cfg(feature = "serde")]
+    pub fn get_persistent_state<T: PersistentState + Default + Clone>(&self, key: &str) -> T {
+        let id = &self.current_component_id;
+        if !self.states.contains::<T>(id)
+            && let Some(saved) = self.persistence.bind::<T>(id, key)
+        {
+            self.states.insert(id.clone(), Box::new(saved));
+        }
+        self.states.get_or_init::<T>(id)
+    }
+
+    /// Read state from a topic and re-render the current component whenever it changes.
+    ///
+    /// Unlike `read_topic`, the component does not need a message to observe updates
+    /// made by the topic owner.
+    pub fn subscribe_topic<T: State + Clone + 'static>(&self, topic: &str) -> Option<T> {
+        self.topics
+            .subscribe(topic, self.current_component_id.clone());
+        self.topics.read_topic(topic)
+    }
+
+    /// Restore the current component's previous state.
+    ///
+    /// Only states set through `Action::update_with_history` are recorded.
+    /// Returns false if there is nothing to undo.
+    pub fn undo(&self) -> bool {
+        let id = &self.current_component_id;
+        match self.history.undo(id, self.states.get_boxed(id)) {
+            Some(previous) => {
+                self.states.insert(id.clone(), previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapply the state most recently reverted by `undo`.
+    ///
+    /// Returns false if there is nothing to redo.
+    pub fn redo(&self) -> bool {
+        let id = &self.current_component_id;
+        match self.history.redo(id, self.states.get_boxed(id)) {
+            Some(next) => {
+                self.states.insert(id.clone(), next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record the current component's state as an undo step before it is replaced
+    pub(crate) fn record_history(&self) {
+        let id = &self.current_component_id;
+        if let Some(previous) = self.states.get_boxed(id) {
+            self.history.push(id, previous);
+        }
+    }
+
     /// Read state from a topic
     pub fn read_topic<T: State + Clone + 'static>(&self, topic: &str) -> Option<T> {
         self.topics.read_topic(topic)
@@ -360,6 +720,26 @@ impl Context {
         self.dispatch.send_to_id(component_id, message);
     }
 
+    /// Send a message to every mounted component
+    pub fn broadcast(&self, message: impl Message) {
+        self.dispatch.broadcast(message);
+    }
+
+    /// Show a route in the app's [`Router`](crate::components::Router)
+    pub fn navigate_to(&self, route: impl Into<Route>) {
+        self.broadcast(RouterMsg::Navigate(route.into()));
+    }
+
+    /// Return to the previous route in the router's history
+    pub fn navigate_back(&self) {
+        self.broadcast(RouterMsg::Back);
+    }
+
+    /// Move forward to the route left with [`Context::navigate_back`]
+    pub fn navigate_forward(&self) {
+        self.broadcast(RouterMsg::Forward);
+    }
+
     /// Send a message to a topic owner
     pub fn send_to_topic(&self, topic: impl Into<String>, message: impl Message) {
         self.dispatch.send_to_topic(topic.into(), message);
@@ -401,16 +781,191 @@ impl Context {
         Self {
             current_component_id: self.current_component_id.child(index),
             dispatch: self.dispatch.clone(),
-            states: self.states.clone(), // Share the state map
-            topics: self.topics.clone(), // Share the topic store
+            states: self.states.clone(),   // Share the state map
+            topics: self.topics.clone(),   // Share the topic store
+            history: self.history.clone(), // Share the undo/redo history
             message_queues: self.message_queues.clone(), // Share the message queues
             topic_message_queues: self.topic_message_queues.clone(), // Share the topic message queues
             effect_tracker: self.effect_tracker.clone(),             // Share the effect tracker
             pending_focus_requests: self.pending_focus_requests.clone(),
             pending_focus_clear: self.pending_focus_clear.clone(),
             rendered_components: self.rendered_components.clone(),
+            visible_lazy: self.visible_lazy.clone(),
             current_is_first_render: self.current_is_first_render.clone(),
+            theme: self.theme.clone(),
+            render_requested: self.render_requested.clone(),
+            clipboard: self.clipboard.clone(),
+            terminal_size: self.terminal_size.clone(),
+            breakpoints: self.breakpoints.clone(),
+            clock: self.clock.clone(),
+            frame_count: self.frame_count.clone(),
+            #[cfg(feature = "effects")]
+            tasks: self.tasks.clone(),
+            toasts: self.toasts.clone(),
+            #[cfg(feature = "serde")]
+            persistence: self.persistence.clone(),
+        }
+    }
+
+    /// Returns the active color theme
+    pub fn theme(&self) -> Theme {
+        self.theme.read().unwrap().clone()
+    }
+
+    /// Replaces the active theme and re-renders the whole tree
+    pub fn set_theme(&self, theme: Theme) {
+        *self.theme.write().unwrap() = theme;
+        self.render_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Switches between the built-in dark and light themes
+    pub fn toggle_theme(&self) {
+        let mode = self.theme.read().unwrap().mode;
+        self.set_theme(Theme::for_mode(mode.toggled()));
+    }
+
+    /// Returns the last known terminal size as (columns, rows), kept up to date
+    /// as the terminal is resized
+    pub fn terminal_size(&self) -> (u16, u16) {
+        *self.terminal_size.read().unwrap()
+    }
+
+    /// Returns the number of frames drawn so far. Time-based animations should
+    /// follow [`Context::elapsed`] instead, as the frame rate varies
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count.load(Ordering::SeqCst)
+    }
+
+    /// Returns the time since the app started. Headless apps only see time
+    /// move when [`Runner::advance_clock`](crate::app::Runner::advance_clock) is called.
+    pub fn elapsed(&self) -> Duration {
+        self.clock.elapsed()
+    }
+
+    /// Returns the breakpoint the current terminal width falls into, using the
+    /// thresholds set with [`App::breakpoints`](crate::App::breakpoints)
+    pub fn breakpoint(&self) -> Breakpoint {
+        let (width, _) = self.terminal_size();
+        self.breakpoints.read().unwrap().breakpoint_for(width)
+    }
+
+    /// Records the terminal size reported by the app
+    pub(crate) fn set_terminal_size(&self, width: u16, height: u16) {
+        *self.terminal_size.write().unwrap() = (width, height);
+    }
+
+    /// Shows `message` as a toast in the top-right corner for the default
+    /// duration of three seconds
+    pub fn toast(&self, message: impl Into<String>, kind: ToastKind) {
+        self.toast_for(message, kind, DEFAULT_TOAST_DURATION);
+    }
+
+    /// Shows `message` as a toast that disappears after `duration`.
+    ///
+    /// Toasts stack below the ones already shown and expire independently.
+    pub fn toast_for(&self, message: impl Into<String>, kind: ToastKind, duration: Duration) {
+        self.toasts.push(Toast {
+            message: message.into(),
+            kind,
+            expires_at: self.clock.elapsed() + duration,
+        });
+        self.render_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Copies `text` to the clipboard.
+    ///
+    /// Returns `ClipboardError::Unavailable` when the `clipboard` feature is off or
+    /// the session has no clipboard (headless, SSH), so callers can fall back.
+    pub fn copy_to_clipboard(&self, text: &str) -> Result<(), ClipboardError> {
+        self.clipboard.set_text(text)
+    }
+
+    /// Reads text from the clipboard.
+    ///
+    /// Fails the same way as [`copy_to_clipboard`](Self::copy_to_clipboard) when no
+    /// clipboard is available.
+    pub fn read_clipboard(&self) -> Result<String, ClipboardError> {
+        self.clipboard.get_text()
+    }
+
+    /// Runs the views again on the next frame, without a message or state change.
+    ///
+    /// For views that read data kept outside component state, such as a shared
+    /// atomic an effect polls, so no dummy message is needed to show a new value.
+    pub fn request_render(&self) {
+        self.render_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Runs a future on the effects runtime and returns a handle to its result.
+    ///
+    /// Unlike effects, a task can be started from any update and hands its
+    /// output back instead of sending a message. The view re-renders when the
+    /// task finishes, and the task is cancelled when the component unmounts.
+    ///
+    /// ```rust,ignore
+    /// let task = ctx.spawn(async { fetch_report().await });
+    /// // later, in view or update
+    /// if let Some(report) = task.take() { ... }
+    /// ```
+    #[cfg(feature = "effects")]
+    pub fn spawn<T, F>(&self, future: F) -> TaskHandle<T>
+    where
+        T: Send + 'static,
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        let render_requested = self.render_requested.clone();
+        self.tasks
+            .spawn(self.current_component_id.clone(), future, move || {
+                render_requested.store(true, Ordering::SeqCst);
+            })
+    }
+
+    /// Like [`Context::spawn`], but at most `max_concurrent` tasks spawned with
+    /// the same `key` run at once; the others queue until one finishes. The
+    /// key is shared by all components, so it can cap requests to one server.
+    ///
+    /// ```rust,ignore
+    /// let thumbnail = ctx.spawn_limited("thumbnails", 4, fetch_thumbnail(url));
+    /// ```
+    #[cfg(feature = "effects")]
+    pub fn spawn_limited<T, F>(
+        &self,
+        key: impl Into<String>,
+        max_concurrent: usize,
+        future: F,
+    ) -> TaskHandle<T>
+    where
+        T: Send + 'static,
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        let render_requested = self.render_requested.clone();
+        self.tasks.spawn_limited(
+            self.current_component_id.clone(),
+            key.into(),
+            max_concurrent,
+            future,
+            move || render_requested.store(true, Ordering::SeqCst),
+        )
+    }
+
+    /// Returns true if a re-render was requested and resets the flag.
+    pub(crate) fn take_render_request(&self) -> bool {
+        self.render_requested.swap(false, Ordering::SeqCst)
+    }
+
+    /// Returns true if the lazy div at `path` was on screen in the last frame
+    pub(crate) fn is_lazy_visible(&self, path: &ComponentId) -> bool {
+        self.visible_lazy.read().unwrap().contains(path)
+    }
+
+    /// Records the lazy divs on screen, returning true if the set changed
+    pub(crate) fn set_visible_lazy(&self, paths: HashSet<ComponentId>) -> bool {
+        let mut visible = self.visible_lazy.write().unwrap();
+        if *visible == paths {
+            return false;
         }
+        *visible = paths;
+        true
     }
 
     /// Request focus for the first focusable element inside the current component
@@ -568,7 +1123,7 @@ impl Context {
             }
         }
 
-        false
+        self.dispatch.has_pending_broadcasts()
     }
 }
 
@@ -593,3 +1148,145 @@ impl Default for Context {
         Self::new(Arc::new(AtomicBool::new(false)))
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Doc {
+        text: String,
+    }
+
+    fn doc(text: &str) -> Doc {
+        Doc {
+            text: text.to_string(),
+        }
+    }
+
+    /// Mirrors how the app applies `Action::UpdateWithHistory`
+    fn update_with_history(ctx: &Context, state: Doc) {
+        ctx.record_history();
+        ctx.set_state(Box::new(state));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Theme(&'static str);
+
+    #[test]
+    fn test_topic_update_marks_subscribers_dirty() {
+        let ctx = Context::default();
+        let owner = ctx.child(0);
+        let subscriber = ctx.child(1);
+        let bystander = ctx.child(2);
+
+        assert_eq!(subscriber.subscribe_topic::<Theme>("theme"), None);
+        assert!(ctx.topics.take_dirty_subscribers().is_empty());
+
+        owner
+            .topics
+            .update_topic("theme".into(), Box::new(Theme("dark")), owner.id().clone());
+
+        let dirty = ctx.topics.take_dirty_subscribers();
+        assert!(dirty.contains(subscriber.id()));
+        assert!(!dirty.contains(bystander.id()));
+        assert_eq!(
+            subscriber.subscribe_topic::<Theme>("theme"),
+            Some(Theme("dark"))
+        );
+
+        // Rejected writes from non-owners do not trigger a re-render
+        bystander.topics.update_topic(
+            "theme".into(),
+            Box::new(Theme("light")),
+            bystander.id().clone(),
+        );
+        assert!(ctx.topics.take_dirty_subscribers().is_empty());
+    }
+
+    #[test]
+    fn test_unsubscribed_component_is_not_marked_dirty() {
+        let ctx = Context::default();
+        let owner = ctx.child(0);
+        let subscriber = ctx.child(1);
+
+        subscriber.subscribe_topic::<Theme>("theme");
+        ctx.topics.unsubscribe(subscriber.id());
+        assert!(ctx.topics.subscribers.read().unwrap().is_empty());
+
+        owner
+            .topics
+            .update_topic("theme".into(), Box::new(Theme("dark")), owner.id().clone());
+        assert!(ctx.topics.take_dirty_subscribers().is_empty());
+    }
+
+    #[test]
+    fn test_undo_restores_previous_state() {
+        let ctx = Context::default();
+        ctx.set_state(Box::new(doc("a")));
+        update_with_history(&ctx, doc("ab"));
+
+        assert!(ctx.undo());
+        assert_eq!(ctx.get_state::<Doc>(), doc("a"));
+        assert!(!ctx.undo());
+    }
+
+    #[test]
+    fn test_redo_reapplies_undone_state() {
+        let ctx = Context::default();
+        ctx.set_state(Box::new(doc("a")));
+        update_with_history(&ctx, doc("ab"));
+        update_with_history(&ctx, doc("abc"));
+
+        assert!(ctx.undo());
+        assert!(ctx.undo());
+        assert!(ctx.redo());
+        assert_eq!(ctx.get_state::<Doc>(), doc("ab"));
+        assert!(ctx.redo());
+        assert_eq!(ctx.get_state::<Doc>(), doc("abc"));
+        assert!(!ctx.redo());
+    }
+
+    #[test]
+    fn test_new_update_discards_redo() {
+        let ctx = Context::default();
+        ctx.set_state(Box::new(doc("a")));
+        update_with_history(&ctx, doc("ab"));
+        ctx.undo();
+        update_with_history(&ctx, doc("ax"));
+
+        assert!(!ctx.redo());
+        assert_eq!(ctx.history.undo_len(ctx.id()), 1);
+    }
+
+    #[test]
+    fn test_history_respects_limit() {
+        let ctx = Context::default();
+        ctx.history.set_limit(2);
+        ctx.set_state(Box::new(doc("0")));
+        for text in ["1", "2", "3", "4"] {
+            update_with_history(&ctx, doc(text));
+        }
+
+        assert_eq!(ctx.history.undo_len(ctx.id()), 2);
+        assert!(ctx.undo());
+        assert!(ctx.undo());
+        assert!(!ctx.undo());
+        assert_eq!(ctx.get_state::<Doc>(), doc("2"));
+    }
+
+    #[test]
+    fn test_history_is_per_component() {
+        let ctx = Context::default();
+        let child = ctx.child(0);
+        ctx.set_state(Box::new(doc("parent")));
+        update_with_history(&ctx, doc("parent2"));
+
+        assert!(!child.undo());
+        assert_eq!(ctx.history.undo_len(ctx.id()), 1);
+    }
+}
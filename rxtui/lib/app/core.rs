@@ -1,28 +1,46 @@
 use crate::app::Context;
+use crate::backend::{Backend, CrosstermBackend, PlainTextBackend};
 use crate::bounds::Rect;
-use crate::buffer::{DoubleBuffer, ScreenBuffer};
+use crate::buffer::{CellUpdate, DoubleBuffer, ScreenBuffer};
 use crate::component::{Action, Component, ComponentId};
+use crate::components::ErrorBoundary;
+use crate::key::KeyWithModifiers;
 use crate::node::Div;
-use crate::node::Node;
-use crate::terminal::TerminalRenderer;
+use crate::node::{Node, flatten_fragments};
+use crate::render_tree::LayoutMode;
+use crate::terminal::{self as term, FlushStrategy, FrameStats};
+use crate::theme::{Theme, ThemeMode};
 use crate::vdom::VDom;
 use crate::vnode::VNode;
+#[cfg(unix)]
+use crossterm::event::KeyEvent;
 use crossterm::{
-    ExecutableCommand, cursor,
-    event::{self, Event},
+    cursor,
+    event::Event,
     execute,
     style::{Print, ResetColor, SetBackgroundColor, SetForegroundColor},
     terminal,
 };
-use std::cell::RefCell;
-use std::io;
+use std::cell::{Cell, RefCell};
+use std::io::{self, IsTerminal, Write};
 use std::rc::Rc;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 
-use super::config::RenderConfig;
+#[cfg(feature = "clipboard")]
+use super::clipboard::SystemClipboard;
+use super::clipboard::{ClipboardBackend, MemoryClipboard};
+use super::config::{Breakpoints, NonTtyMode, RenderConfig};
 use super::context::{FocusRequest, FocusTarget};
-use super::events::{handle_key_event, handle_mouse_event};
-use super::renderer::render_node_to_buffer;
+use super::events::{
+    handle_expired_key_sequence, handle_key_event, handle_mouse_event, handle_paste_event,
+};
+use super::panic::{PanicHookGuard, catch_panic};
+use super::renderer::{render_layout_debug, render_node_to_buffer_at};
+use super::runner::{LoopState, Runner};
+#[cfg(unix)]
+use super::suspend;
 use std::collections::HashMap;
 #[cfg(feature = "effects")]
 use std::collections::HashSet;
@@ -30,6 +48,22 @@ use std::collections::HashSet;
 #[cfg(feature = "effects")]
 use crate::effect::EffectRuntime;
 
+#[cfg(feature = "serde")]
+use super::persist::{FileStateStore, StateStore};
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Screen size assumed when stdout is not a terminal and the size is unknown
+const NON_TTY_SIZE: (u16, u16) = (80, 24);
+
+/// Environment variable naming a file to write the render tree JSON to after each frame
+#[cfg(feature = "serde")]
+const DUMP_TREE_ENV: &str = "RXTUI_DUMP_TREE";
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -37,6 +71,18 @@ use crate::effect::EffectRuntime;
 /// Type alias for the render log callback function.
 type RenderLogFn = Box<dyn Fn(&str)>;
 
+/// A component together with its position in the tree.
+type MountedComponent = (ComponentId, Arc<dyn Component>);
+
+/// A screen change requested by a component's update.
+enum Navigation {
+    /// Show a new root component over the current one
+    Push(Box<dyn Component>),
+
+    /// Return to the previous root component
+    Back,
+}
+
 /// Signal to indicate that the application should exit.
 /// Used to propagate exit requests through the component tree.
 pub struct ExitSignal;
@@ -88,6 +134,9 @@ pub struct App {
     /// Virtual DOM instance that manages the UI tree
     vdom: VDom,
 
+    /// Virtual DOM for the toasts drawn over the UI tree
+    overlay: VDom,
+
     /// Shared flag to control the application lifecycle
     running: Rc<RefCell<bool>>,
 
@@ -100,8 +149,8 @@ pub struct App {
     /// Optional function to call after each render for logging
     render_log_fn: Option<RenderLogFn>,
 
-    /// Terminal renderer for optimized output
-    terminal_renderer: TerminalRenderer,
+    /// Terminal the frames are drawn to and input is read from, unless headless
+    terminal: Option<CrosstermBackend>,
 
     /// Rendering configuration for debugging and optimization control
     config: RenderConfig,
@@ -109,6 +158,48 @@ pub struct App {
     /// Effect runtime for managing async tasks
     #[cfg(feature = "effects")]
     effect_runtime: Option<EffectRuntime>,
+
+    /// Color theme installed into the context when the app starts
+    theme: Option<Theme>,
+
+    /// Whether to ask the terminal for its background color when no theme is set
+    detect_theme: bool,
+
+    /// Store used to load and save persistent component state
+    #[cfg(feature = "serde")]
+    state_store: Option<Arc<dyn StateStore>>,
+
+    /// Clipboard backend installed into the context when the app starts
+    clipboard: Option<Arc<dyn ClipboardBackend>>,
+
+    /// Whether the app renders into memory only, without a terminal
+    headless: bool,
+
+    /// Custom surface the frames are drawn to instead of the terminal
+    backend: Option<Box<dyn Backend>>,
+
+    /// File the render tree is written to as JSON after each frame, from `RXTUI_DUMP_TREE`
+    #[cfg(feature = "serde")]
+    dump_tree_path: Option<std::path::PathBuf>,
+
+    /// Number of frames drawn so far
+    frame: u64,
+
+    /// Context clock reading for the frame being drawn, which drives text animations
+    elapsed: Duration,
+
+    /// Whether the next frame redraws every cell, as a caught panic may have been
+    /// reported over the screen
+    repaint: Cell<bool>,
+
+    /// Whether the rendered tree has animated text that must be redrawn every frame
+    animating: bool,
+
+    /// Screen changes requested during the current expansion, applied once it ends
+    navigation: RefCell<Vec<Navigation>>,
+
+    /// Whether frames are printed as plain text because stdout is not a terminal
+    non_tty: bool,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -125,8 +216,17 @@ impl App {
     /// - Enabling mouse capture for click events
     ///
     /// The terminal state is automatically restored when the app is dropped.
+    ///
+    /// When stdout is not a terminal, none of this happens and frames are printed
+    /// as plain text instead, as configured by [`RenderConfig::non_tty`].
     pub fn new() -> io::Result<Self> {
+        if !io::stdout().is_terminal() {
+            let (width, height) = terminal::size().unwrap_or(NON_TTY_SIZE);
+            return Self::with_plain_output(io::stdout(), width, height);
+        }
+
         terminal::enable_raw_mode()?;
+
         let mut stdout = io::stdout();
 
         // Try to enable keyboard enhancement for better modifier support
@@ -138,9 +238,7 @@ impl App {
         //         | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES,
         // ));
 
-        stdout.execute(terminal::EnterAlternateScreen)?;
-        stdout.execute(cursor::Hide)?;
-        stdout.execute(event::EnableMouseCapture)?;
+        term::init_terminal(&mut stdout)?;
 
         let running = Rc::new(RefCell::new(true));
         let needs_render = Rc::new(RefCell::new(true));
@@ -154,17 +252,100 @@ impl App {
 
         Ok(Self {
             vdom: VDom::new(),
+            overlay: VDom::new(),
             running,
             needs_render,
             double_buffer: DoubleBuffer::new(width, height),
             render_log_fn: None,
-            terminal_renderer: TerminalRenderer::new(),
+            terminal: Some(CrosstermBackend::new()),
             config: RenderConfig::default(),
             #[cfg(feature = "effects")]
             effect_runtime,
+            theme: None,
+            detect_theme: true,
+            #[cfg(feature = "serde")]
+            state_store: None,
+            #[cfg(feature = "clipboard")]
+            clipboard: Some(Arc::new(SystemClipboard::new())),
+            #[cfg(not(feature = "clipboard"))]
+            clipboard: None,
+            headless: false,
+            backend: None,
+            #[cfg(feature = "serde")]
+            dump_tree_path: std::env::var_os(DUMP_TREE_ENV).map(Into::into),
+            frame: 0,
+            elapsed: Duration::ZERO,
+            repaint: Cell::new(false),
+            animating: false,
+            navigation: RefCell::new(Vec::new()),
+            non_tty: false,
         })
     }
 
+    /// Creates an application that renders into an in-memory screen of the given
+    /// size instead of the terminal.
+    ///
+    /// The terminal is left untouched: raw mode is not enabled, no terminal events
+    /// are read and nothing is written to stdout. Drive it through
+    /// [`App::into_runner`] and inspect the frames with [`Runner::screen_lines`].
+    pub fn headless(width: u16, height: u16) -> Self {
+        Self {
+            vdom: VDom::new(),
+            overlay: VDom::new(),
+            running: Rc::new(RefCell::new(true)),
+            needs_render: Rc::new(RefCell::new(true)),
+            double_buffer: DoubleBuffer::new(width, height),
+            render_log_fn: None,
+            terminal: None,
+            config: RenderConfig::default(),
+            #[cfg(feature = "effects")]
+            effect_runtime: Some(EffectRuntime::new()),
+            theme: None,
+            detect_theme: false,
+            #[cfg(feature = "serde")]
+            state_store: None,
+            clipboard: Some(Arc::new(MemoryClipboard::new())),
+            headless: true,
+            backend: None,
+            #[cfg(feature = "serde")]
+            dump_tree_path: None,
+            frame: 0,
+            elapsed: Duration::ZERO,
+            repaint: Cell::new(false),
+            animating: false,
+            navigation: RefCell::new(Vec::new()),
+            non_tty: false,
+        }
+    }
+
+    /// Creates an application that draws its frames to `backend` instead of the
+    /// terminal.
+    ///
+    /// As with [`App::headless`], the terminal is left untouched; input is read
+    /// from the backend's [`poll_event`](Backend::poll_event) instead. The screen
+    /// takes the backend's size, which is checked again before each frame.
+    pub fn with_backend(backend: impl Backend + 'static) -> io::Result<Self> {
+        let (width, height) = backend.size()?;
+        let mut app = Self::headless(width, height);
+        app.backend = Some(Box::new(backend));
+        Ok(app)
+    }
+
+    /// Creates an application that prints its frames to `writer` as plain text,
+    /// as [`App::new`] does when stdout is not a terminal.
+    ///
+    /// By default the first frame is printed and [`App::run`] returns; see
+    /// [`RenderConfig::non_tty`] to keep printing frames instead.
+    pub fn with_plain_output(
+        writer: impl Write + 'static,
+        width: u16,
+        height: u16,
+    ) -> io::Result<Self> {
+        let mut app = Self::with_backend(PlainTextBackend::new(writer, width, height))?;
+        app.non_tty = true;
+        Ok(app)
+    }
+
     /// Runs the application with a component instance.
     ///
     /// This uses the component system that provides:
@@ -179,11 +360,17 @@ impl App {
     /// app.run(root)?;
     /// ```
     ///
-    /// This method blocks until the application exits.
+    /// This method blocks until the application exits. While it runs, a panic
+    /// restores the terminal before the panic message is printed.
     pub fn run<C>(&mut self, root_component: C) -> io::Result<()>
     where
         C: Component,
     {
+        let _panic_hook = (!self.headless).then(|| {
+            PanicHookGuard::install(|| {
+                let _ = term::restore_terminal(&mut io::stdout(), terminal::disable_raw_mode);
+            })
+        });
         self.run_loop(root_component)
     }
 
@@ -200,6 +387,22 @@ impl App {
         self
     }
 
+    /// Sets when terminal output is flushed.
+    ///
+    /// `FlushStrategy::Immediate` shows partial frames sooner over slow links such
+    /// as SSH, at the cost of more writes.
+    pub fn flush_strategy(mut self, strategy: FlushStrategy) -> Self {
+        self.config.flush_strategy = strategy;
+        self
+    }
+
+    /// Sets the size in bytes of the terminal output buffer.
+    /// Default is 8 KiB.
+    pub fn write_buffer_size(mut self, size: usize) -> Self {
+        self.config.write_buffer_size = size;
+        self
+    }
+
     /// Disables double buffering, causing direct terminal rendering.
     /// Warning: This may cause visible flicker during updates.
     pub fn disable_double_buffering(mut self) -> Self {
@@ -243,6 +446,95 @@ impl App {
         self
     }
 
+    /// Sets the color theme available to components through `Context::theme`.
+    ///
+    /// Without this, a dark or light theme is picked from the terminal background,
+    /// which delays startup by up to 100ms on terminals that don't answer.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Persists component state to a JSON file at `path`.
+    ///
+    /// States obtained through `Context::get_persistent_state` are restored from
+    /// the file on startup and written back when the app exits.
+    #[cfg(feature = "serde")]
+    pub fn with_state_store(self, path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(self.state_store(FileStateStore::open(path)?))
+    }
+
+    /// Persists component state using a custom store.
+    #[cfg(feature = "serde")]
+    pub fn state_store(mut self, store: impl StateStore + 'static) -> Self {
+        self.state_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Sets the clipboard used by `Context::copy_to_clipboard` and `Context::read_clipboard`.
+    ///
+    /// With the `clipboard` feature the system clipboard is used by default.
+    pub fn clipboard(mut self, backend: impl ClipboardBackend + 'static) -> Self {
+        self.clipboard = Some(Arc::new(backend));
+        self
+    }
+
+    /// Sets how many undo steps are kept per component.
+    /// Default is 100.
+    pub fn history_limit(mut self, limit: usize) -> Self {
+        self.config.history_limit = limit;
+        self
+    }
+
+    /// Sets the keys that make the app exit, as if a component returned `Action::Exit`.
+    pub fn exit_keys(mut self, keys: impl IntoIterator<Item = KeyWithModifiers>) -> Self {
+        self.config.exit_keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Sets a hook consulted before the app exits, whether through `Action::Exit`
+    /// or an exit key. Returning `false` vetoes the exit and keeps the app running,
+    /// e.g. to ask for confirmation when there are unsaved changes.
+    pub fn on_exit_request(mut self, hook: impl Fn(&Context) -> bool + 'static) -> Self {
+        self.config.on_exit_request = Some(Rc::new(hook));
+        self
+    }
+
+    /// Sets the width thresholds behind [`Context::breakpoint`].
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.config.breakpoints = breakpoints;
+        self
+    }
+
+    /// Sets whether Ctrl+Z suspends the app on Unix (default: true).
+    ///
+    /// Components that bind Ctrl+Z themselves, e.g. for undo, receive it either way.
+    pub fn suspend_on_ctrl_z(mut self, enabled: bool) -> Self {
+        self.config.suspend_on_ctrl_z = enabled;
+        self
+    }
+
+    /// Turns the app into a [`Runner`] that is driven step by step instead of
+    /// blocking in [`App::run`].
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let mut runner = App::new()?.into_runner(MyRootComponent::default());
+    /// while runner.is_running() {
+    ///     runner.process_message_queue();
+    ///     runner.render_frame()?;
+    ///     runner.poll_event(Duration::from_millis(10))?;
+    ///     // ... service other event sources here
+    /// }
+    /// ```
+    pub fn into_runner<C>(mut self, root_component: C) -> Runner
+    where
+        C: Component,
+    {
+        let state = self.start(root_component);
+        Runner::new(self, state)
+    }
+
     /// Main event loop using component-based architecture.
     ///
     /// Manages component state through messages and actions,
@@ -254,43 +546,240 @@ impl App {
     /// 3. External events trigger render
     /// 4. Terminal is resized
     fn run_loop<C>(&mut self, root_component: C) -> io::Result<()>
+    where
+        C: Component,
+    {
+        let mut state = self.start(root_component);
+        let poll_duration = Duration::from_millis(self.config.poll_duration_ms);
+
+        while self.is_running() {
+            self.process_message_queue(&mut state);
+            if !self.is_running() {
+                break;
+            }
+
+            let drawn = self.render_frame(&mut state)?;
+            if self.non_tty {
+                // There is no input to wait for, so pace the loop by hand
+                match self.config.non_tty {
+                    NonTtyMode::SingleFrame if drawn => break,
+                    _ => std::thread::sleep(poll_duration),
+                }
+                continue;
+            }
+            self.poll_event(&mut state, poll_duration)?;
+        }
+
+        state.finish()
+    }
+
+    /// Picks the theme mode from the terminal background.
+    ///
+    /// Only asks the terminal when no theme was set, since the reply can take up to
+    /// the timeout to arrive. Terminals that don't answer get the dark theme.
+    fn detect_theme_mode(&self) -> ThemeMode {
+        let response = self
+            .detect_theme
+            .then(|| term::query_background_color(std::time::Duration::from_millis(100)))
+            .flatten();
+        ThemeMode::detect(response.as_deref())
+    }
+
+    /// Sets up the context and mounts the root component, spawning its effects.
+    fn start<C>(&mut self, root_component: C) -> LoopState
     where
         C: Component,
     {
         let focus_clear_flag = self.vdom.focus_clear_flag();
-        let mut context = Context::new(focus_clear_flag);
+        let context = Context::new(focus_clear_flag);
+        context.history.set_limit(self.config.history_limit);
+        self.vdom.set_layout_mode(if self.config.parallel_layout {
+            LayoutMode::Parallel
+        } else {
+            LayoutMode::Serial
+        });
+        *context.theme.write().unwrap() = self
+            .theme
+            .clone()
+            .unwrap_or_else(|| Theme::for_mode(self.detect_theme_mode()));
+        #[cfg(feature = "serde")]
+        if let Some(store) = &self.state_store {
+            context.persistence.set_store(store.clone());
+        }
+        if let Some(backend) = &self.clipboard {
+            context.clipboard.set_backend(backend.clone());
+        }
+        if let Ok((width, height)) = self.screen_size() {
+            context.set_terminal_size(width, height);
+        }
+        *context.breakpoints.write().unwrap() = self.config.breakpoints;
+        #[cfg(feature = "effects")]
+        if let Some(runtime) = &self.effect_runtime {
+            context.tasks.set_handle(runtime.handle().clone());
+        }
+        if let Some(terminal) = &mut self.terminal {
+            terminal.set_optimized(self.config.terminal_optimizations);
+            let renderer = terminal.renderer_mut();
+            renderer.set_flush_strategy(self.config.flush_strategy);
+            // Nothing has been written yet, so there is nothing to lose if flushing fails
+            let _ = renderer.set_write_buffer_size(self.config.write_buffer_size);
+        }
+        // Custom backends keep real time; only in-memory apps step the clock
+        if self.headless && self.backend.is_none() {
+            context.clock.set_stepped();
+        }
         let mut components: HashMap<ComponentId, Arc<dyn Component>> = HashMap::new();
 
         // Store the root component
         let root_id = ComponentId::default();
         let root_arc = Arc::new(root_component) as Arc<dyn Component>;
-        let root_type_id = root_arc.type_id();
         components.insert(root_id.clone(), root_arc.clone());
 
-        let mut needs_render = true; // Initial render
+        let mut state = LoopState {
+            context,
+            components,
+            // Components mounted by the last expansion, used to deliver broadcasts
+            mounted: Vec::new(),
+            root_id,
+            screens: Vec::new(),
+            vnode_tree: None,
+            needs_render: true, // Initial render
+        };
+        self.mount_root(&mut state);
+        state
+    }
+
+    /// Spawns the effects of the root component and mounts it before its first view.
+    fn mount_root(&self, state: &mut LoopState) {
+        let Some(root) = state.components.get(&state.root_id) else {
+            return;
+        };
+        let context = &mut state.context;
+        context.current_component_id = state.root_id.clone();
 
-        // Spawn effects for root component ONCE before entering the loop
+        // Spawn effects for root component ONCE, the expansion skips the root
         #[cfg(feature = "effects")]
-        if let Some(runtime) = &self.effect_runtime
-            && !context.effect_tracker.has_effects(&root_id, root_type_id)
-        {
-            let effects = root_arc.effects(&context);
-            if !effects.is_empty() {
-                runtime.spawn(root_id.clone(), effects);
-                context
-                    .effect_tracker
-                    .mark_spawned(root_id.clone(), root_type_id);
+        if let Some(runtime) = &self.effect_runtime {
+            let root_type_id = root.type_id();
+            if !context
+                .effect_tracker
+                .has_effects(&state.root_id, root_type_id)
+            {
+                let effects = root.effects(context);
+                if !effects.is_empty() {
+                    runtime.spawn(state.root_id.clone(), effects);
+                    context
+                        .effect_tracker
+                        .mark_spawned(state.root_id.clone(), root_type_id);
+                }
+            }
+        }
+
+        root.on_mount(context);
+        state.mounted = vec![state.root_id.clone()];
+    }
+
+    /// Swaps the root component for each screen change requested by the last
+    /// expansion. The screen being left is unmounted and its effects stopped;
+    /// a covered screen keeps its state so going back restores it.
+    fn apply_navigation(&self, state: &mut LoopState) {
+        let requests = std::mem::take(&mut *self.navigation.borrow_mut());
+        for request in requests {
+            // Nothing to go back to from the first screen
+            if matches!(request, Navigation::Back) && state.screens.is_empty() {
+                continue;
+            }
+
+            #[cfg(feature = "effects")]
+            let leaving = state.mounted.clone();
+            state.unmount_all();
+            #[cfg(feature = "effects")]
+            if let Some(runtime) = &self.effect_runtime {
+                for (comp_id, type_id) in state.context.effect_tracker.get_all() {
+                    if leaving.contains(&comp_id) {
+                        runtime.cleanup(&comp_id);
+                        state.context.effect_tracker.remove(&comp_id, type_id);
+                    }
+                }
+            }
+
+            match request {
+                Navigation::Push(component) => {
+                    let root_id = ComponentId::new((state.screens.len() + 1).to_string());
+                    state
+                        .components
+                        .insert(root_id.clone(), Arc::from(component));
+                    let previous = std::mem::replace(&mut state.root_id, root_id);
+                    state.screens.push(previous);
+                }
+                Navigation::Back => {
+                    let previous = state.screens.pop().unwrap();
+                    let popped = std::mem::replace(&mut state.root_id, previous);
+
+                    // Forget the popped screen so navigating to it again starts afresh
+                    let prefix = format!("{}.", popped.0);
+                    let in_popped = |id: &ComponentId| id == &popped || id.0.starts_with(&prefix);
+                    for id in state.components.keys().filter(|id| in_popped(id)) {
+                        state.context.history.remove(id);
+                    }
+                    state.components.retain(|id, _| !in_popped(id));
+                    state.context.states.retain(|id| !in_popped(id));
+                    state
+                        .context
+                        .rendered_components
+                        .write()
+                        .unwrap()
+                        .retain(|id| !in_popped(id));
+                }
             }
+
+            self.mount_root(state);
+            state.needs_render = true;
         }
+    }
+
+    /// Returns true until the app has been asked to exit and the exit was not vetoed.
+    pub(super) fn is_running(&self) -> bool {
+        *self.running.borrow()
+    }
+
+    /// Returns true for apps created with [`App::headless`].
+    pub(super) fn is_headless(&self) -> bool {
+        self.headless
+    }
+
+    /// Processes pending messages and expands the component tree, ready for
+    /// [`App::render_frame`]. Stops the app if a component requested an exit.
+    pub(super) fn process_message_queue(&mut self, state: &mut LoopState) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("process_message_queue").entered();
+
+        let LoopState {
+            context,
+            components,
+            mounted,
+            root_id,
+            vnode_tree,
+            needs_render,
+            ..
+        } = state;
+
+        loop {
+            // Fan out broadcast messages to every mounted component
+            context.dispatch.deliver_broadcasts(mounted.iter());
 
-        while *self.running.borrow() {
             // Check if we have pending messages that need processing
-            if context.has_pending_messages() {
-                needs_render = true;
+            if context.has_pending_messages() || context.take_render_request() {
+                *needs_render = true;
+            }
+
+            // Drop the toasts whose time is up
+            if context.toasts.expire(context.clock.elapsed()) {
+                *needs_render = true;
             }
 
             // Expand component tree to VNode tree
-            let vnode_tree = if let Some(root_component) = components.get(&root_id) {
+            let tree = if let Some(root_component) = components.get(root_id) {
                 context.current_component_id = root_id.clone();
                 // Create a temporary clone of components to avoid borrow issues
                 let mut temp_components = HashMap::new();
@@ -298,7 +787,7 @@ impl App {
                 // Expand the tree, processing messages and handling exit signals
                 match self.expand_component_tree(
                     root_component.as_ref(),
-                    &mut context,
+                    context,
                     &mut temp_components,
                 ) {
                     Ok(vnode) => {
@@ -309,7 +798,7 @@ impl App {
                             let mut current_instances: HashSet<(ComponentId, std::any::TypeId)> =
                                 HashSet::new();
                             for (comp_id, component) in &temp_components {
-                                if comp_id != &root_id {
+                                if comp_id != root_id {
                                     // Skip root, already handled
                                     current_instances
                                         .insert((comp_id.clone(), component.type_id()));
@@ -319,7 +808,7 @@ impl App {
                             // Spawn effects for newly mounted components (not root)
                             for (comp_id, component) in &temp_components {
                                 // Skip root component as it's already handled
-                                if comp_id != &root_id {
+                                if comp_id != root_id {
                                     let type_id = component.type_id();
 
                                     // Check if this exact component instance (ID + Type) has effects
@@ -329,7 +818,7 @@ impl App {
                                         let original_id = context.current_component_id.clone();
                                         context.current_component_id = comp_id.clone();
 
-                                        let effects = component.effects(&context);
+                                        let effects = component.effects(context);
                                         if !effects.is_empty() {
                                             runtime.spawn(comp_id.clone(), effects);
                                             context
@@ -347,7 +836,7 @@ impl App {
                             let tracked = context.effect_tracker.get_all();
                             for (comp_id, type_id) in tracked {
                                 // Never cleanup root component effects
-                                if comp_id == root_id {
+                                if &comp_id == root_id {
                                     continue;
                                 }
 
@@ -360,122 +849,348 @@ impl App {
                             }
                         }
 
+                        // Let components that left the tree release their resources
+                        // before the new ones set up theirs
+                        let (unmounts, mounts) =
+                            lifecycle_changes(&mounted[1..], components, &temp_components);
+                        let original_id = context.current_component_id.clone();
+                        for (comp_id, component) in unmounts {
+                            #[cfg(feature = "effects")]
+                            context.tasks.cancel(&comp_id);
+                            context.history.remove(&comp_id);
+                            context.topics.unsubscribe(&comp_id);
+                            context.current_component_id = comp_id;
+                            component.on_unmount(context);
+                        }
+                        for (comp_id, component) in mounts {
+                            context.current_component_id = comp_id;
+                            component.on_mount(context);
+                        }
+                        context.current_component_id = original_id;
+
+                        mounted.truncate(1);
+                        mounted.extend(temp_components.keys().cloned());
+
                         // Merge temp_components back into main components map
                         // This is critical for nested components to receive messages
                         components.extend(temp_components);
+
+                        // A component asked for another screen, so this tree is stale
+                        if !self.navigation.borrow().is_empty() {
+                            break;
+                        }
                         vnode
                     }
                     Err(ExitSignal) => {
-                        *self.running.borrow_mut() = false;
-                        break;
+                        if self.config.request_exit(context, &self.running) {
+                            return;
+                        }
+                        // The exit hook vetoed; it may have sent messages to show a prompt
+                        *needs_render = true;
+                        continue;
                     }
                 }
             } else {
                 VNode::div()
             };
 
-            // Render if needed
-            if needs_render || *self.needs_render.borrow() {
-                // Render VNode tree
-                self.vdom.render(vnode_tree);
+            // Topic subscribers may have been expanded before the topic changed in this
+            // pass, so expand again before drawing
+            if !context.topics.take_dirty_subscribers().is_empty() {
+                *needs_render = true;
+                continue;
+            }
 
-                let focus_requests = context.take_focus_requests();
-                self.apply_focus_requests(&context, focus_requests);
+            // Broadcasts sent while expanding may be for components expanded earlier in
+            // this pass, so deliver them and expand again before drawing
+            if context.dispatch.has_pending_broadcasts() {
+                *needs_render = true;
+                continue;
+            }
 
-                let (width, height) = terminal::size()?;
-                self.vdom.layout(width, height);
+            *vnode_tree = Some(tree);
+            return;
+        }
+
+        self.apply_navigation(state);
+        self.process_message_queue(state);
+    }
+
+    /// Renders the tree expanded by the last [`App::process_message_queue`] if a
+    /// render is needed. Returns whether a frame was drawn.
+    pub(super) fn render_frame(&mut self, state: &mut LoopState) -> io::Result<bool> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("render_frame", frame = self.frame).entered();
+
+        let vnode_tree = if state.needs_render || *self.needs_render.borrow() {
+            state.vnode_tree.take()
+        } else {
+            None
+        };
 
+        // Animated text is redrawn every frame even when nothing else changed
+        self.elapsed = state.context.elapsed();
+        let Some(vnode_tree) = vnode_tree else {
+            if self.animating {
                 self.draw()?;
+                self.frame += 1;
+                state
+                    .context
+                    .frame_count
+                    .store(self.frame, Ordering::SeqCst);
+            }
+            return Ok(self.animating);
+        };
+
+        // Render VNode tree
+        self.vdom.render(vnode_tree);
+
+        let focus_requests = state.context.take_focus_requests();
+        self.apply_focus_requests(&state.context, focus_requests);
+
+        self.overlay
+            .render(state.context.toasts.overlay(&state.context.theme()));
+
+        let (width, height) = self.screen_size()?;
+        if self.backend.is_some()
+            && (width, height) != self.double_buffer.front_buffer().dimensions()
+        {
+            state.context.set_terminal_size(width, height);
+            self.double_buffer.resize(width, height);
+        }
+        self.vdom.layout(width, height);
+        self.overlay.layout(width, height);
+
+        // Lazy divs that came into or left view change which children get built,
+        // so expand again before drawing
+        if self.sync_lazy_visibility(&state.context, width, height) {
+            self.process_message_queue(state);
+            if let Some(vnode_tree) = state.vnode_tree.take() {
+                self.vdom.render(vnode_tree);
+                let focus_requests = state.context.take_focus_requests();
+                self.apply_focus_requests(&state.context, focus_requests);
+                self.vdom.layout(width, height);
 
-                // Log render tree if callback is set
-                if let Some(log_fn) = &self.render_log_fn {
-                    let debug_string = self.render_tree_debug_string();
-                    log_fn(&debug_string);
+                // Lazy divs inside the newly built children show up next frame
+                if self.sync_lazy_visibility(&state.context, width, height) {
+                    state.context.render_requested.store(true, Ordering::SeqCst);
                 }
+            }
+        }
+
+        self.animating = self.vdom.get_render_tree().has_animations();
 
-                // Clear render flags
-                *self.needs_render.borrow_mut() = false;
-                needs_render = false;
+        if self.config.warn_overflow {
+            for diagnostic in self.vdom.get_render_tree().overflow_diagnostics() {
+                crate::debug_log!(
+                    "overflow: node at {:?} exceeds its parent's content box by {} columns, {} rows; set `overflow` to clip or scroll it",
+                    diagnostic.path,
+                    diagnostic.overflow_x,
+                    diagnostic.overflow_y
+                );
             }
+        }
 
-            // Poll for events with configurable timeout
-            if event::poll(std::time::Duration::from_millis(
-                self.config.poll_duration_ms,
-            ))? {
-                match event::read()? {
-                    Event::Key(key_event) => {
-                        handle_key_event(&self.vdom, key_event);
-                        // Key events may have triggered messages via event handlers
-                        needs_render = true;
-                    }
-                    Event::Mouse(mouse_event) => {
-                        handle_mouse_event(&self.vdom, mouse_event);
-                        // Mouse events may have triggered messages via event handlers
-                        needs_render = true;
-                    }
-                    Event::Resize(width, height) => {
-                        self.vdom.layout(width, height);
-                        self.double_buffer.resize(width, height);
-                        *self.needs_render.borrow_mut() = true;
-                    }
-                    _ => {}
+        self.draw()?;
+        self.frame += 1;
+        state
+            .context
+            .frame_count
+            .store(self.frame, Ordering::SeqCst);
+
+        #[cfg(feature = "tracing")]
+        {
+            let stats = self.last_frame_stats();
+            tracing::debug!(
+                bytes = stats.bytes,
+                sequences = stats.sequences,
+                "frame drawn"
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        if let Some(path) = &self.dump_tree_path {
+            // Best effort: a failed dump must not stop the app
+            let json = self.render_tree_debug_json();
+            let _ = std::fs::write(path, format!("{json:#}\n"));
+        }
+
+        // Log render tree if callback is set
+        if let Some(log_fn) = &self.render_log_fn {
+            let debug_string = self.render_tree_debug_string();
+            log_fn(&debug_string);
+        }
+
+        // Clear render flags
+        *self.needs_render.borrow_mut() = false;
+        state.needs_render = false;
+        Ok(true)
+    }
+
+    /// Records which lazy divs are on screen in the laid out tree, returning
+    /// true if that changed since the last frame.
+    fn sync_lazy_visibility(&self, context: &Context, width: u16, height: u16) -> bool {
+        let visible = self
+            .vdom
+            .get_render_tree()
+            .visible_lazy_paths(Rect::new(0, 0, width, height));
+        context.set_visible_lazy(visible)
+    }
+
+    /// Waits up to `timeout` for an input event and dispatches it.
+    /// Returns whether an event was handled.
+    ///
+    /// Headless apps without a backend have nothing to read from and return
+    /// immediately.
+    /// Without an event, a key sequence that timed out hands its held keys to
+    /// the regular key handlers.
+    pub(super) fn poll_event(
+        &mut self,
+        state: &mut LoopState,
+        timeout: Duration,
+    ) -> io::Result<bool> {
+        let event = match surface(&mut self.backend, &mut self.terminal) {
+            Some(surface) => surface.poll_event(timeout)?,
+            None => None,
+        };
+        let Some(event) = event else {
+            if handle_expired_key_sequence(&self.vdom, state.context.clock.elapsed()) {
+                state.needs_render = true;
+            }
+            return Ok(false);
+        };
+
+        self.handle_event(state, event)?;
+        Ok(true)
+    }
+
+    /// Dispatches a single terminal event to the render tree.
+    pub(super) fn handle_event(&mut self, state: &mut LoopState, event: Event) -> io::Result<()> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("handle_event", event = ?event).entered();
+
+        match event {
+            #[cfg(unix)]
+            Event::Key(key_event)
+                if self.terminal.is_some() && self.is_suspend_request(&key_event) =>
+            {
+                let mut stdout = io::stdout();
+                suspend::suspend(&mut stdout, terminal::disable_raw_mode, suspend::stop)?;
+                if let Some(terminal) = &mut self.terminal {
+                    suspend::resume(
+                        &mut stdout,
+                        terminal::enable_raw_mode,
+                        terminal.renderer_mut(),
+                        &self.double_buffer,
+                    )?;
                 }
+                // The terminal may have been resized while we were stopped
+                let (width, height) = terminal::size()?;
+                self.double_buffer.resize(width, height);
+                *self.needs_render.borrow_mut() = true;
+            }
+            Event::Key(key_event) if self.config.is_exit_key(&key_event) => {
+                self.config.request_exit(&state.context, &self.running);
+                state.needs_render = true;
+            }
+            Event::Key(key_event) => {
+                handle_key_event(&self.vdom, key_event, state.context.clock.elapsed());
+                // Key events may have triggered messages via event handlers
+                state.needs_render = true;
+            }
+            Event::Mouse(mouse_event) => {
+                handle_mouse_event(&self.vdom, mouse_event);
+                // Mouse events may have triggered messages via event handlers
+                state.needs_render = true;
+            }
+            Event::Paste(text) => {
+                handle_paste_event(&self.vdom, text);
+                state.needs_render = true;
             }
+            Event::Resize(width, height) => {
+                state.context.set_terminal_size(width, height);
+                self.vdom.layout(width, height);
+                self.overlay.layout(width, height);
+                self.double_buffer.resize(width, height);
+                *self.needs_render.borrow_mut() = true;
+            }
+            _ => {}
         }
-
         Ok(())
     }
 
-    /// Expands a component tree into a VNode tree recursively
+    /// Returns true if the key suspends the app: Ctrl+Z while suspending is
+    /// enabled and no component binds the key itself.
+    #[cfg(unix)]
+    pub(super) fn is_suspend_request(&self, key_event: &KeyEvent) -> bool {
+        self.config.suspend_on_ctrl_z
+            && suspend::is_suspend_key(key_event)
+            && KeyWithModifiers::from_key_event(*key_event)
+                .is_some_and(|key| !self.vdom.get_render_tree().binds_key(key))
+    }
+
+    /// Returns the size of the screen the app renders to.
+    fn screen_size(&self) -> io::Result<(u16, u16)> {
+        if let Some(backend) = &self.backend {
+            backend.size()
+        } else if let Some(terminal) = &self.terminal {
+            terminal.size()
+        } else {
+            Ok(self.double_buffer.front_buffer().dimensions())
+        }
+    }
+
+    /// Returns the text of each row of the last drawn frame.
+    pub(super) fn screen_lines(&self) -> Vec<String> {
+        self.double_buffer.front_buffer().lines()
+    }
+
+    /// Expands a component tree into a single VNode tree recursively.
+    ///
+    /// A component that renders several root nodes gets them wrapped in a plain div.
     fn expand_component_tree(
         &self,
         component: &dyn Component,
         context: &mut Context,
         components: &mut HashMap<ComponentId, Arc<dyn Component>>,
     ) -> Result<VNode, ExitSignal> {
+        let mut vnodes = self.expand_guarded(component, context, components)?;
+        if vnodes.len() == 1 {
+            return Ok(vnodes.remove(0));
+        }
+
+        let mut wrapper = Div::new();
+        wrapper.children = vnodes;
+        wrapper.component_path = Some(context.current_component_id.clone());
+        Ok(VNode::Div(wrapper))
+    }
+
+    /// Expands a component, catching panics below it if it is an error boundary.
+    fn expand_guarded(
+        &self,
+        component: &dyn Component,
+        context: &mut Context,
+        components: &mut HashMap<ComponentId, Arc<dyn Component>>,
+    ) -> Result<Vec<VNode>, ExitSignal> {
+        match component.as_any().downcast_ref::<ErrorBoundary>() {
+            Some(boundary) => self.expand_boundary(boundary, context, components),
+            None => self.expand_component(component, context, components),
+        }
+    }
+
+    /// Expands a component into the VNodes it renders, which is more than one
+    /// when its view returns a fragment.
+    fn expand_component(
+        &self,
+        component: &dyn Component,
+        context: &mut Context,
+        components: &mut HashMap<ComponentId, Arc<dyn Component>>,
+    ) -> Result<Vec<VNode>, ExitSignal> {
         // Process all pending messages (regular, owned topics, and unassigned topics)
         let messages = context.drain_all_messages();
         for (msg, topic) in messages {
             let action = component.update(context, msg, topic.as_deref());
-
-            match action {
-                Action::Update(new_state) => {
-                    context
-                        .states
-                        .insert(context.current_component_id.clone(), new_state);
-
-                    // If this was an unassigned topic message and we handled it, claim the topic
-                    if let Some(topic_name) = topic
-                        && context
-                            .topics
-                            .claim_topic(topic_name.clone(), context.current_component_id.clone())
-                    {
-                        // We just claimed this topic, drain its remaining messages
-                        context.drain_topic_if_claimed(&topic_name, &context.current_component_id);
-                    }
-                }
-                Action::UpdateTopic(topic_name, new_state) => {
-                    // Update topic state (idempotent - first writer becomes owner)
-                    context.topics.update_topic(
-                        topic_name.clone(),
-                        new_state,
-                        context.current_component_id.clone(),
-                    );
-
-                    // If this was an unassigned topic message for the same topic, drain it
-                    if let Some(msg_topic) = topic
-                        && msg_topic == topic_name
-                    {
-                        context.drain_topic_if_claimed(&topic_name, &context.current_component_id);
-                    }
-                }
-                Action::Exit => {
-                    return Err(ExitSignal);
-                }
-                Action::None => {
-                    // Component didn't handle this message, leave topic unassigned
-                }
-            }
+            self.apply_action(action, context, topic.as_ref())?;
         }
 
         // Get the node from the component's view
@@ -483,18 +1198,127 @@ impl App {
         let node = component.view(context);
         context.end_component_render();
 
-        // Convert Node to VNode, expanding any nested components
-        self.node_to_vnode(node, context, components, 0)
+        // Convert Node to VNodes, expanding any nested components
+        self.node_to_vnodes(node, context, components, 0)
+    }
+
+    /// Expands an error boundary, rendering its fallback if anything below it
+    /// panics. Components expanded before the panic are dropped, so the failed
+    /// subtree counts as unmounted.
+    fn expand_boundary(
+        &self,
+        boundary: &ErrorBoundary,
+        context: &mut Context,
+        components: &mut HashMap<ComponentId, Arc<dyn Component>>,
+    ) -> Result<Vec<VNode>, ExitSignal> {
+        let boundary_id = context.current_component_id.clone();
+        let mut expanded = HashMap::new();
+        let result = catch_panic(|| self.expand_component(boundary, context, &mut expanded));
+
+        match result {
+            Ok(vnodes) => {
+                components.extend(expanded);
+                vnodes
+            }
+            Err(message) => {
+                crate::debug_log!(error: "error boundary at {:?} caught: {message}", boundary_id);
+                self.repaint.set(true);
+                // The panic may have left the walk inside the failed child
+                context.current_component_id = boundary_id;
+                context.end_component_render();
+                self.node_to_vnodes(boundary.fallback(&message), context, components, 0)
+            }
+        }
+    }
+
+    /// Applies an action returned by a component's update to its state, its
+    /// topics, the navigation stack or the app. `topic` is the topic the handled message was sent to.
+    fn apply_action(
+        &self,
+        action: Action,
+        context: &mut Context,
+        topic: Option<&String>,
+    ) -> Result<(), ExitSignal> {
+        match action {
+            Action::UpdateWithHistory(new_state) => {
+                context.record_history();
+                return self.apply_action(Action::Update(new_state), context, topic);
+            }
+            Action::Update(new_state) => {
+                #[cfg(feature = "serde")]
+                context
+                    .persistence
+                    .save(&context.current_component_id, new_state.as_ref());
+
+                context
+                    .states
+                    .insert(context.current_component_id.clone(), new_state);
+
+                // If this was an unassigned topic message and we handled it, claim the topic
+                if let Some(topic_name) = topic
+                    && context
+                        .topics
+                        .claim_topic(topic_name.clone(), context.current_component_id.clone())
+                {
+                    // We just claimed this topic, drain its remaining messages
+                    context.drain_topic_if_claimed(topic_name, &context.current_component_id);
+                }
+            }
+            Action::UpdateTopic(topic_name, new_state) => {
+                // Update topic state (idempotent - first writer becomes owner)
+                context.topics.update_topic(
+                    topic_name.clone(),
+                    new_state,
+                    context.current_component_id.clone(),
+                );
+
+                // If this was an unassigned topic message for the same topic, drain it
+                if let Some(msg_topic) = topic
+                    && *msg_topic == topic_name
+                {
+                    context.drain_topic_if_claimed(&topic_name, &context.current_component_id);
+                }
+            }
+            Action::Send(message) => {
+                context
+                    .dispatch
+                    .send_boxed_to_id(context.current_component_id.clone(), message);
+            }
+            Action::Batch(actions) => {
+                // Exit returns early, dropping the rest of the batch
+                for action in actions {
+                    self.apply_action(action, context, topic)?;
+                }
+            }
+            Action::Navigate(component) => {
+                self.navigation
+                    .borrow_mut()
+                    .push(Navigation::Push(component));
+            }
+            Action::Back => {
+                self.navigation.borrow_mut().push(Navigation::Back);
+            }
+            Action::Exit => {
+                return Err(ExitSignal);
+            }
+            Action::None => {
+                // Component didn't handle this message, leave topic unassigned
+            }
+        }
+        Ok(())
     }
 
-    /// Converts a Node to a VNode, expanding components recursively
-    fn node_to_vnode(
+    /// Converts a Node to VNodes, expanding components recursively.
+    ///
+    /// Fragments, and components that render them, yield one VNode per child
+    /// so they can be spliced into the parent's child list.
+    fn node_to_vnodes(
         &self,
         node: Node,
         context: &mut Context,
         components: &mut HashMap<ComponentId, Arc<dyn Component>>,
         child_index: usize,
-    ) -> Result<VNode, ExitSignal> {
+    ) -> Result<Vec<VNode>, ExitSignal> {
         match node {
             Node::Component(component) => {
                 // Update context for this component
@@ -505,7 +1329,7 @@ impl App {
                 let component_id = context.current_component_id.clone();
 
                 // Expand the component recursively, propagating any exit signal
-                let vnode = self.expand_component_tree(component.as_ref(), context, components)?;
+                let vnodes = self.expand_guarded(component.as_ref(), context, components)?;
 
                 // Store the component for future updates
                 components.insert(component_id, Arc::clone(&component));
@@ -513,18 +1337,25 @@ impl App {
                 // Restore parent context
                 context.current_component_id = parent_id;
 
-                Ok(vnode)
+                Ok(vnodes)
             }
             Node::Div(div) => {
                 // Track the path through divs to ensure unique component IDs
                 let parent_id = context.current_component_id.clone();
                 context.current_component_id = parent_id.child(child_index);
 
-                // Convert div children
+                // Lazy divs build their children only while they are on screen
+                let lazy_path = div.lazy.then(|| context.current_component_id.clone());
+                let children = match &lazy_path {
+                    Some(path) if !context.is_lazy_visible(path) => Vec::new(),
+                    _ => flatten_fragments(div.children),
+                };
+
+                // Convert div children, with fragments spliced in place
                 let mut vnode_children = Vec::new();
-                for (i, child) in div.children.into_iter().enumerate() {
+                for (i, child) in children.into_iter().enumerate() {
                     // Propagate any exit signal from children
-                    vnode_children.push(self.node_to_vnode(child, context, components, i)?);
+                    vnode_children.extend(self.node_to_vnodes(child, context, components, i)?);
                 }
 
                 // Restore parent context after processing div children
@@ -541,16 +1372,34 @@ impl App {
                 vnode_div.focused = div.focused;
                 vnode_div.hovered = div.hovered;
                 vnode_div.component_path = Some(parent_id);
+                vnode_div.memo_key = div.memo_key;
+                vnode_div.key = div.key;
+                vnode_div.lazy = div.lazy;
+                vnode_div.lazy_path = lazy_path;
 
-                Ok(VNode::Div(vnode_div))
+                Ok(vec![VNode::Div(vnode_div)])
             }
             Node::Text(text) => {
                 // Text nodes are directly converted
-                Ok(VNode::Text(text))
+                Ok(vec![VNode::Text(text)])
             }
             Node::RichText(rich) => {
                 // RichText nodes are directly converted
-                Ok(VNode::RichText(rich))
+                Ok(vec![VNode::RichText(rich)])
+            }
+            Node::Fragment(children) => {
+                // Fragments add no path segment, so their children are numbered
+                // as if the component had rendered them directly
+                let mut vnodes = Vec::new();
+                for (i, child) in flatten_fragments(children).into_iter().enumerate() {
+                    vnodes.extend(self.node_to_vnodes(
+                        child,
+                        context,
+                        components,
+                        child_index + i,
+                    )?);
+                }
+                Ok(vnodes)
             }
         }
     }
@@ -562,6 +1411,26 @@ impl App {
         self.vdom.get_render_tree().debug_string()
     }
 
+    /// Returns the current render tree as nested JSON, with each node's type,
+    /// geometry, style summary and scroll state.
+    ///
+    /// Setting the `RXTUI_DUMP_TREE` environment variable to a file path makes
+    /// [`App::new`] apps write this to the file after every frame.
+    #[cfg(feature = "serde")]
+    pub fn render_tree_debug_json(&self) -> serde_json::Value {
+        self.vdom.get_render_tree().to_debug_json()
+    }
+
+    /// Returns the bytes and escape sequences written to the terminal by the last frame.
+    ///
+    /// Frames drawn without double buffering bypass the terminal renderer and aren't counted.
+    pub fn last_frame_stats(&self) -> FrameStats {
+        self.terminal
+            .as_ref()
+            .map(|terminal| terminal.renderer().last_frame_stats())
+            .unwrap_or_default()
+    }
+
     /// Sets a callback function to be called after each render with the render tree debug string.
     ///
     /// This is useful for logging the render tree state for debugging purposes.
@@ -609,7 +1478,7 @@ impl App {
     /// 2. Diffing against the previous frame
     /// 3. Only updating cells that changed
     fn draw(&mut self) -> io::Result<()> {
-        if self.config.double_buffering {
+        if self.config.double_buffering || self.headless {
             // Use double buffering for flicker-free rendering
             self.draw_with_double_buffer()
         } else {
@@ -624,29 +1493,37 @@ impl App {
         self.double_buffer.clear_back();
 
         // Render the tree to the back buffer
-        if let Some(root) = &self.vdom.get_render_tree().root {
-            let root_ref = root.borrow();
-            let buffer = self.double_buffer.back_buffer_mut();
-            let (width, height) = buffer.dimensions();
-            let clip_rect = Rect::new(0, 0, width, height);
-            render_node_to_buffer(&root_ref, buffer, &clip_rect, None);
+        Self::render_trees(
+            [&self.vdom, &self.overlay],
+            self.double_buffer.back_buffer_mut(),
+            self.elapsed,
+            self.config.debug_layout,
+        );
+
+        let repaint = self.repaint.take();
+        if repaint && let Some(terminal) = &mut self.terminal {
+            // Forget what the terminal is believed to show so every cell is rewritten
+            terminal.renderer_mut().invalidate();
         }
+        // Diff the buffers to find changes, or redraw the entire screen
+        let updates = if self.config.cell_diffing && !repaint {
+            self.double_buffer.diff()
+        } else {
+            self.double_buffer.back_buffer_mut().all_updates()
+        };
+        #[cfg(feature = "tracing")]
+        tracing::debug!(cells = updates.len(), "cell diff");
+        if let Some(surface) = surface(&mut self.backend, &mut self.terminal) {
+            for CellUpdate::Single { x, y, cell } in updates {
+                surface.set_cell(x, y, &cell)?;
+            }
+            surface.flush()?;
 
-        if self.config.cell_diffing {
-            // Diff the buffers to find changes
-            let updates = self.double_buffer.diff();
-
-            // Apply updates to terminal
-            if self.config.terminal_optimizations {
-                self.terminal_renderer.apply_updates(updates)?;
-            } else {
-                // Apply updates without optimizations
-                self.terminal_renderer.apply_updates_direct(updates)?;
+            // Drawing moves the terminal cursor, so a shown cursor is placed again every frame
+            let cursor = self.double_buffer.back_buffer_mut().cursor();
+            if cursor.is_some() || cursor != self.double_buffer.front_buffer().cursor() {
+                surface.set_cursor(cursor)?;
             }
-        } else {
-            // Redraw entire screen without diffing
-            let buffer = self.double_buffer.back_buffer_mut();
-            self.terminal_renderer.draw_full_buffer(buffer)?;
         }
 
         // Swap buffers for next frame
@@ -654,10 +1531,31 @@ impl App {
 
         // Clear all dirty flags after drawing
         self.vdom.get_render_tree().clear_all_dirty();
+        self.overlay.get_render_tree().clear_all_dirty();
 
         Ok(())
     }
 
+    /// Renders each tree into `buffer`, later trees drawing over earlier ones.
+    /// With `debug_layout`, each tree's layout outlines are drawn right after it.
+    fn render_trees<const N: usize>(
+        vdoms: [&VDom; N],
+        buffer: &mut ScreenBuffer,
+        elapsed: Duration,
+        debug_layout: bool,
+    ) {
+        let (width, height) = buffer.dimensions();
+        let clip_rect = Rect::new(0, 0, width, height);
+        for vdom in vdoms {
+            if let Some(root) = &vdom.get_render_tree().root {
+                render_node_to_buffer_at(&root.borrow(), buffer, &clip_rect, None, elapsed);
+                if debug_layout {
+                    render_layout_debug(&root.borrow(), buffer, &clip_rect);
+                }
+            }
+        }
+    }
+
     /// Draws directly to terminal without double buffering (for debugging).
     fn draw_direct(&mut self) -> io::Result<()> {
         // Clear screen
@@ -668,31 +1566,32 @@ impl App {
         let mut buffer = ScreenBuffer::new(width, height);
 
         // Render the tree to the temporary buffer
-        if let Some(root) = &self.vdom.get_render_tree().root {
-            let root_ref = root.borrow();
-            let clip_rect = Rect::new(0, 0, width, height);
-            render_node_to_buffer(&root_ref, &mut buffer, &clip_rect, None);
-        }
+        Self::render_trees(
+            [&self.vdom, &self.overlay],
+            &mut buffer,
+            self.elapsed,
+            self.config.debug_layout,
+        );
 
         // Draw each cell directly to terminal
+        let Some(terminal) = &self.terminal else {
+            return Ok(());
+        };
+        let renderer = terminal.renderer();
         let mut stdout = io::stdout();
         for y in 0..height {
             for x in 0..width {
-                if let Some(cell) = buffer.get_cell(x, y) {
+                if let Some(cell) = buffer.get_cell(x, y)
+                    && !cell.continuation
+                {
                     execute!(stdout, cursor::MoveTo(x, y))?;
 
                     // Set colors if present
                     if let Some(fg) = &cell.fg {
-                        execute!(
-                            stdout,
-                            SetForegroundColor(self.terminal_renderer.color_to_crossterm(*fg))
-                        )?;
+                        execute!(stdout, SetForegroundColor(renderer.color_to_crossterm(*fg)))?;
                     }
                     if let Some(bg) = &cell.bg {
-                        execute!(
-                            stdout,
-                            SetBackgroundColor(self.terminal_renderer.color_to_crossterm(*bg))
-                        )?;
+                        execute!(stdout, SetBackgroundColor(renderer.color_to_crossterm(*bg)))?;
                     }
 
                     // Print character
@@ -708,11 +1607,65 @@ impl App {
 
         // Clear all dirty flags after drawing
         self.vdom.get_render_tree().clear_all_dirty();
+        self.overlay.get_render_tree().clear_all_dirty();
 
         Ok(())
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the components that left the tree since the last expansion, children
+/// first, and those that entered it, parents first.
+///
+/// `mounted` lists the previously mounted components, whose instances are still
+/// in `previous`. A position now holding a component of another type counts as
+/// both an unmount and a mount.
+fn lifecycle_changes(
+    mounted: &[ComponentId],
+    previous: &HashMap<ComponentId, Arc<dyn Component>>,
+    current: &HashMap<ComponentId, Arc<dyn Component>>,
+) -> (Vec<MountedComponent>, Vec<MountedComponent>) {
+    let same_type = |id: &ComponentId| {
+        matches!(
+            (previous.get(id), current.get(id)),
+            (Some(old), Some(new)) if old.type_id() == new.type_id()
+        )
+    };
+
+    let mut unmounts: Vec<MountedComponent> = mounted
+        .iter()
+        .filter(|id| !same_type(id))
+        .filter_map(|id| previous.get(id).map(|c| (id.clone(), Arc::clone(c))))
+        .collect();
+    unmounts.sort_by(|a, b| b.0.0.cmp(&a.0.0));
+
+    let mut mounts: Vec<MountedComponent> = current
+        .iter()
+        .filter(|(id, _)| !mounted.contains(id) || !same_type(id))
+        .map(|(id, c)| (id.clone(), Arc::clone(c)))
+        .collect();
+    mounts.sort_by(|a, b| a.0.0.cmp(&b.0.0));
+
+    (unmounts, mounts)
+}
+
+/// Returns the surface frames are drawn to and input is read from: the custom
+/// backend if there is one, else the terminal.
+fn surface<'a>(
+    backend: &'a mut Option<Box<dyn Backend>>,
+    terminal: &'a mut Option<CrosstermBackend>,
+) -> Option<&'a mut dyn Backend> {
+    match backend {
+        Some(backend) => Some(backend.as_mut()),
+        None => terminal
+            .as_mut()
+            .map(|terminal| terminal as &mut dyn Backend),
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
@@ -720,30 +1673,19 @@ impl App {
 /// Cleanup handler that restores terminal state on application exit.
 ///
 /// Automatically:
-/// - Disables mouse capture
+/// - Disables bracketed paste and mouse capture
 /// - Shows the cursor
 /// - Returns to main screen buffer
 /// - Disables raw mode
 impl Drop for App {
     fn drop(&mut self) {
-        // Note: PopKeyboardEnhancementFlags is commented out since we're not pushing them
-        // use crossterm::event::PopKeyboardEnhancementFlags;
-        use std::io::Write;
-
-        let mut stdout = io::stdout();
+        if self.headless {
+            return;
+        }
 
-        // Pop keyboard enhancement flags if they were enabled
-        // let _ = stdout.execute(PopKeyboardEnhancementFlags);
+        // Note: PopKeyboardEnhancementFlags is not needed since we're not pushing them
 
         // Restore terminal state
-        let _ = stdout.execute(event::DisableMouseCapture);
-        let _ = stdout.execute(cursor::Show);
-        let _ = stdout.execute(terminal::LeaveAlternateScreen);
-
-        // Flush to ensure all commands are sent before disabling raw mode
-        let _ = stdout.flush();
-
-        // Finally disable raw mode
-        let _ = terminal::disable_raw_mode();
+        let _ = term::restore_terminal(&mut io::stdout(), terminal::disable_raw_mode);
     }
 }
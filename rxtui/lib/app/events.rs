@@ -1,9 +1,12 @@
 use crate::key::{Key, KeyWithModifiers};
-use crate::render_tree::RenderNode;
+use crate::keymap::SequenceMatch;
+use crate::node::Propagation;
+use crate::render_tree::{RenderNode, RenderTree};
 use crate::vdom::VDom;
-use crossterm::event::{KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -11,98 +14,109 @@ use std::rc::Rc;
 
 /// Processes keyboard input events.
 ///
+/// Key map sequences are matched first, using `now` to time out sequences
+/// whose keys arrive too far apart. Keys held back by a sequence that broke
+/// are then handled as if no key map had seen them.
+pub fn handle_key_event(vdom: &VDom, key_event: KeyEvent, now: Duration) {
+    let Some(key) = KeyWithModifiers::from_key_event(key_event) else {
+        return;
+    };
+    let render_tree = vdom.get_render_tree();
+
+    // Keys taken by a key map sequence don't reach other handlers
+    let (released, found) = render_tree.match_key_sequence(key, now);
+    for held in released {
+        dispatch_key(render_tree, held);
+    }
+    match found {
+        SequenceMatch::Complete(handler) => handler(),
+        SequenceMatch::Pending => {}
+        SequenceMatch::Unbound => dispatch_key(render_tree, key),
+    }
+}
+
+/// Hands the keys of a key map sequence that timed out by `now` to the other
+/// handlers. Returns true if any keys were held back.
+pub fn handle_expired_key_sequence(vdom: &VDom, now: Duration) -> bool {
+    let render_tree = vdom.get_render_tree();
+    let released = render_tree.expire_key_sequence(now);
+    let expired = !released.is_empty();
+    for held in released {
+        dispatch_key(render_tree, held);
+    }
+    expired
+}
+
+/// Routes a key press outside of key maps.
+///
 /// Handles Tab/Shift+Tab for focus navigation, Enter to activate focused elements,
 /// broadcasts to global handlers,
 /// then routes other keys to the focused element.
-pub fn handle_key_event(vdom: &VDom, key_event: KeyEvent) {
-    // Try to create both simple key and key with modifiers
-    if let Some(key) = Key::from_key_code(key_event.code) {
-        let render_tree = vdom.get_render_tree();
+fn dispatch_key(render_tree: &RenderTree, key_with_modifiers: KeyWithModifiers) {
+    let key = key_with_modifiers.key;
+    // Simple key handlers only see keys without Ctrl, Alt or Meta. This prevents
+    // Ctrl+A from also triggering 'a'
+    let plain = !(key_with_modifiers.ctrl || key_with_modifiers.alt || key_with_modifiers.meta);
 
-        // Handle Tab/BackTab navigation for focus switching
-        if key == Key::Tab {
-            render_tree.focus_next();
-            return;
-        }
-        if key == Key::BackTab {
-            render_tree.focus_prev();
-            return;
-        }
+    // Handle Tab/BackTab navigation for focus switching
+    if key == Key::Tab {
+        render_tree.focus_next();
+        return;
+    }
+    if key == Key::BackTab {
+        render_tree.focus_prev();
+        return;
+    }
 
-        // Handle Enter to activate focused element
-        if key == Key::Enter
-            && let Some(focused) = render_tree.get_focused_node()
-        {
-            // Only simulate click if the element actually has a click handler
-            // This allows elements like TextInput to handle Enter as a regular key
-            if focused.borrow().events.on_click.is_some() {
-                focused.borrow().handle_click();
-                // Return immediately to prevent Enter from being handled again
-                // The click simulation takes precedence
-                return;
-            }
-            // If no click handler, let Enter continue to be processed as a normal key
+    // Handle Enter to activate focused element
+    if key == Key::Enter
+        && let Some(focused) = render_tree.get_focused_node()
+    {
+        // Only simulate click if the element actually has a click handler
+        // This allows elements like TextInput to handle Enter as a regular key
+        if focused.borrow().events.on_click.is_some() {
+            focused.borrow().handle_click();
+            // Return immediately to prevent Enter from being handled again
+            // The click simulation takes precedence
+            return;
         }
+        // If no click handler, let Enter continue to be processed as a normal key
+    }
 
-        // Create KeyWithModifiers for handlers that need it
-        if let Some(key_with_modifiers) = KeyWithModifiers::from_key_event(key_event) {
-            // Phase 1: Always broadcast to global handlers
-            if let Some(root) = &render_tree.root {
-                // Check modifier handlers FIRST (more specific)
-                broadcast_global_key_with_modifiers(root, key_with_modifiers);
-                // Then simple key handlers (less specific)
-                broadcast_global_key(root, key);
-            }
-
-            // Phase 2: Route to focused element for non-global handlers
-            if let Some(focused) = render_tree.get_focused_node() {
-                // Handle scroll navigation for scrollable focused elements
-                let mut handled = false;
-                if focused.borrow().scrollable && focused.borrow().focused {
-                    handled = handle_scroll_key(&focused, key);
-                }
+    // Phase 1: Always broadcast to global handlers
+    if let Some(root) = &render_tree.root {
+        // Check modifier handlers FIRST (more specific)
+        broadcast_global_key_with_modifiers(root, key_with_modifiers);
+        // Then simple key handlers (less specific)
+        broadcast_global_key(root, key);
+    }
 
-                if !handled {
-                    // Check modifier handlers FIRST (more specific)
-                    focused
-                        .borrow()
-                        .handle_key_with_modifiers(key_with_modifiers);
-                    // Only handle simple key if modifiers weren't pressed
-                    // This prevents Ctrl+A from also triggering 'a' handler
-                    if !key_event.modifiers.contains(KeyModifiers::CONTROL)
-                        && !key_event.modifiers.contains(KeyModifiers::ALT)
-                        && !key_event.modifiers.contains(KeyModifiers::META)
-                    {
-                        focused.borrow().handle_key(key);
-                    }
-                }
-            } else {
-                // No focused element, broadcast to all for non-global handlers
-                if let Some(root) = &render_tree.root {
-                    broadcast_key_with_modifiers(root, key_with_modifiers);
-                    if !key_event.modifiers.contains(KeyModifiers::CONTROL)
-                        && !key_event.modifiers.contains(KeyModifiers::ALT)
-                        && !key_event.modifiers.contains(KeyModifiers::META)
-                    {
-                        broadcast_key(root, key);
-                    }
-                }
-            }
-        } else {
-            // Fallback to simple key handling if modifier extraction fails
-            // Phase 1: Always broadcast to global handlers
-            if let Some(root) = &render_tree.root {
-                broadcast_global_key(root, key);
-            }
+    // Phase 2: Route to focused element for non-global handlers
+    if let Some(focused) = render_tree.get_focused_node() {
+        // Handle scroll navigation for scrollable focused elements
+        let mut handled = false;
+        if focused.borrow().scrollable && focused.borrow().focused {
+            handled = handle_scroll_key(&focused, key);
+        }
 
-            // Phase 2: Route to focused element for non-global handlers
-            if let Some(focused) = render_tree.get_focused_node() {
+        if !handled {
+            // Check modifier handlers FIRST (more specific)
+            let handled_with_modifiers = focused
+                .borrow()
+                .handle_key_with_modifiers(key_with_modifiers);
+            // Only handle simple key if no modifier handler claimed it and
+            // modifiers weren't pressed. This also keeps Shift+Left from
+            // moving the cursor when a handler took it
+            if !handled_with_modifiers && plain {
                 focused.borrow().handle_key(key);
-            } else {
-                // No focused element, broadcast to all for non-global handlers
-                if let Some(root) = &render_tree.root {
-                    broadcast_key(root, key);
-                }
+            }
+        }
+    } else {
+        // No focused element, broadcast to all for non-global handlers
+        if let Some(root) = &render_tree.root {
+            broadcast_key_with_modifiers(root, key_with_modifiers);
+            if plain {
+                broadcast_key(root, key);
             }
         }
     }
@@ -119,6 +133,17 @@ pub fn broadcast_key(node: &Rc<RefCell<RenderNode>>, key: Key) {
     }
 }
 
+/// Processes a bracketed paste.
+///
+/// The whole pasted block is delivered to the focused element's paste handler
+/// in one call instead of being replayed as individual keystrokes, so pasting
+/// cannot trigger shortcuts and only causes a single re-render.
+pub fn handle_paste_event(vdom: &VDom, text: String) {
+    if let Some(focused) = vdom.get_render_tree().get_focused_node() {
+        focused.borrow().handle_paste(text);
+    }
+}
+
 /// Recursively broadcasts a key press to global handlers in all nodes.
 ///
 /// Global handlers work regardless of focus state.
@@ -167,14 +192,29 @@ pub fn broadcast_global_key_with_modifiers(
 /// Handles:
 /// - Mouse down events by finding the node at the click position
 /// - Sets focus to the clicked node if it's focusable
-/// - Triggers the node's click handler
+/// - Triggers the click handlers of the node and its ancestors, innermost first
 /// - Mouse wheel events for scrolling
+/// - Dragging a scrollbar thumb to scroll its node
 pub fn handle_mouse_event(vdom: &VDom, mouse_event: MouseEvent) {
     let render_tree = vdom.get_render_tree();
 
     match mouse_event.kind {
         MouseEventKind::Down(_) => {
-            if let Some(node) = render_tree.find_node_at(mouse_event.column, mouse_event.row) {
+            let path = render_tree.hit_test_path(mouse_event.column, mouse_event.row);
+
+            // Pressing a scrollbar thumb grabs it instead of reaching the content below
+            let grabbed = path.iter().find_map(|node| {
+                let region = node.borrow().scrollbar.get()?;
+                region
+                    .thumb_contains(mouse_event.column, mouse_event.row)
+                    .then(|| (node.clone(), mouse_event.row - region.thumb_y))
+            });
+            if grabbed.is_some() {
+                render_tree.set_scrollbar_drag(grabbed);
+                return;
+            }
+
+            if let Some(node) = path.first().cloned() {
                 render_tree.set_hovered_node(Some(node.clone()));
                 // Set focus if the node is focusable
                 {
@@ -186,7 +226,16 @@ pub fn handle_mouse_event(vdom: &VDom, mouse_event: MouseEvent) {
                 }
 
                 // Handle the click
-                node.borrow().handle_click();
+                let (x, y) = local_position(&node, mouse_event.column, mouse_event.row);
+                node.borrow().handle_mouse_down(x, y);
+                render_tree.set_pressed_node(Some(node));
+
+                // Bubble the click from the target outward until a handler stops it
+                for target in &path {
+                    if target.borrow().handle_click() == Propagation::Stop {
+                        break;
+                    }
+                }
             } else {
                 render_tree.set_hovered_node(None);
             }
@@ -223,18 +272,74 @@ pub fn handle_mouse_event(vdom: &VDom, mouse_event: MouseEvent) {
                 render_tree.set_hovered_node(None);
             }
         }
-        MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+        MouseEventKind::Moved => {
             let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
             render_tree.set_hovered_node(hovered);
         }
+        MouseEventKind::Drag(_) => {
+            let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
+            render_tree.set_hovered_node(hovered);
+
+            // A held scrollbar thumb follows the pointer row
+            if let Some((node, grab_offset)) = render_tree.get_scrollbar_drag() {
+                let mut node_ref = node.borrow_mut();
+                if let Some(region) = node_ref.scrollbar.get() {
+                    let thumb_top = mouse_event.row as i32 - grab_offset as i32;
+                    let scroll_y =
+                        region.scroll_for_thumb_at(thumb_top, node_ref.get_max_scroll_y());
+                    if scroll_y != node_ref.scroll_y {
+                        node_ref.set_scroll_y(scroll_y);
+                        node_ref.mark_dirty();
+                    }
+                }
+                return;
+            }
+
+            // Drags go to the node the button was pressed on, wherever the mouse is now
+            if let Some(pressed) = render_tree.get_pressed_node() {
+                let (x, y) = local_position(&pressed, mouse_event.column, mouse_event.row);
+                pressed.borrow().handle_drag(x, y);
+
+                let parent = pressed.borrow().parent.as_ref().and_then(|p| p.upgrade());
+                if let Some(parent) = parent {
+                    let (x, y) = local_position(&parent, mouse_event.column, mouse_event.row);
+                    let (width, height) = (parent.borrow().width, parent.borrow().height);
+                    pressed.borrow().handle_drag_in_parent(x, y, width, height);
+                }
+            }
+        }
         MouseEventKind::Up(_) => {
             let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
             render_tree.set_hovered_node(hovered);
+            render_tree.set_pressed_node(None);
+            render_tree.set_scrollbar_drag(None);
         }
         _ => {}
     }
 }
 
+/// Converts a screen position into one relative to a node's top-left corner,
+/// clamped to the node's bounds and accounting for scrolled ancestors.
+fn local_position(node: &Rc<RefCell<RenderNode>>, column: u16, row: u16) -> (u16, u16) {
+    let mut scroll_offset = 0;
+    let mut parent = node.borrow().parent.as_ref().and_then(|p| p.upgrade());
+    while let Some(ancestor) = parent {
+        let ancestor_ref = ancestor.borrow();
+        if ancestor_ref.scrollable {
+            scroll_offset += ancestor_ref.scroll_y as i32;
+        }
+        parent = ancestor_ref.parent.as_ref().and_then(|p| p.upgrade());
+    }
+
+    let node_ref = node.borrow();
+    let x = column as i32 - node_ref.x as i32;
+    let y = row as i32 - (node_ref.y as i32 - scroll_offset);
+    (
+        x.clamp(0, node_ref.width.saturating_sub(1) as i32) as u16,
+        y.clamp(0, node_ref.height.saturating_sub(1) as i32) as u16,
+    )
+}
+
 /// Finds the nearest scrollable ancestor of a node (including the node itself).
 fn find_scrollable_ancestor(node: &Rc<RefCell<RenderNode>>) -> Option<Rc<RefCell<RenderNode>>> {
     // Check if this node is scrollable
@@ -263,17 +368,13 @@ fn handle_scroll_key(node: &Rc<RefCell<RenderNode>>, key: Key) -> bool {
     }
 
     match key {
-        Key::Up => {
-            if node_ref.update_scroll(-1) {
-                node_ref.mark_dirty();
-                return true;
-            }
+        Key::Up if node_ref.update_scroll(-1) => {
+            node_ref.mark_dirty();
+            return true;
         }
-        Key::Down => {
-            if node_ref.update_scroll(1) {
-                node_ref.mark_dirty();
-                return true;
-            }
+        Key::Down if node_ref.update_scroll(1) => {
+            node_ref.mark_dirty();
+            return true;
         }
         Key::PageUp => {
             // Scroll up by half the viewport height
@@ -309,3 +410,313 @@ fn handle_scroll_key(node: &Rc<RefCell<RenderNode>>, key: Key) -> bool {
 
     false
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::KeyMap;
+    use crate::node::Div;
+    use crate::vnode::VNode;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_bracketed_paste_is_delivered_as_one_message() {
+        let pastes = Rc::new(RefCell::new(Vec::new()));
+        let chars = Rc::new(RefCell::new(0));
+
+        let node: VNode = Div::new()
+            .focusable(true)
+            .on_paste({
+                let pastes = pastes.clone();
+                move |text| pastes.borrow_mut().push(text)
+            })
+            .on_any_char({
+                let chars = chars.clone();
+                move |_| *chars.borrow_mut() += 1
+            })
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(20, 5);
+        vdom.get_render_tree().focus_next();
+
+        handle_paste_event(&vdom, "first line\nsecond\tline".to_string());
+
+        assert_eq!(*pastes.borrow(), vec!["first line\nsecond\tline"]);
+        assert_eq!(*chars.borrow(), 0);
+    }
+
+    #[test]
+    fn test_paste_without_focus_is_ignored() {
+        let pastes = Rc::new(RefCell::new(0));
+        let node: VNode = Div::new()
+            .focusable(true)
+            .on_paste({
+                let pastes = pastes.clone();
+                move |_| *pastes.borrow_mut() += 1
+            })
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(20, 5);
+
+        handle_paste_event(&vdom, "ignored".to_string());
+        assert_eq!(*pastes.borrow(), 0);
+    }
+
+    /// Renders a clickable parent around a child whose click handler is
+    /// `child`, then clicks the child
+    fn click_nested(log: &Rc<RefCell<Vec<&'static str>>>, child: Div<VNode>) {
+        let node: VNode = Div::new()
+            .width(10)
+            .height(3)
+            .on_click({
+                let log = log.clone();
+                move || log.borrow_mut().push("parent")
+            })
+            .child(child.width(4).height(1).into())
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(20, 5);
+        handle_mouse_event(
+            &vdom,
+            MouseEvent {
+                kind: MouseEventKind::Down(crossterm::event::MouseButton::Left),
+                column: 1,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+    }
+
+    #[test]
+    fn test_click_bubbles_from_child_to_parent() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        click_nested(
+            &log,
+            Div::new().on_click({
+                let log = log.clone();
+                move || log.borrow_mut().push("child")
+            }),
+        );
+        assert_eq!(*log.borrow(), ["child", "parent"]);
+
+        // A child without a handler passes the click straight on
+        let log = Rc::new(RefCell::new(Vec::new()));
+        click_nested(&log, Div::new());
+        assert_eq!(*log.borrow(), ["parent"]);
+    }
+
+    #[test]
+    fn test_stopping_child_suppresses_parent_click() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        click_nested(
+            &log,
+            Div::new().on_click_with_propagation({
+                let log = log.clone();
+                move || {
+                    log.borrow_mut().push("child");
+                    Propagation::Stop
+                }
+            }),
+        );
+        assert_eq!(*log.borrow(), ["child"]);
+    }
+
+    fn mouse(vdom: &VDom, kind: MouseEventKind, column: u16, row: u16) {
+        handle_mouse_event(
+            vdom,
+            MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+    }
+
+    #[test]
+    fn test_dragging_scrollbar_thumb_scrolls_content() {
+        use crate::Rect;
+        use crate::app::renderer::render_node_to_buffer;
+        use crate::buffer::ScreenBuffer;
+        use crate::node::Text;
+        use crate::style::Overflow;
+        use crossterm::event::MouseButton;
+
+        let node: VNode = Div::new()
+            .width(10)
+            .height(6)
+            .overflow(Overflow::Scroll)
+            .show_scrollbar(true)
+            .children((0..20).map(|i| Text::new(format!("{i}")).into()).collect())
+            .into();
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 6);
+
+        // Drawing records where the thumb is
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        let mut buffer = ScreenBuffer::new(10, 6);
+        render_node_to_buffer(&root.borrow(), &mut buffer, &Rect::new(0, 0, 10, 6), None);
+        let max_scroll = root.borrow().get_max_scroll_y();
+        assert_eq!(max_scroll, 14);
+
+        // The two-row thumb has four rows to travel; moving it two rows is halfway
+        mouse(&vdom, MouseEventKind::Down(MouseButton::Left), 9, 0);
+        mouse(&vdom, MouseEventKind::Drag(MouseButton::Left), 9, 2);
+        assert_eq!(root.borrow().scroll_y, max_scroll / 2);
+
+        // Dragging past the end clamps, and releasing lets go of the thumb
+        mouse(&vdom, MouseEventKind::Drag(MouseButton::Left), 9, 30);
+        assert_eq!(root.borrow().scroll_y, max_scroll);
+        mouse(&vdom, MouseEventKind::Up(MouseButton::Left), 9, 30);
+        mouse(&vdom, MouseEventKind::Drag(MouseButton::Left), 9, 0);
+        assert_eq!(root.borrow().scroll_y, max_scroll);
+    }
+
+    #[test]
+    fn test_keymap_sequence_takes_keys_before_other_handlers() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let keymap = KeyMap::new().bind("g g", {
+            let log = log.clone();
+            move || log.borrow_mut().push("top")
+        });
+        let node: VNode = Div::new()
+            .keymap(keymap)
+            .on_char_global('x', {
+                let log = log.clone();
+                move || log.borrow_mut().push("x")
+            })
+            .into();
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 5);
+
+        let press = |ch: char, millis: u64| {
+            let event = KeyEvent::new(crossterm::event::KeyCode::Char(ch), KeyModifiers::NONE);
+            handle_key_event(&vdom, event, Duration::from_millis(millis));
+        };
+
+        press('g', 0);
+        press('g', 100);
+        assert_eq!(*log.borrow(), ["top"]);
+
+        // A key breaking the sequence reaches the other handlers
+        press('g', 200);
+        press('x', 300);
+        assert_eq!(*log.borrow(), ["top", "x"]);
+    }
+
+    /// Renders `node` and returns a function pressing a key `millis` after start
+    fn keyboard(node: VNode) -> (VDom, impl Fn(&VDom, KeyCode, KeyModifiers, u64)) {
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 5);
+        let press = |vdom: &VDom, code, modifiers, millis| {
+            handle_key_event(
+                vdom,
+                KeyEvent::new(code, modifiers),
+                Duration::from_millis(millis),
+            );
+        };
+        (vdom, press)
+    }
+
+    fn logger(log: &Rc<RefCell<Vec<String>>>, entry: &str) -> impl Fn() + 'static {
+        let log = log.clone();
+        let entry = entry.to_string();
+        move || log.borrow_mut().push(entry.clone())
+    }
+
+    #[test]
+    fn test_broken_or_expired_sequence_replays_held_keys() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let node: VNode = Div::new()
+            .keymap(KeyMap::new().bind("g g", logger(&log, "top")))
+            .on_char_global('g', logger(&log, "g"))
+            .on_char_global('x', logger(&log, "x"))
+            .into();
+        let (vdom, press) = keyboard(node);
+        let none = KeyModifiers::NONE;
+
+        press(&vdom, KeyCode::Char('g'), none, 0);
+        assert!(log.borrow().is_empty());
+        press(&vdom, KeyCode::Char('x'), none, 100);
+        assert_eq!(*log.borrow(), ["g", "x"]);
+
+        // Nothing is released before the timeout, then the held key is
+        press(&vdom, KeyCode::Char('g'), none, 200);
+        assert!(!handle_expired_key_sequence(
+            &vdom,
+            Duration::from_millis(1200)
+        ));
+        assert!(handle_expired_key_sequence(
+            &vdom,
+            Duration::from_millis(1201)
+        ));
+        assert_eq!(*log.borrow(), ["g", "x", "g"]);
+    }
+
+    #[test]
+    fn test_keymap_leaves_characters_to_focused_text_field() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let field: VNode = Div::new()
+            .focusable(true)
+            .on_any_char({
+                let log = log.clone();
+                move |ch| log.borrow_mut().push(ch.to_string())
+            })
+            .into();
+        let keymap = KeyMap::new()
+            .bind("g g", logger(&log, "top"))
+            .bind("ctrl+k ctrl+c", logger(&log, "comment"));
+        let node: VNode = Div::new().keymap(keymap).children(vec![field]).into();
+        let (vdom, press) = keyboard(node);
+
+        let render_tree = vdom.get_render_tree();
+        let field = render_tree.root.as_ref().unwrap().borrow().children[0].clone();
+        render_tree.set_focused_node(Some(field));
+
+        press(&vdom, KeyCode::Char('g'), KeyModifiers::NONE, 0);
+        press(&vdom, KeyCode::Char('g'), KeyModifiers::NONE, 100);
+        assert_eq!(*log.borrow(), ["g", "g"]);
+
+        // Chords with modifiers still reach the key map
+        press(&vdom, KeyCode::Char('k'), KeyModifiers::CONTROL, 200);
+        press(&vdom, KeyCode::Char('c'), KeyModifiers::CONTROL, 300);
+        assert_eq!(*log.borrow(), ["g", "g", "comment"]);
+    }
+
+    #[test]
+    fn test_keymap_applies_only_along_focus_path() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let panel: VNode = Div::new()
+            .keymap(KeyMap::new().bind("g g", logger(&log, "panel")))
+            .into();
+        let other: VNode = Div::new().focusable(true).into();
+        let node: VNode = Div::new().children(vec![panel, other]).into();
+        let (vdom, press) = keyboard(node);
+        let render_tree = vdom.get_render_tree();
+
+        let other = render_tree.root.as_ref().unwrap().borrow().children[1].clone();
+        render_tree.set_focused_node(Some(other));
+        press(&vdom, KeyCode::Char('g'), KeyModifiers::NONE, 0);
+        press(&vdom, KeyCode::Char('g'), KeyModifiers::NONE, 100);
+        assert!(log.borrow().is_empty());
+
+        // Without focus, every key map in the tree applies
+        render_tree.set_focused_node(None);
+        press(&vdom, KeyCode::Char('g'), KeyModifiers::NONE, 200);
+        press(&vdom, KeyCode::Char('g'), KeyModifiers::NONE, 300);
+        assert_eq!(*log.borrow(), ["panel"]);
+    }
+}
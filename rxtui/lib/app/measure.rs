@@ -0,0 +1,118 @@
+use crate::app::Context;
+use crate::node::{Div, Node, flatten_fragments};
+use crate::vdom::VDom;
+use crate::vnode::VNode;
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the size a node tree takes when laid out as a root within `available`.
+///
+/// The tree is built and laid out separately, so nothing on screen changes.
+/// Components are expanded with their default state, which makes this handy for
+/// sizing a popup to its content before showing it:
+///
+/// ```rust,ignore
+/// let content = node! { div [ text("Saved 3 files") ] };
+/// let (width, height) = rxtui::measure(&content, (80, 24));
+/// ```
+pub fn measure(node: &Node, available: (u16, u16)) -> (u16, u16) {
+    let mut context = Context::default();
+    let mut vdom = VDom::new();
+    let mut roots = expand(node.clone(), &mut context, 0);
+    let root = if roots.len() == 1 {
+        roots.remove(0)
+    } else {
+        let mut wrapper = Div::new();
+        wrapper.children = roots;
+        VNode::Div(wrapper)
+    };
+    vdom.render(root);
+    vdom.layout(available.0, available.1);
+
+    vdom.get_render_tree().root.as_ref().map_or((0, 0), |root| {
+        let root = root.borrow();
+        (root.width, root.height)
+    })
+}
+
+/// Converts a node to VNodes, rendering components with the context's state
+fn expand(node: Node, context: &mut Context, child_index: usize) -> Vec<VNode> {
+    match node {
+        Node::Component(component) => {
+            let parent_id = context.current_component_id.clone();
+            context.current_component_id = parent_id.child(child_index);
+            let node = component.view(context);
+            let vnodes = expand(node, context, 0);
+            context.current_component_id = parent_id;
+            vnodes
+        }
+        Node::Div(mut div) => {
+            let parent_id = context.current_component_id.clone();
+            context.current_component_id = parent_id.child(child_index);
+            let children = flatten_fragments(std::mem::take(&mut div.children));
+            let mut div: Div<VNode> = div.map(|_| unreachable!("children were taken"));
+            for (i, child) in children.into_iter().enumerate() {
+                div.children.extend(expand(child, context, i));
+            }
+            context.current_component_id = parent_id;
+            vec![VNode::Div(div)]
+        }
+        Node::Text(text) => vec![VNode::Text(text)],
+        Node::RichText(rich) => vec![VNode::RichText(rich)],
+        Node::Fragment(children) => flatten_fragments(children)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, child)| expand(child, context, child_index + i))
+            .collect(),
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::layout_node;
+    use crate::node::Text;
+    use crate::style::{Direction, Spacing, TextWrap};
+
+    /// Returns the root size after a real layout
+    fn laid_out(node: Node, available: (u16, u16)) -> (u16, u16) {
+        let vdom = layout_node(node, available.0, available.1);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        let root = root.borrow();
+        (root.width, root.height)
+    }
+
+    #[test]
+    fn test_measure_matches_layout() {
+        let text: Node = Text::new("hello world").into();
+        assert_eq!(measure(&text, (40, 10)), (11, 1));
+        assert_eq!(measure(&text, (40, 10)), laid_out(text, (40, 10)));
+
+        let nested: Node = Div::new()
+            .direction(Direction::Vertical)
+            .padding(Spacing::all(1))
+            .child(Text::new("title").into())
+            .child(
+                Div::new()
+                    .width(8)
+                    .child(Text::new("wrapped words here").wrap(TextWrap::Word).into())
+                    .into(),
+            )
+            .into();
+        let size = measure(&nested, (40, 10));
+        assert_eq!(size, laid_out(nested, (40, 10)));
+        assert_eq!(size, (10, 6));
+    }
+
+    #[test]
+    fn test_measure_is_capped_by_available_space() {
+        let text: Node = Text::new("a long line of text").into();
+        assert_eq!(measure(&text, (5, 3)), (5, 1));
+    }
+}
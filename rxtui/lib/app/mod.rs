@@ -1,12 +1,31 @@
+pub mod clipboard;
+mod clock;
 pub mod config;
 pub mod context;
 pub mod core;
 pub mod events;
+mod measure;
+mod panic;
+#[cfg(feature = "serde")]
+pub mod persist;
 pub mod renderer;
+pub mod runner;
+#[cfg(unix)]
+mod suspend;
+pub mod toast;
 
 //--------------------------------------------------------------------------------------------------
 // Exports
 //--------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "clipboard")]
+pub use clipboard::SystemClipboard;
+pub use clipboard::{ClipboardBackend, ClipboardError, MemoryClipboard};
+pub use config::{Breakpoint, Breakpoints, NonTtyMode};
 pub use context::Context;
 pub use core::App;
+pub use measure::measure;
+#[cfg(feature = "serde")]
+pub use persist::{FileStateStore, MemoryStateStore, PersistentState, StateStore};
+pub use runner::Runner;
+pub use toast::{DEFAULT_TOAST_DURATION, ToastKind};
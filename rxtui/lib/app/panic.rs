@@ -0,0 +1,199 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Arc;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A panic hook as stored by the standard library.
+type PanicHook = dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static;
+
+/// Keeps a terminal-restoring panic hook installed until dropped.
+///
+/// The hook runs `restore` before handing over to the previously installed hook,
+/// so the panic message is printed to a usable terminal instead of the raw
+/// alternate screen. Panics caught by an error boundary skip `restore` but still
+/// reach the previous hook. Dropping the guard reinstates the previous hook.
+pub(crate) struct PanicHookGuard {
+    previous: Arc<PanicHook>,
+}
+
+thread_local! {
+    /// Number of error boundaries the current thread is expanding
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl PanicHookGuard {
+    /// Installs a hook that calls `restore` and then the previous hook.
+    pub(crate) fn install(restore: impl Fn() + Send + Sync + 'static) -> Self {
+        let previous: Arc<PanicHook> = Arc::from(panic::take_hook());
+        let chained = previous.clone();
+        panic::set_hook(Box::new(move |info| {
+            // An error boundary recovers from the panic, so the app keeps its terminal
+            if CATCHING.get() == 0 {
+                restore();
+            }
+            chained(info);
+        }));
+        Self { previous }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Runs `f` for an error boundary, returning the panic message if it panicked.
+pub(crate) fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    CATCHING.set(CATCHING.get() + 1);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(CATCHING.get() - 1);
+    result.map_err(|payload| panic_message(payload.as_ref()))
+}
+
+/// Returns the message a panic was raised with.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "component panicked".to_string()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Drop for PanicHookGuard {
+    fn drop(&mut self) {
+        // Replacing a hook is not allowed while panicking; the process is going down anyway
+        if std::thread::panicking() {
+            return;
+        }
+
+        let previous = self.previous.clone();
+        drop(panic::take_hook());
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terminal::restore_terminal;
+    use std::process::Command;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// Set in the child process that runs a test needing the panic hook to itself
+    const ISOLATED: &str = "RXTUI_ISOLATED_TEST";
+
+    /// Reruns the named test alone in a child process and checks that it passed.
+    ///
+    /// The panic hook is process-wide, so swapping it while other tests run would
+    /// swallow or misroute their panics. Returns false inside the child, where the
+    /// caller goes on to run the test body.
+    fn rerun_isolated(name: &str) -> bool {
+        if std::env::var_os(ISOLATED).is_some() {
+            return false;
+        }
+
+        let module = module_path!().split_once("::").unwrap().1;
+        let output = Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", &format!("{module}::{name}"), "--test-threads=1"])
+            .env(ISOLATED, "1")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{stdout}");
+        assert!(stdout.contains("1 passed"), "{stdout}");
+        true
+    }
+
+    #[test]
+    fn test_panic_restores_terminal_then_restores_hook() {
+        if rerun_isolated("test_panic_restores_terminal_then_restores_hook") {
+            return;
+        }
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let raw_disabled = Arc::new(AtomicBool::new(false));
+        let restores = Arc::new(AtomicUsize::new(0));
+
+        // Keep the test output quiet while the chained hook runs
+        panic::set_hook(Box::new(|_| {}));
+
+        let guard = {
+            let output = output.clone();
+            let raw_disabled = raw_disabled.clone();
+            let restores = restores.clone();
+            PanicHookGuard::install(move || {
+                restores.fetch_add(1, Ordering::SeqCst);
+                let _ = restore_terminal(&mut *output.lock().unwrap(), || {
+                    raw_disabled.store(true, Ordering::SeqCst);
+                    Ok(())
+                });
+            })
+        };
+
+        let result = panic::catch_unwind(|| panic!("component failed"));
+        assert!(result.is_err());
+        assert!(raw_disabled.load(Ordering::SeqCst));
+
+        let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+        let show_cursor = output.find("\x1b[?25h").expect("cursor shown");
+        let leave_alt_screen = output.find("\x1b[?1049l").expect("alternate screen left");
+        assert!(show_cursor < leave_alt_screen);
+
+        // Once the guard is gone, panics no longer touch the terminal
+        drop(guard);
+        let seen = restores.load(Ordering::SeqCst);
+        let _ = panic::catch_unwind(|| panic!("after exit"));
+        assert_eq!(restores.load(Ordering::SeqCst), seen);
+
+        let _ = panic::take_hook();
+    }
+
+    #[test]
+    fn test_caught_panic_reaches_previous_hook_without_restoring() {
+        if rerun_isolated("test_caught_panic_reaches_previous_hook_without_restoring") {
+            return;
+        }
+
+        let reported = Arc::new(AtomicUsize::new(0));
+        let restores = Arc::new(AtomicUsize::new(0));
+        panic::set_hook(Box::new({
+            let reported = reported.clone();
+            move |_| {
+                reported.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+
+        let guard = {
+            let restores = restores.clone();
+            PanicHookGuard::install(move || {
+                restores.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+
+        let result = catch_panic(|| panic!("boundary caught this"));
+        assert_eq!(result.unwrap_err(), "boundary caught this");
+        assert_eq!(reported.load(Ordering::SeqCst), 1);
+        assert_eq!(restores.load(Ordering::SeqCst), 0);
+
+        drop(guard);
+        let _ = panic::take_hook();
+    }
+}
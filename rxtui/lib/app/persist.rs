@@ -0,0 +1,278 @@
+use crate::component::{ComponentId, State};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use super::context::StateMap;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Component state that can be saved to a [`StateStore`] and restored on the next run.
+///
+/// Implemented automatically for any state that is serializable.
+pub trait PersistentState: State + Serialize + DeserializeOwned {}
+
+/// Backing storage for persisted component state.
+///
+/// Values are serialized JSON documents keyed by a stable, user-chosen key.
+pub trait StateStore: Send + Sync {
+    /// Returns the serialized value stored under `key`, if any.
+    fn load(&self, key: &str) -> Option<String>;
+
+    /// Stores a serialized value under `key`.
+    fn save(&self, key: &str, value: String);
+
+    /// Writes any buffered values to the underlying medium.
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// In-memory state store, mainly useful for tests.
+#[derive(Default)]
+pub struct MemoryStateStore {
+    entries: RwLock<HashMap<String, String>>,
+}
+
+/// State store backed by a single JSON file.
+///
+/// The file is read once when opened and rewritten on every flush.
+pub struct FileStateStore {
+    path: PathBuf,
+    entries: RwLock<HashMap<String, serde_json::Value>>,
+}
+
+/// Serializes a type-erased state back into JSON.
+type StateSerializer = Box<dyn Fn(&dyn State) -> Option<String> + Send + Sync>;
+
+/// Binding between a mounted component and its persistence key
+struct PersistenceBinding {
+    key: String,
+    serialize: StateSerializer,
+}
+
+/// Tracks which component states are persisted and where they are stored
+#[derive(Clone, Default)]
+pub(crate) struct Persistence {
+    store: Arc<RwLock<Option<Arc<dyn StateStore>>>>,
+    bindings: Arc<RwLock<HashMap<ComponentId, PersistenceBinding>>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl MemoryStateStore {
+    /// Creates an empty in-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FileStateStore {
+    /// Opens the store at `path`.
+    ///
+    /// A missing file yields an empty store; a file that is not a JSON object is an error.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) if contents.trim().is_empty() => HashMap::new(),
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            path,
+            entries: RwLock::new(entries),
+        })
+    }
+
+    /// Returns the path of the backing file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Persistence {
+    /// Installs the store used for loading and saving state
+    pub(crate) fn set_store(&self, store: Arc<dyn StateStore>) {
+        *self.store.write().unwrap() = Some(store);
+    }
+
+    /// Binds a component to `key` and returns the previously saved value, if any.
+    pub(crate) fn bind<T: PersistentState>(
+        &self,
+        component_id: &ComponentId,
+        key: &str,
+    ) -> Option<T> {
+        let store = self.store.read().unwrap().clone()?;
+
+        self.bindings.write().unwrap().insert(
+            component_id.clone(),
+            PersistenceBinding {
+                key: key.to_string(),
+                serialize: Box::new(|state| {
+                    State::as_any(state)
+                        .downcast_ref::<T>()
+                        .and_then(|typed| serde_json::to_string(typed).ok())
+                }),
+            },
+        );
+
+        store
+            .load(key)
+            .and_then(|value| serde_json::from_str(&value).ok())
+    }
+
+    /// Saves the state of a bound component, ignoring components that are not persisted.
+    pub(crate) fn save(&self, component_id: &ComponentId, state: &dyn State) {
+        let Some(store) = self.store.read().unwrap().clone() else {
+            return;
+        };
+
+        let bindings = self.bindings.read().unwrap();
+        if let Some(binding) = bindings.get(component_id)
+            && let Some(value) = (binding.serialize)(state)
+        {
+            store.save(&binding.key, value);
+        }
+    }
+
+    /// Saves every bound component state and flushes the store.
+    pub(crate) fn save_all(&self, states: &StateMap) -> io::Result<()> {
+        let Some(store) = self.store.read().unwrap().clone() else {
+            return Ok(());
+        };
+
+        let component_ids: Vec<ComponentId> =
+            self.bindings.read().unwrap().keys().cloned().collect();
+        for component_id in component_ids {
+            if let Some(state) = states.get_boxed(&component_id) {
+                self.save(&component_id, state.as_ref());
+            }
+        }
+
+        store.flush()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl<T> PersistentState for T where T: State + Serialize + DeserializeOwned {}
+
+impl StateStore for MemoryStateStore {
+    fn load(&self, key: &str) -> Option<String> {
+        self.entries.read().unwrap().get(key).cloned()
+    }
+
+    fn save(&self, key: &str, value: String) {
+        self.entries.write().unwrap().insert(key.to_string(), value);
+    }
+}
+
+impl StateStore for FileStateStore {
+    fn load(&self, key: &str) -> Option<String> {
+        self.entries
+            .read()
+            .unwrap()
+            .get(key)
+            .map(|value| value.to_string())
+    }
+
+    fn save(&self, key: &str, value: String) {
+        if let Ok(value) = serde_json::from_str(&value) {
+            self.entries.write().unwrap().insert(key.to_string(), value);
+        }
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        let entries = self.entries.read().unwrap();
+        let contents = serde_json::to_string_pretty(&*entries)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&self.path, contents)
+    }
+}
+
+impl<S: StateStore + ?Sized> StateStore for Arc<S> {
+    fn load(&self, key: &str) -> Option<String> {
+        (**self).load(key)
+    }
+
+    fn save(&self, key: &str, value: String) {
+        (**self).save(key, value)
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        (**self).flush()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Context;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        volume: u8,
+        theme: String,
+    }
+
+    #[test]
+    fn test_state_survives_save_load_cycle() {
+        let store: Arc<dyn StateStore> = Arc::new(MemoryStateStore::new());
+
+        // First run: nothing saved yet, so the default is used
+        let ctx = Context::default();
+        ctx.persistence.set_store(store.clone());
+        let settings = ctx.get_persistent_state::<Settings>("settings");
+        assert_eq!(settings, Settings::default());
+
+        ctx.set_state(Box::new(Settings {
+            volume: 7,
+            theme: "dark".to_string(),
+        }));
+        ctx.persistence.save_all(&ctx.states).unwrap();
+
+        // Second run: a fresh context picks up the saved value
+        let ctx = Context::default();
+        ctx.persistence.set_store(store);
+        let settings = ctx.get_persistent_state::<Settings>("settings");
+        assert_eq!(settings.volume, 7);
+        assert_eq!(settings.theme, "dark");
+    }
+
+    #[test]
+    fn test_unbound_components_are_not_saved() {
+        let store = Arc::new(MemoryStateStore::new());
+        let persistence = Persistence::default();
+        persistence.set_store(store.clone());
+
+        persistence.save(&ComponentId::default(), &Settings::default());
+        assert!(store.entries.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_without_store_falls_back_to_default() {
+        let ctx = Context::default();
+        assert_eq!(
+            ctx.get_persistent_state::<Settings>("settings"),
+            Settings::default()
+        );
+    }
+}
@@ -1,9 +1,27 @@
 use crate::bounds::Rect;
 use crate::buffer::{Cell, ScreenBuffer};
 use crate::render_tree::RenderNode;
-use crate::render_tree::RenderNodeType;
-use crate::style::{Color, Overflow};
-use crate::utils::{display_width, substring_by_columns};
+use crate::render_tree::{RenderNodeType, ScrollbarRegion};
+use crate::style::{
+    Animation, Border, BorderEdges, BorderStyle, Color, Overflow, Position, ScrollbarPosition,
+    Spacing, TextStyle,
+};
+use crate::utils::{char_width, display_width, substring_by_columns};
+use std::time::Duration;
+use unicode_segmentation::UnicodeSegmentation;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Color of the outlines and size labels drawn by the layout debug overlay.
+const DEBUG_OUTLINE_COLOR: Color = Color::BrightMagenta;
+
+/// Tint blended over padding regions by the layout debug overlay.
+const DEBUG_PADDING_TINT: Color = Color::Rgb(0, 160, 80);
+
+/// Opacity of [`DEBUG_PADDING_TINT`].
+const DEBUG_PADDING_OPACITY: f32 = 0.35;
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -46,7 +64,176 @@ pub fn render_node_to_buffer(
     clip_rect: &Rect,
     parent_bg: Option<Color>,
 ) {
-    render_node_with_offset(node, buffer, clip_rect, parent_bg, 0);
+    render_node_to_buffer_at(node, buffer, clip_rect, parent_bg, Duration::ZERO);
+}
+
+/// Renders a node like [`render_node_to_buffer`], resolving text animations for
+/// the given time since the app started.
+pub fn render_node_to_buffer_at(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_bg: Option<Color>,
+    elapsed: Duration,
+) {
+    render_node_with_offset(node, buffer, clip_rect, parent_bg, 0, elapsed);
+}
+
+/// Draws the layout debug overlay over an already rendered tree.
+///
+/// Every element gets a thin outline along its bounds with its `WxH` size on the
+/// top edge, and its padding region tinted. Text nodes are left untouched so
+/// content stays readable between the outlines.
+///
+/// ```text
+/// ┌12x4──────┐
+/// │░░░░░░░░░░│ ← tinted padding
+/// │░content ░│
+/// └──────────┘
+/// ```
+pub(crate) fn render_layout_debug(node: &RenderNode, buffer: &mut ScreenBuffer, clip_rect: &Rect) {
+    render_layout_debug_with_offset(node, buffer, clip_rect, 0);
+}
+
+/// Walks the tree for [`render_layout_debug`], following scroll offsets the same
+/// way the normal pass does.
+fn render_layout_debug_with_offset(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_scroll_offset: i32,
+) {
+    if matches!(node.node_type, RenderNodeType::Element) && node.width > 0 && node.height > 0 {
+        let left = node.x as i32;
+        let top = node.y as i32 - parent_scroll_offset;
+        let right = left + node.width as i32 - 1;
+        let bottom = top + node.height as i32 - 1;
+
+        if let Some(style) = &node.style
+            && let Some(padding) = style.padding
+        {
+            let border = style
+                .border
+                .as_ref()
+                .map_or(Spacing::all(0), Border::insets);
+            let content_left = left + (border.left + padding.left) as i32;
+            let content_top = top + (border.top + padding.top) as i32;
+            let content_right = right - (border.right + padding.right) as i32;
+            let content_bottom = bottom - (border.bottom + padding.bottom) as i32;
+            for y in top + border.top as i32..=bottom - border.bottom as i32 {
+                for x in left + border.left as i32..=right - border.right as i32 {
+                    let in_content = (content_left..=content_right).contains(&x)
+                        && (content_top..=content_bottom).contains(&y);
+                    if !in_content && let Some((x, y)) = debug_position(clip_rect, x, y) {
+                        blend_cell(buffer, x, y, DEBUG_PADDING_TINT, DEBUG_PADDING_OPACITY);
+                    }
+                }
+            }
+        }
+
+        if node.width >= 2 && node.height >= 2 {
+            for x in left + 1..right {
+                put_debug_char(buffer, clip_rect, x, top, '─');
+                put_debug_char(buffer, clip_rect, x, bottom, '─');
+            }
+            for y in top + 1..bottom {
+                put_debug_char(buffer, clip_rect, left, y, '│');
+                put_debug_char(buffer, clip_rect, right, y, '│');
+            }
+            put_debug_char(buffer, clip_rect, left, top, '┌');
+            put_debug_char(buffer, clip_rect, right, top, '┐');
+            put_debug_char(buffer, clip_rect, left, bottom, '└');
+            put_debug_char(buffer, clip_rect, right, bottom, '┘');
+
+            // Size label on the top edge, only when it fits between the corners
+            let label = format!("{}x{}", node.width, node.height);
+            if (label.len() as i32) < right - left {
+                for (offset, ch) in label.chars().enumerate() {
+                    put_debug_char(buffer, clip_rect, left + 1 + offset as i32, top, ch);
+                }
+            }
+        }
+    }
+
+    let children_offset = if node.scrollable {
+        parent_scroll_offset + node.scroll_y as i32
+    } else {
+        parent_scroll_offset
+    };
+    for child in &node.children {
+        render_layout_debug_with_offset(&child.borrow(), buffer, clip_rect, children_offset);
+    }
+}
+
+/// Returns the buffer position of a possibly off-screen cell, if it lies within
+/// the clip rect.
+fn debug_position(clip_rect: &Rect, x: i32, y: i32) -> Option<(u16, u16)> {
+    let x = u16::try_from(x).ok()?;
+    let y = u16::try_from(y).ok()?;
+    clip_rect.contains_point(x, y).then_some((x, y))
+}
+
+/// Draws one outline or label character of the layout debug overlay, keeping
+/// the cell's background.
+fn put_debug_char(buffer: &mut ScreenBuffer, clip_rect: &Rect, x: i32, y: i32, ch: char) {
+    let Some((x, y)) = debug_position(clip_rect, x, y) else {
+        return;
+    };
+    let bg = buffer.get_cell(x, y).and_then(|cell| cell.bg);
+    buffer.set_cell(
+        x,
+        y,
+        Cell {
+            fg: Some(DEBUG_OUTLINE_COLOR),
+            bg,
+            ..Cell::new(ch)
+        },
+    );
+}
+
+/// Returns whether a cell of a node drawn at `(left, top)` holds one of its
+/// border's enabled edges or corners.
+fn is_border_cell(border: &Border, node: &RenderNode, left: u16, top: u16, x: u16, y: u16) -> bool {
+    let right = left + node.width - 1;
+    let bottom = top + node.height - 1;
+    [
+        (BorderEdges::TOP, y == top),
+        (BorderEdges::BOTTOM, y == bottom),
+        (BorderEdges::LEFT, x == left),
+        (BorderEdges::RIGHT, x == right),
+        (BorderEdges::TOP_LEFT, x == left && y == top),
+        (BorderEdges::TOP_RIGHT, x == right && y == top),
+        (BorderEdges::BOTTOM_LEFT, x == left && y == bottom),
+        (BorderEdges::BOTTOM_RIGHT, x == right && y == bottom),
+    ]
+    .into_iter()
+    .any(|(edge, on_edge)| on_edge && border.edges.contains(edge))
+}
+
+/// Returns the scroll offset that pins a sticky node to the top of the visible
+/// area, never moving it above its place in the flow or below `content_bottom`.
+///
+/// ```text
+/// ┌──────────┐ ← viewport top
+/// │ Header B │ ← pinned while section B is in view
+/// │ b2       │
+/// │ b3       │
+/// │ Header C │ ← next header pushes B off as it arrives
+/// └──────────┘
+/// ```
+fn sticky_scroll_offset(
+    node: &RenderNode,
+    scroll_offset: i16,
+    viewport_top: u16,
+    content_bottom: Option<i32>,
+) -> i16 {
+    let natural_y = node.y as i32 - scroll_offset as i32;
+    let mut pinned_y = viewport_top as i32;
+    if let Some(bottom) = content_bottom {
+        pinned_y = pinned_y.min(bottom - node.height as i32);
+    }
+    let rendered_y = natural_y.max(pinned_y);
+    (node.y as i32 - rendered_y) as i16
 }
 
 /// Internal function that handles rendering with accumulated scroll offset
@@ -56,6 +243,7 @@ fn render_node_with_offset(
     clip_rect: &Rect,
     parent_bg: Option<Color>,
     parent_scroll_offset: i16,
+    elapsed: Duration,
 ) {
     // Calculate the rendered position with parent scroll offset applied
     // Using i32 to allow negative positions for proper clipping
@@ -97,11 +285,11 @@ fn render_node_with_offset(
             Some(Overflow::Hidden) | Some(Overflow::Scroll) | Some(Overflow::Auto) => {
                 // Clip children to the padding edge (CSS behavior)
                 // This means children can render in padding area but not in border area
-                let border_offset = if style.border.as_ref().is_some_and(|b| b.enabled) {
-                    1
-                } else {
-                    0
-                };
+                // Only the enabled edges take cells from the padding box
+                let border = style
+                    .border
+                    .as_ref()
+                    .map_or(Spacing::all(0), Border::insets);
 
                 // Calculate padding box bounds (inside border, includes padding)
                 //
@@ -113,24 +301,15 @@ fn render_node_with_offset(
                 // │║ └───────┘ ║│
                 // │╚═══════════╝│
                 // └─────────────┘
-                let padding_box_x = rendered_x + border_offset;
+                let padding_box_x = rendered_x + border.left;
                 // Use actual position for padding box to ensure proper clipping
-                let padding_box_y = (rendered_y_i32 + border_offset as i32).max(0) as u16;
-                let padding_box_width = node.width.saturating_sub(border_offset * 2);
-                // Adjust height if padding box starts above viewport
-                let padding_box_height = if rendered_y_i32 + (border_offset as i32) < 0 {
-                    // If padding box starts above viewport, reduce height
-                    let below_viewport = rendered_y_i32 + node.height as i32;
-                    if below_viewport > (border_offset as i32) {
-                        (below_viewport - border_offset as i32)
-                            .min(node.height as i32 - (border_offset as i32) * 2)
-                            as u16
-                    } else {
-                        0
-                    }
-                } else {
-                    node.height.saturating_sub(border_offset * 2)
-                };
+                let padding_box_top = rendered_y_i32 + border.top as i32;
+                let padding_box_bottom = rendered_y_i32 + node.height as i32 - border.bottom as i32;
+                let padding_box_y = padding_box_top.max(0) as u16;
+                let padding_box_width = node.width.saturating_sub(border.left + border.right);
+                // Starting above the viewport leaves only the part below it
+                let padding_box_height =
+                    (padding_box_bottom - padding_box_top.max(0)).max(0) as u16;
 
                 let padding_box_bounds = Rect::new(
                     padding_box_x,
@@ -183,27 +362,10 @@ fn render_node_with_offset(
                 {
                     // Get border characters based on style
                     let (top_left, top, top_right, left, right, bottom_left, bottom, bottom_right) =
-                        match border.style {
-                            crate::style::BorderStyle::Single => {
-                                ('┌', '─', '┐', '│', '│', '└', '─', '┘')
-                            }
-                            crate::style::BorderStyle::Double => {
-                                ('╔', '═', '╗', '║', '║', '╚', '═', '╝')
-                            }
-                            crate::style::BorderStyle::Thick => {
-                                ('┏', '━', '┓', '┃', '┃', '┗', '━', '┛')
-                            }
-                            crate::style::BorderStyle::Rounded => {
-                                ('╭', '─', '╮', '│', '│', '╰', '─', '╯')
-                            }
-                            crate::style::BorderStyle::Dashed => {
-                                ('┌', '╌', '┐', '╎', '╎', '└', '╌', '┘')
-                            }
-                        };
+                        border_chars(border.style);
 
                     // Draw border within the clipped area
                     let border_bounds = node_bounds.intersection(&element_clip);
-                    use crate::style::BorderEdges;
 
                     // Top border
                     if border.edges.contains(BorderEdges::TOP)
@@ -359,25 +521,58 @@ fn render_node_with_offset(
                         cell.bg = style.background.or(parent_bg);
                         buffer.set_cell(right_x, bottom_y, cell);
                     }
+                } else if let Some(border) = &style.border
+                    && border.enabled
+                    && let Some(vertical) = thin_line_orientation(node, border.edges)
+                {
+                    // A one-cell-thick node with a single edge is drawn as a plain line,
+                    // which is how dividers render
+                    let (_, top, _, left, _, _, _, _) = border_chars(border.style);
+                    let ch = if vertical { left } else { top };
+                    let line_bounds = node_bounds.intersection(&element_clip);
+                    for y in line_bounds.y..line_bounds.bottom() {
+                        for x in line_bounds.x..line_bounds.right() {
+                            let mut cell = Cell::new(ch);
+                            cell.fg = Some(border.color);
+                            cell.bg = style.background.or(parent_bg);
+                            buffer.set_cell(x, y, cell);
+                        }
+                    }
                 }
 
-                // Fill the div area with background color if there's any effective background
-                if let Some(bg) = style.background {
-                    effective_bg = Some(bg);
+                // Fill the div area with background color if there's any effective background,
+                // or with its fill glyph; wide glyphs can't tile the area and fall back to spaces
+                let opacity = style.opacity.unwrap_or(1.0);
+                let fill_char = style
+                    .fill_char
+                    .filter(|&ch| char_width(ch) == 1)
+                    .unwrap_or(' ');
+                if style.background.is_some() || fill_char != ' ' {
+                    let bg = style.background;
+                    // Translucent fills keep what is underneath, so children inherit
+                    // the parent's background rather than the unblended color
+                    if opacity >= 1.0 && bg.is_some() {
+                        effective_bg = bg;
+                    }
                     // Fill within the clipped area, but skip border cells if border is enabled
                     let fill_bounds = node_bounds.intersection(&element_clip);
                     let has_border = style.border.as_ref().is_some_and(|b| b.enabled);
+                    let is_thin_line = style.border.as_ref().is_some_and(|b| {
+                        b.enabled && thin_line_orientation(node, b.edges).is_some()
+                    });
 
                     for y in fill_bounds.y..fill_bounds.bottom() {
                         for x in fill_bounds.x..fill_bounds.right() {
                             // Skip border cells if border is enabled
-                            if has_border && node.width > 1 && node.height > 1 {
-                                let is_border_cell = (y == rendered_y
-                                    || y == rendered_y + node.height - 1)
-                                    || (x == rendered_x || x == rendered_x + node.width - 1);
+                            if is_thin_line || (has_border && node.width > 1 && node.height > 1) {
+                                let is_border_cell = is_thin_line
+                                    || style.border.as_ref().is_some_and(|border| {
+                                        is_border_cell(border, node, rendered_x, rendered_y, x, y)
+                                    });
                                 if is_border_cell {
                                     // Set background only if cell is empty (preserve border character)
-                                    if let Some(cell) = buffer.get_cell_mut(x, y)
+                                    if let Some(bg) = bg
+                                        && let Some(cell) = buffer.get_cell_mut(x, y)
                                         && cell.bg.is_none()
                                     {
                                         cell.bg = Some(bg);
@@ -386,14 +581,32 @@ fn render_node_with_offset(
                                 }
                             }
 
-                            let mut cell = Cell::new(' ');
-                            cell.bg = Some(bg);
+                            if opacity < 1.0
+                                && let Some(bg) = bg
+                            {
+                                blend_cell(buffer, x, y, bg, opacity);
+                                continue;
+                            }
+
+                            let mut cell = Cell::new(fill_char);
+                            cell.bg = bg.or(parent_bg);
                             buffer.set_cell(x, y, cell);
                         }
                     }
                 }
             }
 
+            // Opting out of inheritance leaves text without a background of its own
+            // unset, so the terminal's default shows through
+            if node
+                .style
+                .as_ref()
+                .and_then(|s| s.inherit_background)
+                .is_some_and(|inherit| !inherit)
+            {
+                effective_bg = None;
+            }
+
             // Calculate content area to check if we should render children
             // This prevents rendering when border and padding consume all available space
             //
@@ -406,34 +619,19 @@ fn render_node_with_offset(
             //
             // In this case, content_width = 0 and content_height = 0,
             // so we skip rendering children entirely.
-            let padding = node
-                .style
-                .as_ref()
-                .and_then(|s| s.padding)
-                .unwrap_or(crate::style::Spacing::all(0));
-            let border_offset = if node
-                .style
-                .as_ref()
-                .and_then(|s| s.border.as_ref())
-                .is_some_and(|b| b.enabled)
-            {
-                1
-            } else {
-                0
-            };
-
-            let content_width = node
-                .width
-                .saturating_sub(padding.left + padding.right + (border_offset * 2));
-            let content_height = node
-                .height
-                .saturating_sub(padding.top + padding.bottom + (border_offset * 2));
+            let insets = node.content_insets();
+            let content_width = node.width.saturating_sub(insets.left + insets.right);
+            let content_height = node.height.saturating_sub(insets.top + insets.bottom);
 
             // Only render children if there's content area available
             if content_width > 0 && content_height > 0 {
-                // Sort children by z-index for proper layering
+                // Sort children by z-index for proper layering, drawing sticky children
+                // last so they cover the siblings scrolling underneath them
                 let mut sorted_children: Vec<_> = node.children.iter().collect();
-                sorted_children.sort_by_key(|child| child.borrow().z_index);
+                sorted_children.sort_by_key(|child| {
+                    let child = child.borrow();
+                    (child.z_index, child.position_type == Position::Sticky)
+                });
 
                 // Render children in z-index order with the children clip rect and background
                 // Calculate total scroll offset to pass to children
@@ -443,16 +641,34 @@ fn render_node_with_offset(
                     parent_scroll_offset
                 };
 
+                // Sticky children can't be pushed past the bottom of this node's content,
+                // unless this node is the scroller and its content runs on
+                let content_bottom = (!node.scrollable)
+                    .then(|| rendered_y_i32 + node.height as i32 - insets.bottom as i32);
+
                 for child in sorted_children {
+                    let child = child.borrow();
+                    let scroll_offset = if child.position_type == Position::Sticky {
+                        sticky_scroll_offset(
+                            &child,
+                            child_scroll_offset,
+                            children_clip.y,
+                            content_bottom,
+                        )
+                    } else {
+                        child_scroll_offset
+                    };
                     render_node_with_offset(
-                        &child.borrow(),
+                        &child,
                         buffer,
                         &children_clip,
                         effective_bg,
-                        child_scroll_offset,
+                        scroll_offset,
+                        elapsed,
                     );
                 }
 
+                node.scrollbar.set(None);
                 // Render scrollbars if needed (for Scroll and Auto modes)
                 // Only show scrollbar if explicitly enabled via style
                 if node.scrollable
@@ -464,6 +680,17 @@ fn render_node_with_offset(
                 {
                     render_scrollbars(node, buffer, &element_clip, parent_scroll_offset);
                 }
+
+                // Render edge hints for content scrolled out of view (opt-in via style)
+                if node.scrollable
+                    && node
+                        .style
+                        .as_ref()
+                        .and_then(|s| s.scroll_indicators)
+                        .unwrap_or(false)
+                {
+                    render_scroll_indicators(node, buffer, &element_clip, parent_scroll_offset);
+                }
             }
         }
 
@@ -532,11 +759,13 @@ fn render_node_with_offset(
                         if merged_style.background.is_none() {
                             merged_style.background = parent_bg;
                         }
-                        buffer.write_styled_str(
+                        write_text(
+                            buffer,
                             render_x,
                             rendered_y,
                             visible_text,
-                            Some(&merged_style),
+                            &merged_style,
+                            elapsed,
                         );
                     } else {
                         // Fallback to old method if no full text style
@@ -572,6 +801,13 @@ fn render_node_with_offset(
                 }
 
                 if line_y >= clip_rect.y {
+                    let align = node.text_style.as_ref().and_then(|s| s.align);
+                    // Trailing spaces left by word wrapping would skew the alignment
+                    let line = if align.is_some() {
+                        line.trim_end()
+                    } else {
+                        line.as_str()
+                    };
                     let line_width = display_width(line) as u16;
 
                     // Calculate alignment offset for this line
@@ -593,6 +829,20 @@ fn render_node_with_offset(
                         0 // Default to left alignment
                     };
 
+                    if align.is_some() {
+                        pad_aligned_line(
+                            buffer,
+                            Rect::new(rendered_x, line_y, node.width, 1),
+                            align_offset,
+                            line_width,
+                            node.text_style
+                                .as_ref()
+                                .and_then(|s| s.background)
+                                .or(parent_bg),
+                            clip_rect,
+                        );
+                    }
+
                     // Apply alignment offset to the rendered position
                     let aligned_x = rendered_x + align_offset;
                     let text_bounds = crate::bounds::Rect::new(aligned_x, line_y, line_width, 1);
@@ -624,11 +874,13 @@ fn render_node_with_offset(
                                 if merged_style.background.is_none() {
                                     merged_style.background = parent_bg;
                                 }
-                                buffer.write_styled_str(
+                                write_text(
+                                    buffer,
                                     render_x,
                                     line_y,
                                     visible_text,
-                                    Some(&merged_style),
+                                    &merged_style,
+                                    elapsed,
                                 );
                             } else {
                                 // Fallback to old method if no full text style
@@ -707,6 +959,9 @@ fn render_node_with_offset(
                                 visible_end_col,
                             );
                             let render_x = current_x.max(clip_rect.x);
+                            if span.is_cursor {
+                                buffer.set_cursor((render_x, rendered_y));
+                            }
 
                             // Apply span's style, falling back to parent background
                             if let Some(span_style) = &span.style {
@@ -714,11 +969,13 @@ fn render_node_with_offset(
                                 if merged_style.background.is_none() {
                                     merged_style.background = parent_bg;
                                 }
-                                buffer.write_styled_str(
+                                write_text(
+                                    buffer,
                                     render_x,
                                     rendered_y,
                                     visible_text,
-                                    Some(&merged_style),
+                                    &merged_style,
+                                    elapsed,
                                 );
                             } else {
                                 // No style on this span - use default with parent background
@@ -757,11 +1014,16 @@ fn render_node_with_offset(
                 }
 
                 if line_y >= clip_rect.y {
-                    // Calculate total line width
-                    let line_width: u16 = line_spans
+                    let align = node.text_style.as_ref().and_then(|s| s.align);
+
+                    // Calculate total line width, ignoring trailing spaces when aligning
+                    let mut line_width: u16 = line_spans
                         .iter()
                         .map(|span| display_width(&span.content) as u16)
                         .sum();
+                    if align.is_some() {
+                        line_width -= trailing_whitespace_width(line_spans);
+                    }
 
                     // Calculate alignment offset for this line
                     let align_offset = if let Some(text_style) = &node.text_style
@@ -786,6 +1048,27 @@ fn render_node_with_offset(
                     let aligned_x = rendered_x + align_offset;
                     let text_bounds = crate::bounds::Rect::new(aligned_x, line_y, line_width, 1);
 
+                    if align.is_some() {
+                        pad_aligned_line(
+                            buffer,
+                            Rect::new(rendered_x, line_y, node.width, 1),
+                            align_offset,
+                            line_width,
+                            node.text_style
+                                .as_ref()
+                                .and_then(|s| s.background)
+                                .or(parent_bg),
+                            clip_rect,
+                        );
+                    }
+
+                    // Keep the trailing spaces ignored above from spilling past the line
+                    let clip_rect = &if align.is_some() {
+                        clip_rect.intersection(&text_bounds)
+                    } else {
+                        *clip_rect
+                    };
+
                     if text_bounds.intersects(clip_rect) {
                         let mut current_x = aligned_x;
 
@@ -817,6 +1100,9 @@ fn render_node_with_offset(
                                         visible_end_col,
                                     );
                                     let render_x = current_x.max(clip_rect.x);
+                                    if span.is_cursor {
+                                        buffer.set_cursor((render_x, line_y));
+                                    }
 
                                     // Apply span's style, falling back to parent background
                                     if let Some(span_style) = &span.style {
@@ -824,11 +1110,13 @@ fn render_node_with_offset(
                                         if merged_style.background.is_none() {
                                             merged_style.background = parent_bg;
                                         }
-                                        buffer.write_styled_str(
+                                        write_text(
+                                            buffer,
                                             render_x,
                                             line_y,
                                             visible_text,
-                                            Some(&merged_style),
+                                            &merged_style,
+                                            elapsed,
                                         );
                                     } else {
                                         // No style on this span - use default with parent background
@@ -852,6 +1140,245 @@ fn render_node_with_offset(
     }
 }
 
+/// Writes styled text, resolving the style's animation at `elapsed`. Hidden blink
+/// phases keep the background but blank out the glyphs.
+fn write_text(
+    buffer: &mut ScreenBuffer,
+    x: u16,
+    y: u16,
+    text: &str,
+    style: &TextStyle,
+    elapsed: Duration,
+) {
+    let Some(animation) = &style.animation else {
+        buffer.write_styled_str(x, y, text, Some(style));
+        return;
+    };
+
+    let mut style = style.clone();
+    if let Some(color) = animation.color(elapsed) {
+        style.color = Some(color);
+    }
+    if let Animation::Shimmer { highlight, .. } = animation
+        && let Some(band) = animation.highlight(elapsed, display_width(text))
+    {
+        // Draw cell by cell, recoloring the ones under the band
+        let highlighted = TextStyle {
+            color: Some(*highlight),
+            ..style.clone()
+        };
+        let mut column = 0;
+        for (_, grapheme) in text.grapheme_indices(true) {
+            let cell_style = if band.contains(&column) {
+                &highlighted
+            } else {
+                &style
+            };
+            buffer.write_styled_str(x + column as u16, y, grapheme, Some(cell_style));
+            column += display_width(grapheme);
+        }
+        return;
+    }
+    if animation.is_visible(elapsed) {
+        buffer.write_styled_str(x, y, text, Some(&style));
+    } else {
+        buffer.write_styled_str(x, y, &" ".repeat(display_width(text)), Some(&style));
+    }
+}
+
+/// Blends a translucent background over a cell, tinting its colors while
+/// keeping its character visible.
+///
+/// Cells without a background have nothing to blend with and take the color
+/// as is.
+fn blend_cell(buffer: &mut ScreenBuffer, x: u16, y: u16, color: Color, opacity: f32) {
+    let Some(cell) = buffer.get_cell_mut(x, y) else {
+        return;
+    };
+    cell.bg = Some(match cell.bg {
+        Some(under) => color.blend(under, opacity),
+        None => color,
+    });
+    cell.fg = cell.fg.map(|fg| color.blend(fg, opacity));
+}
+
+/// Fills the cells of an aligned line's row that the text doesn't cover, so the
+/// text background spans the node's full content width.
+///
+/// `row` is the line's full row within the node, `offset` where the text starts
+/// in it and `text_width` how many columns the text covers.
+fn pad_aligned_line(
+    buffer: &mut ScreenBuffer,
+    row: Rect,
+    offset: u16,
+    text_width: u16,
+    background: Option<Color>,
+    clip_rect: &Rect,
+) {
+    // Without a background the padding would be indistinguishable from empty cells
+    let Some(background) = background else {
+        return;
+    };
+
+    let text_end = offset.saturating_add(text_width);
+    for column in (0..offset).chain(text_end..row.width) {
+        let x = row.x.saturating_add(column);
+        if clip_rect.contains_point(x, row.y) {
+            buffer.set_cell(x, row.y, Cell::new(' ').with_bg(background));
+        }
+    }
+}
+
+/// Returns the display width of the whitespace ending a wrapped rich text line.
+fn trailing_whitespace_width(spans: &[crate::node::TextSpan]) -> u16 {
+    let mut width = 0;
+    for span in spans.iter().rev() {
+        let trimmed = span.content.trim_end();
+        width += (display_width(&span.content) - display_width(trimmed)) as u16;
+        if !trimmed.is_empty() {
+            break;
+        }
+    }
+    width
+}
+
+/// Renders scrollbar indicators for a scrollable node.
+///
+/// Shows vertical scrollbar when content exceeds viewport.
+fn render_scrollbars(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_scroll_offset: i16,
+) {
+    // Determine if scrollbar is needed
+    let needs_scrollbar = node.content_height > node.height;
+
+    // Only show scrollbar for Auto mode if content overflows
+    if let Some(style) = &node.style
+        && let Some(Overflow::Auto) = style.overflow
+        && !needs_scrollbar
+    {
+        return;
+    }
+
+    // Calculate rendered position with parent scroll offset
+    let rendered_y = if parent_scroll_offset > 0 {
+        node.y.saturating_sub(parent_scroll_offset as u16)
+    } else {
+        node.y
+    };
+    let rendered_x = node.x;
+
+    let scrollbar = node
+        .style
+        .as_ref()
+        .and_then(|s| s.scrollbar_style)
+        .unwrap_or_default();
+
+    // Vertical scrollbar
+    if needs_scrollbar && node.height > 2 {
+        let scrollbar_x = match scrollbar.position {
+            ScrollbarPosition::Right => rendered_x + node.width.saturating_sub(1),
+            ScrollbarPosition::Left => rendered_x,
+        };
+        let scrollbar_height = node.height;
+
+        // Calculate thumb position and size
+        let content_ratio = node.height as f32 / node.content_height as f32;
+        let thumb_height = ((scrollbar_height as f32 * content_ratio).ceil() as u16).max(1);
+        let scroll_ratio =
+            node.scroll_y as f32 / node.content_height.saturating_sub(node.height) as f32;
+        let thumb_y = rendered_y
+            + ((scrollbar_height.saturating_sub(thumb_height) as f32 * scroll_ratio) as u16);
+
+        node.scrollbar.set(Some(ScrollbarRegion {
+            x: scrollbar_x,
+            y: rendered_y,
+            height: scrollbar_height,
+            thumb_y,
+            thumb_height,
+        }));
+
+        // Draw scrollbar track
+        for y in rendered_y..rendered_y + scrollbar_height {
+            if clip_rect.contains_point(scrollbar_x, y) {
+                let (ch, color) = if y >= thumb_y && y < thumb_y + thumb_height {
+                    (scrollbar.thumb_glyph(), scrollbar.thumb_color)
+                } else {
+                    (scrollbar.track_glyph(), scrollbar.track_color)
+                };
+                let mut cell = Cell::new(ch);
+                cell.fg = Some(color);
+                buffer.set_cell(scrollbar_x, y, cell);
+            }
+        }
+    }
+}
+
+/// Renders ▲/▼ hints on the top and bottom rows of a scrollable node.
+///
+/// The top arrow shows while the node is scrolled down, the bottom arrow while
+/// more content remains below the visible area.
+fn render_scroll_indicators(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_scroll_offset: i16,
+) {
+    if node.height == 0 || node.width == 0 {
+        return;
+    }
+
+    // Calculate rendered position with parent scroll offset
+    let rendered_y = if parent_scroll_offset > 0 {
+        node.y.saturating_sub(parent_scroll_offset as u16)
+    } else {
+        node.y
+    };
+    let center_x = node.x + node.width / 2;
+    let bottom_y = rendered_y + node.height - 1;
+
+    let indicators = [
+        (node.scroll_y > 0, rendered_y, '▲'),
+        (node.scroll_y < node.get_max_scroll_y(), bottom_y, '▼'),
+    ];
+    for (visible, y, ch) in indicators {
+        if visible && clip_rect.contains_point(center_x, y) {
+            let mut cell = Cell::new(ch);
+            cell.fg = Some(Color::BrightBlack);
+            cell.bg = buffer.get_cell(center_x, y).and_then(|cell| cell.bg);
+            buffer.set_cell(center_x, y, cell);
+        }
+    }
+}
+
+/// Returns the glyphs for a border style as
+/// `(top_left, top, top_right, left, right, bottom_left, bottom, bottom_right)`.
+fn border_chars(style: BorderStyle) -> (char, char, char, char, char, char, char, char) {
+    match style {
+        BorderStyle::Single => ('┌', '─', '┐', '│', '│', '└', '─', '┘'),
+        BorderStyle::Double => ('╔', '═', '╗', '║', '║', '╚', '═', '╝'),
+        BorderStyle::Thick => ('┏', '━', '┓', '┃', '┃', '┗', '━', '┛'),
+        BorderStyle::Rounded => ('╭', '─', '╮', '│', '│', '╰', '─', '╯'),
+        BorderStyle::Dashed => ('┌', '╌', '┐', '╎', '╎', '└', '╌', '┘'),
+    }
+}
+
+/// Detects a single-edge border on a node that is one cell thick.
+///
+/// Returns `Some(false)` for a one-row node with only the top edge and
+/// `Some(true)` for a one-column node with only the left edge.
+fn thin_line_orientation(node: &RenderNode, edges: BorderEdges) -> Option<bool> {
+    if node.height == 1 && node.width > 0 && edges == BorderEdges::TOP {
+        Some(false)
+    } else if node.width == 1 && node.height > 0 && edges == BorderEdges::LEFT {
+        Some(true)
+    } else {
+        None
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -862,7 +1389,7 @@ mod tests {
     use crate::{
         buffer::ScreenBuffer,
         render_tree::RenderNode,
-        style::{Color, Style},
+        style::{Color, ScrollbarStyle, Style},
     };
     use std::cell::RefCell;
     use std::rc::Rc;
@@ -1759,61 +2286,352 @@ mod tests {
             assert_eq!(child4.x, 20); // Last item at end (30 - 10 = 20)
         }
     }
-}
 
-/// Renders scrollbar indicators for a scrollable node.
-///
-/// Shows vertical scrollbar when content exceeds viewport.
-fn render_scrollbars(
-    node: &RenderNode,
-    buffer: &mut ScreenBuffer,
-    clip_rect: &Rect,
-    parent_scroll_offset: i16,
-) {
-    // Determine if scrollbar is needed
-    let needs_scrollbar = node.content_height > node.height;
+    fn render_scrolled_list(scroll_y: u16) -> ScreenBuffer {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
 
-    // Only show scrollbar for Auto mode if content overflows
-    if let Some(style) = &node.style
-        && let Some(Overflow::Auto) = style.overflow
-        && !needs_scrollbar
-    {
-        return;
+        let node: VNode = Div::new()
+            .width(10)
+            .height(3)
+            .overflow(Overflow::Scroll)
+            .show_scrollbar(false)
+            .scroll_indicators(true)
+            .children(
+                (0..6)
+                    .map(|i| Text::new(format!("item {i}")).into())
+                    .collect(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 3);
+
+        let mut buffer = ScreenBuffer::new(10, 3);
+        let clip_rect = crate::Rect::new(0, 0, 10, 3);
+        if let Some(root) = &vdom.get_render_tree().root {
+            root.borrow_mut().set_scroll_y(scroll_y);
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+        buffer
     }
 
-    // Calculate rendered position with parent scroll offset
-    let rendered_y = if parent_scroll_offset > 0 {
-        node.y.saturating_sub(parent_scroll_offset as u16)
-    } else {
-        node.y
-    };
-    let rendered_x = node.x;
+    #[test]
+    fn test_scroll_indicators_follow_scroll_position() {
+        // At the top only the bottom indicator shows
+        let buffer = render_scrolled_list(0);
+        assert_eq!(buffer.get_cell(5, 0).unwrap().char, '0');
+        assert_eq!(buffer.get_cell(5, 2).unwrap().char, '▼');
+
+        // In the middle both show
+        let buffer = render_scrolled_list(1);
+        assert_eq!(buffer.get_cell(5, 0).unwrap().char, '▲');
+        assert_eq!(buffer.get_cell(5, 2).unwrap().char, '▼');
+
+        // At the end only the top indicator shows
+        let buffer = render_scrolled_list(3);
+        assert_eq!(buffer.get_cell(5, 0).unwrap().char, '▲');
+        assert_eq!(buffer.get_cell(5, 2).unwrap().char, '5');
+    }
 
-    // Vertical scrollbar
-    if needs_scrollbar && node.height > 2 {
-        let scrollbar_x = rendered_x + node.width.saturating_sub(1);
-        let scrollbar_height = node.height;
+    #[test]
+    fn test_center_aligned_wrapped_text_pads_both_sides() {
+        use crate::node::{Div, Text};
+        use crate::style::{TextAlign, TextWrap};
+
+        let node = Div::new()
+            .width(12)
+            .child(
+                Text::new("aa bbbbbbbbbb")
+                    .wrap(TextWrap::Word)
+                    .align(TextAlign::Center)
+                    .background(Color::Blue)
+                    .into(),
+            )
+            .into();
+        let buffer = crate::components::render_to_buffer(node, 14, 3);
+        let lines = buffer.lines();
 
-        // Calculate thumb position and size
-        let content_ratio = node.height as f32 / node.content_height as f32;
-        let thumb_height = ((scrollbar_height as f32 * content_ratio).ceil() as u16).max(1);
-        let scroll_ratio =
-            node.scroll_y as f32 / node.content_height.saturating_sub(node.height) as f32;
-        let thumb_y = rendered_y
-            + ((scrollbar_height.saturating_sub(thumb_height) as f32 * scroll_ratio) as u16);
+        // The short line is centered, ignoring the space it was wrapped at
+        assert_eq!(lines[0], "     aa       ");
+        assert_eq!(lines[1], " bbbbbbbbbb   ");
 
-        // Draw scrollbar track
-        for y in rendered_y..rendered_y + scrollbar_height {
-            if clip_rect.contains_point(scrollbar_x, y) {
-                let ch = if y >= thumb_y && y < thumb_y + thumb_height {
-                    '█' // Thumb
-                } else {
-                    '│' // Track
-                };
-                let mut cell = Cell::new(ch);
-                cell.fg = Some(Color::BrightBlack);
-                buffer.set_cell(scrollbar_x, y, cell);
+        // Padding on both sides carries the background up to the content width
+        for y in 0..2 {
+            for x in 0..12 {
+                assert_eq!(buffer.get_cell(x, y).unwrap().bg, Some(Color::Blue));
             }
+            assert_eq!(buffer.get_cell(12, y).unwrap().bg, None);
         }
     }
+
+    #[test]
+    fn test_right_aligned_wrapped_rich_text() {
+        use crate::node::{Div, RichText};
+        use crate::style::{TextAlign, TextWrap};
+
+        let node = Div::new()
+            .width(12)
+            .child(
+                RichText::new()
+                    .text("aa ")
+                    .colored("bbbbbbbbbb", Color::Red)
+                    .wrap(TextWrap::Word)
+                    .align(TextAlign::Right)
+                    .into(),
+            )
+            .into();
+        let lines = crate::components::render_to_buffer(node, 14, 3).lines();
+
+        assert_eq!(lines[0], "          aa  ");
+        assert_eq!(lines[1], "  bbbbbbbbbb  ");
+    }
+
+    #[test]
+    fn test_translucent_overlay_blends_with_background() {
+        use crate::node::{Div, Text};
+
+        let white = Color::Rgb(255, 255, 255);
+        let overlay = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(4)
+            .height(1)
+            .background(Color::Rgb(0, 0, 0))
+            .opacity(0.5);
+        let node = Div::new()
+            .width(8)
+            .height(2)
+            .background(white)
+            .child(Text::new("ab").color(Color::Rgb(0, 0, 255)).into())
+            .child(overlay.into())
+            .into();
+        let buffer = crate::components::render_to_buffer(node, 8, 2);
+
+        // Under the overlay the white turns mid-gray and text stays visible, dimmed
+        let cell = buffer.get_cell(0, 0).unwrap();
+        assert_eq!(cell.bg, Some(Color::Rgb(128, 128, 128)));
+        assert_eq!(cell.char, 'a');
+        assert_eq!(cell.fg, Some(Color::Rgb(0, 0, 128)));
+        assert_eq!(
+            buffer.get_cell(3, 0).unwrap().bg,
+            Some(Color::Rgb(128, 128, 128))
+        );
+
+        // Outside the overlay nothing changes
+        assert_eq!(buffer.get_cell(4, 0).unwrap().bg, Some(white));
+        assert_eq!(buffer.get_cell(0, 1).unwrap().bg, Some(white));
+    }
+
+    #[test]
+    fn test_text_skips_parent_background_when_inheritance_disabled() {
+        use crate::node::{Div, Text};
+
+        let colored = |inherit: bool| {
+            Div::new()
+                .width(6)
+                .height(1)
+                .background(Color::Blue)
+                .inherit_background(inherit)
+                .child(Text::new("hi").into())
+                .into()
+        };
+
+        let buffer = crate::components::render_to_buffer(colored(true), 6, 1);
+        assert_eq!(buffer.get_cell(0, 0).unwrap().bg, Some(Color::Blue));
+
+        let buffer = crate::components::render_to_buffer(colored(false), 6, 1);
+        assert_eq!(buffer.get_cell(0, 0).unwrap().char, 'h');
+        assert_eq!(buffer.get_cell(0, 0).unwrap().bg, None);
+        assert_eq!(buffer.get_cell(1, 0).unwrap().bg, None);
+        // The element still fills the cells around the text
+        assert_eq!(buffer.get_cell(4, 0).unwrap().bg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_fill_char_fills_interior() {
+        use crate::node::{Div, Text};
+
+        let panel = |fill: char| {
+            Div::new()
+                .width(5)
+                .height(3)
+                .border(BorderStyle::Single)
+                .background(Color::Blue)
+                .fill_char(fill)
+                .child(Text::new("a").into())
+                .into()
+        };
+
+        let buffer = crate::components::render_to_buffer(panel('.'), 5, 3);
+        assert_eq!(buffer.lines(), ["┌───┐", "│a..│", "└───┘"]);
+        assert_eq!(buffer.get_cell(2, 1).unwrap().bg, Some(Color::Blue));
+
+        // Wide glyphs can't tile a cell grid, so the area stays blank
+        let buffer = crate::components::render_to_buffer(panel('界'), 5, 3);
+        assert_eq!(buffer.lines()[1], "│a  │");
+    }
+
+    fn render_with_scrollbar(scrollbar: ScrollbarStyle) -> ScreenBuffer {
+        use crate::node::{Div, Text};
+
+        let node = Div::new()
+            .width(10)
+            .height(4)
+            .overflow(Overflow::Scroll)
+            .show_scrollbar(true)
+            .scrollbar_style(scrollbar)
+            .children((0..8).map(|i| Text::new(format!("{i}")).into()).collect())
+            .into();
+        crate::components::render_to_buffer(node, 10, 4)
+    }
+
+    #[test]
+    fn test_custom_scrollbar_glyphs_and_colors() {
+        let buffer = render_with_scrollbar(
+            ScrollbarStyle::default()
+                .track('░', Color::Blue)
+                .thumb('▓', Color::Yellow),
+        );
+
+        // Half the content is visible, so the thumb covers the top two rows
+        let column: Vec<_> = (0..4)
+            .map(|y| {
+                let cell = buffer.get_cell(9, y).unwrap();
+                (cell.char, cell.fg)
+            })
+            .collect();
+        assert_eq!(
+            column,
+            [
+                ('▓', Some(Color::Yellow)),
+                ('▓', Some(Color::Yellow)),
+                ('░', Some(Color::Blue)),
+                ('░', Some(Color::Blue)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scrollbar_on_left_and_wide_glyphs_fall_back() {
+        let buffer = render_with_scrollbar(
+            ScrollbarStyle::default()
+                .thumb('表', Color::Green)
+                .position(ScrollbarPosition::Left),
+        );
+
+        let thumb = buffer.get_cell(0, 0).unwrap();
+        assert_eq!(thumb.char, ScrollbarStyle::DEFAULT_THUMB);
+        assert_eq!(thumb.fg, Some(Color::Green));
+        assert_eq!(buffer.get_cell(0, 3).unwrap().char, '│');
+        assert_ne!(buffer.get_cell(9, 0).unwrap().char, '█');
+    }
+
+    /// Component drawing a padded box around a smaller padded one
+    struct Boxes;
+
+    impl crate::Component for Boxes {
+        fn view(&self, _ctx: &crate::Context) -> crate::Node {
+            use crate::{Div, Spacing, Text};
+            Div::new()
+                .width(20)
+                .height(6)
+                .padding(Spacing::all(1))
+                .child(
+                    Div::new()
+                        .width(8)
+                        .height(3)
+                        .padding(Spacing::all(1))
+                        .child(Text::new("hi").into())
+                        .into(),
+                )
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_sticky_header_pins_to_viewport_top() {
+        use crate::node::{Div, Text};
+        use crate::style::{Dimension, Direction, Position};
+
+        let section = |name: &str| {
+            let header = Div::new()
+                .position(Position::Sticky)
+                .background(Color::Blue)
+                .width_dim(Dimension::Percentage(1.0))
+                .child(Text::new(name.to_uppercase()).into());
+            let rows = (1..=3).map(|i| Text::new(format!("{name}{i}")).into());
+            Div::new()
+                .direction(Direction::Vertical)
+                .children(std::iter::once(header.into()).chain(rows).collect())
+        };
+        let node: crate::Node = Div::new()
+            .direction(Direction::Vertical)
+            .overflow(Overflow::Scroll)
+            .show_scrollbar(false)
+            .width(4)
+            .height(4)
+            .child(section("a").into())
+            .child(section("b").into())
+            .into();
+        let vdom = crate::components::layout_node(node, 4, 4);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+
+        let lines_at = |scroll_y: u16| {
+            root.borrow_mut().set_scroll_y(scroll_y);
+            let mut buffer = ScreenBuffer::new(4, 4);
+            render_node_to_buffer(&root.borrow(), &mut buffer, &Rect::new(0, 0, 4, 4), None);
+            buffer
+                .lines()
+                .iter()
+                .map(|line| line.trim_end().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Section A is partly scrolled out, so its header covers a1
+        assert_eq!(lines_at(2), ["A", "a3", "B", "b1"]);
+        // Only the header's own row of section A is left
+        assert_eq!(lines_at(3), ["A", "B", "b1", "b2"]);
+        // Section A is gone and B's header sits in its natural place
+        assert_eq!(lines_at(4), ["B", "b1", "b2", "b3"]);
+        assert_eq!(lines_at(0), ["A", "a1", "a2", "a3"]);
+    }
+
+    #[test]
+    fn test_debug_layout_outlines_every_node() {
+        let backend = crate::TestBackend::new(24, 8);
+        let config = crate::app::config::RenderConfig {
+            debug_layout: true,
+            ..Default::default()
+        };
+        let mut runner = crate::App::with_backend(backend.clone())
+            .unwrap()
+            .render_config(config)
+            .into_runner(Boxes);
+        runner.process_message_queue();
+        runner.render_frame().unwrap();
+
+        assert_eq!(backend.line(0), "┌20x6──────────────┐");
+        assert_eq!(backend.line(1), "│┌8x3───┐          │");
+        assert_eq!(backend.line(2), "││hi    │          │");
+        assert_eq!(backend.line(3), "│└──────┘          │");
+        assert_eq!(backend.line(5), "└──────────────────┘");
+        assert_eq!(backend.line(6), "");
+        assert_eq!(backend.cell_at(1, 1).unwrap().fg, Some(DEBUG_OUTLINE_COLOR));
+
+        // Padding is tinted, content is not
+        assert_eq!(backend.cell_at(0, 2).unwrap().bg, Some(DEBUG_PADDING_TINT));
+        assert_eq!(backend.cell_at(5, 2).unwrap().bg, None);
+    }
 }
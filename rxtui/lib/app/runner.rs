@@ -0,0 +1,1622 @@
+use crate::app::Context;
+use crate::component::{Component, ComponentId};
+use crate::key::{Key, KeyWithModifiers};
+use crate::vnode::VNode;
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::terminal;
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::core::App;
+use super::panic::PanicHookGuard;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// State carried between iterations of the event loop.
+pub(crate) struct LoopState {
+    /// Context shared by every component of the app
+    pub(super) context: Context,
+
+    /// Every component expanded so far, keyed by its position in the tree
+    pub(super) components: HashMap<ComponentId, Arc<dyn Component>>,
+
+    /// Components mounted by the last expansion, used to deliver broadcasts
+    pub(super) mounted: Vec<ComponentId>,
+
+    /// Identifier of the root component
+    pub(super) root_id: ComponentId,
+
+    /// Roots of the screens covered by navigation, the most recent last
+    pub(super) screens: Vec<ComponentId>,
+
+    /// Tree produced by the last expansion, waiting to be rendered
+    pub(super) vnode_tree: Option<VNode>,
+
+    /// Whether an event or message since the last frame requires a render
+    pub(super) needs_render: bool,
+}
+
+/// An app driven step by step from the caller's own loop.
+///
+/// Created with [`App::into_runner`]. Each step of [`App::run`] is exposed on its
+/// own so rxtui can be interleaved with other event sources:
+///
+/// ```text
+///     ┌────────────────────────┐
+///     │ process_message_queue  │ ◄── update components, expand the tree
+///     └───────────┬────────────┘
+///     ┌───────────▼────────────┐
+///     │      render_frame      │ ◄── draw if anything changed
+///     └───────────┬────────────┘
+///     ┌───────────▼────────────┐
+///     │ poll_event(timeout)    │ ◄── read and dispatch terminal input
+///     └───────────┬────────────┘
+///                 ▼
+///       your own I/O, then repeat while is_running()
+/// ```
+pub struct Runner {
+    app: App,
+    state: LoopState,
+
+    /// Restores the terminal if a step panics; absent for headless apps
+    _panic_hook: Option<PanicHookGuard>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl LoopState {
+    /// Unmounts the components and saves persistent state when the loop ends.
+    pub(super) fn finish(&mut self) -> io::Result<()> {
+        self.unmount_all();
+
+        #[cfg(feature = "serde")]
+        self.context.persistence.save_all(&self.context.states)?;
+
+        Ok(())
+    }
+
+    /// Unmounts everything still in the tree, children first and the root last.
+    pub(super) fn unmount_all(&mut self) {
+        let mut mounted = std::mem::take(&mut self.mounted);
+        if !mounted.is_empty() {
+            let root_id = mounted.remove(0);
+            mounted.sort_by(|a, b| b.0.cmp(&a.0));
+            mounted.push(root_id);
+        }
+        for comp_id in mounted {
+            #[cfg(feature = "effects")]
+            self.context.tasks.cancel(&comp_id);
+            self.context.topics.unsubscribe(&comp_id);
+            if let Some(component) = self.components.get(&comp_id) {
+                self.context.current_component_id = comp_id;
+                component.on_unmount(&self.context);
+            }
+        }
+    }
+}
+
+impl Runner {
+    pub(super) fn new(app: App, state: LoopState) -> Self {
+        let _panic_hook = (!app.is_headless()).then(|| {
+            PanicHookGuard::install(|| {
+                let _ = crate::terminal::restore_terminal(
+                    &mut io::stdout(),
+                    terminal::disable_raw_mode,
+                );
+            })
+        });
+
+        Self {
+            app,
+            state,
+            _panic_hook,
+        }
+    }
+
+    /// Returns true until a component or exit key made the app exit.
+    pub fn is_running(&self) -> bool {
+        self.app.is_running()
+    }
+
+    /// Waits up to `timeout` for a terminal event and dispatches it to the UI.
+    /// Returns whether an event was handled.
+    pub fn poll_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        self.app.poll_event(&mut self.state, timeout)
+    }
+
+    /// Delivers pending messages to their components and expands the component
+    /// tree for the next frame.
+    pub fn process_message_queue(&mut self) {
+        self.app.process_message_queue(&mut self.state);
+    }
+
+    /// Draws the tree expanded by the last [`Runner::process_message_queue`] if
+    /// anything changed since the previous frame, or redraws animated text.
+    /// Returns whether a frame was drawn.
+    pub fn render_frame(&mut self) -> io::Result<bool> {
+        self.app.render_frame(&mut self.state)
+    }
+
+    /// Handles pending messages and draws the resulting frame, returning its
+    /// rows with trailing spaces trimmed.
+    pub fn step_frame(&mut self) -> io::Result<Vec<String>> {
+        self.process_message_queue();
+        self.render_frame()?;
+        Ok(self
+            .screen_lines()
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect())
+    }
+
+    /// Injects a key press, routed exactly like one read from the terminal.
+    ///
+    /// The event is dispatched to the tree drawn by the last
+    /// [`Runner::render_frame`]; messages it sends are handled by the next
+    /// [`Runner::process_message_queue`].
+    pub fn send_key(&mut self, key: Key) -> io::Result<()> {
+        self.send_key_with_modifiers(KeyWithModifiers::new(key))
+    }
+
+    /// Injects a key press with modifiers held.
+    pub fn send_key_with_modifiers(&mut self, key: KeyWithModifiers) -> io::Result<()> {
+        self.app
+            .handle_event(&mut self.state, Event::Key(key.to_key_event()))
+    }
+
+    /// Injects a mouse event, routed exactly like one read from the terminal.
+    pub fn send_mouse(&mut self, mouse_event: MouseEvent) -> io::Result<()> {
+        self.app
+            .handle_event(&mut self.state, Event::Mouse(mouse_event))
+    }
+
+    /// Injects a terminal resize, as if the window changed to `width` x `height`.
+    pub fn resize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        self.app
+            .handle_event(&mut self.state, Event::Resize(width, height))
+    }
+
+    /// Injects a left click at the given cell.
+    pub fn click(&mut self, column: u16, row: u16) -> io::Result<()> {
+        self.send_mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    /// Moves the app's clock forward, expiring timeouts such as toasts on the
+    /// next [`Runner::process_message_queue`].
+    ///
+    /// Headless apps start with a clock that only moves when advanced here.
+    pub fn advance_clock(&mut self, by: Duration) {
+        self.state.context.clock.advance(by);
+    }
+
+    /// Returns the root context, e.g. to send messages from other event sources.
+    pub fn context(&self) -> &Context {
+        &self.state.context
+    }
+
+    /// Returns the text of each row of the last drawn frame.
+    pub fn screen_lines(&self) -> Vec<String> {
+        self.app.screen_lines()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Drop for Runner {
+    fn drop(&mut self) {
+        let _ = self.state.finish();
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{Action, Message, MessageExt};
+    use crate::keymap::KeyMap;
+    use crate::node::{Div, Node, Text};
+    use std::any::Any;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone)]
+    struct Increment;
+
+    #[derive(Debug, Clone, Default)]
+    struct Count(u32);
+
+    struct Counter;
+
+    impl Component for Counter {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Increment>().is_some() {
+                let Count(count) = ctx.get_state::<Count>();
+                return Action::update(Count(count + 1));
+            }
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Count(count) = ctx.get_state::<Count>();
+            Text::new(format!("count: {count}")).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_manual_steps_update_state_and_render() {
+        let mut runner = App::headless(12, 2).into_runner(Counter);
+        assert!(runner.is_running());
+
+        // Nothing is drawn before the first step
+        assert_eq!(runner.screen_lines()[0].trim_end(), "");
+
+        runner.process_message_queue();
+        assert!(runner.render_frame().unwrap());
+        assert_eq!(runner.screen_lines()[0].trim_end(), "count: 0");
+
+        // Nothing changed, so no frame is drawn
+        runner.process_message_queue();
+        assert!(!runner.render_frame().unwrap());
+
+        // Messages from outside the app are picked up by the next step
+        runner.context().broadcast(Increment);
+        runner.context().broadcast(Increment);
+        runner.process_message_queue();
+        assert!(runner.render_frame().unwrap());
+        assert_eq!(runner.screen_lines()[0].trim_end(), "count: 2");
+
+        // Headless apps have no terminal input to wait for
+        assert!(!runner.poll_event(Duration::from_secs(1)).unwrap());
+        assert!(runner.is_running());
+    }
+
+    struct Ticker;
+
+    impl Component for Ticker {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let elapsed = ctx.elapsed().as_millis();
+            Text::new(format!("{} {elapsed}", ctx.frame_count())).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_frame_count_and_elapsed_follow_steps() {
+        let mut runner = App::headless(12, 1).into_runner(Ticker);
+        assert_eq!(runner.context().frame_count(), 0);
+        let start = runner.context().elapsed();
+
+        for _ in 0..3 {
+            runner.context().request_render();
+            runner.advance_clock(Duration::from_millis(16));
+            runner.step_frame().unwrap();
+        }
+
+        assert_eq!(runner.context().frame_count(), 3);
+        assert_eq!(
+            runner.context().elapsed() - start,
+            Duration::from_millis(48)
+        );
+        // The view of the third frame saw the two frames drawn before it
+        let elapsed = (start + Duration::from_millis(48)).as_millis();
+        assert_eq!(runner.step_frame().unwrap()[0], format!("2 {elapsed}"));
+    }
+
+    /// Sink standing in for a stdout that is piped instead of a terminal.
+    #[derive(Clone, Default)]
+    struct PipedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl io::Write for PipedOutput {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_non_tty_app_prints_single_plain_frame() {
+        let output = PipedOutput::default();
+        let mut app = App::with_plain_output(output.clone(), 12, 3).unwrap();
+
+        // Returns after the first frame instead of waiting for input
+        app.run(Counter).unwrap();
+
+        let printed = String::from_utf8(output.0.borrow().clone()).unwrap();
+        assert_eq!(printed, "count: 0\n");
+        assert!(!printed.contains('\x1b'));
+    }
+
+    #[derive(Debug, Clone)]
+    enum NavMsg {
+        Open,
+        Close,
+    }
+
+    struct Home;
+
+    struct Details;
+
+    impl Component for Home {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+            match msg.downcast::<NavMsg>() {
+                Some(NavMsg::Open) => Action::navigate(Details),
+                Some(NavMsg::Close) => Action::back(),
+                None => Counter.update(ctx, msg, topic),
+            }
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Count(count) = ctx.get_state::<Count>();
+            Text::new(format!("home: {count}")).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for Details {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+            match msg.downcast::<NavMsg>() {
+                Some(NavMsg::Close) => Action::back(),
+                Some(NavMsg::Open) => Action::none(),
+                None => Counter.update(ctx, msg, topic),
+            }
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Count(count) = ctx.get_state::<Count>();
+            Text::new(format!("details: {count}")).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_navigate_pushes_screen_and_back_restores_previous() {
+        let mut runner = App::headless(12, 1).into_runner(Home);
+        assert_eq!(runner.step_frame().unwrap()[0], "home: 0");
+
+        // Back on the first screen has nowhere to go
+        runner.context().broadcast(NavMsg::Close);
+        runner.context().broadcast(Increment);
+        assert_eq!(runner.step_frame().unwrap()[0], "home: 1");
+
+        runner.context().broadcast(NavMsg::Open);
+        assert_eq!(runner.step_frame().unwrap()[0], "details: 0");
+        runner.context().broadcast(Increment);
+        assert_eq!(runner.step_frame().unwrap()[0], "details: 1");
+
+        // The covered screen kept its state
+        runner.context().broadcast(NavMsg::Close);
+        assert_eq!(runner.step_frame().unwrap()[0], "home: 1");
+
+        // The popped screen was forgotten, so opening it again starts afresh
+        runner.context().broadcast(NavMsg::Open);
+        assert_eq!(runner.step_frame().unwrap()[0], "details: 0");
+        assert!(runner.is_running());
+    }
+
+    #[derive(Debug, Clone)]
+    enum BatchMsg {
+        Save,
+        Saved,
+        Quit,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct BatchState {
+        saves: u32,
+        confirmed: u32,
+    }
+
+    struct Batching;
+
+    impl Component for Batching {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            let Some(msg) = msg.downcast::<BatchMsg>() else {
+                return Action::none();
+            };
+            let mut state = ctx.get_state::<BatchState>();
+            match msg {
+                BatchMsg::Save => {
+                    state.saves += 1;
+                    Action::batch([Action::update(state), Action::send(BatchMsg::Saved)])
+                }
+                BatchMsg::Saved => {
+                    state.confirmed += 1;
+                    Action::update(state)
+                }
+                BatchMsg::Quit => {
+                    state.saves += 1;
+                    let mut skipped = state.clone();
+                    skipped.saves += 10;
+                    Action::batch([
+                        Action::update(state),
+                        Action::exit(),
+                        Action::update(skipped),
+                    ])
+                }
+            }
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let state = ctx.get_state::<BatchState>();
+            Text::new(format!("{} {}", state.saves, state.confirmed)).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_batch_applies_update_and_send() {
+        let mut runner = App::headless(12, 1).into_runner(Batching);
+        assert_eq!(runner.step_frame().unwrap()[0], "0 0");
+
+        runner.context().broadcast(BatchMsg::Save);
+        assert_eq!(runner.step_frame().unwrap()[0], "1 0");
+        // The follow-up message is handled on the next update cycle
+        assert_eq!(runner.step_frame().unwrap()[0], "1 1");
+    }
+
+    #[test]
+    fn test_exit_in_batch_stops_app_and_skips_rest() {
+        let mut runner = App::headless(12, 1).into_runner(Batching);
+        runner.step_frame().unwrap();
+
+        runner.context().broadcast(BatchMsg::Quit);
+        runner.process_message_queue();
+        assert!(!runner.is_running());
+
+        let state = runner.context().get_state::<BatchState>();
+        assert_eq!(state.saves, 1);
+    }
+
+    #[derive(Debug, Clone)]
+    enum FormMsg {
+        Focused(&'static str),
+        Clicked,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct FormState {
+        focused: &'static str,
+        clicks: u32,
+    }
+
+    struct Form;
+
+    impl Component for Form {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            let Some(msg) = msg.downcast::<FormMsg>() else {
+                return Action::none();
+            };
+            let mut state = ctx.get_state::<FormState>();
+            match msg {
+                FormMsg::Focused(name) => state.focused = name,
+                FormMsg::Clicked => state.clicks += 1,
+            }
+            Action::update(state)
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let state = ctx.get_state::<FormState>();
+            let field = |name: &'static str| {
+                Div::new()
+                    .focusable(true)
+                    .on_focus(ctx.handler(FormMsg::Focused(name)))
+                    .child(Text::new(name).into())
+                    .into()
+            };
+            Div::new()
+                .child(Text::new(format!("{} {}", state.focused, state.clicks)).into())
+                .child(field("first"))
+                .child(field("second"))
+                .child(
+                    Div::new()
+                        .on_click(ctx.handler(FormMsg::Clicked))
+                        .child(Text::new("[ok]").into())
+                        .into(),
+                )
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_injected_tab_moves_focus() {
+        let mut runner = App::headless(20, 4).into_runner(Form);
+        assert_eq!(runner.step_frame().unwrap()[0], " 0");
+
+        runner.send_key(Key::Tab).unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], "first 0");
+
+        runner.send_key(Key::Tab).unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], "second 0");
+
+        runner
+            .send_key_with_modifiers(KeyWithModifiers::with_shift(Key::BackTab))
+            .unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], "first 0");
+    }
+
+    #[test]
+    fn test_injected_click_fires_handler() {
+        let mut runner = App::headless(20, 4).into_runner(Form);
+        runner.step_frame().unwrap();
+        assert_eq!(runner.screen_lines()[3].trim_end(), "[ok]");
+
+        runner.click(1, 3).unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], " 1");
+
+        // Clicks outside any handler change nothing
+        runner.click(15, 3).unwrap();
+        runner.click(1, 3).unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], " 2");
+    }
+
+    struct SizeReport;
+
+    impl Component for SizeReport {
+        fn view(&self, ctx: &Context) -> Node {
+            let (width, height) = ctx.terminal_size();
+            Text::new(format!("{width}x{height}")).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Binds Ctrl+Z through a handler, a keymap, or not at all.
+    struct Undo(Option<bool>);
+
+    impl Component for Undo {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            let ctrl_z = KeyWithModifiers::with_ctrl(Key::Char('z'));
+            match self.0 {
+                Some(true) => Div::new().on_key_with_modifiers_global(ctrl_z, || {}),
+                Some(false) => Div::new().keymap(KeyMap::new().bind_keys(vec![ctrl_z], || {})),
+                None => Div::new(),
+            }
+            .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_ctrl_z_suspends_unless_bound_or_disabled() {
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let ctrl_z = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+        let suspends = |app: App, undo: Undo| {
+            let mut runner = app.into_runner(undo);
+            runner.step_frame().unwrap();
+            runner.app.is_suspend_request(&ctrl_z)
+        };
+
+        assert!(suspends(App::headless(10, 1), Undo(None)));
+        assert!(!suspends(App::headless(10, 1), Undo(Some(true))));
+        assert!(!suspends(App::headless(10, 1), Undo(Some(false))));
+        assert!(!suspends(
+            App::headless(10, 1).suspend_on_ctrl_z(false),
+            Undo(None)
+        ));
+    }
+
+    #[test]
+    fn test_view_sees_terminal_size_after_resize() {
+        let mut runner = App::headless(20, 2).into_runner(SizeReport);
+        assert_eq!(runner.step_frame().unwrap()[0], "20x2");
+
+        runner.resize(30, 5).unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], "30x5");
+        assert_eq!(runner.context().terminal_size(), (30, 5));
+        assert_eq!(runner.screen_lines().len(), 5);
+    }
+
+    struct Blinking;
+
+    impl Component for Blinking {
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("alert")
+                .animation(crate::style::Animation::Blink {
+                    period: Duration::from_millis(200),
+                })
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_blinking_text_toggles_every_period() {
+        let mut runner = App::headless(10, 1).into_runner(Blinking);
+
+        // Animated text keeps drawing frames although nothing else changes, and
+        // follows the clock however many frames are drawn in between
+        let mut frames = Vec::new();
+        for _ in 0..6 {
+            frames.push(runner.step_frame().unwrap().remove(0));
+            assert_eq!(runner.step_frame().unwrap()[0], frames[frames.len() - 1]);
+            runner.advance_clock(Duration::from_millis(100));
+        }
+        assert_eq!(frames, ["alert", "alert", "", "", "alert", "alert"]);
+        assert!(runner.render_frame().unwrap());
+    }
+
+    /// Row of texts where the middle ones come from nested fragments
+    struct Row;
+
+    impl Component for Row {
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .direction(crate::Direction::Horizontal)
+                .gap(1)
+                .child(Text::new("a").into())
+                .child(Node::fragment(vec![
+                    Text::new("b").into(),
+                    Node::fragment(vec![Text::new("c").into()]),
+                ]))
+                .child(Text::new("d").into())
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_fragment_children_are_siblings_in_parent() {
+        let mut runner = App::headless(12, 2).into_runner(Row);
+
+        // The gap applies between every text, so none of them share a wrapper box
+        assert_eq!(runner.step_frame().unwrap()[0], "a b c d");
+        assert_eq!(runner.screen_lines()[1].trim_end(), "");
+    }
+
+    /// Renders a label and a counter as two root nodes
+    struct Labeled;
+
+    impl Component for Labeled {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Node::fragment(vec![
+                Text::new("a").into(),
+                Node::Component(Arc::new(Counter)),
+            ])
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct LabeledRow;
+
+    impl Component for LabeledRow {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .direction(crate::Direction::Horizontal)
+                .gap(1)
+                .child(Node::Component(Arc::new(Labeled)))
+                .child(Text::new("z").into())
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_component_fragment_roots_are_siblings_with_stable_state() {
+        let mut runner = App::headless(20, 2).into_runner(LabeledRow);
+        assert_eq!(runner.step_frame().unwrap()[0], "a count: 0 z");
+
+        // The counter keeps its id, and so its state, across renders
+        for expected in ["a count: 1 z", "a count: 2 z"] {
+            runner.context().broadcast(Increment);
+            assert_eq!(runner.step_frame().unwrap()[0], expected);
+        }
+    }
+
+    /// Scroll area whose last child is a lazy panel below the fold
+    struct Feed;
+
+    impl Component for Feed {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .width(10)
+                .height(4)
+                .overflow(crate::Overflow::Scroll)
+                .child(Text::new("top").into())
+                .child(Div::new().height(3).into())
+                .child(
+                    Div::new()
+                        .height(2)
+                        .lazy(true)
+                        .child(Text::new("panel").into())
+                        .into(),
+                )
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn scroll(runner: &mut Runner, kind: MouseEventKind) {
+        runner
+            .send_mouse(MouseEvent {
+                kind,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+            .unwrap();
+        runner.step_frame().unwrap();
+    }
+
+    #[test]
+    fn test_lazy_children_are_built_only_while_visible() {
+        let mut runner = App::headless(10, 4).into_runner(Feed);
+        runner.step_frame().unwrap();
+        assert!(!runner.app.render_tree_debug_string().contains("panel"));
+
+        scroll(&mut runner, MouseEventKind::ScrollDown);
+        assert!(runner.app.render_tree_debug_string().contains("panel"));
+        assert!(runner.screen_lines()[2].starts_with("panel"));
+
+        // Scrolling back out of view unmounts the children again
+        scroll(&mut runner, MouseEventKind::ScrollUp);
+        assert!(!runner.app.render_tree_debug_string().contains("panel"));
+    }
+
+    /// Scroll area listing lazy rows, far more than fit on screen
+    struct LazyList;
+
+    impl Component for LazyList {
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .width(10)
+                .height(4)
+                .overflow(crate::Overflow::Scroll)
+                .children(
+                    (0..20)
+                        .map(|row| {
+                            Div::new()
+                                .height(1)
+                                .lazy(true)
+                                .child(Text::new(format!("row {row}")).into())
+                                .into()
+                        })
+                        .collect(),
+                )
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_lazy_children_scrolled_above_the_screen_are_unmounted() {
+        let mut runner = App::headless(10, 4).into_runner(LazyList);
+        runner.step_frame().unwrap();
+        let built = |runner: &Runner| {
+            let tree = runner.app.render_tree_debug_string();
+            (0..20)
+                .filter(|row| tree.contains(&format!("row {row}\"")))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(built(&runner), [0, 1, 2, 3]);
+
+        // Rows scrolled off the top are unmounted, not kept as the list grows
+        for _ in 0..3 {
+            scroll(&mut runner, MouseEventKind::ScrollDown);
+        }
+        assert!(runner.screen_lines()[0].starts_with("row 9"));
+        assert_eq!(built(&runner), [9, 10, 11, 12]);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Hide;
+
+    #[derive(Debug, Clone, Default)]
+    struct Hidden(bool);
+
+    /// Counts its lifecycle hooks
+    struct Tracked {
+        mounts: Arc<AtomicUsize>,
+        unmounts: Arc<AtomicUsize>,
+    }
+
+    impl Component for Tracked {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("tracked").into()
+        }
+
+        fn on_mount(&self, _ctx: &Context) {
+            self.mounts.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_unmount(&self, _ctx: &Context) {
+            self.unmounts.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Shows a tracked child until told to hide it
+    struct Host {
+        mounts: Arc<AtomicUsize>,
+        unmounts: Arc<AtomicUsize>,
+    }
+
+    impl Component for Host {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Hide>().is_some() {
+                return Action::update(Hidden(true));
+            }
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Hidden(hidden) = ctx.get_state::<Hidden>();
+            let mut div = Div::new();
+            if !hidden {
+                div = div.child(Node::Component(Arc::new(Tracked {
+                    mounts: self.mounts.clone(),
+                    unmounts: self.unmounts.clone(),
+                })));
+            }
+            div.into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_mount_fires_once_and_unmount_on_removal() {
+        let mounts = Arc::new(AtomicUsize::new(0));
+        let unmounts = Arc::new(AtomicUsize::new(0));
+        let mut runner = App::headless(10, 2).into_runner(Host {
+            mounts: mounts.clone(),
+            unmounts: unmounts.clone(),
+        });
+
+        // Re-rendering keeps the child mounted
+        for _ in 0..3 {
+            runner.context().broadcast(Increment);
+            assert_eq!(runner.step_frame().unwrap()[0], "tracked");
+        }
+        assert_eq!(mounts.load(Ordering::SeqCst), 1);
+        assert_eq!(unmounts.load(Ordering::SeqCst), 0);
+
+        runner.context().broadcast(Hide);
+        assert_eq!(runner.step_frame().unwrap()[0], "");
+        assert_eq!(unmounts.load(Ordering::SeqCst), 1);
+
+        // Already unmounted, so the app exiting doesn't unmount it again
+        drop(runner);
+        assert_eq!(mounts.load(Ordering::SeqCst), 1);
+        assert_eq!(unmounts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_exit_unmounts_components_still_in_tree() {
+        let mounts = Arc::new(AtomicUsize::new(0));
+        let unmounts = Arc::new(AtomicUsize::new(0));
+        let mut runner = App::headless(10, 2).into_runner(Host {
+            mounts: mounts.clone(),
+            unmounts: unmounts.clone(),
+        });
+        runner.step_frame().unwrap();
+
+        drop(runner);
+        assert_eq!(unmounts.load(Ordering::SeqCst), 1);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Revert;
+
+    #[derive(Debug, Clone, Default)]
+    struct Edits(u32);
+
+    /// Counts increments, keeping each count as an undo step
+    struct Editor;
+
+    impl Component for Editor {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Increment>().is_some() {
+                let Edits(edits) = ctx.get_state::<Edits>();
+                return Action::update_with_history(Edits(edits + 1));
+            }
+            if msg.downcast::<Revert>().is_some() {
+                ctx.undo();
+            }
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            Text::new(format!("edits {}", ctx.get_state::<Edits>().0)).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Shows an editor, hiding and showing it again on each `Hide`
+    struct Desk;
+
+    impl Component for Desk {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Hide>().is_some() {
+                let Hidden(hidden) = ctx.get_state::<Hidden>();
+                return Action::update(Hidden(!hidden));
+            }
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Hidden(hidden) = ctx.get_state::<Hidden>();
+            let mut div = Div::new();
+            if !hidden {
+                div = div.child(crate::node! { node(Editor) });
+            }
+            div.into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_remounted_component_does_not_undo_into_previous_instance() {
+        let mut runner = App::headless(10, 2).into_runner(Desk);
+        runner.step_frame().unwrap();
+        runner.context().broadcast(Increment);
+        runner.context().broadcast(Increment);
+        assert_eq!(runner.step_frame().unwrap()[0], "edits 2");
+
+        runner.context().broadcast(Hide);
+        assert_eq!(runner.step_frame().unwrap()[0], "");
+        runner.context().broadcast(Hide);
+        runner.step_frame().unwrap();
+
+        // Only the edit made since remounting can be undone
+        runner.context().broadcast(Increment);
+        assert_eq!(runner.step_frame().unwrap()[0], "edits 3");
+        for _ in 0..2 {
+            runner.context().broadcast(Revert);
+            assert_eq!(runner.step_frame().unwrap()[0], "edits 2");
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct Total(u32);
+
+    /// Owns the "total" topic, adding one on each `Increment`
+    struct Publisher;
+
+    impl Component for Publisher {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Increment>().is_some() {
+                let Total(total) = ctx.read_topic::<Total>("total").unwrap_or_default();
+                return Action::update_topic("total", Total(total + 1));
+            }
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new().into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Shows the "total" topic
+    struct Subscriber;
+
+    impl Component for Subscriber {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Total(total) = ctx.subscribe_topic::<Total>("total").unwrap_or_default();
+            Text::new(format!("total {total}")).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Lays out a subscriber above the publisher it follows
+    struct Dashboard;
+
+    impl Component for Dashboard {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .child(crate::node! { node(Subscriber) })
+                .child(crate::node! { node(Publisher) })
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_subscriber_renders_topic_updated_later_in_the_tree() {
+        let mut runner = App::headless(10, 2).into_runner(Dashboard);
+        assert_eq!(runner.step_frame().unwrap()[0], "total 0");
+
+        // The subscriber is expanded before the publisher updates the topic, yet
+        // the frame already shows the new value
+        runner.context().broadcast(Increment);
+        assert_eq!(runner.step_frame().unwrap()[0], "total 1");
+        runner.context().broadcast(Increment);
+        assert_eq!(runner.step_frame().unwrap()[0], "total 2");
+    }
+
+    #[derive(Debug, Clone)]
+    struct SaveAll;
+
+    /// Records "editors" for each `SaveAll` it receives
+    struct Editors(Arc<Mutex<Vec<&'static str>>>);
+
+    /// Records "status" for each `SaveAll` it receives
+    struct StatusLine(Arc<Mutex<Vec<&'static str>>>);
+
+    impl Component for Editors {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<SaveAll>().is_some() {
+                self.0.lock().unwrap().push("editors");
+            }
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("editors").into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for StatusLine {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<SaveAll>().is_some() {
+                self.0.lock().unwrap().push("status");
+            }
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("status").into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Shows an editor area above a status line
+    struct Workbench(Arc<Mutex<Vec<&'static str>>>);
+
+    impl Component for Workbench {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .child(crate::node! { node(Editors(self.0.clone())) })
+                .child(crate::node! { node(StatusLine(self.0.clone())) })
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_broadcast_reaches_every_component() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut runner = App::headless(20, 2).into_runner(Workbench(received.clone()));
+        runner.step_frame().unwrap();
+
+        runner.context().broadcast(SaveAll);
+        runner.step_frame().unwrap();
+        assert_eq!(*received.lock().unwrap(), ["editors", "status"]);
+
+        // Delivered broadcasts are consumed
+        runner.step_frame().unwrap();
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    /// Records the uid and topic name it sees on every render
+    struct Tagged(Arc<Mutex<Vec<(u64, String)>>>);
+
+    impl Component for Tagged {
+        fn view(&self, ctx: &Context) -> Node {
+            let topic = ctx.unique_topic("selection");
+            self.0.lock().unwrap().push((ctx.component_uid(), topic));
+            Text::new("tag").into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct TwoTags(Arc<Mutex<Vec<(u64, String)>>>);
+
+    impl Component for TwoTags {
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .child(Node::Component(Arc::new(Tagged(self.0.clone()))))
+                .child(Node::Component(Arc::new(Tagged(self.0.clone()))))
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_component_uids_and_topics_are_distinct_and_stable() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut runner = App::headless(10, 2).into_runner(TwoTags(seen.clone()));
+        runner.step_frame().unwrap();
+        runner.step_frame().unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 4);
+        assert_ne!(seen[0].0, seen[1].0);
+        assert_ne!(seen[0].1, seen[1].1);
+        assert!(seen[0].1.starts_with("selection"));
+
+        // The second render sees the same values in the same order
+        assert_eq!(seen[0..2], seen[2..4]);
+    }
+
+    /// Shows a value an effect stores outside component state
+    #[cfg(feature = "effects")]
+    struct Polled {
+        value: Arc<AtomicUsize>,
+        views: Arc<AtomicUsize>,
+    }
+
+    #[cfg(feature = "effects")]
+    impl Component for Polled {
+        fn view(&self, _ctx: &Context) -> Node {
+            self.views.fetch_add(1, Ordering::SeqCst);
+            Text::new(format!("value: {}", self.value.load(Ordering::SeqCst))).into()
+        }
+
+        fn effects(&self, ctx: &Context) -> Vec<crate::effect::Effect> {
+            let ctx = ctx.clone();
+            let value = self.value.clone();
+            vec![Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                value.store(7, Ordering::SeqCst);
+                ctx.request_render();
+            })]
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[cfg(feature = "effects")]
+    #[test]
+    fn test_request_render_from_effect_reruns_view() {
+        let views = Arc::new(AtomicUsize::new(0));
+        let polled = Polled {
+            value: Arc::new(AtomicUsize::new(0)),
+            views: views.clone(),
+        };
+        let mut runner = App::headless(12, 1).into_runner(polled);
+        assert_eq!(runner.step_frame().unwrap()[0], "value: 0");
+        let initial_views = views.load(Ordering::SeqCst);
+
+        let mut rendered = false;
+        for _ in 0..200 {
+            std::thread::sleep(Duration::from_millis(5));
+            runner.process_message_queue();
+            if runner.render_frame().unwrap() {
+                rendered = true;
+                break;
+            }
+        }
+
+        assert!(rendered, "request_render never triggered a frame");
+        assert_eq!(runner.screen_lines()[0].trim_end(), "value: 7");
+        assert!(views.load(Ordering::SeqCst) > initial_views);
+    }
+
+    #[cfg(feature = "effects")]
+    #[test]
+    fn test_spawned_task_result_can_be_taken() {
+        let mut runner = App::headless(12, 1).into_runner(Counter);
+        runner.step_frame().unwrap();
+
+        let task = runner.context().spawn(async { 6 * 7 });
+        for _ in 0..200 {
+            if task.is_finished() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        assert_eq!(task.take(), Some(42));
+        assert_eq!(task.take(), None);
+        // Finishing the task asks for a render so views can pick up the result
+        runner.process_message_queue();
+        assert!(runner.render_frame().unwrap());
+    }
+
+    #[cfg(feature = "effects")]
+    type SharedTask = Arc<Mutex<Option<crate::effect::TaskHandle<()>>>>;
+
+    #[cfg(feature = "effects")]
+    struct Worker(SharedTask);
+
+    #[cfg(feature = "effects")]
+    struct TaskHost(SharedTask);
+
+    #[cfg(feature = "effects")]
+    impl Component for Worker {
+        fn on_mount(&self, ctx: &Context) {
+            let task = ctx.spawn(async { tokio::time::sleep(Duration::from_secs(60)).await });
+            *self.0.lock().unwrap() = Some(task);
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("working").into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[cfg(feature = "effects")]
+    impl Component for TaskHost {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Hide>().is_some() {
+                return Action::update(Count(1));
+            }
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Count(hidden) = ctx.get_state::<Count>();
+            if hidden == 1 {
+                return Text::new("idle").into();
+            }
+            Div::new()
+                .child(Node::Component(Arc::new(Worker(self.0.clone()))))
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[cfg(feature = "effects")]
+    #[test]
+    fn test_unmounting_component_cancels_its_tasks() {
+        let task: SharedTask = Arc::new(Mutex::new(None));
+        let mut runner = App::headless(12, 1).into_runner(TaskHost(task.clone()));
+        assert_eq!(runner.step_frame().unwrap()[0], "working");
+        let task = task.lock().unwrap().clone().unwrap();
+        assert!(!task.is_finished());
+
+        runner.context().broadcast(Hide);
+        assert_eq!(runner.step_frame().unwrap()[0], "idle");
+
+        // A cancelled task never finishes, so waiting yields nothing
+        let waited = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(async { tokio::time::timeout(Duration::from_secs(5), task.wait()).await });
+        assert_eq!(waited, Ok(None));
+    }
+
+    #[cfg(feature = "effects")]
+    #[test]
+    fn test_spawn_limited_caps_concurrent_tasks() {
+        let runner = App::headless(12, 1).into_runner(Counter);
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let running = running.clone();
+                let peak = peak.clone();
+                runner.context().spawn_limited("fetch", 2, async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(30)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            for task in &tasks {
+                assert_eq!(task.wait().await, Some(()));
+            }
+        });
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    /// Subscriber that records the names of spans and the messages of events.
+    #[cfg(feature = "tracing")]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::field::Visit for Recorder {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.0.lock().unwrap().push(format!("{value:?}"));
+            }
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut names = self.0.lock().unwrap();
+            names.push(span.metadata().name().to_string());
+            tracing::span::Id::from_u64(names.len() as u64)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            event.record(&mut Recorder(self.0.clone()));
+        }
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_render_pass_emits_tracing_events() {
+        let names = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Recorder(names.clone());
+
+        tracing::subscriber::with_default(recorder, || {
+            let mut runner = App::headless(12, 1).into_runner(Counter);
+            runner.step_frame().unwrap();
+        });
+
+        let names = names.lock().unwrap();
+        for expected in [
+            "process_message_queue",
+            "render_frame",
+            "cell diff",
+            "frame drawn",
+        ] {
+            assert!(
+                names.iter().any(|name| name == expected),
+                "{expected} in {names:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Ctrl+Z handling on Unix.
+//!
+//! In raw mode the terminal no longer turns Ctrl+Z into `SIGTSTP`, so the app
+//! receives it as a key press. Suspending restores the terminal before stopping
+//! the process; when the shell continues it with `SIGCONT`, the terminal is set
+//! up again and the last frame is redrawn in full.
+
+use crate::buffer::DoubleBuffer;
+use crate::terminal::{TerminalRenderer, init_terminal, restore_terminal};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::io::{self, Write};
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns true for the Ctrl+Z key press that suspends the app.
+pub(crate) fn is_suspend_key(key_event: &KeyEvent) -> bool {
+    key_event.kind != KeyEventKind::Release
+        && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(key_event.code, KeyCode::Char('z') | KeyCode::Char('Z'))
+}
+
+/// Restores the terminal and stops the process.
+///
+/// `stop` returns once the process has been continued.
+pub(crate) fn suspend(
+    out: &mut impl Write,
+    disable_raw_mode: impl FnOnce() -> io::Result<()>,
+    stop: impl FnOnce() -> io::Result<()>,
+) -> io::Result<()> {
+    restore_terminal(out, disable_raw_mode)?;
+    stop()
+}
+
+/// Re-enters raw mode and the alternate screen, then redraws the frame that was
+/// on screen before suspending.
+pub(crate) fn resume(
+    out: &mut impl Write,
+    enable_raw_mode: impl FnOnce() -> io::Result<()>,
+    renderer: &mut TerminalRenderer,
+    double_buffer: &DoubleBuffer,
+) -> io::Result<()> {
+    enable_raw_mode()?;
+    init_terminal(out)?;
+
+    // Whatever ran in the foreground meanwhile left the terminal in an unknown state
+    renderer.invalidate();
+    renderer.draw_full_buffer(double_buffer.front_buffer())
+}
+
+/// Stops the process with `SIGTSTP`, returning after it receives `SIGCONT`.
+pub(crate) fn stop() -> io::Result<()> {
+    // SAFETY: raise only sends a signal to the calling process; SIGTSTP has its
+    // default action since the app installs no handler for it
+    if unsafe { libc::raise(libc::SIGTSTP) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Cell;
+    use std::cell::{Cell as Flag, RefCell};
+    use std::rc::Rc;
+
+    #[derive(Clone, Default)]
+    struct Capture(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Capture {
+        fn take(&self) -> String {
+            String::from_utf8(std::mem::take(&mut *self.0.borrow_mut())).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_is_suspend_key() {
+        let ctrl_z = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+        assert!(is_suspend_key(&ctrl_z));
+        assert!(!is_suspend_key(&KeyEvent::new(
+            KeyCode::Char('z'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_suspend_key(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn test_suspend_restores_terminal_before_stopping() {
+        let capture = Capture::default();
+        let raw_disabled = Rc::new(Flag::new(false));
+        let stopped = Flag::new(false);
+
+        suspend(
+            &mut capture.clone(),
+            || {
+                raw_disabled.set(true);
+                Ok(())
+            },
+            || {
+                // The terminal must already be usable by the shell at this point
+                assert!(raw_disabled.get());
+                stopped.set(true);
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert!(stopped.get());
+        let output = capture.take();
+        assert!(output.contains("\x1b[?25h"));
+        assert!(output.contains("\x1b[?1049l"));
+    }
+
+    #[test]
+    fn test_resume_reinitializes_and_redraws_last_frame() {
+        let capture = Capture::default();
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        let mut double_buffer = DoubleBuffer::new(4, 2);
+
+        // Draw and present a frame
+        double_buffer
+            .back_buffer_mut()
+            .set_cell(1, 0, Cell::new('A'));
+        double_buffer
+            .back_buffer_mut()
+            .set_cell(2, 1, Cell::new('B'));
+        renderer.apply_updates(double_buffer.diff()).unwrap();
+        double_buffer.swap();
+        capture.take();
+
+        let raw_enabled = Flag::new(false);
+        resume(
+            &mut capture.clone(),
+            || {
+                raw_enabled.set(true);
+                Ok(())
+            },
+            &mut renderer,
+            &double_buffer,
+        )
+        .unwrap();
+
+        assert!(raw_enabled.get());
+        let output = capture.take();
+        let enter_alt_screen = output
+            .find("\x1b[?1049h")
+            .expect("alternate screen entered");
+        let hide_cursor = output.find("\x1b[?25l").expect("cursor hidden");
+        let first_cell = output.find('A').expect("frame redrawn");
+        assert!(enter_alt_screen < first_cell && hide_cursor < first_cell);
+        assert!(output.contains('B'));
+
+        // Every cell is redrawn, not only the ones that differ from a blank screen
+        let moves = output.matches("\x1b[1;").count() + output.matches("\x1b[2;").count();
+        assert_eq!(moves, 8);
+    }
+}
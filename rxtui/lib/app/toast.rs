@@ -0,0 +1,200 @@
+use crate::node::{Div, Text};
+use crate::style::{AlignItems, Color, Direction, Spacing};
+use crate::theme::Theme;
+use crate::vnode::VNode;
+use std::sync::RwLock;
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// How long a toast stays on screen unless another duration is given
+pub const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(3);
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Kind of a toast, which picks its border color from the theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastKind {
+    /// Neutral information
+    #[default]
+    Info,
+
+    /// An operation completed
+    Success,
+
+    /// Something needs attention
+    Warning,
+
+    /// An operation failed
+    Error,
+}
+
+/// A transient message waiting to expire
+#[derive(Debug, Clone)]
+pub(crate) struct Toast {
+    pub message: String,
+    pub kind: ToastKind,
+
+    /// Clock time at which the toast is removed
+    pub expires_at: Duration,
+}
+
+/// Toasts currently on screen, shared by every context
+#[derive(Default)]
+pub(crate) struct ToastStore {
+    toasts: RwLock<Vec<Toast>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ToastKind {
+    /// Returns the theme color used for this kind
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            ToastKind::Info => theme.primary,
+            ToastKind::Success => theme.success,
+            ToastKind::Warning => theme.warning,
+            ToastKind::Error => theme.error,
+        }
+    }
+}
+
+impl ToastStore {
+    /// Adds a toast below the ones already shown
+    pub(crate) fn push(&self, toast: Toast) {
+        self.toasts.write().unwrap().push(toast);
+    }
+
+    /// Removes the toasts whose time is up, returning true if any were removed
+    pub(crate) fn expire(&self, now: Duration) -> bool {
+        let mut toasts = self.toasts.write().unwrap();
+        let before = toasts.len();
+        toasts.retain(|toast| toast.expires_at > now);
+        toasts.len() != before
+    }
+
+    /// Builds the overlay stacking the toasts in the top-right corner
+    pub(crate) fn overlay(&self, theme: &Theme) -> VNode {
+        let toasts = self.toasts.read().unwrap();
+        let stack = toasts.iter().map(|toast| {
+            let text = Text::new(toast.message.clone()).color(theme.text);
+            VNode::Div(
+                Div::new()
+                    .width_content()
+                    .border_color(toast.kind.color(theme))
+                    .background(theme.surface)
+                    .padding(Spacing::horizontal(1))
+                    .child(VNode::Text(text)),
+            )
+        });
+
+        VNode::Div(
+            Div::new()
+                .width_auto()
+                .direction(Direction::Vertical)
+                .align_items(AlignItems::End)
+                .padding(Spacing::horizontal(1))
+                .children(stack.collect()),
+        )
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::component::{Action, Component, Message, MessageExt};
+    use crate::key::Key;
+    use crate::node::Node;
+    use crate::{Context, Div};
+
+    #[derive(Debug, Clone)]
+    struct Notify(&'static str, u64);
+
+    /// Shows a toast for the given number of seconds on each key press
+    struct Notifier;
+
+    impl Component for Notifier {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if let Some(Notify(message, secs)) = msg.downcast::<Notify>() {
+                ctx.toast_for(*message, ToastKind::Success, Duration::from_secs(*secs));
+            }
+            Action::none()
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            Div::new()
+                .on_char_global('a', ctx.handler(Notify("Saved", 2)))
+                .on_char_global('b', ctx.handler(Notify("Synced", 4)))
+                .child(Text::new("app").into())
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn shows(lines: &[String], message: &str) -> bool {
+        lines.iter().any(|line| line.contains(message))
+    }
+
+    #[test]
+    fn test_toast_appears_and_expires_after_timeout() {
+        let mut runner = App::headless(30, 8).into_runner(Notifier);
+        runner.step_frame().unwrap();
+
+        runner.send_key(Key::Char('a')).unwrap();
+        let lines = runner.step_frame().unwrap();
+        assert!(shows(&lines, "│ Saved │"), "{lines:?}");
+        // One column in from the right edge, which the trimmed lines hide
+        let row = &runner.screen_lines()[1];
+        assert!(row.ends_with("│ Saved │ "), "{row:?}");
+
+        runner.advance_clock(Duration::from_millis(1999));
+        assert!(shows(&runner.step_frame().unwrap(), "Saved"));
+        runner.advance_clock(Duration::from_millis(1));
+        let lines = runner.step_frame().unwrap();
+        assert!(!shows(&lines, "Saved"), "{lines:?}");
+        assert!(lines[0].starts_with("app"));
+    }
+
+    #[test]
+    fn test_stacked_toasts_expire_independently() {
+        let mut runner = App::headless(30, 8).into_runner(Notifier);
+        runner.step_frame().unwrap();
+
+        runner.send_key(Key::Char('b')).unwrap();
+        runner.step_frame().unwrap();
+        runner.advance_clock(Duration::from_secs(1));
+        runner.send_key(Key::Char('a')).unwrap();
+        let lines = runner.step_frame().unwrap();
+        let synced = lines.iter().position(|line| line.contains("Synced"));
+        let saved = lines.iter().position(|line| line.contains("Saved"));
+        assert!(synced < saved && saved.is_some(), "{lines:?}");
+
+        // "Saved" expires at 3s, "Synced" lasts until 4s
+        runner.advance_clock(Duration::from_secs(2));
+        let lines = runner.step_frame().unwrap();
+        assert!(
+            shows(&lines, "Synced") && !shows(&lines, "Saved"),
+            "{lines:?}"
+        );
+        runner.advance_clock(Duration::from_secs(1));
+        assert!(!shows(&runner.step_frame().unwrap(), "Synced"));
+    }
+}
@@ -0,0 +1,594 @@
+//! Render targets for the cells of each frame.
+//!
+//! The app renders every frame into a cell buffer and sends the cells that
+//! changed to a [`Backend`]. [`CrosstermBackend`] writes them to a terminal; other
+//! backends can draw them anywhere, e.g. into a DOM grid or an in-memory buffer.
+
+use crate::buffer::{Cell, CellStyle, CellUpdate, ScreenBuffer};
+use crate::style::TextStyle;
+use crate::terminal::TerminalRenderer;
+use crate::utils::char_width;
+use crossterm::event::{self, Event};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A surface the app draws cells to.
+///
+/// Cells arrive through [`set_cell`](Backend::set_cell) and become visible on
+/// [`flush`](Backend::flush), which the app calls once per frame.
+pub trait Backend {
+    /// Sets the cell at column `x`, row `y`
+    fn set_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()>;
+
+    /// Shows the cells set since the last flush
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Returns the size of the surface in columns and rows
+    fn size(&self) -> io::Result<(u16, u16)>;
+
+    /// Shows the cursor at a position, or hides it for `None`
+    fn set_cursor(&mut self, position: Option<(u16, u16)>) -> io::Result<()>;
+
+    /// Waits up to `timeout` for an input event. Backends without input return
+    /// `None` right away.
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+        Ok(None)
+    }
+}
+
+/// Backend writing to a terminal through crossterm and reading its input events.
+///
+/// Cells are collected until [`flush`](Backend::flush) and then written in
+/// batched runs, with redundant cursor moves and color changes left out. This is
+/// the backend [`App::new`](crate::App::new) draws with.
+pub struct CrosstermBackend {
+    renderer: TerminalRenderer,
+    pending: Vec<CellUpdate>,
+
+    /// Whether flushed cells are batched into runs or written one by one
+    optimized: bool,
+}
+
+/// In-memory backend for testing, with helpers to inspect what was drawn.
+///
+/// Clones share the same screen, so keep one to inspect while the app draws to
+/// another:
+///
+/// ```rust,ignore
+/// let backend = TestBackend::new(40, 10);
+/// let mut runner = App::with_backend(backend.clone())?.into_runner(MyApp);
+/// runner.process_message_queue();
+/// runner.render_frame()?;
+///
+/// assert!(backend.contains_text("Welcome"));
+/// backend.assert_styled(0, 0, TextStyle { bold: Some(true), ..Default::default() });
+/// ```
+#[derive(Clone)]
+pub struct TestBackend {
+    state: Rc<RefCell<TestScreen>>,
+}
+
+/// Backend printing each frame as plain text, without escape sequences.
+///
+/// Used by [`App::new`](crate::App::new) when stdout is not a terminal, so
+/// `cmd | cat` and CI logs get readable output. Colors and styles are dropped,
+/// and trailing spaces and blank rows at the bottom are trimmed.
+pub struct PlainTextBackend {
+    writer: Box<dyn Write>,
+    buffer: ScreenBuffer,
+}
+
+/// Screen shared by the clones of a [`TestBackend`]
+struct TestScreen {
+    buffer: ScreenBuffer,
+    cursor: Option<(u16, u16)>,
+    frames: usize,
+    events: VecDeque<Event>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl CrosstermBackend {
+    /// Creates a backend writing to stdout
+    pub fn new() -> Self {
+        Self::with_renderer(TerminalRenderer::new())
+    }
+
+    /// Creates a backend writing escape sequences to `writer`
+    pub fn with_writer(writer: impl Write + 'static) -> Self {
+        Self::with_renderer(TerminalRenderer::with_writer(writer))
+    }
+
+    fn with_renderer(renderer: TerminalRenderer) -> Self {
+        Self {
+            renderer,
+            pending: Vec::new(),
+            optimized: true,
+        }
+    }
+
+    /// Sets whether flushed cells are batched into runs (default: true)
+    pub(crate) fn set_optimized(&mut self, optimized: bool) {
+        self.optimized = optimized;
+    }
+
+    /// Returns the renderer, for settings and state beyond the [`Backend`] trait
+    pub(crate) fn renderer(&self) -> &TerminalRenderer {
+        &self.renderer
+    }
+
+    /// Returns the renderer mutably, e.g. to forget its state after a suspend
+    pub(crate) fn renderer_mut(&mut self) -> &mut TerminalRenderer {
+        &mut self.renderer
+    }
+}
+
+impl PlainTextBackend {
+    /// Creates a backend printing frames of the given size to `writer`
+    pub fn new(writer: impl Write + 'static, width: u16, height: u16) -> Self {
+        Self {
+            writer: Box::new(writer),
+            buffer: ScreenBuffer::new(width, height),
+        }
+    }
+}
+
+impl TestBackend {
+    /// Creates a blank screen of the given size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(TestScreen {
+                buffer: ScreenBuffer::new(width, height),
+                cursor: None,
+                frames: 0,
+                events: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Queues an input event for the app to read on its next poll
+    pub fn push_event(&self, event: Event) {
+        self.state.borrow_mut().events.push_back(event);
+    }
+
+    /// Resizes the screen; the app picks up the new size on its next frame
+    pub fn resize(&self, width: u16, height: u16) {
+        self.state.borrow_mut().buffer.resize(width, height);
+    }
+
+    /// Returns the cell at column `x`, row `y`, or `None` outside the screen
+    pub fn cell_at(&self, x: u16, y: u16) -> Option<Cell> {
+        self.state.borrow().buffer.get_cell(x, y).cloned()
+    }
+
+    /// Returns the text of row `y` without trailing spaces
+    pub fn line(&self, y: u16) -> String {
+        let state = self.state.borrow();
+        let lines = state.buffer.lines();
+        lines
+            .get(y as usize)
+            .map_or_else(String::new, |line| line.trim_end().to_string())
+    }
+
+    /// Returns the text of every row without trailing spaces
+    pub fn lines(&self) -> Vec<String> {
+        let state = self.state.borrow();
+        state
+            .buffer
+            .lines()
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    /// Returns true if any row contains `text`
+    pub fn contains_text(&self, text: &str) -> bool {
+        self.lines().iter().any(|line| line.contains(text))
+    }
+
+    /// Returns where the cursor is shown, or `None` while it is hidden
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.state.borrow().cursor
+    }
+
+    /// Returns how many frames have been flushed
+    pub fn frames(&self) -> usize {
+        self.state.borrow().frames
+    }
+
+    /// Panics unless the cell at `x`, `y` matches every field set in `style`.
+    ///
+    /// Colors and attributes left as `None` are not checked, so
+    /// `TextStyle { bold: Some(true), ..Default::default() }` only checks boldness.
+    #[track_caller]
+    pub fn assert_styled(&self, x: u16, y: u16, style: TextStyle) {
+        let Some(cell) = self.cell_at(x, y) else {
+            panic!("cell ({x}, {y}) is outside the screen");
+        };
+        let attributes = CellStyle::from_text_style(&style);
+        let checks = [
+            (
+                "color",
+                style.color.is_none_or(|color| cell.fg == Some(color)),
+            ),
+            (
+                "background",
+                style.background.is_none_or(|color| cell.bg == Some(color)),
+            ),
+            (
+                "bold",
+                style.bold.is_none() || cell.style.bold == attributes.bold,
+            ),
+            (
+                "italic",
+                style.italic.is_none() || cell.style.italic == attributes.italic,
+            ),
+            (
+                "underline",
+                style.underline.is_none() || cell.style.underline == attributes.underline,
+            ),
+            (
+                "strikethrough",
+                style.strikethrough.is_none()
+                    || cell.style.strikethrough == attributes.strikethrough,
+            ),
+        ];
+        for (field, matches) in checks {
+            assert!(
+                matches,
+                "cell ({x}, {y}) {:?} has unexpected {field}: {cell:?}, expected {style:?}",
+                cell.char
+            );
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Backend for CrosstermBackend {
+    fn set_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        self.pending.push(CellUpdate::Single {
+            x,
+            y,
+            cell: cell.clone(),
+        });
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let updates = std::mem::take(&mut self.pending);
+        if self.optimized {
+            self.renderer.apply_updates(updates)
+        } else {
+            self.renderer.apply_updates_direct(updates)
+        }
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+
+    fn set_cursor(&mut self, position: Option<(u16, u16)>) -> io::Result<()> {
+        self.renderer.set_cursor(position)
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        if event::poll(timeout)? {
+            event::read().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl Backend for TestBackend {
+    fn set_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        let buffer = &mut self.state.borrow_mut().buffer;
+        buffer.set_cell(x, y, cell.clone());
+        // Wide characters arrive without their continuation cell
+        if char_width(cell.char) == 2 {
+            buffer.set_cell(x.saturating_add(1), y, Cell::continuation_of(cell));
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.state.borrow_mut().frames += 1;
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(self.state.borrow().buffer.dimensions())
+    }
+
+    fn set_cursor(&mut self, position: Option<(u16, u16)>) -> io::Result<()> {
+        self.state.borrow_mut().cursor = position;
+        Ok(())
+    }
+
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+        Ok(self.state.borrow_mut().events.pop_front())
+    }
+}
+
+impl Backend for PlainTextBackend {
+    fn set_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        self.buffer.set_cell(x, y, cell.clone());
+        // Wide characters arrive without their continuation cell
+        if char_width(cell.char) == 2 {
+            self.buffer
+                .set_cell(x.saturating_add(1), y, Cell::continuation_of(cell));
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let lines = self.buffer.lines();
+        let rows = lines.iter().rposition(|line| !line.trim_end().is_empty());
+        for line in &lines[..rows.map_or(0, |last| last + 1)] {
+            writeln!(self.writer, "{}", line.trim_end())?;
+        }
+        self.writer.flush()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(self.buffer.dimensions())
+    }
+
+    fn set_cursor(&mut self, _position: Option<(u16, u16)>) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for CrosstermBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Context};
+    use crate::component::{Action, Component, Message, MessageExt};
+    use crate::components::TextInput;
+    use crate::node::{Div, Node, Text};
+    use crate::style::{Color, Direction};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::any::Any;
+    use std::cell::{Cell as StdCell, RefCell};
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    /// Positions of the cells set in a frame
+    type Positions = Vec<(u16, u16)>;
+
+    /// Backend recording the cells it is given, shared with the test
+    #[derive(Clone, Default)]
+    struct Recording {
+        size: Rc<StdCell<(u16, u16)>>,
+        cells: Rc<RefCell<BTreeMap<(u16, u16), char>>>,
+        frame: Rc<RefCell<Positions>>,
+        flushed: Rc<RefCell<Vec<Positions>>>,
+    }
+
+    impl Recording {
+        fn row(&self, y: u16) -> String {
+            let cells = self.cells.borrow();
+            (0..self.size.get().0)
+                .map(|x| cells.get(&(x, y)).copied().unwrap_or(' '))
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        }
+    }
+
+    impl Backend for Recording {
+        fn set_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+            self.cells.borrow_mut().insert((x, y), cell.char);
+            self.frame.borrow_mut().push((x, y));
+            Ok(())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            let frame = std::mem::take(&mut *self.frame.borrow_mut());
+            self.flushed.borrow_mut().push(frame);
+            Ok(())
+        }
+
+        fn size(&self) -> io::Result<(u16, u16)> {
+            Ok(self.size.get())
+        }
+
+        fn set_cursor(&mut self, _position: Option<(u16, u16)>) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Rename(&'static str);
+
+    #[derive(Debug, Clone, Default)]
+    struct Name(&'static str);
+
+    struct Label;
+
+    impl Component for Label {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            match msg.downcast::<Rename>() {
+                Some(Rename(name)) => Action::update(Name(name)),
+                None => Action::none(),
+            }
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let Name(name) = ctx.get_state::<Name>();
+            Text::new(format!("hello {name}")).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_render_loop_draws_to_backend() {
+        let backend = Recording::default();
+        backend.size.set((12, 2));
+        let mut runner = App::with_backend(backend.clone())
+            .unwrap()
+            .into_runner(Label);
+
+        runner.process_message_queue();
+        assert!(runner.render_frame().unwrap());
+        assert_eq!(backend.row(0), "hello");
+        assert_eq!(backend.flushed.borrow().len(), 1);
+
+        // Only the cells that changed are sent again; the space was already blank
+        runner.context().broadcast(Rename("you"));
+        runner.process_message_queue();
+        assert!(runner.render_frame().unwrap());
+        assert_eq!(backend.row(0), "hello you");
+        assert_eq!(backend.flushed.borrow()[1], [(6, 0), (7, 0), (8, 0)]);
+
+        // The screen follows the backend's size
+        backend.size.set((20, 3));
+        runner.context().broadcast(Rename("again"));
+        runner.process_message_queue();
+        assert!(runner.render_frame().unwrap());
+        assert_eq!(runner.screen_lines().len(), 3);
+        assert_eq!(backend.row(0), "hello again");
+    }
+
+    #[test]
+    fn test_app_reads_input_and_places_cursor_through_backend() {
+        let backend = TestBackend::new(8, 3);
+        let mut runner = App::with_backend(backend.clone())
+            .unwrap()
+            .into_runner(TextInput::new().width(8));
+        runner.process_message_queue();
+        runner.render_frame().unwrap();
+        assert_eq!(backend.cursor(), None);
+
+        // Each key is read from the backend and handled before the next frame
+        for code in [KeyCode::Tab, KeyCode::Char('h'), KeyCode::Char('i')] {
+            backend.push_event(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+            assert!(runner.poll_event(Duration::ZERO).unwrap());
+            runner.process_message_queue();
+            runner.render_frame().unwrap();
+        }
+        assert!(!runner.poll_event(Duration::ZERO).unwrap());
+
+        // The cursor sits after the text, inside the border and padding
+        assert_eq!(backend.line(1), "│ hi   │");
+        assert_eq!(backend.cursor(), Some((4, 1)));
+    }
+
+    /// Component drawing a fixed tree
+    struct Fixed(fn() -> Node);
+
+    impl Component for Fixed {
+        fn view(&self, _ctx: &Context) -> Node {
+            (self.0)()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn draw(backend: &TestBackend, view: fn() -> Node) {
+        let mut runner = App::with_backend(backend.clone())
+            .unwrap()
+            .into_runner(Fixed(view));
+        runner.process_message_queue();
+        runner.render_frame().unwrap();
+    }
+
+    fn sample() -> Node {
+        Div::new()
+            .direction(Direction::Vertical)
+            .child(Text::new("Title").color(Color::Red).bold().into())
+            .child(Text::new("界 ok").background(Color::Blue).into())
+            .into()
+    }
+
+    #[test]
+    fn test_test_backend_reads_text() {
+        let backend = TestBackend::new(10, 3);
+        draw(&backend, sample);
+
+        assert_eq!(backend.frames(), 1);
+        assert_eq!(backend.line(0), "Title");
+        assert_eq!(backend.line(1), "界 ok");
+        assert_eq!(backend.lines(), ["Title", "界 ok", ""]);
+        assert_eq!(backend.line(7), "");
+        assert!(backend.contains_text("le"));
+        assert!(backend.contains_text("界 o"));
+        assert!(!backend.contains_text("Title 界"));
+
+        assert_eq!(backend.cell_at(0, 1).unwrap().char, '界');
+        assert!(backend.cell_at(1, 1).unwrap().is_continuation());
+        assert_eq!(backend.cell_at(10, 0), None);
+    }
+
+    #[test]
+    fn test_test_backend_asserts_styles() {
+        let backend = TestBackend::new(10, 3);
+        draw(&backend, sample);
+
+        let red_bold = TextStyle {
+            color: Some(Color::Red),
+            bold: Some(true),
+            ..Default::default()
+        };
+        backend.assert_styled(4, 0, red_bold);
+        backend.assert_styled(
+            3,
+            1,
+            TextStyle {
+                background: Some(Color::Blue),
+                bold: Some(false),
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected color")]
+    fn test_test_backend_rejects_wrong_style() {
+        let backend = TestBackend::new(10, 3);
+        draw(&backend, sample);
+        backend.assert_styled(
+            0,
+            1,
+            TextStyle {
+                color: Some(Color::Red),
+                ..Default::default()
+            },
+        );
+    }
+}
@@ -18,6 +18,7 @@
 use crate::style::{Color, TextStyle};
 use crate::utils::char_width;
 use std::fmt;
+use std::sync::Arc;
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -40,6 +41,10 @@ pub struct Cell {
 
     /// Additional styling attributes
     pub style: CellStyle,
+
+    /// Whether this cell is the right half of a wide character in the cell to its left.
+    /// Continuation cells are never printed; the terminal fills them when drawing the glyph.
+    pub(crate) continuation: bool,
 }
 
 /// Style attributes that can be applied to a cell.
@@ -56,6 +61,9 @@ pub struct CellStyle {
 
     /// Strikethrough text
     pub strikethrough: bool,
+
+    /// URL of the hyperlink the cell belongs to
+    pub link: Option<Arc<str>>,
 }
 
 /// A buffer representing the entire terminal screen as a 2D grid of cells.
@@ -71,6 +79,9 @@ pub struct ScreenBuffer {
 
     /// Height in rows
     height: u16,
+
+    /// Where the focused text cursor was drawn, if anywhere
+    cursor: Option<(u16, u16)>,
 }
 
 /// Double buffer system for flicker-free rendering.
@@ -108,6 +119,7 @@ impl CellStyle {
             italic: text_style.italic.unwrap_or(false),
             underline: text_style.underline.unwrap_or(false),
             strikethrough: text_style.strikethrough.unwrap_or(false),
+            link: text_style.link.as_deref().map(Arc::from),
         }
     }
 
@@ -118,6 +130,7 @@ impl CellStyle {
             italic: self.italic || other.italic,
             underline: self.underline || other.underline,
             strikethrough: self.strikethrough || other.strikethrough,
+            link: other.link.clone().or(self.link),
         }
     }
 }
@@ -130,6 +143,7 @@ impl Cell {
             fg: None,
             bg: None,
             style: CellStyle::default(),
+            continuation: false,
         }
     }
 
@@ -138,6 +152,30 @@ impl Cell {
         Self::new(' ')
     }
 
+    /// Creates the right half of a wide character, sharing the character's colors.
+    pub fn continuation_of(cell: &Cell) -> Self {
+        Self {
+            char: ' ',
+            continuation: true,
+            ..cell.clone()
+        }
+    }
+
+    /// Returns true if this is the right half of a wide character.
+    pub fn is_continuation(&self) -> bool {
+        self.continuation
+    }
+
+    /// Returns a blank cell that keeps this cell's colors, used when a wide
+    /// character is split by an overwrite.
+    fn blanked(&self) -> Self {
+        Self {
+            char: ' ',
+            continuation: false,
+            ..self.clone()
+        }
+    }
+
     /// Sets the foreground color.
     pub fn with_fg(mut self, color: Color) -> Self {
         self.fg = Some(color);
@@ -167,6 +205,7 @@ impl ScreenBuffer {
             cells,
             width,
             height,
+            cursor: None,
         }
     }
 
@@ -192,8 +231,37 @@ impl ScreenBuffer {
 
     /// Sets the cell at the given position.
     ///
-    /// Does nothing if the position is out of bounds.
+    /// Overwriting either half of a wide character blanks the other half, so no
+    /// stray half glyph is left behind. Does nothing if the position is out of bounds.
+    ///
+    /// ```text
+    /// Before:  [中][··]        set_cell(0, 'a')        After:  [a][ ]
+    ///           ^   └─ continuation                             └─ blanked
+    /// ```
     pub fn set_cell(&mut self, x: u16, y: u16, cell: Cell) {
+        let Some(target) = self.get_cell(x, y) else {
+            return;
+        };
+
+        // Replacing a continuation orphans the wide character on its left
+        if target.continuation
+            && !cell.continuation
+            && x > 0
+            && let Some(left) = self.get_cell_mut(x - 1, y)
+        {
+            *left = left.blanked();
+        }
+
+        // Replacing a wide character orphans its continuation on the right, unless
+        // the new cell is itself wide and will be followed by its own continuation
+        if !cell.continuation
+            && char_width(cell.char) < 2
+            && let Some(right) = self.get_cell_mut(x + 1, y)
+            && right.continuation
+        {
+            *right = right.blanked();
+        }
+
         if let Some(target) = self.get_cell_mut(x, y) {
             *target = cell;
         }
@@ -206,6 +274,17 @@ impl ScreenBuffer {
                 *cell = Cell::empty();
             }
         }
+        self.cursor = None;
+    }
+
+    /// Returns where the focused text cursor was drawn, if anywhere.
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// Records where the text cursor is drawn.
+    pub(crate) fn set_cursor(&mut self, position: (u16, u16)) {
+        self.cursor = Some(position);
     }
 
     /// Resizes the buffer to new dimensions.
@@ -234,6 +313,36 @@ impl ScreenBuffer {
         (self.width, self.height)
     }
 
+    /// Returns an update for every cell, skipping wide-character continuations.
+    pub fn all_updates(&self) -> Vec<CellUpdate> {
+        let mut updates = Vec::new();
+        for (y, row) in (0..self.height).zip(&self.cells) {
+            for (x, cell) in (0..self.width).zip(row) {
+                if !cell.continuation {
+                    updates.push(CellUpdate::Single {
+                        x,
+                        y,
+                        cell: cell.clone(),
+                    });
+                }
+            }
+        }
+        updates
+    }
+
+    /// Returns the characters of each row, skipping wide-character continuations.
+    pub fn lines(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter(|cell| !cell.continuation)
+                    .map(|cell| cell.char)
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Fills a rectangular region with the given cell.
     pub fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, cell: Cell) {
         for dy in 0..height {
@@ -262,15 +371,14 @@ impl ScreenBuffer {
             let mut cell = Cell::new(ch);
             cell.fg = fg;
             cell.bg = bg;
-            self.set_cell(current_x, y, cell);
-
-            // For wide characters, fill the next cell with a space
-            // This ensures proper rendering in terminals
-            if ch_width == 2 && current_x + 1 < self.width {
-                let mut space_cell = Cell::new(' ');
-                space_cell.fg = fg;
-                space_cell.bg = bg;
-                self.set_cell(current_x + 1, y, space_cell);
+
+            // Wide characters occupy the next cell as a continuation
+            if ch_width == 2 {
+                let continuation = Cell::continuation_of(&cell);
+                self.set_cell(current_x, y, cell);
+                self.set_cell(current_x + 1, y, continuation);
+            } else {
+                self.set_cell(current_x, y, cell);
             }
 
             current_x += ch_width as u16;
@@ -307,16 +415,14 @@ impl ScreenBuffer {
             cell.fg = fg;
             cell.bg = bg;
             cell.style = cell_style.clone();
-            self.set_cell(current_x, y, cell);
-
-            // For wide characters, fill the next cell with a space
-            // This ensures proper rendering in terminals
-            if ch_width == 2 && current_x + 1 < self.width {
-                let mut space_cell = Cell::new(' ');
-                space_cell.fg = fg;
-                space_cell.bg = bg;
-                space_cell.style = cell_style.clone();
-                self.set_cell(current_x + 1, y, space_cell);
+
+            // Wide characters occupy the next cell as a continuation
+            if ch_width == 2 {
+                let continuation = Cell::continuation_of(&cell);
+                self.set_cell(current_x, y, cell);
+                self.set_cell(current_x + 1, y, continuation);
+            } else {
+                self.set_cell(current_x, y, cell);
             }
 
             current_x += ch_width as u16;
@@ -342,6 +448,11 @@ impl DoubleBuffer {
         std::mem::swap(&mut self.front, &mut self.back);
     }
 
+    /// Provides access to the front buffer, i.e. what's currently on screen.
+    pub fn front_buffer(&self) -> &ScreenBuffer {
+        &self.front
+    }
+
     /// Provides mutable access to the back buffer for rendering.
     pub fn back_buffer_mut(&mut self) -> &mut ScreenBuffer {
         &mut self.back
@@ -358,25 +469,45 @@ impl DoubleBuffer {
     /// This is the core of the flicker-free rendering system. By comparing
     /// buffers cell-by-cell, we can determine exactly what needs to be updated
     /// on the terminal.
+    ///
+    /// Continuation cells are never emitted: printing the wide character on their
+    /// left redraws them, so a changed continuation re-emits that character instead.
     pub fn diff(&self) -> Vec<CellUpdate> {
         let mut updates = Vec::new();
         let (width, height) = self.front.dimensions();
 
         for y in 0..height {
+            let mut emitted_left = false;
             for x in 0..width {
                 let front_cell = self.front.get_cell(x, y);
                 let back_cell = self.back.get_cell(x, y);
 
-                match (front_cell, back_cell) {
+                let emitted = match (front_cell, back_cell) {
                     (Some(front), Some(back)) if front != back => {
-                        updates.push(CellUpdate::Single {
-                            x,
-                            y,
-                            cell: back.clone(),
-                        });
+                        if !back.continuation {
+                            updates.push(CellUpdate::Single {
+                                x,
+                                y,
+                                cell: back.clone(),
+                            });
+                            true
+                        } else if !emitted_left && x > 0 {
+                            // Redraw the wide character this continuation belongs to
+                            if let Some(left) = self.back.get_cell(x - 1, y) {
+                                updates.push(CellUpdate::Single {
+                                    x: x - 1,
+                                    y,
+                                    cell: left.clone(),
+                                });
+                            }
+                            false
+                        } else {
+                            false
+                        }
                     }
-                    _ => {}
-                }
+                    _ => false,
+                };
+                emitted_left = emitted;
             }
         }
 
@@ -513,4 +644,58 @@ mod tests {
         // Total: 6 changes
         assert!(actual_changes == 6);
     }
+
+    #[test]
+    fn test_wide_char_marks_continuation() {
+        let mut buffer = ScreenBuffer::new(6, 1);
+        buffer.write_str(0, 0, "中a", Some(Color::Red), None);
+
+        assert_eq!(buffer.get_cell(0, 0).unwrap().char, '中');
+        let right = buffer.get_cell(1, 0).unwrap();
+        assert!(right.continuation);
+        assert_eq!(right.fg, Some(Color::Red));
+        assert_eq!(buffer.get_cell(2, 0).unwrap().char, 'a');
+    }
+
+    #[test]
+    fn test_overwriting_left_half_clears_right_half() {
+        let mut buffer = ScreenBuffer::new(6, 1);
+        buffer.write_str(0, 0, "中", None, Some(Color::Blue));
+
+        buffer.set_cell(0, 0, Cell::new('x'));
+        assert_eq!(buffer.get_cell(0, 0).unwrap().char, 'x');
+        let right = buffer.get_cell(1, 0).unwrap();
+        assert!(!right.continuation);
+        assert_eq!(right.char, ' ');
+        assert_eq!(right.bg, Some(Color::Blue));
+
+        // Overwriting the right half blanks the wide character on its left
+        buffer.write_str(2, 0, "文", None, None);
+        buffer.write_str(3, 0, "b", None, None);
+        assert_eq!(buffer.get_cell(2, 0).unwrap().char, ' ');
+        assert_eq!(buffer.get_cell(3, 0).unwrap().char, 'b');
+    }
+
+    #[test]
+    fn test_diff_skips_continuation_cells() {
+        let mut db = DoubleBuffer::new(6, 1);
+        db.back_buffer_mut().write_str(0, 0, "中", None, None);
+        let updates = db.diff();
+        assert_eq!(updates.len(), 1);
+        assert!(matches!(&updates[0], CellUpdate::Single { x: 0, cell, .. } if cell.char == '中'));
+        db.swap();
+
+        // Narrow characters replacing the wide one update both columns
+        db.clear_back();
+        db.back_buffer_mut().write_str(0, 0, "ab", None, None);
+        assert_eq!(db.diff().len(), 2);
+        db.swap();
+
+        // A wide character over two narrow ones only sends the glyph
+        db.clear_back();
+        db.back_buffer_mut().write_str(0, 0, "中", None, None);
+        let updates = db.diff();
+        assert_eq!(updates.len(), 1);
+        assert!(matches!(&updates[0], CellUpdate::Single { x: 0, .. }));
+    }
 }
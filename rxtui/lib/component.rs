@@ -14,9 +14,26 @@ pub enum Action {
     /// Update the component's state
     Update(Box<dyn State>),
 
+    /// Update the component's state, keeping the previous state for `Context::undo`
+    UpdateWithHistory(Box<dyn State>),
+
     /// Update a topic's state (idempotent - first writer becomes owner)
     UpdateTopic(String, Box<dyn State>),
 
+    /// Queue a message for this component, handled on the next update cycle
+    Send(Box<dyn Message>),
+
+    /// Apply several actions in order. An `Exit` ends the batch, so the
+    /// actions after it are skipped.
+    Batch(Vec<Action>),
+
+    /// Replace the root component with a new screen, keeping the current one
+    /// on the navigation stack
+    Navigate(Box<dyn Component>),
+
+    /// Return to the screen below the current one on the navigation stack
+    Back,
+
     /// No action needed
     None,
 
@@ -288,6 +305,19 @@ pub trait Component: 'static {
         vec![]
     }
 
+    /// Called once when the component enters the tree.
+    ///
+    /// The root component is mounted before its first view; nested components
+    /// right after theirs. Use it for setup that isn't an effect, such as
+    /// opening files or registering subscriptions.
+    fn on_mount(&self, _ctx: &Context) {}
+
+    /// Called once when the component leaves the tree, or when the app exits.
+    ///
+    /// Release anything acquired in [`on_mount`](Self::on_mount) here. Children
+    /// are unmounted before their parents.
+    fn on_unmount(&self, _ctx: &Context) {}
+
     /// Get the TypeId of this component for identity tracking
     fn type_id(&self) -> TypeId {
         self.as_any().type_id()
@@ -309,12 +339,46 @@ impl Action {
         Action::Update(Box::new(state))
     }
 
+    /// Create an UpdateWithHistory action with the given state
+    #[inline]
+    pub fn update_with_history(state: impl State) -> Self {
+        Action::UpdateWithHistory(Box::new(state))
+    }
+
     /// Create an UpdateTopic action with the given topic and state
     #[inline]
     pub fn update_topic(topic: impl Into<String>, state: impl State) -> Self {
         Action::UpdateTopic(topic.into(), Box::new(state))
     }
 
+    /// Create a Send action that queues a follow-up message for this component
+    #[inline]
+    pub fn send(message: impl Message) -> Self {
+        Action::Send(Box::new(message))
+    }
+
+    /// Create a Batch action that applies each of the given actions in order
+    ///
+    /// ```rust,ignore
+    /// Action::batch([Action::update(state), Action::send(Msg::Saved)])
+    /// ```
+    #[inline]
+    pub fn batch(actions: impl IntoIterator<Item = Action>) -> Self {
+        Action::Batch(actions.into_iter().collect())
+    }
+
+    /// Create a Navigate action that shows the given component as the new root
+    #[inline]
+    pub fn navigate(component: impl Component) -> Self {
+        Action::Navigate(Box::new(component))
+    }
+
+    /// Create a Back action that returns to the previous screen
+    #[inline(always)]
+    pub fn back() -> Self {
+        Action::Back
+    }
+
     /// Create a None action (no-op)
     #[inline(always)]
     pub fn none() -> Self {
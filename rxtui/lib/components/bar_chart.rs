@@ -0,0 +1,278 @@
+use crate::Context;
+use crate::component::{Action, Component, Message};
+use crate::node::{Div, Node, Text};
+use crate::style::{Color, Direction, TextAlign, TextStyle};
+use crate::utils::{display_width, pad_to_width};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Character used to draw bars
+const BAR: char = '█';
+
+/// Default total width of the chart in cells
+const DEFAULT_WIDTH: u16 = 40;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Horizontal bar chart with a label column and one bar per row.
+///
+/// Bars are normalized so the largest value fills the space left after the
+/// label and value columns:
+///
+/// ```rust,ignore
+/// let chart = BarChart::new()
+///     .bar("cpu", 72.0)
+///     .colored_bar("mem", 91.0, Color::Red)
+///     .suffix("%")
+///     .width(40);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BarChart {
+    bars: Vec<Bar>,
+    width: u16,
+    color: Option<Color>,
+    suffix: Option<String>,
+}
+
+/// A single labeled bar
+#[derive(Debug, Clone)]
+struct Bar {
+    label: String,
+    value: f64,
+    color: Option<Color>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl BarChart {
+    /// Creates an empty bar chart
+    pub fn new() -> Self {
+        Self {
+            bars: Vec::new(),
+            width: DEFAULT_WIDTH,
+            color: None,
+            suffix: None,
+        }
+    }
+
+    /// Adds a bar drawn in the chart color
+    pub fn bar(mut self, label: impl Into<String>, value: f64) -> Self {
+        self.bars.push(Bar {
+            label: label.into(),
+            value,
+            color: None,
+        });
+        self
+    }
+
+    /// Adds a bar with its own color
+    pub fn colored_bar(mut self, label: impl Into<String>, value: f64, color: Color) -> Self {
+        self.bars.push(Bar {
+            label: label.into(),
+            value,
+            color: Some(color),
+        });
+        self
+    }
+
+    /// Sets the total width of the chart in cells, including labels and values
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the default bar color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Shows each value after its bar, followed by `suffix` (e.g. `"%"` or `" ms"`)
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Returns the formatted value column for a bar, if values are shown
+    fn value_text(&self, bar: &Bar) -> Option<String> {
+        self.suffix
+            .as_ref()
+            .map(|suffix| format!("{}{suffix}", bar.value))
+    }
+
+    /// Width of the label column, including the gap before the bars
+    fn label_width(&self) -> usize {
+        self.bars
+            .iter()
+            .map(|bar| display_width(&bar.label))
+            .max()
+            .map_or(0, |width| width + 1)
+    }
+
+    /// Width of the value column, including the gap after the bars
+    fn value_width(&self) -> usize {
+        self.bars
+            .iter()
+            .filter_map(|bar| self.value_text(bar))
+            .map(|text| display_width(&text) + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Maximum bar length in cells
+    fn bar_width(&self) -> usize {
+        (self.width as usize).saturating_sub(self.label_width() + self.value_width())
+    }
+
+    /// Returns the length of every bar, proportional to the largest value
+    fn bar_lengths(&self) -> Vec<usize> {
+        let max = self.bars.iter().map(|bar| bar.value).fold(0.0, f64::max);
+        let bar_width = self.bar_width();
+
+        self.bars
+            .iter()
+            .map(|bar| {
+                if max <= 0.0 || !bar.value.is_finite() || bar.value <= 0.0 {
+                    return 0;
+                }
+                ((bar.value / max) * bar_width as f64).round() as usize
+            })
+            .collect()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for BarChart {
+    fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        Action::none()
+    }
+
+    fn view(&self, _ctx: &Context) -> Node {
+        let label_width = self.label_width();
+        let bar_width = self.bar_width();
+
+        let rows = self
+            .bars
+            .iter()
+            .zip(self.bar_lengths())
+            .map(|(bar, length)| {
+                let mut row = Div::new().direction(Direction::Horizontal).height(1);
+
+                if label_width > 0 {
+                    row = row.child(
+                        Div::new()
+                            .width(label_width as u16)
+                            .child(
+                                Text::new(pad_to_width(
+                                    &bar.label,
+                                    label_width - 1,
+                                    TextAlign::Left,
+                                ))
+                                .into(),
+                            )
+                            .into(),
+                    );
+                }
+
+                let mut bar_text = Text::new(BAR.to_string().repeat(length));
+                if let Some(color) = bar.color.or(self.color) {
+                    bar_text.style = Some(TextStyle {
+                        color: Some(color),
+                        ..Default::default()
+                    });
+                }
+                row = row.child(
+                    Div::new()
+                        .width(bar_width as u16)
+                        .child(bar_text.into())
+                        .into(),
+                );
+
+                if let Some(value) = self.value_text(bar) {
+                    row = row.child(Text::new(format!(" {value}")).into());
+                }
+
+                row.into()
+            })
+            .collect();
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .width(self.width)
+            .children(rows)
+            .into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Default for BarChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_buffer;
+
+    fn row_text(buffer: &crate::buffer::ScreenBuffer, y: u16, width: u16) -> String {
+        (0..width)
+            .map(|x| buffer.get_cell(x, y).unwrap().char)
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_bar_lengths_are_proportional() {
+        let chart = BarChart::new()
+            .bar("a", 10.0)
+            .bar("b", 5.0)
+            .bar("c", 2.5)
+            .bar("d", 0.0)
+            .width(22);
+
+        // Label column is "a " (2 cells), leaving 20 for bars
+        assert_eq!(chart.bar_width(), 20);
+        assert_eq!(chart.bar_lengths(), vec![20, 10, 5, 0]);
+    }
+
+    #[test]
+    fn test_largest_value_fills_bar_width() {
+        let chart = BarChart::new()
+            .bar("cpu", 30.0)
+            .colored_bar("memory", 90.0, Color::Red)
+            .suffix("%")
+            .width(20);
+
+        // 7 cells of labels and 4 cells of values (" 90%") leave 9 for bars
+        assert_eq!(chart.bar_width(), 9);
+        assert_eq!(chart.bar_lengths(), vec![3, 9]);
+
+        let buffer = render_to_buffer(chart.view(&Context::default()), 20, 2);
+        assert_eq!(row_text(&buffer, 0, 20), "cpu    ███       30%");
+        assert_eq!(row_text(&buffer, 1, 20), "memory █████████ 90%");
+        assert_eq!(buffer.get_cell(7, 1).unwrap().fg, Some(Color::Red));
+    }
+}
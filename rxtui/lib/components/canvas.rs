@@ -0,0 +1,260 @@
+use crate::Context;
+use crate::component::{Action, Component, Message};
+use crate::node::{Div, Node, Text};
+use crate::style::{Color, Direction, TextStyle};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Braille pattern with no dots raised; the other patterns add their dot bits to it
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// Dot bit for each sub-pixel of a cell, indexed by `[y][x]`
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Drawing surface with 2x4 pixels per cell, drawn with Braille characters.
+///
+/// Pixel `(0, 0)` is the top-left dot. A canvas of `cols x rows` cells has
+/// `cols * 2` by `rows * 4` pixels, giving plots and diagrams four times the
+/// vertical resolution of the cell grid:
+///
+/// ```rust,ignore
+/// let mut canvas = Canvas::new(20, 5).color(Color::Cyan);
+/// canvas.line(0, 19, 39, 0);
+/// canvas.set_pixel(20, 10);
+/// node! {
+///     div [ node(canvas) ]
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    /// Raised dots of each cell, row-major
+    cells: Vec<u8>,
+
+    /// Width in cells
+    cols: u16,
+
+    /// Height in cells
+    rows: u16,
+
+    /// Color of the dots
+    color: Option<Color>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Canvas {
+    /// Creates a blank canvas of `cols x rows` cells
+    pub fn new(cols: u16, rows: u16) -> Self {
+        Self {
+            cells: vec![0; cols as usize * rows as usize],
+            cols,
+            rows,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the dots
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the width in pixels
+    pub fn pixel_width(&self) -> u32 {
+        self.cols as u32 * 2
+    }
+
+    /// Returns the height in pixels
+    pub fn pixel_height(&self) -> u32 {
+        self.rows as u32 * 4
+    }
+
+    /// Raises the dot at `(x, y)`. Pixels outside the canvas are ignored.
+    pub fn set_pixel(&mut self, x: u32, y: u32) {
+        if let Some((index, bit)) = self.dot(x, y) {
+            self.cells[index] |= bit;
+        }
+    }
+
+    /// Lowers the dot at `(x, y)`. Pixels outside the canvas are ignored.
+    pub fn unset_pixel(&mut self, x: u32, y: u32) {
+        if let Some((index, bit)) = self.dot(x, y) {
+            self.cells[index] &= !bit;
+        }
+    }
+
+    /// Returns whether the dot at `(x, y)` is raised
+    pub fn pixel(&self, x: u32, y: u32) -> bool {
+        self.dot(x, y)
+            .is_some_and(|(index, bit)| self.cells[index] & bit != 0)
+    }
+
+    /// Draws a straight line between two pixels, both ends included.
+    ///
+    /// Coordinates may lie outside the canvas; only the visible part is drawn.
+    pub fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) {
+        // Bresenham's algorithm, stepping one pixel at a time along the major axis
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let step_x = if x0 < x1 { 1 } else { -1 };
+        let step_y = if y0 < y1 { 1 } else { -1 };
+        let (mut x, mut y) = (x0, y0);
+        let mut error = dx + dy;
+
+        loop {
+            if x >= 0 && y >= 0 {
+                self.set_pixel(x as u32, y as u32);
+            }
+            if x == x1 && y == y1 {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Lowers every dot
+    pub fn clear(&mut self) {
+        self.cells.fill(0);
+    }
+
+    /// Returns each row of cells as a line of Braille characters
+    pub fn lines(&self) -> Vec<String> {
+        if self.cols == 0 {
+            return vec![String::new(); self.rows as usize];
+        }
+        self.cells
+            .chunks(self.cols as usize)
+            .map(|row| {
+                row.iter()
+                    .map(|&dots| char::from_u32(BRAILLE_BLANK + dots as u32).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the cell index and dot bit of pixel `(x, y)`, if it's on the canvas
+    fn dot(&self, x: u32, y: u32) -> Option<(usize, u8)> {
+        if x >= self.pixel_width() || y >= self.pixel_height() {
+            return None;
+        }
+        let index = (y / 4) as usize * self.cols as usize + (x / 2) as usize;
+        Some((index, BRAILLE_DOTS[(y % 4) as usize][(x % 2) as usize]))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Canvas {
+    fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        Action::none()
+    }
+
+    fn view(&self, _ctx: &Context) -> Node {
+        let style = self.color.map(|color| TextStyle {
+            color: Some(color),
+            ..Default::default()
+        });
+
+        let mut container = Div::new()
+            .direction(Direction::Vertical)
+            .width(self.cols)
+            .height(self.rows);
+        for line in self.lines() {
+            let mut text = Text::new(line);
+            text.style = style.clone();
+            container = container.child(text.into());
+        }
+
+        container.into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_buffer;
+
+    #[test]
+    fn test_sub_pixels_map_to_braille_dots() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.set_pixel(0, 0);
+        assert_eq!(canvas.lines(), ["\u{2801}\u{2800}"]);
+
+        // Bottom-right dot of the second cell is dot 8
+        canvas.set_pixel(3, 3);
+        assert_eq!(canvas.lines(), ["\u{2801}\u{2880}"]);
+
+        // Raising every dot of a cell gives the full pattern
+        for y in 0..4 {
+            for x in 0..2 {
+                canvas.set_pixel(x, y);
+            }
+        }
+        assert_eq!(canvas.lines(), ["\u{28FF}\u{2880}"]);
+
+        canvas.unset_pixel(0, 3);
+        assert_eq!(canvas.lines(), ["\u{28BF}\u{2880}"]);
+        assert!(!canvas.pixel(0, 3));
+    }
+
+    #[test]
+    fn test_out_of_range_pixels_are_ignored() {
+        let mut canvas = Canvas::new(1, 1);
+        canvas.set_pixel(2, 0);
+        canvas.set_pixel(0, 4);
+        assert_eq!(canvas.lines(), ["\u{2800}"]);
+    }
+
+    #[test]
+    fn test_line_draws_every_pixel_between_its_ends() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.line(-2, 1, 3, 1);
+        assert_eq!(canvas.lines(), ["\u{2812}\u{2812}"]);
+
+        // A diagonal steps one pixel on each axis at a time
+        let mut canvas = Canvas::new(2, 1);
+        canvas.line(0, 0, 3, 3);
+        assert!((0..4).all(|i| canvas.pixel(i, i)));
+        assert_eq!(canvas.lines(), ["\u{2811}\u{2884}"]);
+    }
+
+    #[test]
+    fn test_renders_one_text_row_per_cell_row() {
+        let mut canvas = Canvas::new(2, 2).color(Color::Green);
+        canvas.set_pixel(0, 4);
+        let buffer = render_to_buffer(canvas.view(&Context::default()), 2, 2);
+
+        assert_eq!(buffer.get_cell(0, 1).unwrap().char, '\u{2801}');
+        assert_eq!(buffer.get_cell(0, 1).unwrap().fg, Some(Color::Green));
+    }
+}
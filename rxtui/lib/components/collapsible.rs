@@ -0,0 +1,229 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::node::{Div, Node, Text};
+use crate::style::{Color, Direction, Style, TextStyle};
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for Collapsible component
+#[derive(Debug, Clone)]
+pub enum CollapsibleMsg {
+    /// Expand or collapse the content
+    Toggle,
+}
+
+/// State for Collapsible component
+#[derive(Debug, Clone, Default)]
+struct CollapsibleState {
+    /// Expanded flag once the user has toggled; None until then
+    expanded: Option<bool>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Section with a clickable header that shows or hides its content.
+///
+/// The header is focusable and toggles on click or Enter. Collapsed content is
+/// not rendered at all, so it takes no space in the layout:
+///
+/// ```rust,ignore
+/// let details = Collapsible::new("Details", || {
+///     node! { div [ text("Hidden until expanded") ] }
+/// })
+/// .on_toggle(|expanded| log::info!("expanded: {expanded}"));
+/// ```
+pub struct Collapsible {
+    header: String,
+    content: Box<dyn Fn() -> Node>,
+    initially_expanded: bool,
+    header_color: Option<Color>,
+    focus_style: Option<Style>,
+    on_toggle: Option<Box<dyn Fn(bool)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Collapsible {
+    /// Creates a collapsed section; `content` builds the body whenever it is shown
+    pub fn new(header: impl Into<String>, content: impl Fn() -> Node + 'static) -> Self {
+        Self {
+            header: header.into(),
+            content: Box::new(content),
+            initially_expanded: false,
+            header_color: None,
+            focus_style: None,
+            on_toggle: None,
+        }
+    }
+
+    /// Sets whether the section starts expanded
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.initially_expanded = expanded;
+        self
+    }
+
+    /// Sets the header text color
+    pub fn header_color(mut self, color: Color) -> Self {
+        self.header_color = Some(color);
+        self
+    }
+
+    /// Sets the header style while it has focus
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Sets the callback invoked with the new expanded state after each toggle
+    pub fn on_toggle(mut self, callback: impl Fn(bool) + 'static) -> Self {
+        self.on_toggle = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns whether the content is currently shown
+    fn is_expanded(&self, state: &CollapsibleState) -> bool {
+        state.expanded.unwrap_or(self.initially_expanded)
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        if let Some(msg) = msg.downcast::<CollapsibleMsg>() {
+            let mut state = ctx.get_state::<CollapsibleState>();
+            match msg {
+                CollapsibleMsg::Toggle => {
+                    let expanded = !self.is_expanded(&state);
+                    state.expanded = Some(expanded);
+                    if let Some(callback) = &self.on_toggle {
+                        callback(expanded);
+                    }
+                    return Action::update(state);
+                }
+            }
+        }
+        Action::none()
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<CollapsibleState>();
+        let expanded = self.is_expanded(&state);
+
+        let indicator = if expanded { '▾' } else { '▸' };
+        let mut title = Text::new(format!("{indicator} {}", self.header));
+        if let Some(color) = self.header_color {
+            title.style = Some(TextStyle {
+                color: Some(color),
+                ..Default::default()
+            });
+        }
+
+        let mut header = Div::new()
+            .focusable(true)
+            .height(1)
+            .on_click(ctx.handler(CollapsibleMsg::Toggle))
+            .child(title.into());
+        if let Some(style) = &self.focus_style {
+            header = header.focus_style(style.clone());
+        }
+
+        let mut container = Div::new()
+            .direction(Direction::Vertical)
+            .child(header.into());
+        if expanded {
+            container = container.child((self.content)());
+        }
+
+        container.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Collapsible {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Collapsible::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Collapsible::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::layout_node;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn section() -> Collapsible {
+        Collapsible::new("Details", || {
+            Div::new()
+                .child(Text::new("line one").into())
+                .child(Text::new("line two").into())
+                .into()
+        })
+    }
+
+    fn toggle(collapsible: &Collapsible, ctx: &Context) -> bool {
+        if let Action::Update(state) =
+            Collapsible::update(collapsible, ctx, Box::new(CollapsibleMsg::Toggle), None)
+        {
+            ctx.set_state(state);
+        }
+        collapsible.is_expanded(&ctx.get_state::<CollapsibleState>())
+    }
+
+    fn root_child_count(collapsible: &Collapsible, ctx: &Context) -> usize {
+        let vdom = layout_node(Collapsible::view(collapsible, ctx), 20, 10);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        root.borrow().children.len()
+    }
+
+    #[test]
+    fn test_toggle_changes_expanded_state() {
+        let toggles = Rc::new(RefCell::new(Vec::new()));
+        let sink = toggles.clone();
+        let collapsible = section().on_toggle(move |expanded| sink.borrow_mut().push(expanded));
+        let ctx = Context::default();
+
+        assert!(!collapsible.is_expanded(&ctx.get_state::<CollapsibleState>()));
+        assert!(toggle(&collapsible, &ctx));
+        assert!(!toggle(&collapsible, &ctx));
+        assert_eq!(*toggles.borrow(), vec![true, false]);
+
+        // Starting expanded flips the first toggle the other way
+        let ctx = Context::default();
+        assert!(!toggle(&section().expanded(true), &ctx));
+    }
+
+    #[test]
+    fn test_collapsed_content_is_not_rendered() {
+        let collapsible = section();
+        let ctx = Context::default();
+
+        // Only the header exists while collapsed
+        assert_eq!(root_child_count(&collapsible, &ctx), 1);
+
+        toggle(&collapsible, &ctx);
+        assert_eq!(root_child_count(&collapsible, &ctx), 2);
+    }
+}
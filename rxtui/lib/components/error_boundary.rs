@@ -0,0 +1,202 @@
+use crate::Context;
+use crate::component::{Action, Component, Message};
+use crate::node::Node;
+use std::sync::Arc;
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Renders a fallback instead of crashing the app when its child panics.
+///
+/// A panic in the `update` or `view` of the child, or of any component below
+/// it, is caught while the tree is expanded. The boundary then shows the
+/// fallback built from the panic message, and the rest of the tree renders as
+/// usual. The child is tried again whenever the boundary re-renders.
+///
+/// ```rust,ignore
+/// let panel = ErrorBoundary::new(Chart::new(data), |error| {
+///     node! { text(format!("chart failed: {error}"), color: red) }
+/// });
+/// ```
+pub struct ErrorBoundary {
+    child: Arc<dyn Component>,
+    fallback: Box<dyn Fn(&str) -> Node>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ErrorBoundary {
+    /// Wraps `child`; `fallback` builds the node shown with the panic message
+    pub fn new(child: impl Component, fallback: impl Fn(&str) -> Node + 'static) -> Self {
+        Self {
+            child: Arc::new(child),
+            fallback: Box::new(fallback),
+        }
+    }
+
+    /// Builds the node shown in place of the child after it panicked
+    pub(crate) fn fallback(&self, message: &str) -> Node {
+        (self.fallback)(message)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for ErrorBoundary {
+    fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        Action::none()
+    }
+
+    fn view(&self, _ctx: &Context) -> Node {
+        Node::Component(self.child.clone())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::node::{Div, Text};
+    use crate::style::Direction;
+
+    struct Faulty;
+
+    impl Component for Faulty {
+        fn view(&self, _ctx: &Context) -> Node {
+            panic!("chart data missing");
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Blow;
+
+    /// Renders fine but panics handling any message
+    struct Fuse;
+
+    impl Component for Fuse {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            panic!("fuse blew");
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("armed").into()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    struct Healthy;
+
+    impl Component for Healthy {
+        fn view(&self, _ctx: &Context) -> Node {
+            Text::new("chart").into()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    /// Page with a boundary around `child` between a header and a footer.
+    struct Page<C: Fn() -> ErrorBoundary + 'static>(C);
+
+    impl<C: Fn() -> ErrorBoundary + 'static> Component for Page<C> {
+        fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .direction(Direction::Vertical)
+                .children(vec![
+                    Text::new("header").into(),
+                    crate::node! { node((self.0)()) },
+                    Text::new("footer").into(),
+                ])
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn render(page: impl Component) -> Vec<String> {
+        let mut runner = App::headless(30, 3).into_runner(page);
+        runner.step_frame().unwrap()
+    }
+
+    fn fallback(error: &str) -> Node {
+        Text::new(format!("failed: {error}")).into()
+    }
+
+    #[test]
+    fn test_panicking_child_renders_fallback() {
+        let lines = render(Page(|| ErrorBoundary::new(Faulty, fallback)));
+        assert_eq!(lines, ["header", "failed: chart data missing", "footer"]);
+    }
+
+    #[test]
+    fn test_healthy_child_renders_normally() {
+        let lines = render(Page(|| ErrorBoundary::new(Healthy, fallback)));
+        assert_eq!(lines, ["header", "chart", "footer"]);
+    }
+
+    #[test]
+    fn test_root_boundary_renders_fallback() {
+        let lines = render(ErrorBoundary::new(Faulty, fallback));
+        assert_eq!(lines, ["failed: chart data missing", "", ""]);
+    }
+
+    #[test]
+    fn test_panicking_update_renders_fallback() {
+        let mut runner =
+            App::headless(30, 3).into_runner(Page(|| ErrorBoundary::new(Fuse, fallback)));
+        assert_eq!(runner.step_frame().unwrap(), ["header", "armed", "footer"]);
+
+        runner.context().broadcast(Blow);
+        assert_eq!(
+            runner.step_frame().unwrap(),
+            ["header", "failed: fuse blew", "footer"]
+        );
+    }
+}
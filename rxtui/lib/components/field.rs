@@ -0,0 +1,255 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::TextInput;
+use crate::node::{Div, Node, Text};
+use crate::style::Direction;
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for Field component
+#[derive(Debug, Clone)]
+pub enum FieldMsg {
+    /// The input content changed
+    Changed(String),
+
+    /// The input lost focus
+    Blurred,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Outcome of a field's last validation.
+///
+/// Kept as the field's own state, or published to its validity topic when one
+/// is set so other components can read it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldValidity {
+    /// Input content when it was last validated
+    pub value: String,
+
+    /// Message returned by the validator, or `None` if the value passed
+    pub error: Option<String>,
+}
+
+/// Function checking a field's value, returning the message to show when invalid
+pub type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
+
+/// Text input that validates its value and shows the error below it.
+///
+/// The validator runs whenever the content changes and when the input loses
+/// focus. Invalid values render the message in the theme's error color.
+/// Setting a validity topic publishes each [`FieldValidity`] so a submit button
+/// can check the whole form with [`Field::all_valid`]:
+///
+/// ```rust,ignore
+/// let email = Field::new(|| TextInput::new().placeholder("Email"))
+///     .validate(|value| {
+///         if value.contains('@') {
+///             Ok(())
+///         } else {
+///             Err("Enter a valid email".into())
+///         }
+///     })
+///     .validity_topic("signup.email");
+///
+/// // In the submit button's view
+/// let enabled = Field::all_valid(ctx, &["signup.email", "signup.name"]);
+/// ```
+pub struct Field {
+    input: Box<dyn Fn() -> TextInput>,
+    validator: Option<Validator>,
+    validity_topic: Option<String>,
+    on_change: Option<Box<dyn Fn(String)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Field {
+    /// Creates a field around the input built by `input`.
+    ///
+    /// The field installs its own change and blur callbacks on the input; use
+    /// [`Field::on_change`] to observe changes.
+    pub fn new(input: impl Fn() -> TextInput + 'static) -> Self {
+        Self {
+            input: Box::new(input),
+            validator: None,
+            validity_topic: None,
+            on_change: None,
+        }
+    }
+
+    /// Sets the function that checks the value
+    pub fn validate(mut self, validator: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Publishes the field's validity to `topic` instead of keeping it private
+    pub fn validity_topic(mut self, topic: impl Into<String>) -> Self {
+        self.validity_topic = Some(topic.into());
+        self
+    }
+
+    /// Sets the callback invoked with the new content after each change
+    pub fn on_change(mut self, callback: impl Fn(String) + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns true if every field publishing to `topics` holds a valid value.
+    ///
+    /// Fields that have not been validated yet count as invalid.
+    pub fn all_valid(ctx: &Context, topics: &[&str]) -> bool {
+        topics.iter().all(|topic| {
+            ctx.subscribe_topic::<FieldValidity>(topic)
+                .is_some_and(|validity| validity.error.is_none())
+        })
+    }
+
+    /// Returns the result of the last validation
+    fn validity(&self, ctx: &Context) -> FieldValidity {
+        match &self.validity_topic {
+            Some(topic) => ctx.topics.read_topic(topic).unwrap_or_default(),
+            None => ctx.get_state::<FieldValidity>(),
+        }
+    }
+
+    /// Runs the validator against `value`
+    fn check(&self, value: String) -> FieldValidity {
+        let error = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator(&value).err());
+        FieldValidity { value, error }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<FieldMsg>() else {
+            return Action::none();
+        };
+
+        let validity = match msg {
+            FieldMsg::Changed(value) => {
+                if let Some(callback) = &self.on_change {
+                    callback(value.clone());
+                }
+                self.check(value.clone())
+            }
+            FieldMsg::Blurred => self.check(self.validity(ctx).value),
+        };
+
+        match &self.validity_topic {
+            Some(topic) => Action::update_topic(topic.clone(), validity),
+            None => Action::update(validity),
+        }
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let input = (self.input)()
+            .on_change(ctx.handler_with_value(FieldMsg::Changed))
+            .on_blur(ctx.handler(FieldMsg::Blurred));
+
+        let mut container = Div::new()
+            .direction(Direction::Vertical)
+            .child(crate::node! { node(input) });
+        if let Some(error) = self.validity(ctx).error {
+            container = container.child(Text::new(error).color(ctx.theme().error).into());
+        }
+
+        container.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Field {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Field::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Field::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Runner};
+    use crate::key::Key;
+
+    fn email() -> Field {
+        Field::new(|| TextInput::new().width(20)).validate(|value| {
+            if value.contains('@') {
+                Ok(())
+            } else {
+                Err("missing @".into())
+            }
+        })
+    }
+
+    fn type_text(runner: &mut Runner, text: &str) {
+        for ch in text.chars() {
+            runner.send_key(Key::Char(ch)).unwrap();
+            runner.step_frame().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_invalid_value_shows_error_and_valid_value_clears_it() {
+        let mut runner = App::headless(30, 6).into_runner(email());
+        let lines = runner.step_frame().unwrap();
+        assert!(!lines.iter().any(|line| line.contains("missing @")));
+
+        runner.send_key(Key::Tab).unwrap();
+        runner.step_frame().unwrap();
+        type_text(&mut runner, "me");
+        let lines = runner.step_frame().unwrap();
+        assert!(lines.iter().any(|line| line == "missing @"), "{lines:?}");
+
+        type_text(&mut runner, "@x");
+        let lines = runner.step_frame().unwrap();
+        assert!(!lines.iter().any(|line| line.contains("missing @")));
+    }
+
+    #[test]
+    fn test_validity_topic_reports_form_validity() {
+        let field = email().validity_topic("form.email");
+        let ctx = Context::default();
+        assert!(!Field::all_valid(&ctx, &["form.email"]));
+
+        let apply = |msg: FieldMsg| {
+            if let Action::UpdateTopic(topic, state) = field.update(&ctx, Box::new(msg), None) {
+                ctx.topics.update_topic(topic, state, ctx.id().clone());
+            }
+        };
+        apply(FieldMsg::Changed("nope".into()));
+        assert!(!Field::all_valid(&ctx, &["form.email"]));
+        assert_eq!(field.validity(&ctx).error.as_deref(), Some("missing @"));
+
+        apply(FieldMsg::Changed("a@b".into()));
+        apply(FieldMsg::Blurred);
+        assert!(Field::all_valid(&ctx, &["form.email"]));
+        assert_eq!(field.validity(&ctx).value, "a@b");
+    }
+}
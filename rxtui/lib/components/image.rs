@@ -0,0 +1,232 @@
+use crate::Context;
+use crate::component::{Action, Component, Message};
+use crate::node::{Div, Node, RichText};
+use crate::style::{Color, Direction, TextStyle};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Upper half block; the foreground paints the top pixel and the background the bottom one
+const UPPER_HALF_BLOCK: char = '▀';
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Renders an RGB image with Unicode half-block characters.
+///
+/// Every terminal cell shows two vertically stacked pixels: the foreground color of
+/// `▀` paints the top pixel and the background color paints the bottom one. The
+/// image is nearest-neighbor scaled to the requested cell size, and requires a
+/// truecolor terminal to look right.
+///
+/// Decoding image files is left to the caller; pass raw RGB bytes:
+///
+/// ```rust,ignore
+/// let logo = Image::from_rgb(32, 32, pixels).size(16, 8);
+/// node! {
+///     div [ node(logo) ]
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Image {
+    /// Source pixels, 3 bytes per pixel, row-major
+    pixels: Vec<u8>,
+
+    /// Source width in pixels
+    width: usize,
+
+    /// Source height in pixels
+    height: usize,
+
+    /// Rendered width in cells
+    cols: u16,
+
+    /// Rendered height in cells (two pixels per cell)
+    rows: u16,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Image {
+    /// Creates an image from packed RGB bytes.
+    ///
+    /// By default it renders one cell per pixel column and one cell per two pixel rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rgb.len()` is not `width * height * 3`.
+    pub fn from_rgb(width: usize, height: usize, rgb: impl Into<Vec<u8>>) -> Self {
+        let pixels = rgb.into();
+        assert_eq!(
+            pixels.len(),
+            width * height * 3,
+            "RGB buffer of {width}x{height} image must hold {} bytes",
+            width * height * 3
+        );
+
+        Self {
+            pixels,
+            width,
+            height,
+            cols: width.min(u16::MAX as usize) as u16,
+            rows: height.div_ceil(2).min(u16::MAX as usize) as u16,
+        }
+    }
+
+    /// Sets the rendered size in terminal cells
+    pub fn size(mut self, cols: u16, rows: u16) -> Self {
+        self.cols = cols;
+        self.rows = rows;
+        self
+    }
+
+    /// Returns the source pixel at `(x, y)`
+    fn pixel(&self, x: usize, y: usize) -> Color {
+        let i = (y * self.width + x) * 3;
+        Color::Rgb(self.pixels[i], self.pixels[i + 1], self.pixels[i + 2])
+    }
+
+    /// Samples the source for output pixel `(x, y)` of a `cols x rows * 2` grid
+    fn sample(&self, x: usize, y: usize) -> Color {
+        let src_x = x * self.width / self.cols as usize;
+        let src_y = y * self.height / (self.rows as usize * 2);
+        self.pixel(src_x, src_y)
+    }
+
+    /// Builds one line of half blocks, merging neighbouring cells with the same colors
+    fn row(&self, row: usize) -> RichText {
+        let mut text = RichText::new();
+        let mut run: Option<(Color, Color, usize)> = None;
+
+        for col in 0..self.cols as usize {
+            let top = self.sample(col, row * 2);
+            let bottom = self.sample(col, row * 2 + 1);
+            run = match run {
+                Some((t, b, len)) if t == top && b == bottom => Some((t, b, len + 1)),
+                Some((t, b, len)) => {
+                    text = Self::push_run(text, t, b, len);
+                    Some((top, bottom, 1))
+                }
+                None => Some((top, bottom, 1)),
+            };
+        }
+
+        match run {
+            Some((top, bottom, len)) => Self::push_run(text, top, bottom, len),
+            None => text,
+        }
+    }
+
+    fn push_run(text: RichText, top: Color, bottom: Color, len: usize) -> RichText {
+        text.styled(
+            UPPER_HALF_BLOCK.to_string().repeat(len),
+            TextStyle {
+                color: Some(top),
+                background: Some(bottom),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Image {
+    fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        Action::none()
+    }
+
+    fn view(&self, _ctx: &Context) -> Node {
+        let mut container = Div::new()
+            .direction(Direction::Vertical)
+            .width(self.cols)
+            .height(self.rows);
+
+        if self.width > 0 && self.height > 0 && self.cols > 0 {
+            for row in 0..self.rows as usize {
+                container = container.child(self.row(row).into());
+            }
+        }
+
+        container.into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_buffer;
+
+    const RED: (u8, u8, u8) = (255, 0, 0);
+    const BLUE: (u8, u8, u8) = (0, 0, 255);
+
+    fn rgb(pixels: &[(u8, u8, u8)]) -> Vec<u8> {
+        pixels.iter().flat_map(|&(r, g, b)| [r, g, b]).collect()
+    }
+
+    #[test]
+    fn test_solid_image_renders_half_blocks() {
+        let image = Image::from_rgb(2, 2, rgb(&[RED, RED, RED, RED]));
+        let buffer = render_to_buffer(image.view(&Context::default()), 2, 1);
+
+        for x in 0..2 {
+            let cell = buffer.get_cell(x, 0).unwrap();
+            assert_eq!(cell.char, UPPER_HALF_BLOCK);
+            assert_eq!(cell.fg, Some(Color::Rgb(255, 0, 0)));
+            assert_eq!(cell.bg, Some(Color::Rgb(255, 0, 0)));
+        }
+    }
+
+    #[test]
+    fn test_top_and_bottom_pixels_share_a_cell() {
+        let image = Image::from_rgb(2, 2, rgb(&[RED, RED, BLUE, BLUE]));
+        let buffer = render_to_buffer(image.view(&Context::default()), 2, 1);
+
+        let cell = buffer.get_cell(1, 0).unwrap();
+        assert_eq!(cell.char, UPPER_HALF_BLOCK);
+        assert_eq!(cell.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(cell.bg, Some(Color::Rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn test_nearest_neighbor_scaling() {
+        // 2x1 source stretched to 4 cells: each pixel covers two columns
+        let image = Image::from_rgb(2, 1, rgb(&[RED, BLUE])).size(4, 1);
+        let row = image.row(0);
+
+        assert_eq!(row.spans.len(), 2);
+        assert_eq!(row.spans[0].content, "▀▀");
+        assert_eq!(
+            row.spans[0].style.as_ref().unwrap().color,
+            Some(Color::Rgb(255, 0, 0))
+        );
+        assert_eq!(
+            row.spans[1].style.as_ref().unwrap().background,
+            Some(Color::Rgb(0, 0, 255))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must hold 12 bytes")]
+    fn test_rejects_short_buffer() {
+        Image::from_rgb(2, 2, vec![0; 6]);
+    }
+}
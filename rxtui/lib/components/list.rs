@@ -0,0 +1,369 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Div, Node, Text};
+use crate::style::{Direction, TextStyle};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Idle time after which type-ahead starts a new search
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for List component
+#[derive(Debug, Clone)]
+pub enum ListMsg {
+    /// Move the cursor up one item
+    Up,
+
+    /// Move the cursor down one item
+    Down,
+
+    /// Move the cursor to the first item
+    First,
+
+    /// Move the cursor to the last item
+    Last,
+
+    /// Select the item under the cursor, or toggle it in multi-select mode
+    Toggle,
+
+    /// Move the cursor to an item and select or toggle it
+    Select(usize),
+
+    /// Extend the type-ahead search with a typed character
+    Type(char),
+}
+
+/// State for List component
+#[derive(Debug, Clone, Default)]
+struct ListState {
+    /// Index of the item under the cursor
+    cursor: usize,
+
+    /// Indices of the selected items
+    selected: BTreeSet<usize>,
+
+    /// Lowercased prefix typed so far
+    search: String,
+
+    /// Clock time of the last typed character
+    searched_at: Duration,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Vertical list of items with single or multi-select.
+///
+/// When focused, Up/Down move the cursor, Home/End jump to the ends, and Enter
+/// or Space select the item under it. Clicking an item selects it too. In
+/// multi-select mode selecting toggles the item, and each row shows a checkbox.
+/// Typing jumps to the first item starting with the typed prefix, selecting it
+/// in single-select mode; the prefix resets after a second without typing:
+///
+/// ```text
+///   › [x] Apples
+///     [ ] Pears
+///     [x] Plums
+/// ```
+///
+/// ```rust,ignore
+/// let fruit = List::new(["Apples", "Pears", "Plums"])
+///     .multi_select(true)
+///     .on_select(|selected| log::info!("selected: {selected:?}"));
+/// ```
+pub struct List {
+    items: Vec<String>,
+    multi_select: bool,
+    on_select: Option<Box<dyn Fn(Vec<usize>)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl List {
+    /// Creates a single-select list of the given items
+    pub fn new(items: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            multi_select: false,
+            on_select: None,
+        }
+    }
+
+    /// Sets whether several items can be selected at once
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Sets the callback invoked with the selected indices, in order, after each change
+    pub fn on_select(mut self, callback: impl Fn(Vec<usize>) + 'static) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Selects `index`, replacing the selection or toggling it in multi-select mode
+    fn select(&self, state: &mut ListState, index: usize) {
+        if !self.multi_select {
+            state.selected = BTreeSet::from([index]);
+        } else if !state.selected.remove(&index) {
+            state.selected.insert(index);
+        }
+    }
+
+    /// Extends the search with `ch` and moves the cursor to the first match
+    fn type_ahead(&self, ctx: &Context, state: &mut ListState, ch: char) {
+        let now = ctx.clock.elapsed();
+        if now.saturating_sub(state.searched_at) >= TYPE_AHEAD_TIMEOUT {
+            state.search.clear();
+        }
+        state.search.extend(ch.to_lowercase());
+        state.searched_at = now;
+
+        let found = self
+            .items
+            .iter()
+            .position(|item| item.to_lowercase().starts_with(&state.search));
+        if let Some(index) = found {
+            state.cursor = index;
+            if !self.multi_select {
+                self.select(state, index);
+            }
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<ListMsg>() else {
+            return Action::none();
+        };
+        if self.items.is_empty() {
+            return Action::none();
+        }
+
+        let mut state = ctx.get_state::<ListState>();
+        let last = self.items.len() - 1;
+        let before = state.selected.clone();
+        match msg {
+            ListMsg::Up => state.cursor = state.cursor.saturating_sub(1),
+            ListMsg::Down => state.cursor = (state.cursor + 1).min(last),
+            ListMsg::First => state.cursor = 0,
+            ListMsg::Last => state.cursor = last,
+            ListMsg::Toggle => {
+                let cursor = state.cursor.min(last);
+                self.select(&mut state, cursor);
+            }
+            ListMsg::Select(index) => {
+                state.cursor = (*index).min(last);
+                let cursor = state.cursor;
+                self.select(&mut state, cursor);
+            }
+            ListMsg::Type(ch) => self.type_ahead(ctx, &mut state, *ch),
+        }
+
+        if state.selected != before
+            && let Some(callback) = &self.on_select
+        {
+            callback(state.selected.iter().copied().collect());
+        }
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<ListState>();
+        let theme = ctx.theme();
+
+        let rows = self.items.iter().enumerate().map(|(index, item)| {
+            let selected = state.selected.contains(&index);
+            let pointer = if index == state.cursor { "› " } else { "  " };
+            let checkbox = match (self.multi_select, selected) {
+                (false, _) => "",
+                (true, true) => "[x] ",
+                (true, false) => "[ ] ",
+            };
+
+            let mut label = Text::new(format!("{pointer}{checkbox}{item}"));
+            label.style = Some(TextStyle {
+                color: Some(if selected { theme.accent } else { theme.text }),
+                bold: selected.then_some(true),
+                ..Default::default()
+            });
+
+            Div::new()
+                .height(1)
+                .on_click(ctx.handler(ListMsg::Select(index)))
+                .child(label.into())
+                .into()
+        });
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .focusable(true)
+            .on_key(Key::Up, ctx.handler(ListMsg::Up))
+            .on_key(Key::Down, ctx.handler(ListMsg::Down))
+            .on_key(Key::Home, ctx.handler(ListMsg::First))
+            .on_key(Key::End, ctx.handler(ListMsg::Last))
+            .on_key(Key::Enter, ctx.handler(ListMsg::Toggle))
+            .on_key(Key::Char(' '), ctx.handler(ListMsg::Toggle))
+            .on_any_char({
+                let type_ahead = ctx.handler_with_value(ListMsg::Type);
+                // Space is taken by selection
+                move |ch| {
+                    if !ch.is_whitespace() {
+                        type_ahead(ch)
+                    }
+                }
+            })
+            .children(rows.collect())
+            .into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for List {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        List::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        List::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_buffer;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn fruit() -> List {
+        List::new(["Apples", "Pears", "Plums"])
+    }
+
+    fn send(list: &List, ctx: &Context, msg: ListMsg) {
+        if let Action::Update(state) = List::update(list, ctx, Box::new(msg), None) {
+            ctx.set_state(state);
+        }
+    }
+
+    fn selected(ctx: &Context) -> Vec<usize> {
+        ctx.get_state::<ListState>().selected.into_iter().collect()
+    }
+
+    fn lines(list: &List, ctx: &Context) -> Vec<String> {
+        render_to_buffer(List::view(list, ctx), 20, 3)
+            .lines()
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_single_select_replaces_selection() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let sink = changes.clone();
+        let list = fruit().on_select(move |selected| sink.borrow_mut().push(selected));
+        let ctx = Context::default();
+
+        send(&list, &ctx, ListMsg::Select(1));
+        send(&list, &ctx, ListMsg::Select(2));
+        assert_eq!(selected(&ctx), [2]);
+
+        // Selecting the same item again changes nothing
+        send(&list, &ctx, ListMsg::Toggle);
+        assert_eq!(*changes.borrow(), [vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn test_multi_select_toggles_membership() {
+        let list = fruit().multi_select(true);
+        let ctx = Context::default();
+
+        send(&list, &ctx, ListMsg::Toggle);
+        send(&list, &ctx, ListMsg::Select(2));
+        assert_eq!(selected(&ctx), [0, 2]);
+        assert_eq!(
+            lines(&list, &ctx),
+            ["  [x] Apples", "  [ ] Pears", "› [x] Plums"]
+        );
+
+        send(&list, &ctx, ListMsg::First);
+        send(&list, &ctx, ListMsg::Toggle);
+        assert_eq!(selected(&ctx), [2]);
+    }
+
+    #[test]
+    fn test_type_ahead_jumps_to_prefix_and_resets_when_idle() {
+        let list = List::new(["apple", "apricot", "banana", "blueberry"]);
+        let ctx = Context::default();
+        ctx.clock.set_stepped();
+
+        send(&list, &ctx, ListMsg::Type('b'));
+        send(&list, &ctx, ListMsg::Type('a'));
+        assert_eq!(selected(&ctx), [2]);
+
+        send(&list, &ctx, ListMsg::Type('l'));
+        assert_eq!(
+            ctx.get_state::<ListState>().cursor,
+            2,
+            "no item starts with \"bal\""
+        );
+        ctx.clock
+            .advance(TYPE_AHEAD_TIMEOUT - Duration::from_millis(1));
+        send(&list, &ctx, ListMsg::Type('u'));
+        assert_eq!(ctx.get_state::<ListState>().cursor, 2);
+
+        // After a pause the search starts over
+        ctx.clock.advance(TYPE_AHEAD_TIMEOUT);
+        send(&list, &ctx, ListMsg::Type('b'));
+        send(&list, &ctx, ListMsg::Type('l'));
+        assert_eq!(selected(&ctx), [3]);
+        ctx.clock.advance(TYPE_AHEAD_TIMEOUT);
+        send(&list, &ctx, ListMsg::Type('B'));
+        assert_eq!(selected(&ctx), [2]);
+    }
+
+    #[test]
+    fn test_arrow_keys_move_cursor_within_bounds() {
+        let list = fruit();
+        let ctx = Context::default();
+        assert_eq!(lines(&list, &ctx)[0], "› Apples");
+
+        send(&list, &ctx, ListMsg::Up);
+        assert_eq!(ctx.get_state::<ListState>().cursor, 0);
+        for _ in 0..3 {
+            send(&list, &ctx, ListMsg::Down);
+        }
+        assert_eq!(ctx.get_state::<ListState>().cursor, 2);
+        send(&list, &ctx, ListMsg::Up);
+        send(&list, &ctx, ListMsg::Toggle);
+        assert_eq!(selected(&ctx), [1]);
+        assert_eq!(lines(&list, &ctx), ["  Apples", "› Pears", "  Plums"]);
+    }
+}
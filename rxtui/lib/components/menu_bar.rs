@@ -0,0 +1,567 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Div, Node, Propagation, Text};
+use crate::style::{Border, BorderStyle, Dimension, Direction, Style, TextStyle};
+use crate::utils::display_width;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Z-index that lifts an open menu bar above the content rendered after it
+const OPEN_MENU_Z_INDEX: i32 = 100;
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for MenuBar component
+#[derive(Debug, Clone)]
+pub enum MenuBarMsg {
+    /// Open the top-level menu at an index, or close it if it's already open
+    Open(usize),
+
+    /// Move to the previous top-level menu, or back out of a submenu
+    Left,
+
+    /// Move to the next top-level menu, or into the highlighted submenu
+    Right,
+
+    /// Highlight the previous item in the innermost open menu
+    Up,
+
+    /// Highlight the next item in the innermost open menu, opening the menu if closed
+    Down,
+
+    /// Activate the highlighted item, or open its submenu
+    Enter,
+
+    /// Close the innermost open menu
+    Close,
+
+    /// Activate the item at a path of indices below the open top-level menu
+    Select(Vec<usize>),
+}
+
+/// State for MenuBar component
+#[derive(Debug, Clone, Default)]
+struct MenuBarState {
+    /// Index of the highlighted top-level menu
+    active: usize,
+
+    /// Highlighted item in each open menu, outermost first; empty while closed
+    path: Vec<usize>,
+}
+
+/// Sends an item's message to the menu bar's topic, or broadcasts it
+type MenuAction = Box<dyn Fn(&Context, Option<&str>)>;
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Entry in a menu: either an action carrying a message or a nested submenu.
+pub struct MenuItem {
+    label: String,
+    action: Option<MenuAction>,
+    children: Vec<MenuItem>,
+}
+
+/// Row of top-level menus that open dropdowns of [`MenuItem`]s.
+///
+/// Clicking a menu title or pressing Enter/Down opens its dropdown. Left/Right
+/// move between menus and in and out of submenus, Up/Down move between items,
+/// Enter activates the highlighted item and Esc closes the innermost menu.
+/// Dropdowns are drawn over the content below the bar.
+///
+/// Activating an item broadcasts its message, or sends it to the topic set
+/// with [`MenuBar::topic`]:
+///
+/// ```rust,ignore
+/// let menu = MenuBar::new([
+///     MenuItem::submenu("File", [
+///         MenuItem::new("Open", AppMsg::Open),
+///         MenuItem::submenu("Export", [
+///             MenuItem::new("PDF", AppMsg::Export(Format::Pdf)),
+///             MenuItem::new("HTML", AppMsg::Export(Format::Html)),
+///         ]),
+///         MenuItem::new("Quit", AppMsg::Quit),
+///     ]),
+///     MenuItem::submenu("Help", [MenuItem::new("About", AppMsg::About)]),
+/// ]);
+/// ```
+pub struct MenuBar {
+    menus: Vec<MenuItem>,
+    topic: Option<String>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl MenuItem {
+    /// Creates an item that emits `message` when activated
+    pub fn new<M: Message + Clone>(label: impl Into<String>, message: M) -> Self {
+        Self {
+            label: label.into(),
+            action: Some(Box::new(move |ctx, topic| match topic {
+                Some(topic) => ctx.send_to_topic(topic, message.clone()),
+                None => ctx.broadcast(message.clone()),
+            })),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates an item that opens a nested menu of `items`
+    pub fn submenu(label: impl Into<String>, items: impl IntoIterator<Item = MenuItem>) -> Self {
+        Self {
+            label: label.into(),
+            action: None,
+            children: items.into_iter().collect(),
+        }
+    }
+
+    /// Returns whether the item opens a submenu
+    fn has_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+impl MenuBar {
+    /// Creates a menu bar from its top-level menus
+    pub fn new(menus: impl IntoIterator<Item = MenuItem>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            topic: None,
+        }
+    }
+
+    /// Sends activated items' messages to `topic` instead of broadcasting them
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    /// Returns the item reached by following `path` down from a top-level menu
+    fn item_at(&self, active: usize, path: &[usize]) -> Option<&MenuItem> {
+        path.iter()
+            .try_fold(self.menus.get(active)?, |item, &index| {
+                item.children.get(index)
+            })
+    }
+
+    /// Opens the top-level menu at `index`, activating it directly if it has no items
+    fn open(&self, ctx: &Context, state: &mut MenuBarState, index: usize) {
+        state.active = index;
+        state.path.clear();
+        match self.menus.get(index) {
+            Some(menu) if menu.has_submenu() => state.path.push(0),
+            Some(menu) => self.activate(ctx, menu),
+            None => {}
+        }
+    }
+
+    /// Highlights the top-level menu `offset` places away, keeping it open if one was
+    fn cycle(&self, ctx: &Context, state: &mut MenuBarState, offset: isize) {
+        let count = self.menus.len() as isize;
+        let index = (state.active as isize + offset).rem_euclid(count) as usize;
+        if state.path.is_empty() || !self.menus[index].has_submenu() {
+            state.active = index;
+            state.path.clear();
+        } else {
+            self.open(ctx, state, index);
+        }
+    }
+
+    /// Emits the item's message
+    fn activate(&self, ctx: &Context, item: &MenuItem) {
+        if let Some(action) = &item.action {
+            action(ctx, self.topic.as_deref());
+        }
+    }
+
+    /// Opens the highlighted item's submenu or activates it and closes the menus
+    fn enter(&self, ctx: &Context, state: &mut MenuBarState) {
+        let Some(item) = self.item_at(state.active, &state.path) else {
+            return;
+        };
+        if item.has_submenu() {
+            state.path.push(0);
+        } else {
+            self.activate(ctx, item);
+            state.path.clear();
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<MenuBarMsg>() else {
+            return Action::none();
+        };
+        if self.menus.is_empty() {
+            return Action::none();
+        }
+
+        let mut state = ctx.get_state::<MenuBarState>();
+        let open = !state.path.is_empty();
+        match msg {
+            MenuBarMsg::Open(index) => {
+                if open && state.active == *index {
+                    state.path.clear();
+                } else {
+                    self.open(ctx, &mut state, *index);
+                    ctx.focus_self();
+                }
+            }
+            MenuBarMsg::Left if state.path.len() > 1 => {
+                state.path.pop();
+            }
+            MenuBarMsg::Left => self.cycle(ctx, &mut state, -1),
+            MenuBarMsg::Right => {
+                let item = self.item_at(state.active, &state.path);
+                if open && item.is_some_and(MenuItem::has_submenu) {
+                    state.path.push(0);
+                } else {
+                    self.cycle(ctx, &mut state, 1);
+                }
+            }
+            MenuBarMsg::Up | MenuBarMsg::Down if open => {
+                let depth = state.path.len() - 1;
+                let count = self
+                    .item_at(state.active, &state.path[..depth])
+                    .map_or(1, |menu| menu.children.len());
+                let offset = if matches!(msg, MenuBarMsg::Up) { -1 } else { 1 };
+                let index = &mut state.path[depth];
+                *index = (*index as isize + offset).rem_euclid(count as isize) as usize;
+            }
+            MenuBarMsg::Up => {}
+            MenuBarMsg::Down | MenuBarMsg::Enter if !open => {
+                let active = state.active;
+                self.open(ctx, &mut state, active);
+            }
+            MenuBarMsg::Down => {}
+            MenuBarMsg::Enter => self.enter(ctx, &mut state),
+            MenuBarMsg::Close => {
+                state.path.pop();
+            }
+            MenuBarMsg::Select(path) => {
+                if self.item_at(state.active, path).is_none() {
+                    return Action::none();
+                }
+                state.path = path.clone();
+                self.enter(ctx, &mut state);
+            }
+        }
+
+        Action::update(state)
+    }
+
+    /// Builds the dropdown listing `items`, with the submenu of the highlighted
+    /// item drawn beside it when one is open
+    fn dropdown(
+        &self,
+        ctx: &Context,
+        items: &[MenuItem],
+        prefix: &mut Vec<usize>,
+        path: &[usize],
+    ) -> Div<Node> {
+        let theme = ctx.theme();
+        let label_width = items
+            .iter()
+            .map(|item| display_width(&item.label))
+            .max()
+            .unwrap_or(0);
+        // A space either side of the label plus the submenu arrow
+        let width = label_width as u16 + 4;
+
+        let rows = items.iter().enumerate().map(|(index, item)| {
+            let highlighted = path.first() == Some(&index);
+            let arrow = if item.has_submenu() { '▸' } else { ' ' };
+            let padding = label_width - display_width(&item.label);
+            let mut label = Text::new(format!(" {}{} {arrow} ", item.label, " ".repeat(padding)));
+            label.style = Some(TextStyle {
+                color: Some(if highlighted {
+                    theme.background
+                } else {
+                    theme.text
+                }),
+                background: highlighted.then_some(theme.accent),
+                ..Default::default()
+            });
+
+            prefix.push(index);
+            let mut row = Div::new()
+                .width(width)
+                .height(1)
+                .on_click(ctx.handler(MenuBarMsg::Select(prefix.clone())))
+                .child(label.into());
+            if highlighted && path.len() > 1 {
+                // Line the submenu's top border up with the row, just past this menu's border
+                let submenu = self
+                    .dropdown(ctx, &item.children, prefix, &path[1..])
+                    .absolute_position()
+                    .left(width as i16 + 1)
+                    .top(-1);
+                row = row.child(submenu.into());
+            }
+            prefix.pop();
+            row.into()
+        });
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .border_style_with_color(BorderStyle::Single, theme.border)
+            .background(theme.surface)
+            .width(width + 2)
+            .height(items.len() as u16 + 2)
+            // Keep clicks from reaching the title or parent row the dropdown is drawn in
+            .on_click_with_propagation(|| Propagation::Stop)
+            .children(rows.collect())
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<MenuBarState>();
+        let theme = ctx.theme();
+        let open = !state.path.is_empty();
+
+        let titles = self.menus.iter().enumerate().map(|(index, menu)| {
+            let active = index == state.active;
+            let mut title = Text::new(format!(" {} ", menu.label));
+            title.style = Some(TextStyle {
+                color: Some(if active && open {
+                    theme.background
+                } else {
+                    theme.text
+                }),
+                background: (active && open).then_some(theme.accent),
+                underline: (active && !open).then_some(true),
+                ..Default::default()
+            });
+
+            let mut item = Div::new()
+                .width(display_width(&menu.label) as u16 + 2)
+                .height(1)
+                .on_click(ctx.handler(MenuBarMsg::Open(index)))
+                .child(title.into());
+            if active && open {
+                let dropdown = self
+                    .dropdown(ctx, &menu.children, &mut Vec::new(), &state.path)
+                    .absolute_position()
+                    .top(1);
+                item = item.child(dropdown.into());
+            }
+            item.into()
+        });
+
+        // The default focus border would leave no room for a one-row bar
+        let focus = Style {
+            background: Some(theme.surface),
+            border: Some(Border {
+                enabled: false,
+                ..Border::new(theme.border)
+            }),
+            ..Default::default()
+        };
+
+        Div::new()
+            .direction(Direction::Horizontal)
+            .width_dim(Dimension::Percentage(1.0))
+            .height(1)
+            .background(theme.surface)
+            .z_index(if open { OPEN_MENU_Z_INDEX } else { 0 })
+            .focusable(true)
+            .focus_style(focus)
+            .on_key(Key::Left, ctx.handler(MenuBarMsg::Left))
+            .on_key(Key::Right, ctx.handler(MenuBarMsg::Right))
+            .on_key(Key::Up, ctx.handler(MenuBarMsg::Up))
+            .on_key(Key::Down, ctx.handler(MenuBarMsg::Down))
+            .on_key(Key::Enter, ctx.handler(MenuBarMsg::Enter))
+            .on_key(Key::Esc, ctx.handler(MenuBarMsg::Close))
+            .children(titles.collect())
+            .into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for MenuBar {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        MenuBar::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        MenuBar::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Runner};
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppMsg {
+        New,
+        Open,
+        Export(&'static str),
+        About,
+    }
+
+    /// Renders a menu bar above a line of content and records the messages it emits
+    struct Host {
+        received: Arc<Mutex<Vec<AppMsg>>>,
+    }
+
+    impl Component for Host {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if let Some(msg) = msg.downcast::<AppMsg>() {
+                self.received.lock().unwrap().push(msg.clone());
+            }
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            let menu = MenuBar::new([
+                MenuItem::submenu(
+                    "File",
+                    [
+                        MenuItem::new("New", AppMsg::New),
+                        MenuItem::new("Open", AppMsg::Open),
+                        MenuItem::submenu(
+                            "Export",
+                            [
+                                MenuItem::new("PDF", AppMsg::Export("pdf")),
+                                MenuItem::new("HTML", AppMsg::Export("html")),
+                            ],
+                        ),
+                    ],
+                ),
+                MenuItem::submenu("Help", [MenuItem::new("About", AppMsg::About)]),
+            ]);
+
+            Div::new()
+                .direction(Direction::Vertical)
+                .child(crate::node! { node(menu) })
+                .child(Text::new("content").into())
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn host_runner() -> (Runner, Arc<Mutex<Vec<AppMsg>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let host = Host {
+            received: received.clone(),
+        };
+        let mut runner = App::headless(30, 6).into_runner(host);
+        runner.step_frame().unwrap();
+        (runner, received)
+    }
+
+    fn click(runner: &mut Runner, column: u16, row: u16) -> Vec<String> {
+        runner
+            .send_mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+            .unwrap();
+        runner.step_frame().unwrap()
+    }
+
+    fn press(runner: &mut Runner, keys: &[Key]) -> Vec<String> {
+        for key in keys {
+            runner.send_key(*key).unwrap();
+            runner.step_frame().unwrap();
+        }
+        runner.step_frame().unwrap()
+    }
+
+    #[test]
+    fn test_click_opens_dropdown_over_content() {
+        let (mut runner, received) = host_runner();
+        assert_eq!(
+            runner.step_frame().unwrap()[..2],
+            [" File  Help", "content"]
+        );
+
+        let lines = click(&mut runner, 2, 0);
+        assert_eq!(
+            lines[..5],
+            [
+                " File  Help",
+                "┌──────────┐",
+                "│ New      │",
+                "│ Open     │",
+                "│ Export ▸ │",
+            ]
+        );
+
+        // Clicking the title again closes the menu
+        assert_eq!(click(&mut runner, 2, 0)[1], "content");
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_keyboard_navigates_into_submenu_and_activates() {
+        let (mut runner, received) = host_runner();
+        click(&mut runner, 2, 0);
+
+        let lines = press(&mut runner, &[Key::Down, Key::Down, Key::Right]);
+        assert_eq!(lines[4], "│ Export ▸ ││ PDF    │");
+
+        press(&mut runner, &[Key::Down, Key::Enter]);
+        assert_eq!(*received.lock().unwrap(), [AppMsg::Export("html")]);
+        assert_eq!(runner.step_frame().unwrap()[1], "content");
+    }
+
+    #[test]
+    fn test_arrows_move_between_menus_and_esc_closes() {
+        let (mut runner, received) = host_runner();
+        click(&mut runner, 2, 0);
+
+        // Right on a plain item moves to the next menu, wrapping around
+        let lines = press(&mut runner, &[Key::Right]);
+        assert_eq!(lines[2], "      │ About   │");
+        press(&mut runner, &[Key::Right, Key::Left, Key::Esc]);
+        assert_eq!(runner.step_frame().unwrap()[1], "content");
+
+        // Enter reopens the highlighted menu
+        press(&mut runner, &[Key::Enter, Key::Enter]);
+        assert_eq!(*received.lock().unwrap(), [AppMsg::About]);
+    }
+
+    #[test]
+    fn test_clicking_item_emits_its_message() {
+        let (mut runner, received) = host_runner();
+        click(&mut runner, 2, 0);
+        click(&mut runner, 3, 3);
+
+        assert_eq!(*received.lock().unwrap(), [AppMsg::Open]);
+        assert_eq!(runner.step_frame().unwrap()[1], "content");
+    }
+}
@@ -13,9 +13,113 @@ pub mod text_input;
 /// Spinner component for loading animations
 pub mod spinner;
 
+/// Half-block image component for RGB pixel data
+pub mod image;
+
+/// Single-line block chart for data series
+pub mod sparkline;
+
+/// Horizontal bar chart with labeled rows
+pub mod bar_chart;
+
+/// Section with a header that expands and collapses its content
+pub mod collapsible;
+
+/// Wrapper that renders a fallback when its child panics
+pub mod error_boundary;
+
+/// Text input with validation and an inline error message
+pub mod field;
+
+/// Integer input with step buttons and bounds
+pub mod number_input;
+
+/// Horizontal slider with a draggable thumb
+pub mod slider;
+
+/// Shimmering placeholder for content that is still loading
+pub mod skeleton;
+
+/// Vertical list with single or multi-select
+pub mod list;
+
+/// Braille drawing surface with 2x4 pixels per cell
+pub mod canvas;
+
+/// Two panes with a draggable divider between them
+pub mod split_pane;
+
+/// Bar of dropdown menus with nested submenus
+pub mod menu_bar;
+
+/// Named screens with back and forward history
+pub mod router;
+
 //--------------------------------------------------------------------------------------------------
 // Exports
 //--------------------------------------------------------------------------------------------------
 
+pub use bar_chart::BarChart;
+pub use canvas::Canvas;
+pub use collapsible::{Collapsible, CollapsibleMsg};
+pub use error_boundary::ErrorBoundary;
+pub use field::{Field, FieldMsg, FieldValidity, Validator};
+pub use image::Image;
+pub use list::{List, ListMsg};
+pub use menu_bar::{MenuBar, MenuBarMsg, MenuItem};
+pub use number_input::{NumberInput, NumberInputMsg};
+pub use router::{Route, Router, RouterMsg};
+pub use skeleton::{Skeleton, SkeletonShape};
+pub use slider::{Slider, SliderMsg};
+pub use sparkline::Sparkline;
 pub use spinner::{Spinner, SpinnerMsg, SpinnerSpeed, SpinnerType};
+pub use split_pane::{SplitPane, SplitPaneMsg};
 pub use text_input::TextInput;
+
+//--------------------------------------------------------------------------------------------------
+// Functions: Testing
+//--------------------------------------------------------------------------------------------------
+
+/// Lays out a component-free node tree in a fresh virtual DOM
+#[cfg(test)]
+pub(crate) fn layout_node(node: crate::Node, width: u16, height: u16) -> crate::vdom::VDom {
+    use crate::vnode::VNode;
+
+    fn to_vnode(node: crate::Node) -> VNode {
+        match node {
+            crate::Node::Div(mut div) => {
+                div.children = crate::node::flatten_fragments(div.children);
+                VNode::Div(div.map(to_vnode))
+            }
+            crate::Node::Fragment(children) => {
+                to_vnode(crate::Node::Div(crate::Div::new().children(children)))
+            }
+            crate::Node::Text(text) => VNode::Text(text),
+            crate::Node::RichText(rich) => VNode::RichText(rich),
+            crate::Node::Component(_) => panic!("nested components are not expanded here"),
+        }
+    }
+
+    let mut vdom = crate::vdom::VDom::new();
+    vdom.render(to_vnode(node));
+    vdom.layout(width, height);
+    vdom
+}
+
+/// Lays out and renders a component-free node tree into a fresh buffer
+#[cfg(test)]
+pub(crate) fn render_to_buffer(
+    node: crate::Node,
+    width: u16,
+    height: u16,
+) -> crate::buffer::ScreenBuffer {
+    use crate::app::renderer::render_node_to_buffer;
+
+    let vdom = layout_node(node, width, height);
+    let mut buffer = crate::buffer::ScreenBuffer::new(width, height);
+    let clip_rect = crate::Rect::new(0, 0, width, height);
+    if let Some(root) = &vdom.get_render_tree().root {
+        render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+    }
+    buffer
+}
@@ -0,0 +1,355 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::TextInput;
+use crate::key::Key;
+use crate::node::{Div, Node, Text};
+use crate::style::{Dimension, Direction, TextAlign};
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for NumberInput component
+#[derive(Debug, Clone)]
+pub enum NumberInputMsg {
+    /// Add one step to the value
+    Increment,
+
+    /// Subtract one step from the value
+    Decrement,
+
+    /// Character typed while focused
+    CharInput(char),
+
+    /// Remove the last typed character
+    Backspace,
+
+    /// Parse and apply the typed digits (Enter or losing focus)
+    Commit,
+}
+
+/// State for NumberInput component
+#[derive(Debug, Clone, Default)]
+struct NumberInputState {
+    /// Value set by the user; None until the first change
+    value: Option<i64>,
+
+    /// Text typed since the last commit, if the user is editing
+    editing: Option<String>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Integer input with `-`/`+` buttons, clamped to a range.
+///
+/// Up/Down arrows and the buttons step the value. Digits can also be typed and
+/// are applied on Enter or when the input loses focus; any other character is
+/// rejected. Shares the default look of [`TextInput`]:
+///
+/// ```rust,ignore
+/// let quantity = NumberInput::new()
+///     .value(1)
+///     .range(1, 99)
+///     .step(1)
+///     .on_change(|n| log::info!("quantity: {n}"));
+/// ```
+pub struct NumberInput {
+    initial: i64,
+    min: i64,
+    max: i64,
+    step: i64,
+    width: u16,
+    on_change: Option<Box<dyn Fn(i64)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl NumberInput {
+    /// Creates an input starting at 0 with a step of 1 and no bounds
+    pub fn new() -> Self {
+        Self {
+            initial: 0,
+            min: i64::MIN,
+            max: i64::MAX,
+            step: 1,
+            width: 16,
+            on_change: None,
+        }
+    }
+
+    /// Sets the value shown until the user changes it
+    pub fn value(mut self, value: i64) -> Self {
+        self.initial = value;
+        self
+    }
+
+    /// Sets the smallest allowed value
+    pub fn min(mut self, min: i64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the largest allowed value
+    pub fn max(mut self, max: i64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the smallest and largest allowed values
+    pub fn range(self, min: i64, max: i64) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Sets how much the buttons and arrow keys change the value
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width in cells, including the border
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the callback invoked with the new value after each change
+    pub fn on_change(mut self, callback: impl Fn(i64) + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the current value, clamped to the range
+    fn current(&self, state: &NumberInputState) -> i64 {
+        self.clamp(state.value.unwrap_or(self.initial))
+    }
+
+    /// Returns the value a step starts from: the typed text if it parses, else the current value
+    fn step_base(&self, state: &NumberInputState) -> i64 {
+        match state.editing.as_deref().map(str::parse::<i64>) {
+            Some(Ok(typed)) => self.clamp(typed),
+            _ => self.current(state),
+        }
+    }
+
+    fn clamp(&self, value: i64) -> i64 {
+        value.clamp(self.min, self.max.max(self.min))
+    }
+
+    /// Stores `value` and notifies the callback if it changed
+    fn set(&self, state: &mut NumberInputState, value: i64) {
+        let value = self.clamp(value);
+        let previous = self.current(state);
+        state.value = Some(value);
+        state.editing = None;
+        if value != previous
+            && let Some(callback) = &self.on_change
+        {
+            callback(value);
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<NumberInputMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<NumberInputState>();
+        let current = self.current(&state);
+        match msg {
+            NumberInputMsg::Increment => {
+                let base = self.step_base(&state);
+                self.set(&mut state, base.saturating_add(self.step))
+            }
+            NumberInputMsg::Decrement => {
+                let base = self.step_base(&state);
+                self.set(&mut state, base.saturating_sub(self.step))
+            }
+            NumberInputMsg::CharInput(ch) => {
+                let editing = state.editing.get_or_insert_with(String::new);
+                let accepted =
+                    ch.is_ascii_digit() || (*ch == '-' && editing.is_empty() && self.min < 0);
+                if !accepted {
+                    return Action::none();
+                }
+                editing.push(*ch);
+            }
+            NumberInputMsg::Backspace => {
+                state
+                    .editing
+                    .get_or_insert_with(|| current.to_string())
+                    .pop();
+            }
+            NumberInputMsg::Commit => {
+                // Nothing typed, or only a sign, keeps the previous value
+                if let Some(Ok(value)) = state.editing.take().map(|text| text.parse::<i64>()) {
+                    self.set(&mut state, value);
+                }
+            }
+        }
+
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<NumberInputState>();
+        let shown = state
+            .editing
+            .clone()
+            .unwrap_or_else(|| self.current(&state).to_string());
+
+        let mut style = TextInput::default_style();
+        style.width = Some(Dimension::Fixed(self.width));
+
+        let button = |label: &'static str, msg: NumberInputMsg| {
+            Div::new()
+                .on_click(ctx.handler(msg))
+                .child(Text::new(label).into())
+                .into()
+        };
+
+        Div::new()
+            .style(style)
+            .direction(Direction::Horizontal)
+            .focusable(true)
+            .on_key(Key::Up, ctx.handler(NumberInputMsg::Increment))
+            .on_key(Key::Down, ctx.handler(NumberInputMsg::Decrement))
+            .on_key(Key::Backspace, ctx.handler(NumberInputMsg::Backspace))
+            .on_key(Key::Enter, ctx.handler(NumberInputMsg::Commit))
+            .on_blur(ctx.handler(NumberInputMsg::Commit))
+            .on_any_char(ctx.handler_with_value(NumberInputMsg::CharInput))
+            .child(button("-", NumberInputMsg::Decrement))
+            .child(
+                Div::new()
+                    .flex_grow(1.0)
+                    .child(Text::new(shown).align(TextAlign::Center).into())
+                    .into(),
+            )
+            .child(button("+", NumberInputMsg::Increment))
+            .into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for NumberInput {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        NumberInput::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        NumberInput::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_buffer;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn send(input: &NumberInput, ctx: &Context, msg: NumberInputMsg) {
+        if let Action::Update(state) = NumberInput::update(input, ctx, Box::new(msg), None) {
+            ctx.set_state(state);
+        }
+    }
+
+    fn shown(input: &NumberInput, ctx: &Context) -> String {
+        render_to_buffer(NumberInput::view(input, ctx), 16, 3).lines()[1].clone()
+    }
+
+    #[test]
+    fn test_steps_clamp_at_bounds() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let input = NumberInput::new().value(8).range(0, 10).step(2).on_change({
+            let changes = changes.clone();
+            move |n| changes.borrow_mut().push(n)
+        });
+        let ctx = Context::default();
+
+        send(&input, &ctx, NumberInputMsg::Increment);
+        send(&input, &ctx, NumberInputMsg::Increment);
+        send(&input, &ctx, NumberInputMsg::Increment);
+        assert_eq!(input.current(&ctx.get_state()), 10);
+
+        for _ in 0..6 {
+            send(&input, &ctx, NumberInputMsg::Decrement);
+        }
+        assert_eq!(input.current(&ctx.get_state()), 0);
+
+        // Pinned at a bound, further steps don't report a change
+        assert_eq!(*changes.borrow(), [10, 8, 6, 4, 2, 0]);
+        assert_eq!(shown(&input, &ctx), "│ -    0     + │");
+    }
+
+    #[test]
+    fn test_typing_rejects_non_digits_and_clamps_on_commit() {
+        let input = NumberInput::new().range(0, 500);
+        let ctx = Context::default();
+
+        for ch in ['4', 'a', '2', 'x', '-'] {
+            send(&input, &ctx, NumberInputMsg::CharInput(ch));
+        }
+        assert_eq!(
+            ctx.get_state::<NumberInputState>().editing.as_deref(),
+            Some("42")
+        );
+
+        send(&input, &ctx, NumberInputMsg::CharInput('0'));
+        send(&input, &ctx, NumberInputMsg::CharInput('0'));
+        send(&input, &ctx, NumberInputMsg::Commit);
+        assert_eq!(input.current(&ctx.get_state()), 500);
+
+        // Backspace edits the committed value
+        send(&input, &ctx, NumberInputMsg::Backspace);
+        send(&input, &ctx, NumberInputMsg::Commit);
+        assert_eq!(input.current(&ctx.get_state()), 50);
+    }
+
+    #[test]
+    fn test_steps_start_from_typed_text() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let input = NumberInput::new().value(5).range(0, 100).on_change({
+            let changes = changes.clone();
+            move |n| changes.borrow_mut().push(n)
+        });
+        let ctx = Context::default();
+
+        send(&input, &ctx, NumberInputMsg::CharInput('4'));
+        send(&input, &ctx, NumberInputMsg::CharInput('2'));
+        send(&input, &ctx, NumberInputMsg::Increment);
+        assert_eq!(input.current(&ctx.get_state()), 43);
+        assert_eq!(ctx.get_state::<NumberInputState>().editing, None);
+
+        // Text that doesn't parse steps from the committed value
+        send(&input, &ctx, NumberInputMsg::Backspace);
+        send(&input, &ctx, NumberInputMsg::Backspace);
+        send(&input, &ctx, NumberInputMsg::Decrement);
+        assert_eq!(input.current(&ctx.get_state()), 42);
+        assert_eq!(*changes.borrow(), [43, 42]);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::node::{Div, Node};
+use std::collections::HashMap;
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Builds the node shown for a route
+type RouteFactory = Box<dyn Fn(&Route) -> Node>;
+
+/// State for Router component
+#[derive(Debug, Clone, Default)]
+struct RouterState {
+    /// Visited routes; empty until the first navigation
+    history: Vec<Route>,
+
+    /// Position of the current route in the history
+    index: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for Router component, usually sent with [`Context::navigate_to`],
+/// [`Context::navigate_back`] and [`Context::navigate_forward`]
+#[derive(Debug, Clone)]
+pub enum RouterMsg {
+    /// Show a route, dropping any routes ahead of the current one in the history
+    Navigate(Route),
+
+    /// Return to the previous route in the history
+    Back,
+
+    /// Move forward to the route left with `Back`
+    Forward,
+}
+
+/// A route name together with the parameters passed to its screen.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Route {
+    /// Name the route was registered under
+    pub path: String,
+
+    /// Parameters passed along with the navigation
+    pub params: HashMap<String, String>,
+}
+
+/// Shows one of several named screens and keeps a back/forward history.
+///
+/// Any component can switch screens through the context, passing parameters
+/// along with the route:
+///
+/// ```rust,ignore
+/// let router = Router::new("home")
+///     .route("home", |_| Home.into())
+///     .route("user", |route| UserPage::new(route.param("id")).into())
+///     .fallback(|route| node! { text(format!("no page at {}", route.path)) });
+///
+/// ctx.navigate_to(Route::new("user").with_param("id", "42"));
+/// ctx.navigate_back();
+/// ```
+pub struct Router {
+    initial: Route,
+    routes: HashMap<String, RouteFactory>,
+    fallback: Option<RouteFactory>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Route {
+    /// Creates a route without parameters
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            params: HashMap::new(),
+        }
+    }
+
+    /// Adds a parameter passed to the route's screen
+    pub fn with_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the value of a parameter, if it was passed
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params.get(key).map(String::as_str)
+    }
+}
+
+impl Router {
+    /// Creates a router that starts on the given route
+    pub fn new(initial: impl Into<Route>) -> Self {
+        Self {
+            initial: initial.into(),
+            routes: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    /// Registers the factory that builds the screen for a route
+    pub fn route(
+        mut self,
+        path: impl Into<String>,
+        factory: impl Fn(&Route) -> Node + 'static,
+    ) -> Self {
+        self.routes.insert(path.into(), Box::new(factory));
+        self
+    }
+
+    /// Sets the screen shown for routes that were not registered; empty by default
+    pub fn fallback(mut self, factory: impl Fn(&Route) -> Node + 'static) -> Self {
+        self.fallback = Some(Box::new(factory));
+        self
+    }
+
+    /// Returns the route currently shown
+    fn current<'a>(&'a self, state: &'a RouterState) -> &'a Route {
+        state.history.get(state.index).unwrap_or(&self.initial)
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<RouterMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<RouterState>();
+        if state.history.is_empty() {
+            state.history.push(self.initial.clone());
+        }
+
+        match msg {
+            RouterMsg::Navigate(route) => {
+                state.history.truncate(state.index + 1);
+                state.history.push(route.clone());
+                state.index = state.history.len() - 1;
+            }
+            RouterMsg::Back if state.index > 0 => state.index -= 1,
+            RouterMsg::Forward if state.index + 1 < state.history.len() => state.index += 1,
+            RouterMsg::Back | RouterMsg::Forward => return Action::none(),
+        }
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<RouterState>();
+        let route = self.current(&state);
+
+        match self.routes.get(&route.path).or(self.fallback.as_ref()) {
+            Some(factory) => factory(route),
+            None => Div::new().into(),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl From<&str> for Route {
+    fn from(path: &str) -> Self {
+        Route::new(path)
+    }
+}
+
+impl From<String> for Route {
+    fn from(path: String) -> Self {
+        Route::new(path)
+    }
+}
+
+impl Component for Router {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Router::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Router::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::node::Text;
+
+    fn router() -> Router {
+        Router::new("home")
+            .route("home", |_| Text::new("home").into())
+            .route("user", |route| {
+                Text::new(format!("user {}", route.param("id").unwrap_or("?"))).into()
+            })
+    }
+
+    #[test]
+    fn test_navigate_to_registered_route_renders_it() {
+        let mut runner = App::headless(20, 1).into_runner(router());
+        assert_eq!(runner.step_frame().unwrap()[0], "home");
+
+        runner
+            .context()
+            .navigate_to(Route::new("user").with_param("id", "42"));
+        assert_eq!(runner.step_frame().unwrap()[0], "user 42");
+
+        runner.context().navigate_back();
+        assert_eq!(runner.step_frame().unwrap()[0], "home");
+
+        runner.context().navigate_forward();
+        assert_eq!(runner.step_frame().unwrap()[0], "user 42");
+
+        // Navigating from the middle of the history drops the routes ahead
+        runner.context().navigate_back();
+        runner.context().navigate_to("user");
+        assert_eq!(runner.step_frame().unwrap()[0], "user ?");
+        runner.context().navigate_forward();
+        assert_eq!(runner.step_frame().unwrap()[0], "user ?");
+    }
+
+    #[test]
+    fn test_unknown_route_renders_fallback() {
+        let mut runner = App::headless(20, 1).into_runner(router());
+        runner.step_frame().unwrap();
+        runner.context().navigate_to("missing");
+        assert_eq!(runner.step_frame().unwrap()[0], "");
+
+        let fallback = router().fallback(|route| Text::new(format!("no {}", route.path)).into());
+        let mut runner = App::headless(20, 1).into_runner(fallback);
+        runner.step_frame().unwrap();
+        runner.context().navigate_to("missing");
+        assert_eq!(runner.step_frame().unwrap()[0], "no missing");
+    }
+}
@@ -0,0 +1,220 @@
+use crate::Context;
+use crate::component::{Action, Component, Message};
+use crate::node::{Div, Node, Text};
+use crate::style::{Animation, Color, Direction, TextStyle};
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Character filling the placeholder cells
+const FILL: char = '█';
+
+/// Width of the shimmer band in cells
+const SHIMMER_WIDTH: u16 = 4;
+
+/// How long the shimmer band stays on each cell
+const SHIMMER_PERIOD: Duration = Duration::from_millis(50);
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Shape of the placeholder drawn by a [`Skeleton`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkeletonShape {
+    /// Lines of text; the last of several lines is drawn shorter
+    Lines(u16),
+
+    /// Solid block of the given number of rows
+    Block(u16),
+}
+
+/// Placeholder standing in for content that is still loading.
+///
+/// Draws gray blocks in the shape of the expected content with a highlight
+/// sweeping across them:
+///
+/// ```rust,ignore
+/// let view = if state.loaded {
+///     node! { text(state.summary.clone()) }
+/// } else {
+///     node! { node(Skeleton::lines(3).width(40)) }
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct Skeleton {
+    shape: SkeletonShape,
+    width: u16,
+    color: Option<Color>,
+    highlight: Option<Color>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Skeleton {
+    /// Creates a placeholder for `count` lines of text
+    pub fn lines(count: u16) -> Self {
+        Self::new(SkeletonShape::Lines(count))
+    }
+
+    /// Creates a solid placeholder `height` rows tall
+    pub fn block(height: u16) -> Self {
+        Self::new(SkeletonShape::Block(height))
+    }
+
+    fn new(shape: SkeletonShape) -> Self {
+        Self {
+            shape,
+            width: 20,
+            color: None,
+            highlight: None,
+        }
+    }
+
+    /// Sets the width in cells
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the color of the blocks, defaulting to the theme's surface color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the color of the shimmer, defaulting to the theme's muted color
+    pub fn highlight(mut self, color: Color) -> Self {
+        self.highlight = Some(color);
+        self
+    }
+
+    /// Returns the width of each placeholder row
+    fn row_widths(&self) -> Vec<u16> {
+        match self.shape {
+            SkeletonShape::Lines(count) => (0..count)
+                .map(|row| {
+                    if count > 1 && row == count - 1 {
+                        self.width * 3 / 5
+                    } else {
+                        self.width
+                    }
+                })
+                .collect(),
+            SkeletonShape::Block(height) => vec![self.width; height as usize],
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Skeleton {
+    fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        Action::none()
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let theme = ctx.theme();
+        let style = TextStyle {
+            color: Some(self.color.unwrap_or(theme.surface)),
+            animation: Some(Animation::Shimmer {
+                highlight: self.highlight.unwrap_or(theme.muted),
+                width: SHIMMER_WIDTH,
+                period: SHIMMER_PERIOD,
+            }),
+            ..Default::default()
+        };
+
+        let rows = self.row_widths().into_iter().map(|width| {
+            let mut row = Text::new(FILL.to_string().repeat(width as usize));
+            row.style = Some(style.clone());
+            row.into()
+        });
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .children(rows.collect())
+            .into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rect;
+    use crate::app::renderer::render_node_to_buffer_at;
+    use crate::buffer::ScreenBuffer;
+    use crate::components::{layout_node, render_to_buffer};
+
+    fn view(skeleton: &Skeleton) -> Node {
+        skeleton.view(&Context::default())
+    }
+
+    /// Returns the highlighted columns of the first row at `elapsed`
+    fn highlighted_columns(skeleton: &Skeleton, elapsed: Duration) -> Vec<u16> {
+        let vdom = layout_node(view(skeleton), 10, 2);
+        let mut buffer = ScreenBuffer::new(10, 2);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        render_node_to_buffer_at(
+            &root.borrow(),
+            &mut buffer,
+            &Rect::new(0, 0, 10, 2),
+            None,
+            elapsed,
+        );
+        (0..10)
+            .filter(|&x| buffer.get_cell(x, 0).unwrap().fg == Some(Color::White))
+            .collect()
+    }
+
+    #[test]
+    fn test_lines_render_placeholder_rows() {
+        let buffer = render_to_buffer(view(&Skeleton::lines(3).width(10)), 12, 5);
+        let lines = buffer.lines();
+        assert_eq!(lines[0].trim_end(), "██████████");
+        assert_eq!(lines[1].trim_end(), "██████████");
+        assert_eq!(lines[2].trim_end(), "██████");
+        assert_eq!(lines[3].trim_end(), "");
+
+        let buffer = render_to_buffer(view(&Skeleton::block(2).width(4)), 12, 5);
+        let rows = buffer
+            .lines()
+            .iter()
+            .filter(|line| line.contains(FILL))
+            .count();
+        assert_eq!(rows, 2);
+    }
+
+    #[test]
+    fn test_highlight_advances_over_time() {
+        let skeleton = Skeleton::block(1).width(10).highlight(Color::White);
+        let period = SHIMMER_PERIOD;
+
+        assert_eq!(highlighted_columns(&skeleton, 2 * period), [0, 1]);
+        assert_eq!(highlighted_columns(&skeleton, 5 * period), [1, 2, 3, 4]);
+        assert_eq!(highlighted_columns(&skeleton, 6 * period), [2, 3, 4, 5]);
+        // The band holds its position within a period
+        assert_eq!(
+            highlighted_columns(&skeleton, 6 * period + Duration::from_millis(1)),
+            [2, 3, 4, 5]
+        );
+    }
+}
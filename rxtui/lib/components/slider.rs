@@ -0,0 +1,308 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Div, Node, Text};
+use crate::style::{Border, Direction, Style};
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for Slider component
+#[derive(Debug, Clone)]
+pub enum SliderMsg {
+    /// Move the thumb to a column of the track
+    Seek(u16),
+
+    /// Raise the value by one step
+    StepUp,
+
+    /// Lower the value by one step
+    StepDown,
+
+    /// Jump to the minimum
+    First,
+
+    /// Jump to the maximum
+    Last,
+}
+
+/// State for Slider component
+#[derive(Debug, Clone, Default)]
+struct SliderState {
+    /// Value set by the user; None until the first change
+    value: Option<f64>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Horizontal slider with a track and a draggable thumb.
+///
+/// Pressing anywhere on the track jumps the thumb there, and dragging moves it
+/// along. When focused, Left/Right step the value and Home/End jump to the ends:
+///
+/// ```text
+///   ━━━━━━━━●──────────
+///   min     value    max
+/// ```
+///
+/// ```rust,ignore
+/// let volume = Slider::new()
+///     .range(0.0, 100.0)
+///     .step(5.0)
+///     .on_change(|value| log::info!("volume: {value}"));
+/// ```
+pub struct Slider {
+    initial: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    width: u16,
+    on_change: Option<Box<dyn Fn(f64)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Slider {
+    /// Creates a slider from 0 to 1 starting at 0, stepping by 0.05
+    pub fn new() -> Self {
+        Self {
+            initial: 0.0,
+            min: 0.0,
+            max: 1.0,
+            step: 0.05,
+            width: 20,
+            on_change: None,
+        }
+    }
+
+    /// Sets the value shown until the user changes it
+    pub fn value(mut self, value: f64) -> Self {
+        self.initial = value;
+        self
+    }
+
+    /// Sets the values at the left and right ends of the track
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Sets how much the arrow keys change the value
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the track width in cells
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Sets the callback invoked with the new value after each change
+    pub fn on_change(mut self, callback: impl Fn(f64) + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the current value, clamped to the range
+    fn current(&self, state: &SliderState) -> f64 {
+        self.clamp(state.value.unwrap_or(self.initial))
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min.min(self.max), self.max.max(self.min))
+    }
+
+    /// Returns the last column of the track
+    fn last_column(&self) -> u16 {
+        self.width.saturating_sub(1)
+    }
+
+    /// Maps a track column to a value
+    fn value_at(&self, column: u16) -> f64 {
+        if self.last_column() == 0 {
+            return self.min;
+        }
+        let fraction = column.min(self.last_column()) as f64 / self.last_column() as f64;
+        self.min + fraction * (self.max - self.min)
+    }
+
+    /// Maps a value to the column of the thumb
+    fn thumb_column(&self, value: f64) -> u16 {
+        let span = self.max - self.min;
+        if span == 0.0 {
+            return 0;
+        }
+        let fraction = (value - self.min) / span;
+        (fraction * self.last_column() as f64).round() as u16
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<SliderMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<SliderState>();
+        let current = self.current(&state);
+        let value = self.clamp(match msg {
+            SliderMsg::Seek(column) => self.value_at(*column),
+            SliderMsg::StepUp => current + self.step,
+            SliderMsg::StepDown => current - self.step,
+            SliderMsg::First => self.min,
+            SliderMsg::Last => self.max,
+        });
+
+        if value == current {
+            return Action::none();
+        }
+        state.value = Some(value);
+        if let Some(callback) = &self.on_change {
+            callback(value);
+        }
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<SliderState>();
+        let theme = ctx.theme();
+        let thumb = self.thumb_column(self.current(&state));
+
+        let seek = ctx.handler_with_value(SliderMsg::Seek);
+        let drag = ctx.handler_with_value(SliderMsg::Seek);
+
+        // Plain text segments stay transparent to clicks, so presses reach the div
+        let filled = Text::new(format!("{}●", "━".repeat(thumb as usize))).color(theme.accent);
+        let rest = Text::new("─".repeat(self.last_column().saturating_sub(thumb) as usize))
+            .color(theme.muted);
+
+        // The default focus border would leave no room for a one-row track
+        let focus = Style {
+            background: Some(theme.surface),
+            border: Some(Border {
+                enabled: false,
+                ..Border::new(theme.border)
+            }),
+            ..Default::default()
+        };
+
+        Div::new()
+            .direction(Direction::Horizontal)
+            .width(self.width)
+            .height(1)
+            .focusable(true)
+            .focus_style(focus)
+            .on_mouse_down(move |x, _| seek(x))
+            .on_drag(move |x, _| drag(x))
+            .on_key(Key::Right, ctx.handler(SliderMsg::StepUp))
+            .on_key(Key::Left, ctx.handler(SliderMsg::StepDown))
+            .on_key(Key::Home, ctx.handler(SliderMsg::First))
+            .on_key(Key::End, ctx.handler(SliderMsg::Last))
+            .child(filled.into())
+            .child(rest.into())
+            .into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for Slider {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Slider::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Slider::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Runner};
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn slider_runner(slider: Slider) -> (Runner, Rc<RefCell<Vec<f64>>>) {
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let slider = slider.width(11).on_change({
+            let values = values.clone();
+            move |value| values.borrow_mut().push(value)
+        });
+        let mut runner = App::headless(20, 2).into_runner(slider);
+        runner.step_frame().unwrap();
+        (runner, values)
+    }
+
+    fn mouse(runner: &mut Runner, kind: MouseEventKind, column: u16) {
+        runner
+            .send_mouse(MouseEvent {
+                kind,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+            .unwrap();
+        runner.step_frame().unwrap();
+    }
+
+    #[test]
+    fn test_drag_moves_thumb_to_pointer() {
+        let (mut runner, values) = slider_runner(Slider::new());
+        assert_eq!(runner.step_frame().unwrap()[0], "●──────────");
+
+        // Pressing on the track jumps there, dragging follows the pointer
+        mouse(&mut runner, MouseEventKind::Down(MouseButton::Left), 2);
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 5);
+        assert_eq!(runner.step_frame().unwrap()[0], "━━━━━●─────");
+
+        // Dragging past the end clamps, and drags stop after release
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 18);
+        mouse(&mut runner, MouseEventKind::Up(MouseButton::Left), 18);
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 0);
+        assert_eq!(*values.borrow(), [0.2, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_arrow_keys_step_value() {
+        let (mut runner, values) = slider_runner(Slider::new().range(0.0, 10.0).step(2.0));
+        runner.send_key(Key::Tab).unwrap();
+        runner.step_frame().unwrap();
+
+        runner.send_key(Key::Right).unwrap();
+        runner.send_key(Key::Right).unwrap();
+        assert_eq!(runner.step_frame().unwrap()[0], "━━━━●──────");
+        runner.send_key(Key::Left).unwrap();
+        runner.send_key(Key::End).unwrap();
+        runner.send_key(Key::Right).unwrap();
+        runner.step_frame().unwrap();
+        assert_eq!(*values.borrow(), [2.0, 4.0, 2.0, 10.0]);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::Context;
+use crate::component::{Action, Component, Message};
+use crate::node::{Node, Text};
+use crate::style::{Color, TextStyle};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Block characters from lowest to highest
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Single-line chart drawing a data series with block characters.
+///
+/// Values are scaled between the minimum and maximum of the data unless fixed
+/// bounds are given, which keeps the scale stable while the series changes:
+///
+/// ```rust,ignore
+/// let cpu = Sparkline::new(samples).width(30).min(0.0).max(100.0).color(Color::Green);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Sparkline {
+    data: Vec<f64>,
+    width: Option<u16>,
+    min: Option<f64>,
+    max: Option<f64>,
+    color: Option<Color>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Sparkline {
+    /// Creates a sparkline for the given data series
+    pub fn new(data: impl Into<Vec<f64>>) -> Self {
+        Self {
+            data: data.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the width in cells.
+    /// Only the most recent `width` values are shown. Defaults to the length of the data.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Fixes the value drawn as the lowest block
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Fixes the value drawn as the highest block
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the color of the blocks
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the block characters for the visible part of the series
+    fn line(&self) -> String {
+        let visible = match self.width {
+            Some(width) => &self.data[self.data.len().saturating_sub(width as usize)..],
+            None => &self.data[..],
+        };
+
+        let finite = || visible.iter().copied().filter(|v| v.is_finite());
+        let min = self
+            .min
+            .unwrap_or_else(|| finite().fold(f64::INFINITY, f64::min));
+        let max = self
+            .max
+            .unwrap_or_else(|| finite().fold(f64::NEG_INFINITY, f64::max));
+        let range = max - min;
+
+        visible
+            .iter()
+            .map(|&value| {
+                if !value.is_finite() || range <= 0.0 || !range.is_finite() {
+                    return BLOCKS[0];
+                }
+                let level = ((value - min) / range * (BLOCKS.len() - 1) as f64).round();
+                BLOCKS[level.clamp(0.0, (BLOCKS.len() - 1) as f64) as usize]
+            })
+            .collect()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Sparkline {
+    fn update(&self, _ctx: &Context, _msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        Action::none()
+    }
+
+    fn view(&self, _ctx: &Context) -> Node {
+        let mut text = Text::new(self.line());
+        if let Some(color) = self.color {
+            text.style = Some(TextStyle {
+                color: Some(color),
+                ..Default::default()
+            });
+        }
+        text.into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_maps_to_blocks() {
+        let sparkline = Sparkline::new(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        assert_eq!(sparkline.line(), "▁▂▃▄▅▆▇█");
+
+        let sparkline = Sparkline::new(vec![10.0, 0.0, 5.0]);
+        assert_eq!(sparkline.line(), "█▁▅");
+    }
+
+    #[test]
+    fn test_flat_series_renders_lowest_block() {
+        assert_eq!(Sparkline::new(vec![3.0; 4]).line(), "▁▁▁▁");
+        assert_eq!(Sparkline::new(vec![]).line(), "");
+    }
+
+    #[test]
+    fn test_fixed_bounds_and_width() {
+        let sparkline = Sparkline::new(vec![100.0, 0.0, 50.0, 100.0])
+            .width(3)
+            .min(0.0)
+            .max(200.0);
+        // Only the last three values are shown, scaled against 0..200
+        assert_eq!(sparkline.line(), "▁▃▅");
+
+        // Values beyond a fixed bound are clamped
+        assert_eq!(
+            Sparkline::new(vec![-5.0, 15.0]).min(0.0).max(10.0).line(),
+            "▁█"
+        );
+    }
+}
@@ -14,6 +14,9 @@ use std::time::Duration;
 pub enum SpinnerMsg {
     /// Advance to the next frame
     Tick,
+
+    /// Stop or resume the animation
+    SetPaused(bool),
 }
 
 /// State for Spinner component
@@ -21,6 +24,15 @@ pub enum SpinnerMsg {
 struct SpinnerState {
     /// Current frame index
     frame_index: usize,
+
+    /// Clock reading when the current frame appeared; None before the first tick
+    frame_started: Option<Duration>,
+
+    /// Pause state set by a message; None until the first `SetPaused`
+    paused: Option<bool>,
+
+    /// The `paused` prop last seen by update; a new value drops the message's pause state
+    paused_prop: bool,
 }
 
 /// Spinner pattern data
@@ -33,11 +45,12 @@ struct SpinnerPattern {
 //--------------------------------------------------------------------------------------------------
 
 /// Animation speed for the spinner
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SpinnerSpeed {
     /// Slow animation (150ms per frame)
     Slow,
     /// Normal animation (80ms per frame)
+    #[default]
     Normal,
     /// Fast animation (50ms per frame)
     Fast,
@@ -46,8 +59,9 @@ pub enum SpinnerSpeed {
 }
 
 /// Available spinner types
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum SpinnerType {
+    #[default]
     Dots,
     Dots2,
     Dots3,
@@ -123,19 +137,15 @@ pub enum SpinnerType {
 pub struct Spinner {
     spinner_type: SpinnerType,
     speed: SpinnerSpeed,
+    frame_durations: Vec<u64>,
+    paused: bool,
     color: Option<Color>,
 }
 
 //--------------------------------------------------------------------------------------------------
-// Trait Implementations: SpinnerSpeed
+// Methods: SpinnerSpeed
 //--------------------------------------------------------------------------------------------------
 
-impl Default for SpinnerSpeed {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl SpinnerSpeed {
     fn interval(&self) -> u64 {
         match self {
@@ -147,16 +157,6 @@ impl SpinnerSpeed {
     }
 }
 
-//--------------------------------------------------------------------------------------------------
-// Trait Implementations: SpinnerType
-//--------------------------------------------------------------------------------------------------
-
-impl Default for SpinnerType {
-    fn default() -> Self {
-        Self::Dots
-    }
-}
-
 //--------------------------------------------------------------------------------------------------
 // Constants
 //--------------------------------------------------------------------------------------------------
@@ -511,6 +511,8 @@ impl Spinner {
         Self {
             spinner_type: SpinnerType::default(),
             speed: SpinnerSpeed::default(),
+            frame_durations: Vec::new(),
+            paused: false,
             color: None,
         }
     }
@@ -527,6 +529,30 @@ impl Spinner {
         self
     }
 
+    /// Set how long each frame is shown, in milliseconds
+    ///
+    /// The durations match the frames by index and override the speed, so a
+    /// spinner can dwell longer on some frames. A shorter list repeats.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let spinner = Spinner::new()
+    ///     .spinner_type(SpinnerType::Weather)
+    ///     .frame_durations(vec![400, 100, 100, 100]);
+    /// ```
+    pub fn frame_durations(mut self, durations: Vec<u64>) -> Self {
+        self.frame_durations = durations;
+        self
+    }
+
+    /// Set whether the spinner is paused
+    ///
+    /// [`SpinnerMsg::SetPaused`] also stops or resumes it, until this prop changes.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
     /// Set the spinner color
     pub fn color(mut self, color: Color) -> Self {
         self.color = Some(color);
@@ -553,10 +579,32 @@ impl Spinner {
     fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
         if let Some(msg) = msg.downcast::<SpinnerMsg>() {
             let mut state = ctx.get_state::<SpinnerState>();
+            let now = ctx.elapsed();
+
+            // A new `paused` prop from the parent overrides an earlier SetPaused
+            let prop_changed = state.paused_prop != self.paused;
+            if prop_changed {
+                state.paused = None;
+                state.paused_prop = self.paused;
+                state.frame_started = Some(now);
+            }
+
             match msg {
                 SpinnerMsg::Tick => {
-                    let frames = self.get_frames();
-                    state.frame_index = (state.frame_index + 1) % frames.len();
+                    if self.is_paused(&state) {
+                        return if prop_changed {
+                            Action::update(state)
+                        } else {
+                            Action::none()
+                        };
+                    }
+                    self.advance(&mut state, now);
+                    return Action::update(state);
+                }
+                SpinnerMsg::SetPaused(paused) => {
+                    // Resuming shows the held frame for a full interval
+                    state.paused = Some(*paused);
+                    state.frame_started = Some(now);
                     return Action::update(state);
                 }
             }
@@ -584,14 +632,60 @@ impl Spinner {
         text.into()
     }
 
+    /// Returns whether the animation is stopped
+    fn is_paused(&self, state: &SpinnerState) -> bool {
+        match state.paused {
+            Some(paused) if state.paused_prop == self.paused => paused,
+            _ => self.paused,
+        }
+    }
+
+    /// Moves to the frame that should be showing at `now` on the context clock, so
+    /// late ticks catch up instead of slowing the animation down
+    fn advance(&self, state: &mut SpinnerState, now: Duration) {
+        let frames = self.get_frames().len();
+        let Some(mut started) = state.frame_started else {
+            state.frame_index = (state.frame_index + 1) % frames;
+            state.frame_started = Some(now);
+            return;
+        };
+
+        loop {
+            let interval = self.frame_interval(state.frame_index);
+            if now.saturating_sub(started) < interval {
+                break;
+            }
+            started += interval;
+            state.frame_index = (state.frame_index + 1) % frames;
+            if interval.is_zero() {
+                break;
+            }
+        }
+        state.frame_started = Some(started);
+    }
+
+    /// Returns how long the frame at `frame_index` stays on screen
+    fn frame_interval(&self, frame_index: usize) -> Duration {
+        let ms = if self.frame_durations.is_empty() {
+            self.speed.interval()
+        } else {
+            self.frame_durations[frame_index % self.frame_durations.len()]
+        };
+        Duration::from_millis(ms)
+    }
+
     fn effects(&self, ctx: &Context) -> Vec<Effect> {
         let ctx = ctx.clone();
-        let interval = self.speed.interval();
+        let spinner = self.clone();
 
         let effect = Box::pin(async move {
             loop {
-                tokio::time::sleep(Duration::from_millis(interval)).await;
-                ctx.send(SpinnerMsg::Tick);
+                let state = ctx.get_state::<SpinnerState>();
+                tokio::time::sleep(spinner.frame_interval(state.frame_index)).await;
+                // Keep polling while paused so the animation resumes on its own
+                if !spinner.is_paused(&ctx.get_state::<SpinnerState>()) {
+                    ctx.send(SpinnerMsg::Tick);
+                }
             }
         });
 
@@ -630,3 +724,128 @@ impl Default for Spinner {
         Self::new()
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn send(spinner: &Spinner, ctx: &Context, msg: SpinnerMsg) {
+        if let Action::Update(state) = spinner.update(ctx, Box::new(msg), None) {
+            ctx.states.insert(ctx.id().clone(), state);
+        }
+    }
+
+    /// Returns a context whose clock only moves when advanced
+    fn stepped_context() -> Context {
+        let ctx = Context::default();
+        ctx.clock.set_stepped();
+        ctx
+    }
+
+    /// Lets the current frame's interval pass and ticks, like the effect loop
+    fn tick(spinner: &Spinner, ctx: &Context) {
+        let state = ctx.get_state::<SpinnerState>();
+        ctx.clock.advance(spinner.frame_interval(state.frame_index));
+        send(spinner, ctx, SpinnerMsg::Tick);
+    }
+
+    fn frame_index(ctx: &Context) -> usize {
+        ctx.get_state::<SpinnerState>().frame_index
+    }
+
+    /// Returns the delays after which the first `ticks` ticks are sent, feeding
+    /// each tick back through `update` like the effect loop does
+    fn tick_schedule(spinner: &Spinner, ticks: usize) -> Vec<u64> {
+        let ctx = stepped_context();
+        (0..ticks)
+            .map(|_| {
+                tick(spinner, &ctx);
+                ctx.elapsed().as_millis() as u64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_frame_durations_set_cumulative_tick_delays() {
+        let spinner = Spinner::new()
+            .custom_pattern(vec!["a", "b", "c"])
+            .frame_durations(vec![300, 100, 50]);
+        assert_eq!(tick_schedule(&spinner, 5), [300, 400, 450, 750, 850]);
+
+        // Without per-frame durations every frame uses the speed's interval
+        let spinner = Spinner::new().speed(SpinnerSpeed::Custom(20));
+        assert_eq!(tick_schedule(&spinner, 3), [20, 40, 60]);
+    }
+
+    #[test]
+    fn test_ticks_do_not_advance_while_paused() {
+        let spinner = Spinner::new().custom_pattern(vec!["a", "b", "c"]);
+        let ctx = stepped_context();
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 1);
+
+        send(&spinner, &ctx, SpinnerMsg::SetPaused(true));
+        tick(&spinner, &ctx);
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 1);
+
+        send(&spinner, &ctx, SpinnerMsg::SetPaused(false));
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 2);
+
+        // A spinner created paused holds its first frame until resumed
+        let spinner = spinner.paused(true);
+        let ctx = stepped_context();
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 0);
+        send(&spinner, &ctx, SpinnerMsg::SetPaused(false));
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 1);
+    }
+
+    #[test]
+    fn test_paused_prop_change_overrides_message() {
+        let running = Spinner::new().custom_pattern(vec!["a", "b", "c"]);
+        let ctx = stepped_context();
+        send(&running, &ctx, SpinnerMsg::SetPaused(true));
+        tick(&running, &ctx);
+        assert_eq!(frame_index(&ctx), 0);
+
+        // The parent re-renders with a new prop value, which wins over the message
+        let paused = running.clone().paused(true);
+        tick(&paused, &ctx);
+        assert_eq!(frame_index(&ctx), 0);
+
+        // Resuming through the prop shows the held frame for a full interval
+        tick(&running, &ctx);
+        assert_eq!(frame_index(&ctx), 0);
+        tick(&running, &ctx);
+        assert_eq!(frame_index(&ctx), 1);
+    }
+
+    #[test]
+    fn test_late_ticks_catch_up_with_the_clock() {
+        let spinner = Spinner::new()
+            .custom_pattern(vec!["a", "b", "c", "d"])
+            .speed(SpinnerSpeed::Custom(100));
+        let ctx = stepped_context();
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 1);
+
+        // A tick before the frame's time is up leaves it showing
+        ctx.clock.advance(Duration::from_millis(50));
+        send(&spinner, &ctx, SpinnerMsg::Tick);
+        assert_eq!(frame_index(&ctx), 1);
+
+        // A tick arriving two and a half frames late skips the frames it missed
+        ctx.clock.advance(Duration::from_millis(200));
+        send(&spinner, &ctx, SpinnerMsg::Tick);
+        assert_eq!(frame_index(&ctx), 3);
+        tick(&spinner, &ctx);
+        assert_eq!(frame_index(&ctx), 0);
+    }
+}
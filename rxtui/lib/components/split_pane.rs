@@ -0,0 +1,304 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::node::{Div, Divider, Node};
+use crate::style::{Color, Dimension, Direction};
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for SplitPane component
+#[derive(Debug, Clone)]
+pub enum SplitPaneMsg {
+    /// Move the divider to a cell along the split axis, given the pane's total length
+    DragTo { position: u16, length: u16 },
+}
+
+/// State for SplitPane component
+#[derive(Debug, Clone, Default)]
+struct SplitPaneState {
+    /// Ratio set by dragging; None until the first drag
+    ratio: Option<f32>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// Two panes separated by a divider that can be dragged to resize them.
+///
+/// A horizontal split places the panes side by side with a vertical divider
+/// between them; a vertical split stacks them. The split fills its parent and
+/// the first pane takes `ratio` of the space, with the second getting the rest
+/// after the divider:
+///
+/// ```rust,ignore
+/// let split = SplitPane::horizontal(
+///     || node! { div [ text("files") ] },
+///     || node! { div [ text("editor") ] },
+/// )
+/// .ratio(0.3)
+/// .min_sizes(10, 20)
+/// .on_resize(|ratio| log::info!("split at {ratio}"));
+/// ```
+pub struct SplitPane {
+    direction: Direction,
+    first: Box<dyn Fn() -> Node>,
+    second: Box<dyn Fn() -> Node>,
+    initial: f32,
+    min_first: u16,
+    min_second: u16,
+    divider_color: Option<Color>,
+    on_resize: Option<Box<dyn Fn(f32)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl SplitPane {
+    /// Creates a split with the panes side by side and a vertical divider
+    pub fn horizontal(
+        first: impl Fn() -> Node + 'static,
+        second: impl Fn() -> Node + 'static,
+    ) -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            first: Box::new(first),
+            second: Box::new(second),
+            initial: 0.5,
+            min_first: 1,
+            min_second: 1,
+            divider_color: None,
+            on_resize: None,
+        }
+    }
+
+    /// Creates a split with the panes stacked and a horizontal divider
+    pub fn vertical(
+        first: impl Fn() -> Node + 'static,
+        second: impl Fn() -> Node + 'static,
+    ) -> Self {
+        Self {
+            direction: Direction::Vertical,
+            ..Self::horizontal(first, second)
+        }
+    }
+
+    /// Sets the share of the space given to the first pane until the user drags
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.initial = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the smallest size in cells each pane can be dragged to
+    pub fn min_sizes(mut self, first: u16, second: u16) -> Self {
+        self.min_first = first;
+        self.min_second = second;
+        self
+    }
+
+    /// Sets the divider color
+    pub fn divider_color(mut self, color: Color) -> Self {
+        self.divider_color = Some(color);
+        self
+    }
+
+    /// Sets the callback invoked with the new ratio after each drag
+    pub fn on_resize(mut self, callback: impl Fn(f32) + 'static) -> Self {
+        self.on_resize = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the share of the space given to the first pane
+    fn current(&self, state: &SplitPaneState) -> f32 {
+        state.ratio.unwrap_or(self.initial)
+    }
+
+    /// Returns the first pane's size for a divider dragged to `position`,
+    /// keeping both panes at or above their minimum sizes where they fit.
+    /// The second pane's minimum wins when both can't be met.
+    fn clamp_position(&self, position: u16, length: u16) -> u16 {
+        let max = length.saturating_sub(1 + self.min_second);
+        position.max(self.min_first).min(max)
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(SplitPaneMsg::DragTo { position, length }) = msg.downcast::<SplitPaneMsg>() else {
+            return Action::none();
+        };
+        if *length == 0 {
+            return Action::none();
+        }
+
+        let mut state = ctx.get_state::<SplitPaneState>();
+        let ratio = self.clamp_position(*position, *length) as f32 / *length as f32;
+        if ratio == self.current(&state) {
+            return Action::none();
+        }
+        state.ratio = Some(ratio);
+        if let Some(callback) = &self.on_resize {
+            callback(ratio);
+        }
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<SplitPaneState>();
+        let ratio = self.current(&state);
+        let full = Dimension::Percentage(1.0);
+
+        // The divider runs across the split axis, so a side-by-side split gets a vertical line
+        let (divider, first, second) = match self.direction {
+            Direction::Horizontal => (
+                Divider::vertical(),
+                Div::new()
+                    .width_dim(Dimension::Percentage(ratio))
+                    .height_dim(full),
+                Div::new().width_dim(Dimension::Auto).height_dim(full),
+            ),
+            Direction::Vertical => (
+                Divider::horizontal(),
+                Div::new()
+                    .width_dim(full)
+                    .height_dim(Dimension::Percentage(ratio)),
+                Div::new().width_dim(full).height_dim(Dimension::Auto),
+            ),
+        };
+
+        let direction = self.direction;
+        let drag = ctx.handler_with_value(move |(position, length)| SplitPaneMsg::DragTo {
+            position,
+            length,
+        });
+        let divider = divider
+            .color(self.divider_color.unwrap_or(ctx.theme().border))
+            .into_div()
+            .on_drag_in_parent(move |x, y, width, height| match direction {
+                Direction::Horizontal => drag((x, width)),
+                Direction::Vertical => drag((y, height)),
+            });
+
+        Div::new()
+            .direction(self.direction)
+            .width_dim(full)
+            .height_dim(full)
+            .child(first.child((self.first)()).into())
+            .child(divider.into())
+            .child(second.child((self.second)()).into())
+            .into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for SplitPane {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        SplitPane::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        SplitPane::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Runner};
+    use crate::node::Text;
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn split_runner(split: SplitPane) -> (Runner, Rc<RefCell<Vec<f32>>>) {
+        let ratios = Rc::new(RefCell::new(Vec::new()));
+        let split = split.on_resize({
+            let ratios = ratios.clone();
+            move |ratio| ratios.borrow_mut().push(ratio)
+        });
+        let mut runner = App::headless(20, 4).into_runner(split);
+        runner.step_frame().unwrap();
+        (runner, ratios)
+    }
+
+    fn mouse(runner: &mut Runner, kind: MouseEventKind, column: u16, row: u16) {
+        runner
+            .send_mouse(MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+            .unwrap();
+        runner.step_frame().unwrap();
+    }
+
+    fn panes() -> (impl Fn() -> Node, impl Fn() -> Node) {
+        (|| Text::new("a").into(), || Text::new("b").into())
+    }
+
+    #[test]
+    fn test_dragging_divider_updates_ratio() {
+        let (first, second) = panes();
+        let (mut runner, ratios) = split_runner(SplitPane::horizontal(first, second));
+        assert_eq!(runner.step_frame().unwrap()[0], "a         │b");
+
+        mouse(&mut runner, MouseEventKind::Down(MouseButton::Left), 10, 1);
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 5, 1);
+        assert_eq!(runner.step_frame().unwrap()[0], "a    │b");
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 15, 2);
+        assert_eq!(runner.step_frame().unwrap()[0], "a              │b");
+        assert_eq!(*ratios.borrow(), [0.25, 0.75]);
+
+        // Drags that start inside a pane leave the split alone
+        mouse(&mut runner, MouseEventKind::Up(MouseButton::Left), 15, 2);
+        mouse(&mut runner, MouseEventKind::Down(MouseButton::Left), 2, 0);
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 8, 0);
+        assert_eq!(ratios.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_drag_respects_min_sizes() {
+        let (first, second) = panes();
+        let split = SplitPane::horizontal(first, second).min_sizes(4, 6);
+        let (mut runner, ratios) = split_runner(split);
+
+        mouse(&mut runner, MouseEventKind::Down(MouseButton::Left), 10, 0);
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 1, 0);
+        assert_eq!(runner.step_frame().unwrap()[0], "a   │b");
+
+        // 20 columns less the divider and a 6 column second pane leave 13
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 19, 0);
+        assert_eq!(runner.step_frame().unwrap()[0], "a            │b");
+        assert_eq!(*ratios.borrow(), [0.2, 0.65]);
+    }
+
+    #[test]
+    fn test_vertical_split_drags_along_rows() {
+        let (first, second) = panes();
+        let (mut runner, ratios) = split_runner(SplitPane::vertical(first, second));
+        assert!(runner.screen_lines()[2].starts_with('─'));
+        assert_eq!(runner.screen_lines()[3].trim_end(), "b");
+
+        mouse(&mut runner, MouseEventKind::Down(MouseButton::Left), 3, 2);
+        mouse(&mut runner, MouseEventKind::Drag(MouseButton::Left), 3, 1);
+        assert!(runner.screen_lines()[1].starts_with('─'));
+        assert_eq!(runner.screen_lines()[2].trim_end(), "b");
+        assert_eq!(*ratios.borrow(), [0.25]);
+    }
+}
@@ -6,6 +6,7 @@ use crate::style::{
     Border, BorderEdges, BorderStyle, Color, Dimension, Overflow, Position, Spacing, Style,
     TextStyle, TextWrap,
 };
+use crate::utils::{next_grapheme_boundary, prev_grapheme_boundary};
 use crate::{Context, Div};
 use std::any::Any;
 use std::rc::Rc;
@@ -55,6 +56,8 @@ pub enum TextInputMsg {
     /// Selection operations
     SelectLeft,
     SelectRight,
+    SelectHome,
+    SelectEnd,
     SelectAll,
     SelectWord,
     ClearSelection,
@@ -63,6 +66,7 @@ pub enum TextInputMsg {
     Cut,
     Copy,
     Paste(String),
+    PasteFromClipboard,
 
     /// Submit (Enter key)
     Submit,
@@ -166,6 +170,7 @@ pub struct TextInput {
     on_change: Option<Box<dyn Fn(String)>>,
     on_submit: Option<Box<dyn Fn()>>,
     on_blur: Option<Box<dyn Fn()>>,
+    on_copy: Option<Box<dyn Fn(String)>>,
     key_handlers: Vec<(Key, Rc<dyn Fn()>)>,
     key_global_handlers: Vec<(Key, Rc<dyn Fn()>)>,
     key_with_modifiers_handlers: Vec<(KeyWithModifiers, Rc<dyn Fn()>)>,
@@ -176,22 +181,79 @@ pub struct TextInput {
 // Methods
 //--------------------------------------------------------------------------------------------------
 
+impl TextInputState {
+    /// Returns the selected range as `(start, end)` char offsets, if anything is selected
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let (start, end) = (self.selection_start?, self.selection_end?);
+        let (start, end) = (start.min(end), start.max(end));
+        (start != end).then_some((start, end))
+    }
+
+    /// Returns the currently selected text, if any
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        Some(self.content.chars().skip(start).take(end - start).collect())
+    }
+
+    /// Returns the end of the selection the cursor is not at, which stays put
+    /// while the selection is extended
+    fn selection_anchor(&self) -> Option<usize> {
+        let (start, end) = self.selection()?;
+        Some(if self.cursor_position == start {
+            end
+        } else {
+            start
+        })
+    }
+
+    /// Selects from `anchor` to the cursor, or nothing if they meet
+    fn select_from(&mut self, anchor: usize) {
+        let (start, end) = (
+            anchor.min(self.cursor_position),
+            anchor.max(self.cursor_position),
+        );
+        if start == end {
+            self.clear_selection();
+        } else {
+            self.selection_start = Some(start);
+            self.selection_end = Some(end);
+        }
+    }
+
+    /// Drops the selection
+    fn clear_selection(&mut self) {
+        self.selection_start = None;
+        self.selection_end = None;
+    }
+}
+
 impl TextInput {
     /// Helper to delete selected text
     fn delete_selection(&self, state: &mut TextInputState) {
-        if let (Some(start), Some(end)) = (state.selection_start, state.selection_end) {
-            let (start, end) = if start < end {
-                (start, end)
-            } else {
-                (end, start)
-            };
+        if let Some((start, end)) = state.selection() {
             let mut chars: Vec<char> = state.content.chars().collect();
             chars.drain(start..end);
             state.content = chars.into_iter().collect();
             state.cursor_position = start;
-            state.selection_start = None;
-            state.selection_end = None;
         }
+        state.clear_selection();
+    }
+
+    /// Moves the cursor while extending the selection from its anchor
+    fn extend_selection(&self, state: &mut TextInputState, new_position: usize) {
+        let anchor = state.selection_anchor().unwrap_or(state.cursor_position);
+        state.cursor_position = new_position;
+        state.select_from(anchor);
+    }
+
+    /// Inserts text at the cursor, replacing any selection
+    fn insert_text(&self, state: &mut TextInputState, text: &str) {
+        self.delete_selection(state);
+        let mut chars: Vec<char> = state.content.chars().collect();
+        let char_pos = state.cursor_position.min(chars.len());
+        chars.splice(char_pos..char_pos, text.chars());
+        state.content = chars.into_iter().collect();
+        state.cursor_position = char_pos + text.chars().count();
     }
 
     /// Find the previous word boundary from the given position
@@ -298,7 +360,7 @@ impl TextInput {
         state.content = chars.into_iter().collect();
     }
     /// Creates the default style for TextInput components
-    fn default_style() -> Style {
+    pub(crate) fn default_style() -> Style {
         Style {
             padding: Some(Spacing::horizontal(1)),
             width: Some(Dimension::Fixed(30)),
@@ -325,6 +387,10 @@ impl TextInput {
             strikethrough: None,
             wrap: None,
             align: None,
+            vertical_align: None,
+            tab_width: None,
+            animation: None,
+            link: None,
         }
     }
 
@@ -339,6 +405,10 @@ impl TextInput {
             strikethrough: None,
             wrap: None,
             align: None,
+            vertical_align: None,
+            tab_width: None,
+            animation: None,
+            link: None,
         }
     }
 
@@ -353,6 +423,10 @@ impl TextInput {
             strikethrough: None,
             wrap: None,
             align: None,
+            vertical_align: None,
+            tab_width: None,
+            animation: None,
+            link: None,
         }
     }
 
@@ -367,6 +441,10 @@ impl TextInput {
             strikethrough: None,
             wrap: None,
             align: None,
+            vertical_align: None,
+            tab_width: None,
+            animation: None,
+            link: None,
         }
     }
 
@@ -390,6 +468,7 @@ impl TextInput {
             on_change: None,
             on_submit: None,
             on_blur: None,
+            on_copy: None,
             key_handlers: Vec::new(),
             key_global_handlers: Vec::new(),
             key_with_modifiers_handlers: Vec::new(),
@@ -439,6 +518,12 @@ impl TextInput {
         self
     }
 
+    /// Sets the callback that receives the selected text on copy (Ctrl+C) or cut (Ctrl+X)
+    pub fn on_copy(mut self, callback: impl Fn(String) + 'static) -> Self {
+        self.on_copy = Some(Box::new(callback));
+        self
+    }
+
     /// Registers a key handler that fires when the input is focused.
     pub fn on_key(mut self, key: Key, handler: impl Fn() + 'static) -> Self {
         self.key_handlers.push((key, Rc::new(handler)));
@@ -486,8 +571,7 @@ impl TextInput {
                 TextInputMsg::Blurred => {
                     state.focused = false;
                     // Clear selection when losing focus
-                    state.selection_start = None;
-                    state.selection_end = None;
+                    state.clear_selection();
 
                     if let Some(callback) = &self.on_blur {
                         callback();
@@ -500,40 +584,28 @@ impl TextInput {
                         // Alt+b and Alt+f are common word navigation shortcuts
                         // These often come through as ESC followed by the character
 
-                        // For now, just handle regular character input
-                        // Clear selection and insert at cursor
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        }
-
-                        // Convert to char indices
-                        let char_pos = state.cursor_position;
-                        let mut chars: Vec<char> = state.content.chars().collect();
-
-                        // Insert character at cursor position
-                        if char_pos <= chars.len() {
-                            chars.insert(char_pos, *ch);
-                            state.content = chars.into_iter().collect();
-                            state.cursor_position += 1;
+                        // Typing over a selection replaces it
+                        self.insert_text(&mut state, ch.encode_utf8(&mut [0; 4]));
 
-                            // Call on_change callback
-                            if let Some(callback) = &self.on_change {
-                                callback(state.content.clone());
-                            }
+                        // Call on_change callback
+                        if let Some(callback) = &self.on_change {
+                            callback(state.content.clone());
                         }
                     }
                 }
                 TextInputMsg::Backspace => {
                     // Only process backspace when focused
                     if state.focused {
-                        if state.selection_start.is_some() {
+                        if state.selection().is_some() {
                             self.delete_selection(&mut state);
                         } else if state.cursor_position > 0 {
-                            // Delete character before cursor
+                            // Delete the grapheme cluster before cursor
+                            let start =
+                                prev_grapheme_boundary(&state.content, state.cursor_position);
                             let mut chars: Vec<char> = state.content.chars().collect();
-                            chars.remove(state.cursor_position - 1);
+                            chars.drain(start..state.cursor_position);
                             state.content = chars.into_iter().collect();
-                            state.cursor_position -= 1;
+                            state.cursor_position = start;
 
                             // Call on_change callback
                             if let Some(callback) = &self.on_change {
@@ -544,13 +616,15 @@ impl TextInput {
                 }
                 TextInputMsg::Delete => {
                     if state.focused {
-                        if state.selection_start.is_some() {
+                        if state.selection().is_some() {
                             self.delete_selection(&mut state);
                         } else {
-                            // Delete character after cursor
+                            // Delete the grapheme cluster after cursor
                             let mut chars: Vec<char> = state.content.chars().collect();
                             if state.cursor_position < chars.len() {
-                                chars.remove(state.cursor_position);
+                                let end =
+                                    next_grapheme_boundary(&state.content, state.cursor_position);
+                                chars.drain(state.cursor_position..end);
                                 state.content = chars.into_iter().collect();
 
                                 // Call on_change callback
@@ -563,7 +637,7 @@ impl TextInput {
                 }
                 TextInputMsg::DeleteWordBackward => {
                     if state.focused {
-                        if state.selection_start.is_some() {
+                        if state.selection().is_some() {
                             self.delete_selection(&mut state);
                         } else {
                             self.delete_word_backward(&mut state);
@@ -576,7 +650,7 @@ impl TextInput {
                 }
                 TextInputMsg::DeleteWordForward => {
                     if state.focused {
-                        if state.selection_start.is_some() {
+                        if state.selection().is_some() {
                             self.delete_selection(&mut state);
                         } else {
                             self.delete_word_forward(&mut state);
@@ -585,7 +659,7 @@ impl TextInput {
                 }
                 TextInputMsg::DeleteToLineStart => {
                     if state.focused {
-                        if state.selection_start.is_some() {
+                        if state.selection().is_some() {
                             self.delete_selection(&mut state);
                         } else {
                             self.delete_to_line_start(&mut state);
@@ -594,7 +668,7 @@ impl TextInput {
                 }
                 TextInputMsg::DeleteToLineEnd => {
                     if state.focused {
-                        if state.selection_start.is_some() {
+                        if state.selection().is_some() {
                             self.delete_selection(&mut state);
                         } else {
                             self.delete_to_line_end(&mut state);
@@ -603,64 +677,134 @@ impl TextInput {
                 }
                 TextInputMsg::CursorLeft => {
                     if state.focused && state.cursor_position > 0 {
-                        state.cursor_position -= 1;
+                        state.cursor_position =
+                            prev_grapheme_boundary(&state.content, state.cursor_position);
                         // Clear selection when moving cursor
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
                     }
                 }
                 TextInputMsg::CursorRight => {
                     if state.focused {
                         let char_count = state.content.chars().count();
                         if state.cursor_position < char_count {
-                            state.cursor_position += 1;
+                            state.cursor_position =
+                                next_grapheme_boundary(&state.content, state.cursor_position);
                         }
                         // Clear selection when moving cursor
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
                     }
                 }
                 TextInputMsg::CursorHome => {
                     if state.focused {
                         state.cursor_position = 0;
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
                     }
                 }
                 TextInputMsg::CursorEnd => {
                     if state.focused {
                         state.cursor_position = state.content.chars().count();
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
                     }
                 }
                 TextInputMsg::CursorWordLeft => {
                     if state.focused {
                         state.cursor_position =
                             self.find_word_boundary_left(&state.content, state.cursor_position);
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
                     }
                 }
                 TextInputMsg::CursorWordRight => {
                     if state.focused {
                         state.cursor_position =
                             self.find_word_boundary_right(&state.content, state.cursor_position);
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
                     }
                 }
-                // TODO: Implement selection operations
-                TextInputMsg::SelectLeft
-                | TextInputMsg::SelectRight
-                | TextInputMsg::SelectAll
-                | TextInputMsg::SelectWord
-                | TextInputMsg::ClearSelection => {
-                    // Will be implemented when we add selection support
+                TextInputMsg::SelectLeft => {
+                    if state.focused {
+                        let target = prev_grapheme_boundary(&state.content, state.cursor_position);
+                        self.extend_selection(&mut state, target);
+                    }
+                }
+                TextInputMsg::SelectRight => {
+                    if state.focused {
+                        let target = next_grapheme_boundary(&state.content, state.cursor_position);
+                        self.extend_selection(&mut state, target);
+                    }
+                }
+                TextInputMsg::SelectHome => {
+                    if state.focused {
+                        self.extend_selection(&mut state, 0);
+                    }
+                }
+                TextInputMsg::SelectEnd => {
+                    if state.focused {
+                        let end = state.content.chars().count();
+                        self.extend_selection(&mut state, end);
+                    }
+                }
+                TextInputMsg::SelectAll => {
+                    if state.focused && !state.content.is_empty() {
+                        state.cursor_position = state.content.chars().count();
+                        state.select_from(0);
+                    }
+                }
+                TextInputMsg::SelectWord => {
+                    if state.focused {
+                        let start =
+                            self.find_word_boundary_left(&state.content, state.cursor_position);
+                        let end =
+                            self.find_word_boundary_right(&state.content, state.cursor_position);
+                        state.cursor_position = end;
+                        state.select_from(start);
+                    }
+                }
+                TextInputMsg::ClearSelection => {
+                    state.clear_selection();
+                }
+                TextInputMsg::Copy => {
+                    if let Some(text) = state.selected_text() {
+                        // Without a clipboard, on_copy is the only way to get the text out
+                        let _ = ctx.copy_to_clipboard(&text);
+                        if let Some(callback) = &self.on_copy {
+                            callback(text);
+                        }
+                    }
+                }
+                TextInputMsg::Cut => {
+                    if state.focused
+                        && let Some(text) = state.selected_text()
+                    {
+                        let _ = ctx.copy_to_clipboard(&text);
+                        if let Some(callback) = &self.on_copy {
+                            callback(text);
+                        }
+                        self.delete_selection(&mut state);
+
+                        if let Some(callback) = &self.on_change {
+                            callback(state.content.clone());
+                        }
+                    }
+                }
+                TextInputMsg::Paste(text) => {
+                    if state.focused {
+                        self.insert_text(&mut state, text);
+
+                        if let Some(callback) = &self.on_change {
+                            callback(state.content.clone());
+                        }
+                    }
                 }
-                // TODO: Implement clipboard operations
-                TextInputMsg::Cut | TextInputMsg::Copy | TextInputMsg::Paste(_) => {
-                    // Will be implemented when we add clipboard support
+                TextInputMsg::PasteFromClipboard => {
+                    if state.focused
+                        && let Ok(text) = ctx.read_clipboard()
+                    {
+                        self.insert_text(&mut state, &text);
+
+                        if let Some(callback) = &self.on_change {
+                            callback(state.content.clone());
+                        }
+                    }
                 }
                 TextInputMsg::Submit => {
                     // Call on_submit callback when Enter is pressed
@@ -672,8 +816,7 @@ impl TextInput {
                     if self.clear_on_submit {
                         state.content.clear();
                         state.cursor_position = 0;
-                        state.selection_start = None;
-                        state.selection_end = None;
+                        state.clear_selection();
 
                         // Call on_change callback to notify of cleared content
                         if let Some(callback) = &self.on_change {
@@ -684,8 +827,7 @@ impl TextInput {
                 TextInputMsg::Clear => {
                     state.content.clear();
                     state.cursor_position = 0;
-                    state.selection_start = None;
-                    state.selection_end = None;
+                    state.clear_selection();
 
                     // Call on_change callback
                     if let Some(callback) = &self.on_change {
@@ -766,6 +908,36 @@ impl TextInput {
                 KeyWithModifiers::with_ctrl(Key::Char('f')),
                 ctx.handler(TextInputMsg::CursorRight),
             )
+            // Shift+movement extends the selection
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_shift(Key::Left),
+                ctx.handler(TextInputMsg::SelectLeft),
+            )
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_shift(Key::Right),
+                ctx.handler(TextInputMsg::SelectRight),
+            )
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_shift(Key::Home),
+                ctx.handler(TextInputMsg::SelectHome),
+            )
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_shift(Key::End),
+                ctx.handler(TextInputMsg::SelectEnd),
+            )
+            // Clipboard handlers
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_ctrl(Key::Char('c')),
+                ctx.handler(TextInputMsg::Copy),
+            )
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_ctrl(Key::Char('x')),
+                ctx.handler(TextInputMsg::Cut),
+            )
+            .on_key_with_modifiers(
+                KeyWithModifiers::with_ctrl(Key::Char('v')),
+                ctx.handler(TextInputMsg::PasteFromClipboard),
+            )
             // Word deletion handlers
             .on_key_with_modifiers(
                 KeyWithModifiers::with_ctrl(Key::Char('w')),
@@ -805,6 +977,7 @@ impl TextInput {
                 KeyWithModifiers::with_ctrl(Key::Char('k')),
                 ctx.handler(TextInputMsg::DeleteToLineEnd),
             )
+            .on_paste(ctx.handler_with_value(TextInputMsg::Paste))
             .on_any_char(ctx.handler_with_value(|ch| {
                 // Only handle regular character input
                 // Control sequences are handled by on_key_with_modifiers above
@@ -849,8 +1022,16 @@ impl TextInput {
                     .cursor_style
                     .clone()
                     .unwrap_or_else(Self::default_cursor_style);
-                let mut rich_text =
-                    RichText::with_cursor(&display_content, state.cursor_position, cursor_style);
+                let mut rich_text = if let Some((start, end)) = state.selection() {
+                    // Highlight the selected range instead of the block cursor
+                    let selection_style = self
+                        .selection_style
+                        .clone()
+                        .unwrap_or_else(Self::default_selection_style);
+                    RichText::with_selection(&display_content, start, end, selection_style)
+                } else {
+                    RichText::with_cursor(&display_content, state.cursor_position, cursor_style)
+                };
 
                 // Apply wrapping if specified
                 if let Some(wrap) = self.wrap {
@@ -1401,3 +1582,170 @@ impl Default for TextInput {
         Self::new()
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧";
+
+    fn focused_state(content: &str, cursor_position: usize) -> Context {
+        let ctx = Context::default();
+        ctx.set_state(Box::new(TextInputState {
+            focused: true,
+            content: content.to_string(),
+            cursor_position,
+            ..Default::default()
+        }));
+        ctx
+    }
+
+    fn send(input: &TextInput, ctx: &Context, msg: TextInputMsg) -> TextInputState {
+        if let Action::Update(state) = input.update(ctx, Box::new(msg), None) {
+            ctx.set_state(state);
+        }
+        ctx.get_state::<TextInputState>()
+    }
+
+    #[test]
+    fn test_cursor_moves_over_family_emoji_as_one_unit() {
+        let input = TextInput::new();
+        let ctx = focused_state(&format!("a{FAMILY}b"), 1);
+
+        let state = send(&input, &ctx, TextInputMsg::CursorRight);
+        assert_eq!(state.cursor_position, 1 + FAMILY.chars().count());
+
+        let state = send(&input, &ctx, TextInputMsg::CursorLeft);
+        assert_eq!(state.cursor_position, 1);
+    }
+
+    #[test]
+    fn test_backspace_and_delete_remove_whole_grapheme() {
+        let input = TextInput::new();
+        let end = 1 + FAMILY.chars().count();
+        let ctx = focused_state(&format!("a{FAMILY}b"), end);
+
+        let state = send(&input, &ctx, TextInputMsg::Backspace);
+        assert_eq!(state.content, "ab");
+        assert_eq!(state.cursor_position, 1);
+
+        let ctx = focused_state(&format!("a{FAMILY}b"), 1);
+        let state = send(&input, &ctx, TextInputMsg::Delete);
+        assert_eq!(state.content, "ab");
+        assert_eq!(state.cursor_position, 1);
+    }
+
+    #[test]
+    fn test_shift_arrows_extend_selection() {
+        let input = TextInput::new();
+        let ctx = focused_state("hello world", 5);
+
+        send(&input, &ctx, TextInputMsg::SelectRight);
+        let state = send(&input, &ctx, TextInputMsg::SelectRight);
+        assert_eq!(state.selection(), Some((5, 7)));
+        assert_eq!(state.selected_text().as_deref(), Some(" w"));
+
+        // Moving back past the anchor flips the selection direction
+        send(&input, &ctx, TextInputMsg::SelectLeft);
+        send(&input, &ctx, TextInputMsg::SelectLeft);
+        let state = send(&input, &ctx, TextInputMsg::SelectLeft);
+        assert_eq!(state.selection(), Some((4, 5)));
+        assert_eq!(state.selected_text().as_deref(), Some("o"));
+
+        // Plain cursor movement drops the selection
+        let state = send(&input, &ctx, TextInputMsg::CursorRight);
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn test_selection_start_and_end_follow_selection() {
+        let input = TextInput::new();
+        let ctx = focused_state("hello world", 5);
+
+        send(&input, &ctx, TextInputMsg::SelectLeft);
+        let state = send(&input, &ctx, TextInputMsg::SelectLeft);
+        assert_eq!(state.selection_start, Some(3));
+        assert_eq!(state.selection_end, Some(5));
+
+        let state = send(&input, &ctx, TextInputMsg::CursorLeft);
+        assert_eq!(state.selection_start, None);
+        assert_eq!(state.selection_end, None);
+    }
+
+    #[test]
+    fn test_selection_set_through_fields_is_extended() {
+        let input = TextInput::new();
+        let ctx = focused_state("hello world", 6);
+        ctx.set_state(Box::new(TextInputState {
+            selection_start: Some(0),
+            selection_end: Some(6),
+            ..ctx.get_state::<TextInputState>()
+        }));
+
+        // The cursor sits at the end, so the start stays anchored
+        let state = send(&input, &ctx, TextInputMsg::SelectRight);
+        assert_eq!(state.selected_text().as_deref(), Some("hello w"));
+    }
+
+    #[test]
+    fn test_typing_replaces_selection() {
+        let input = TextInput::new();
+        let ctx = focused_state("hello world", 11);
+
+        send(&input, &ctx, TextInputMsg::SelectHome);
+        let state = send(&input, &ctx, TextInputMsg::CharInput('x'));
+        assert_eq!(state.content, "x");
+        assert_eq!(state.cursor_position, 1);
+        assert_eq!(state.selection(), None);
+
+        let ctx = focused_state("hello world", 6);
+        for _ in 0..5 {
+            send(&input, &ctx, TextInputMsg::SelectRight);
+        }
+        let state = send(&input, &ctx, TextInputMsg::Paste("there".into()));
+        assert_eq!(state.content, "hello there");
+    }
+
+    #[test]
+    fn test_copy_and_cut_report_selected_text() {
+        let copied = Rc::new(std::cell::RefCell::new(Vec::new()));
+        let sink = copied.clone();
+        let input = TextInput::new().on_copy(move |text| sink.borrow_mut().push(text));
+        let ctx = focused_state("hello world", 0);
+
+        send(&input, &ctx, TextInputMsg::SelectAll);
+        send(&input, &ctx, TextInputMsg::Copy);
+        send(&input, &ctx, TextInputMsg::CursorHome);
+        send(&input, &ctx, TextInputMsg::SelectRight);
+        let state = send(&input, &ctx, TextInputMsg::Cut);
+
+        assert_eq!(*copied.borrow(), vec!["hello world", "h"]);
+        assert_eq!(state.content, "ello world");
+    }
+
+    #[test]
+    fn test_selection_renders_highlighted_range() {
+        let style = TextStyle {
+            background: Some(Color::Blue),
+            ..Default::default()
+        };
+        let rich = RichText::with_selection("hello", 1, 3, style.clone());
+        let contents: Vec<_> = rich.spans.iter().map(|s| s.content.as_str()).collect();
+        assert_eq!(contents, vec!["h", "el", "lo"]);
+        assert_eq!(rich.spans[1].style, Some(style));
+        assert_eq!(rich.spans[0].style, None);
+    }
+
+    #[test]
+    fn test_with_cursor_highlights_whole_grapheme() {
+        let text = format!("a{FAMILY}b");
+        let rich = RichText::with_cursor(&text, 1, TextStyle::default());
+        let cursor = rich.spans.iter().find(|span| span.is_cursor).unwrap();
+        assert_eq!(cursor.content, FAMILY);
+        assert_eq!(rich.spans.last().unwrap().content, "b");
+    }
+}
@@ -27,10 +27,22 @@
 //! - Property/style updates
 //! - Child additions/removals
 //! - Node replacements
+//!
+//! [`Patch`] refers to render nodes directly and drives the terminal pipeline.
+//! Custom render targets receive each patch as self-contained [`PatchOp`]s that
+//! locate nodes by path instead; see [`VDom::apply_patches_to`](crate::VDom::apply_patches_to).
+//!
+//! Children are matched by position unless any of them has a key, in which
+//! case keyed children are matched by key so their render nodes, along with
+//! scroll position and focus, survive insertions and reordering.
 
+use crate::node::{DivStyles, TextSpan};
 use crate::render_tree::{RenderNode, RenderNodeType};
+use crate::style::TextStyle;
+use crate::utils::{expand_span_tabs, expand_tabs, tab_width_of};
 use crate::vnode::VNode;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 //--------------------------------------------------------------------------------------------------
@@ -55,6 +67,7 @@ pub enum Patch {
     UpdateText {
         node: Rc<RefCell<RenderNode>>,
         new_text: String,
+        new_source: crate::node::TextContent,
         new_style: Option<crate::style::TextStyle>,
     },
 
@@ -84,6 +97,74 @@ pub enum Patch {
         parent: Rc<RefCell<RenderNode>>,
         index: usize,
     },
+
+    /// Replace a div's children with reused and new nodes, in order.
+    /// Emitted by keyed matching before the reused children are diffed.
+    ReorderChildren {
+        parent: Rc<RefCell<RenderNode>>,
+        children: Vec<KeyedChild>,
+    },
+}
+
+/// A child in the new order produced by keyed matching.
+#[derive(Debug, Clone)]
+pub enum KeyedChild {
+    /// An existing render node matched to the new child
+    Existing(Rc<RefCell<RenderNode>>),
+
+    /// A new child with no match in the old children
+    New(Box<VNode>),
+}
+
+/// A self-contained change for a custom render target.
+///
+/// Nodes are located by their path of child indices from the root, so `[]` is the
+/// root and `[1, 0]` the first child of its second child. Paths refer to the tree
+/// as it is just before the operation, with earlier operations already applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchOp {
+    /// Replace the node at `path`, or create the root, with a new subtree
+    Replace { path: Vec<usize>, node: VNode },
+
+    /// Set the content and style of the text node at `path`
+    UpdateText {
+        path: Vec<usize>,
+        text: String,
+        style: Option<TextStyle>,
+    },
+
+    /// Set the spans and style of the rich text node at `path`
+    UpdateRichText {
+        path: Vec<usize>,
+        spans: Vec<TextSpan>,
+        style: Option<TextStyle>,
+    },
+
+    /// Set the styles of the div at `path`
+    UpdateStyle { path: Vec<usize>, styles: DivStyles },
+
+    /// Insert a new subtree as child `index` of the div at `parent`
+    Insert {
+        parent: Vec<usize>,
+        index: usize,
+        node: VNode,
+    },
+
+    /// Remove child `index` of the div at `parent`
+    Remove { parent: Vec<usize>, index: usize },
+
+    /// Take child `from` of the div at `parent` out and reinsert it at `to`
+    Move {
+        parent: Vec<usize>,
+        from: usize,
+        to: usize,
+    },
+}
+
+/// A render target that mirrors the UI by applying [`PatchOp`]s.
+pub trait PatchBackend {
+    /// Applies a single operation
+    fn apply(&mut self, op: &PatchOp);
 }
 
 /// Context for accumulating patches during the diff process.
@@ -93,6 +174,59 @@ pub struct DiffContext {
     pub patches: Vec<Patch>,
 }
 
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Patch {
+    /// Describes this patch as operations on the tree as it is before the patch.
+    pub(crate) fn to_ops(&self) -> Vec<PatchOp> {
+        match self {
+            Patch::Replace { old, new } => vec![PatchOp::Replace {
+                path: node_path(old),
+                node: new.clone(),
+            }],
+            Patch::UpdateText {
+                node,
+                new_text,
+                new_style,
+                ..
+            } => vec![PatchOp::UpdateText {
+                path: node_path(node),
+                text: new_text.clone(),
+                style: new_style.clone(),
+            }],
+            Patch::UpdateRichText {
+                node,
+                new_spans,
+                new_style,
+            } => vec![PatchOp::UpdateRichText {
+                path: node_path(node),
+                spans: new_spans.clone(),
+                style: new_style.clone(),
+            }],
+            Patch::UpdateProps { node, div } => vec![PatchOp::UpdateStyle {
+                path: node_path(node),
+                styles: div.styles.clone(),
+            }],
+            Patch::AddChild {
+                parent,
+                child,
+                index,
+            } => vec![PatchOp::Insert {
+                parent: node_path(parent),
+                index: (*index).min(parent.borrow().children.len()),
+                node: child.clone(),
+            }],
+            Patch::RemoveChild { parent, index } => vec![PatchOp::Remove {
+                parent: node_path(parent),
+                index: *index,
+            }],
+            Patch::ReorderChildren { parent, children } => reorder_ops(parent, children),
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -137,10 +271,22 @@ fn diff_node(context: &mut DiffContext, old: &Rc<RefCell<RenderNode>>, new: &VNo
                 _ => true,
             };
 
-            if old_text != &new_text.content || style_changed {
+            // Static or shared content pointing at the same string is unchanged
+            let same_source = old_ref
+                .text_source
+                .as_ref()
+                .is_some_and(|source| source.ptr_eq(&new_text.content));
+            if same_source && !style_changed {
+                return;
+            }
+
+            // The render tree holds tab-expanded text, so compare against that
+            let new_content = expand_tabs(&new_text.content, tab_width_of(new_style));
+            if old_text.as_str() != new_content || style_changed {
                 context.patches.push(Patch::UpdateText {
                     node: old.clone(),
-                    new_text: new_text.content.clone(),
+                    new_text: new_content.into_owned(),
+                    new_source: new_text.content.clone(),
                     new_style: new_text.style.clone(),
                 });
             }
@@ -148,10 +294,12 @@ fn diff_node(context: &mut DiffContext, old: &Rc<RefCell<RenderNode>>, new: &VNo
         (RenderNodeType::RichText(old_spans), VNode::RichText(new_rich)) => {
             // Check if spans or style have changed
             let style_changed = old_ref.text_style != new_rich.style;
-            if old_spans != &new_rich.spans || style_changed {
+            let new_spans =
+                expand_span_tabs(&new_rich.spans, tab_width_of(new_rich.style.as_ref()));
+            if old_spans.as_slice() != new_spans.as_ref() || style_changed {
                 context.patches.push(Patch::UpdateRichText {
                     node: old.clone(),
-                    new_spans: new_rich.spans.clone(),
+                    new_spans: new_spans.into_owned(),
                     new_style: new_rich.style.clone(),
                 });
             }
@@ -184,12 +332,21 @@ fn diff_div(
         let is_focused = old_ref.focused;
         let is_hovered = old_ref.hovered;
         // Get effective style based on the preserved focus/hover state
-        let new_style = RenderNode::compose_state_style(
+        let mut new_style = RenderNode::compose_state_style(
             &new_div.styles,
             new_div.focusable,
             is_focused,
             is_hovered,
         );
+        // Percentage spacing is resolved into padding/margin during layout
+        if let (Some(old_s), Some(new_s)) = (old_style, &mut new_style) {
+            if new_s.padding_percent.is_some() {
+                new_s.padding = old_s.padding;
+            }
+            if new_s.margin_percent.is_some() {
+                new_s.margin = old_s.margin;
+            }
+        }
         let new_style_ref = &new_style;
 
         // Check if dimensions changed (including percentage values)
@@ -201,7 +358,10 @@ fn diff_div(
             (None, None) => false,
         };
 
-        old_style != new_style_ref || dimensions_changed
+        old_style != new_style_ref
+            || dimensions_changed
+            || old_ref.memo_key != new_div.memo_key
+            || old_ref.lazy_path != new_div.lazy_path
     };
 
     if props_changed {
@@ -211,26 +371,37 @@ fn diff_div(
         });
     }
 
+    // Memoized subtrees with unchanged dependencies keep their children as they are
+    if new_div.memo_key.is_some() && old_ref.memo_key == new_div.memo_key {
+        return;
+    }
+
     diff_children(context, old_node, &old_ref.children, &new_div.children);
 }
 
 /// Diffs two lists of children, handling additions, removals, and updates.
 ///
-/// Currently implements a simple index-based diff:
+/// Without keys this is a simple index-based diff:
 /// 1. Diffs common children by index
 /// 2. Adds new children if new list is longer
 /// 3. Removes extra children if old list is longer
 ///
-/// ## Note
-///
-/// Currently implements a simple index-based diff. Could be optimized
-/// with a key-based algorithm for better handling of reordered children.
+/// If any child has a key, [`diff_keyed_children`] matches them instead.
 fn diff_children(
     context: &mut DiffContext,
     parent: &Rc<RefCell<RenderNode>>,
     old_children: &[Rc<RefCell<RenderNode>>],
     new_children: &[VNode],
 ) {
+    let has_keys = old_children
+        .iter()
+        .any(|child| child.borrow().key.is_some())
+        || new_children.iter().any(|child| vnode_key(child).is_some());
+    if has_keys {
+        diff_keyed_children(context, parent, old_children, new_children);
+        return;
+    }
+
     let old_len = old_children.len();
     let new_len = new_children.len();
     let min_len = old_len.min(new_len);
@@ -256,3 +427,150 @@ fn diff_children(
         }
     }
 }
+
+/// Diffs children by key, reusing the old render node with the same key.
+///
+/// Keyed children match the old child with the same key wherever it was, and
+/// unkeyed children match the old unkeyed children in order. If the matches
+/// change the order or any child is added or removed, a `ReorderChildren` patch
+/// rearranges the parent first; the matched pairs are then diffed as usual.
+fn diff_keyed_children(
+    context: &mut DiffContext,
+    parent: &Rc<RefCell<RenderNode>>,
+    old_children: &[Rc<RefCell<RenderNode>>],
+    new_children: &[VNode],
+) {
+    let mut keyed = HashMap::new();
+    let mut unkeyed = Vec::new();
+    for (index, child) in old_children.iter().enumerate() {
+        match child.borrow().key {
+            Some(key) => {
+                keyed.entry(key).or_insert(index);
+            }
+            None => unkeyed.push(index),
+        }
+    }
+    let mut unkeyed = unkeyed.into_iter();
+
+    let matches: Vec<Option<usize>> = new_children
+        .iter()
+        .map(|child| match vnode_key(child) {
+            // Removing the entry leaves duplicate keys unmatched
+            Some(key) => keyed.remove(&key),
+            None => unkeyed.next(),
+        })
+        .collect();
+
+    let in_place = matches.len() == old_children.len()
+        && matches
+            .iter()
+            .enumerate()
+            .all(|(index, matched)| *matched == Some(index));
+    if !in_place {
+        let children = matches
+            .iter()
+            .zip(new_children)
+            .map(|(matched, child)| match matched {
+                Some(index) => KeyedChild::Existing(old_children[*index].clone()),
+                None => KeyedChild::New(Box::new(child.clone())),
+            })
+            .collect();
+        context.patches.push(Patch::ReorderChildren {
+            parent: parent.clone(),
+            children,
+        });
+    }
+
+    for (matched, child) in matches.iter().zip(new_children) {
+        if let Some(index) = matched {
+            diff_node(context, &old_children[*index], child);
+        }
+    }
+}
+
+/// Returns the child indices leading from the root to `node`
+fn node_path(node: &Rc<RefCell<RenderNode>>) -> Vec<usize> {
+    let mut path = Vec::new();
+    let mut current = node.clone();
+    loop {
+        let parent = current
+            .borrow()
+            .parent
+            .as_ref()
+            .and_then(|parent| parent.upgrade());
+        let Some(parent) = parent else { break };
+        if let Some(index) = parent
+            .borrow()
+            .children
+            .iter()
+            .position(|child| Rc::ptr_eq(child, &current))
+        {
+            path.push(index);
+        }
+        current = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Breaks a reorder into removals of dropped children, then moves and inserts
+/// that build the new order from the front.
+fn reorder_ops(parent: &Rc<RefCell<RenderNode>>, children: &[KeyedChild]) -> Vec<PatchOp> {
+    let path = node_path(parent);
+    let mut ops = Vec::new();
+    // Existing nodes in their current order; `None` marks an inserted child
+    let mut current: Vec<Option<Rc<RefCell<RenderNode>>>> =
+        parent.borrow().children.iter().cloned().map(Some).collect();
+
+    let kept = |node: &Rc<RefCell<RenderNode>>| {
+        children
+            .iter()
+            .any(|child| matches!(child, KeyedChild::Existing(kept) if Rc::ptr_eq(kept, node)))
+    };
+    for index in (0..current.len()).rev() {
+        if !current[index].as_ref().is_some_and(kept) {
+            current.remove(index);
+            ops.push(PatchOp::Remove {
+                parent: path.clone(),
+                index,
+            });
+        }
+    }
+
+    for (to, child) in children.iter().enumerate() {
+        match child {
+            KeyedChild::Existing(node) => {
+                let from = current
+                    .iter()
+                    .position(|entry| entry.as_ref().is_some_and(|entry| Rc::ptr_eq(entry, node)))
+                    .unwrap_or(to);
+                if from != to {
+                    let entry = current.remove(from);
+                    current.insert(to, entry);
+                    ops.push(PatchOp::Move {
+                        parent: path.clone(),
+                        from,
+                        to,
+                    });
+                }
+            }
+            KeyedChild::New(node) => {
+                current.insert(to, None);
+                ops.push(PatchOp::Insert {
+                    parent: path.clone(),
+                    index: to,
+                    node: (**node).clone(),
+                });
+            }
+        }
+    }
+    ops
+}
+
+/// Returns the key of a div node
+fn vnode_key(node: &VNode) -> Option<u64> {
+    match node {
+        VNode::Div(div) => div.key,
+        _ => None,
+    }
+}
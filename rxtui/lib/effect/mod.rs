@@ -169,6 +169,7 @@
 //--------------------------------------------------------------------------------------------------
 
 mod runtime;
+mod task;
 mod types;
 
 //--------------------------------------------------------------------------------------------------
@@ -176,4 +177,6 @@ mod types;
 //--------------------------------------------------------------------------------------------------
 
 pub use runtime::EffectRuntime;
+pub use task::TaskHandle;
+pub(crate) use task::TaskSpawner;
 pub use types::Effect;
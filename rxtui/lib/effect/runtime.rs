@@ -48,7 +48,7 @@ impl EffectRuntime {
     }
 
     /// Get the runtime handle for spawning tasks
-    fn handle(&self) -> &Handle {
+    pub(crate) fn handle(&self) -> &Handle {
         match &self.runtime_handle {
             RuntimeHandle::Owned(runtime) => runtime.handle(),
             RuntimeHandle::Existing(handle) => handle,
@@ -0,0 +1,187 @@
+use crate::component::ComponentId;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::runtime::Handle;
+use tokio::sync::{Semaphore, watch};
+use tokio::task::AbortHandle;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Handle to a task started with [`Context::spawn`](crate::Context::spawn).
+///
+/// Cloning the handle is cheap, so it can be kept in component state. The
+/// result is taken once, either by polling with [`TaskHandle::take`] or by
+/// awaiting [`TaskHandle::wait`] from an effect.
+pub struct TaskHandle<T> {
+    /// Output of the task once it finished, until taken
+    result: Arc<Mutex<Option<T>>>,
+
+    /// Flips to true when the task finished; closed if it was cancelled
+    done: watch::Receiver<bool>,
+
+    /// Aborts the task
+    abort: AbortHandle,
+}
+
+/// Spawns tasks owned by components and cancels them when their owner unmounts.
+#[derive(Clone, Default)]
+pub(crate) struct TaskSpawner {
+    /// Runtime of the app's effects, installed when the app starts
+    handle: Arc<RwLock<Option<Handle>>>,
+
+    /// Tasks of each component that may still be running
+    tasks: Arc<RwLock<HashMap<ComponentId, Vec<AbortHandle>>>>,
+
+    /// Permits shared by the tasks spawned under each concurrency key
+    limits: Arc<Mutex<HashMap<String, Arc<Semaphore>>>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl<T> TaskHandle<T> {
+    /// Returns true once the task has produced its result
+    pub fn is_finished(&self) -> bool {
+        *self.done.borrow()
+    }
+
+    /// Takes the result if the task finished and nobody took it yet
+    pub fn take(&self) -> Option<T> {
+        self.result.lock().unwrap().take()
+    }
+
+    /// Waits for the task and takes its result. Returns `None` if the task was
+    /// cancelled or its result was already taken.
+    pub async fn wait(&self) -> Option<T> {
+        let mut done = self.done.clone();
+        done.wait_for(|finished| *finished).await.ok()?;
+        self.take()
+    }
+
+    /// Cancels the task if it is still running
+    pub fn cancel(&self) {
+        self.abort.abort();
+    }
+}
+
+impl TaskSpawner {
+    /// Sets the runtime the tasks run on
+    pub(crate) fn set_handle(&self, handle: Handle) {
+        *self.handle.write().unwrap() = Some(handle);
+    }
+
+    /// Runs a future owned by `owner`, calling `on_done` after it finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the app has no effects runtime and no Tokio runtime is running.
+    pub(crate) fn spawn<T, F>(
+        &self,
+        owner: ComponentId,
+        future: F,
+        on_done: impl FnOnce() + Send + 'static,
+    ) -> TaskHandle<T>
+    where
+        T: Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        let handle = self
+            .handle
+            .read()
+            .unwrap()
+            .clone()
+            .or_else(|| Handle::try_current().ok())
+            .expect("Context::spawn needs the effects runtime or a running Tokio runtime");
+
+        let result = Arc::new(Mutex::new(None));
+        let (done_tx, done) = watch::channel(false);
+        let slot = result.clone();
+        let task = handle.spawn(async move {
+            let output = future.await;
+            *slot.lock().unwrap() = Some(output);
+            let _ = done_tx.send(true);
+            on_done();
+        });
+
+        let abort = task.abort_handle();
+        let mut tasks = self.tasks.write().unwrap();
+        let owned = tasks.entry(owner).or_default();
+        owned.retain(|task| !task.is_finished());
+        owned.push(abort.clone());
+
+        TaskHandle {
+            result,
+            done,
+            abort,
+        }
+    }
+
+    /// Like [`TaskSpawner::spawn`], but the future waits for one of the
+    /// `max_concurrent` permits of `key` before it starts. The limit is fixed
+    /// by the first task spawned under the key.
+    pub(crate) fn spawn_limited<T, F>(
+        &self,
+        owner: ComponentId,
+        key: String,
+        max_concurrent: usize,
+        future: F,
+        on_done: impl FnOnce() + Send + 'static,
+    ) -> TaskHandle<T>
+    where
+        T: Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        let semaphore = self
+            .limits
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_insert_with(|| Arc::new(Semaphore::new(max_concurrent.max(1))))
+            .clone();
+
+        self.spawn(
+            owner,
+            async move {
+                // The semaphore is never closed, so acquiring only waits
+                let _permit = semaphore.acquire_owned().await;
+                future.await
+            },
+            on_done,
+        )
+    }
+
+    /// Cancels every task the component spawned
+    pub(crate) fn cancel(&self, owner: &ComponentId) {
+        if let Some(tasks) = self.tasks.write().unwrap().remove(owner) {
+            for task in tasks {
+                task.abort();
+            }
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl<T> Clone for TaskHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            result: self.result.clone(),
+            done: self.done.clone(),
+            abort: self.abort.clone(),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for TaskHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
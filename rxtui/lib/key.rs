@@ -88,6 +88,18 @@ impl KeyWithModifiers {
         })
     }
 
+    /// Converts back into a crossterm KeyEvent, e.g. to inject synthetic input
+    pub fn to_key_event(&self) -> crossterm::event::KeyEvent {
+        use crossterm::event::{KeyEvent, KeyModifiers};
+
+        let mut modifiers = KeyModifiers::NONE;
+        modifiers.set(KeyModifiers::CONTROL, self.ctrl);
+        modifiers.set(KeyModifiers::ALT, self.alt);
+        modifiers.set(KeyModifiers::SHIFT, self.shift);
+        modifiers.set(KeyModifiers::META, self.meta);
+        KeyEvent::new(self.key.to_key_code(), modifiers)
+    }
+
     /// Checks if this is a platform-specific shortcut
     /// On macOS: uses Cmd (meta), on others: uses Ctrl
     pub fn is_primary_modifier(&self) -> bool {
@@ -139,6 +151,9 @@ pub enum Key {
     /// Delete key
     Delete,
 
+    /// Insert key
+    Insert,
+
     /// Arrow keys
     Up,
     Down,
@@ -164,6 +179,23 @@ pub enum Key {
     F10,
     F11,
     F12,
+
+    /// Media key, for terminals that report them
+    Media(MediaKey),
+}
+
+/// Media keys reported by terminals supporting the kitty keyboard protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaKey {
+    Play,
+    Pause,
+    PlayPause,
+    Stop,
+    TrackNext,
+    TrackPrevious,
+    LowerVolume,
+    RaiseVolume,
+    MuteVolume,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -185,6 +217,7 @@ impl Key {
             KeyCode::BackTab => Some(Key::BackTab),
             KeyCode::Backspace => Some(Key::Backspace),
             KeyCode::Delete => Some(Key::Delete),
+            KeyCode::Insert => Some(Key::Insert),
             KeyCode::Up => Some(Key::Up),
             KeyCode::Down => Some(Key::Down),
             KeyCode::Left => Some(Key::Left),
@@ -205,9 +238,84 @@ impl Key {
             KeyCode::F(10) => Some(Key::F10),
             KeyCode::F(11) => Some(Key::F11),
             KeyCode::F(12) => Some(Key::F12),
+            KeyCode::Media(media) => MediaKey::from_media_key_code(media).map(Key::Media),
+            _ => None,
+        }
+    }
+
+    /// Converts a Key back into the crossterm KeyCode it was created from.
+    pub fn to_key_code(self) -> crossterm::event::KeyCode {
+        use crossterm::event::KeyCode;
+
+        match self {
+            Key::Char(c) => KeyCode::Char(c),
+            Key::Esc => KeyCode::Esc,
+            Key::Enter => KeyCode::Enter,
+            Key::Tab => KeyCode::Tab,
+            Key::BackTab => KeyCode::BackTab,
+            Key::Backspace => KeyCode::Backspace,
+            Key::Delete => KeyCode::Delete,
+            Key::Insert => KeyCode::Insert,
+            Key::Up => KeyCode::Up,
+            Key::Down => KeyCode::Down,
+            Key::Left => KeyCode::Left,
+            Key::Right => KeyCode::Right,
+            Key::PageUp => KeyCode::PageUp,
+            Key::PageDown => KeyCode::PageDown,
+            Key::Home => KeyCode::Home,
+            Key::End => KeyCode::End,
+            Key::F1 => KeyCode::F(1),
+            Key::F2 => KeyCode::F(2),
+            Key::F3 => KeyCode::F(3),
+            Key::F4 => KeyCode::F(4),
+            Key::F5 => KeyCode::F(5),
+            Key::F6 => KeyCode::F(6),
+            Key::F7 => KeyCode::F(7),
+            Key::F8 => KeyCode::F(8),
+            Key::F9 => KeyCode::F(9),
+            Key::F10 => KeyCode::F(10),
+            Key::F11 => KeyCode::F(11),
+            Key::F12 => KeyCode::F(12),
+            Key::Media(media) => KeyCode::Media(media.to_media_key_code()),
+        }
+    }
+}
+
+impl MediaKey {
+    /// Converts a crossterm MediaKeyCode, returning None for keys without a variant.
+    pub fn from_media_key_code(code: crossterm::event::MediaKeyCode) -> Option<Self> {
+        use crossterm::event::MediaKeyCode;
+
+        match code {
+            MediaKeyCode::Play => Some(MediaKey::Play),
+            MediaKeyCode::Pause => Some(MediaKey::Pause),
+            MediaKeyCode::PlayPause => Some(MediaKey::PlayPause),
+            MediaKeyCode::Stop => Some(MediaKey::Stop),
+            MediaKeyCode::TrackNext => Some(MediaKey::TrackNext),
+            MediaKeyCode::TrackPrevious => Some(MediaKey::TrackPrevious),
+            MediaKeyCode::LowerVolume => Some(MediaKey::LowerVolume),
+            MediaKeyCode::RaiseVolume => Some(MediaKey::RaiseVolume),
+            MediaKeyCode::MuteVolume => Some(MediaKey::MuteVolume),
             _ => None,
         }
     }
+
+    /// Converts back into the crossterm MediaKeyCode.
+    pub fn to_media_key_code(self) -> crossterm::event::MediaKeyCode {
+        use crossterm::event::MediaKeyCode;
+
+        match self {
+            MediaKey::Play => MediaKeyCode::Play,
+            MediaKey::Pause => MediaKeyCode::Pause,
+            MediaKey::PlayPause => MediaKeyCode::PlayPause,
+            MediaKey::Stop => MediaKeyCode::Stop,
+            MediaKey::TrackNext => MediaKeyCode::TrackNext,
+            MediaKey::TrackPrevious => MediaKeyCode::TrackPrevious,
+            MediaKey::LowerVolume => MediaKeyCode::LowerVolume,
+            MediaKey::RaiseVolume => MediaKeyCode::RaiseVolume,
+            MediaKey::MuteVolume => MediaKeyCode::MuteVolume,
+        }
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -224,6 +332,7 @@ impl std::fmt::Display for Key {
             Key::BackTab => write!(f, "BackTab"),
             Key::Backspace => write!(f, "Backspace"),
             Key::Delete => write!(f, "Delete"),
+            Key::Insert => write!(f, "Insert"),
             Key::Up => write!(f, "↑"),
             Key::Down => write!(f, "↓"),
             Key::Left => write!(f, "←"),
@@ -244,6 +353,66 @@ impl std::fmt::Display for Key {
             Key::F10 => write!(f, "F10"),
             Key::F11 => write!(f, "F11"),
             Key::F12 => write!(f, "F12"),
+            Key::Media(media) => write!(f, "{media:?}"),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MediaKeyCode};
+
+    const FUNCTION_KEYS: [Key; 12] = [
+        Key::F1,
+        Key::F2,
+        Key::F3,
+        Key::F4,
+        Key::F5,
+        Key::F6,
+        Key::F7,
+        Key::F8,
+        Key::F9,
+        Key::F10,
+        Key::F11,
+        Key::F12,
+    ];
+
+    #[test]
+    fn test_function_keys_map_from_key_codes() {
+        for (n, key) in (1..=12).zip(FUNCTION_KEYS) {
+            assert_eq!(Key::from_key_code(KeyCode::F(n)), Some(key));
+            assert_eq!(key.to_key_code(), KeyCode::F(n));
+            assert_eq!(key.to_string(), format!("F{n}"));
+        }
+        assert_eq!(Key::from_key_code(KeyCode::F(13)), None);
+        assert_eq!(Key::from_key_code(KeyCode::Insert), Some(Key::Insert));
+        assert_eq!(
+            Key::from_key_code(KeyCode::Media(MediaKeyCode::PlayPause)),
+            Some(Key::Media(MediaKey::PlayPause))
+        );
+    }
+
+    #[test]
+    fn test_keys_round_trip_through_key_with_modifiers() {
+        let keys = FUNCTION_KEYS.into_iter().chain([
+            Key::Insert,
+            Key::Delete,
+            Key::Media(MediaKey::RaiseVolume),
+        ]);
+        for key in keys {
+            let event = KeyEvent::new(
+                key.to_key_code(),
+                KeyModifiers::SHIFT | KeyModifiers::CONTROL,
+            );
+            let parsed = KeyWithModifiers::from_key_event(event).unwrap();
+            assert_eq!(parsed.key, key);
+            assert!(parsed.shift && parsed.ctrl && !parsed.alt);
+            assert_eq!(parsed.to_key_event(), event);
         }
     }
 }
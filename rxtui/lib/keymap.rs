@@ -0,0 +1,463 @@
+//! Key bindings, including multi-key sequences.
+//!
+//! A [`KeyMap`] binds sequences such as `"g g"` or `"ctrl+k ctrl+c"` to
+//! handlers. Attached to a div with [`Div::keymap`](crate::Div::keymap), its
+//! bindings work while focus is inside the div, or anywhere when nothing is
+//! focused. Keys that start a bound sequence are held back from other handlers
+//! until the sequence completes. If it breaks or times out instead, the held
+//! keys are handed to the other handlers as if no key map had seen them.
+
+use crate::key::{Key, KeyWithModifiers};
+use std::rc::Rc;
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// How long a sequence waits for its next key unless another timeout is set
+pub const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Handler invoked when a bound sequence completes
+type SequenceHandler = Rc<dyn Fn()>;
+
+/// Key sequences bound to handlers.
+///
+/// ```rust,ignore
+/// let keymap = KeyMap::new()
+///     .bind("g g", ctx.handler(Msg::ScrollToTop))
+///     .bind("shift+g", ctx.handler(Msg::ScrollToBottom))
+///     .bind("ctrl+k ctrl+c", ctx.handler(Msg::Comment));
+///
+/// node! { div(keymap: keymap) [ ... ] }
+/// ```
+#[derive(Clone)]
+pub struct KeyMap {
+    bindings: Vec<(Vec<KeyWithModifiers>, SequenceHandler)>,
+    timeout: Duration,
+}
+
+/// Result of feeding a key to a [`SequenceMatcher`]
+pub(crate) enum SequenceMatch {
+    /// The key completed a binding, whose handler is returned
+    Complete(SequenceHandler),
+
+    /// The key continues a sequence still waiting for more keys
+    Pending,
+
+    /// The key is not part of any binding
+    Unbound,
+}
+
+/// Tracks the keys of a sequence in progress across key events
+#[derive(Debug, Default)]
+pub(crate) struct SequenceMatcher {
+    pending: Vec<KeyWithModifiers>,
+    last_key_at: Duration,
+
+    /// How long the pending keys wait for the next one
+    timeout: Duration,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl KeyMap {
+    /// Creates an empty key map
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            timeout: DEFAULT_SEQUENCE_TIMEOUT,
+        }
+    }
+
+    /// Binds a sequence of space-separated keys to a handler.
+    ///
+    /// Each key is a name or character, optionally prefixed with modifiers
+    /// joined by `+`, e.g. `"g g"`, `"ctrl+k ctrl+c"` or `"alt+shift+f1"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sequence cannot be parsed; see [`parse_sequence`].
+    pub fn bind(self, sequence: &str, handler: impl Fn() + 'static) -> Self {
+        let keys = parse_sequence(sequence)
+            .unwrap_or_else(|| panic!("invalid key sequence: {sequence:?}"));
+        self.bind_keys(keys, handler)
+    }
+
+    /// Binds an already parsed sequence of keys to a handler
+    pub fn bind_keys(mut self, keys: Vec<KeyWithModifiers>, handler: impl Fn() + 'static) -> Self {
+        if !keys.is_empty() {
+            self.bindings.push((keys, Rc::new(handler)));
+        }
+        self
+    }
+
+    /// Returns true if a bound sequence starts with `key`
+    pub(crate) fn starts_with(&self, key: KeyWithModifiers) -> bool {
+        self.bindings
+            .iter()
+            .any(|(sequence, _)| sequence.first() == Some(&key))
+    }
+
+    /// Sets how long a sequence waits between keys before starting over
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl SequenceMatcher {
+    /// Feeds a key pressed at `now`, matching against the bindings of `keymaps`.
+    ///
+    /// A pause longer than a key map's timeout breaks its sequences. When the key
+    /// breaks the sequence in progress, it is tried again as the start of a new one.
+    /// Unless `may_start` is set, the key only continues a sequence already in
+    /// progress and is otherwise unbound.
+    ///
+    /// Returns the keys held back by the broken sequence, which should be handled
+    /// as regular key presses before the result for `key`.
+    pub(crate) fn feed(
+        &mut self,
+        keymaps: &[KeyMap],
+        key: KeyWithModifiers,
+        now: Duration,
+        may_start: bool,
+    ) -> (Vec<KeyWithModifiers>, SequenceMatch) {
+        let gap = now.saturating_sub(self.last_key_at);
+        self.last_key_at = now;
+
+        let mut released = Vec::new();
+        if !self.pending.is_empty() {
+            self.pending.push(key);
+            let live = keymaps.iter().filter(|keymap| gap <= keymap.timeout);
+            match Self::lookup(live, &self.pending) {
+                SequenceMatch::Unbound => {}
+                found => return (released, self.settle(keymaps, found)),
+            }
+            self.pending.pop();
+            released = std::mem::take(&mut self.pending);
+        }
+
+        if !may_start {
+            return (released, SequenceMatch::Unbound);
+        }
+        self.pending.push(key);
+        let found = Self::lookup(keymaps.iter(), &self.pending);
+        (released, self.settle(keymaps, found))
+    }
+
+    /// Returns true while no keys are held back for a sequence
+    pub(crate) fn is_idle(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Gives up on a sequence that has waited too long for its next key at `now`,
+    /// returning the keys it held back.
+    pub(crate) fn expire(&mut self, now: Duration) -> Vec<KeyWithModifiers> {
+        if now.saturating_sub(self.last_key_at) > self.timeout {
+            std::mem::take(&mut self.pending)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the first binding equal to `keys`, or whether one starts with them
+    fn lookup<'a>(
+        keymaps: impl Iterator<Item = &'a KeyMap>,
+        keys: &[KeyWithModifiers],
+    ) -> SequenceMatch {
+        let mut pending = false;
+        for (sequence, handler) in keymaps.flat_map(|keymap| &keymap.bindings) {
+            if sequence.as_slice() == keys {
+                return SequenceMatch::Complete(handler.clone());
+            }
+            pending |= sequence.starts_with(keys);
+        }
+        if pending {
+            SequenceMatch::Pending
+        } else {
+            SequenceMatch::Unbound
+        }
+    }
+
+    /// Keeps the pending keys only while a sequence is still in progress, waiting
+    /// as long as the most patient key map that can still complete it
+    fn settle(&mut self, keymaps: &[KeyMap], found: SequenceMatch) -> SequenceMatch {
+        if matches!(found, SequenceMatch::Pending) {
+            self.timeout = keymaps
+                .iter()
+                .filter(|keymap| {
+                    keymap
+                        .bindings
+                        .iter()
+                        .any(|(sequence, _)| sequence.starts_with(&self.pending))
+                })
+                .map(|keymap| keymap.timeout)
+                .max()
+                .unwrap_or_default();
+        } else {
+            self.pending.clear();
+        }
+        found
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Parses a space-separated key sequence such as `"ctrl+k ctrl+c"`.
+///
+/// Modifiers are `ctrl`, `alt`, `shift` and `meta` (also `cmd` or `super`).
+/// Keys are single characters, `space`, or names like `esc`, `enter`, `tab`,
+/// `backspace`, `delete`, `insert`, `up`, `page_down`, `home` and `f1`–`f12`.
+/// Names and modifiers are case-insensitive. Returns `None` for an empty or
+/// unrecognized sequence.
+pub fn parse_sequence(sequence: &str) -> Option<Vec<KeyWithModifiers>> {
+    let keys: Option<Vec<_>> = sequence.split_whitespace().map(parse_key).collect();
+    keys.filter(|keys| !keys.is_empty())
+}
+
+/// Parses a single key with optional modifiers, e.g. `"ctrl+shift+a"`
+fn parse_key(spec: &str) -> Option<KeyWithModifiers> {
+    let mut parts: Vec<&str> = spec.split('+').collect();
+    // A trailing empty part means the key itself is '+'
+    if parts.len() > 1 && parts.last() == Some(&"") {
+        parts.pop();
+        *parts.last_mut()? = "+";
+    }
+
+    let (name, modifiers) = parts.split_last()?;
+    let mut key = KeyWithModifiers::new(parse_key_name(name)?);
+    for modifier in modifiers {
+        match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => key.ctrl = true,
+            "alt" | "option" => key.alt = true,
+            "shift" => key.shift = true,
+            "meta" | "cmd" | "super" => key.meta = true,
+            _ => return None,
+        }
+    }
+    Some(key)
+}
+
+/// Parses a key name or single character
+fn parse_key_name(name: &str) -> Option<Key> {
+    let mut chars = name.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Some(Key::Char(ch));
+    }
+
+    let key = match name.to_ascii_lowercase().as_str() {
+        "space" => Key::Char(' '),
+        "esc" | "escape" => Key::Esc,
+        "enter" | "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "backtab" | "back_tab" => Key::BackTab,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "insert" | "ins" => Key::Insert,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "pageup" | "page_up" => Key::PageUp,
+        "pagedown" | "page_down" => Key::PageDown,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        _ => return None,
+    };
+    Some(key)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for KeyMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sequences: Vec<_> = self.bindings.iter().map(|(keys, _)| keys).collect();
+        f.debug_struct("KeyMap")
+            .field("sequences", &sequences)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn key(ch: char) -> KeyWithModifiers {
+        KeyWithModifiers::new(Key::Char(ch))
+    }
+
+    /// Feeds each key a given number of milliseconds after the previous one,
+    /// listing released keys as `"release <char>"` before the key's outcome
+    fn feed(
+        matcher: &mut SequenceMatcher,
+        keymap: &KeyMap,
+        keys: &[(KeyWithModifiers, u64)],
+    ) -> Vec<String> {
+        let mut outcomes = Vec::new();
+        for &(key, delay) in keys {
+            let now = matcher.last_key_at + Duration::from_millis(delay);
+            let (released, found) = matcher.feed(std::slice::from_ref(keymap), key, now, true);
+            outcomes.extend(
+                released
+                    .iter()
+                    .map(|key| format!("release {}", describe(key))),
+            );
+            outcomes.push(outcome(found).to_string());
+        }
+        outcomes
+    }
+
+    fn outcome(found: SequenceMatch) -> &'static str {
+        match found {
+            SequenceMatch::Complete(handler) => {
+                handler();
+                "complete"
+            }
+            SequenceMatch::Pending => "pending",
+            SequenceMatch::Unbound => "unbound",
+        }
+    }
+
+    fn describe(key: &KeyWithModifiers) -> String {
+        match key.key {
+            Key::Char(ch) if key.ctrl => format!("ctrl+{ch}"),
+            Key::Char(ch) => ch.to_string(),
+            other => format!("{other:?}"),
+        }
+    }
+
+    fn logging_keymap(log: &Rc<RefCell<Vec<&'static str>>>) -> KeyMap {
+        let top = log.clone();
+        let comment = log.clone();
+        KeyMap::new()
+            .bind("g g", move || top.borrow_mut().push("top"))
+            .bind("ctrl+k ctrl+c", move || {
+                comment.borrow_mut().push("comment")
+            })
+    }
+
+    #[test]
+    fn test_parse_sequence() {
+        let ctrl_k = KeyWithModifiers::with_ctrl(Key::Char('k'));
+        assert_eq!(parse_sequence("ctrl+k ctrl+c").unwrap()[0], ctrl_k);
+        assert_eq!(parse_sequence("g g"), Some(vec![key('g'), key('g')]));
+        assert_eq!(
+            parse_sequence("Alt+Shift+F1"),
+            Some(vec![KeyWithModifiers {
+                alt: true,
+                shift: true,
+                ..KeyWithModifiers::new(Key::F1)
+            }])
+        );
+        assert_eq!(
+            parse_sequence("ctrl++"),
+            Some(vec![KeyWithModifiers::with_ctrl(Key::Char('+'))])
+        );
+        assert_eq!(
+            parse_sequence("space page_down"),
+            Some(vec![key(' '), KeyWithModifiers::new(Key::PageDown)])
+        );
+        assert_eq!(parse_sequence("hyper+x"), None);
+        assert_eq!(parse_sequence("  "), None);
+    }
+
+    #[test]
+    fn test_sequence_fires_only_when_keys_arrive_in_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let keymap = logging_keymap(&log);
+        let mut matcher = SequenceMatcher::default();
+
+        assert_eq!(
+            feed(&mut matcher, &keymap, &[(key('g'), 0), (key('g'), 100)]),
+            ["pending", "complete"]
+        );
+        // The held key is released to the other handlers before the breaking key
+        assert_eq!(
+            feed(&mut matcher, &keymap, &[(key('g'), 0), (key('x'), 100)]),
+            ["pending", "release g", "unbound"]
+        );
+
+        // A broken sequence lets the breaking key start a new one
+        let ctrl = KeyWithModifiers::with_ctrl;
+        assert_eq!(
+            feed(
+                &mut matcher,
+                &keymap,
+                &[
+                    (key('g'), 0),
+                    (ctrl(Key::Char('k')), 100),
+                    (ctrl(Key::Char('c')), 100)
+                ]
+            ),
+            ["pending", "release g", "pending", "complete"]
+        );
+        assert_eq!(*log.borrow(), ["top", "comment"]);
+    }
+
+    #[test]
+    fn test_timeout_between_keys_resets_sequence() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let keymap = logging_keymap(&log).timeout(Duration::from_millis(500));
+        let mut matcher = SequenceMatcher::default();
+
+        assert_eq!(
+            feed(&mut matcher, &keymap, &[(key('g'), 0), (key('g'), 501)]),
+            ["pending", "release g", "pending"]
+        );
+        assert!(log.borrow().is_empty());
+
+        // The late key started a fresh sequence, which a timely key completes
+        assert_eq!(
+            feed(&mut matcher, &keymap, &[(key('g'), 500)]),
+            ["complete"]
+        );
+        assert_eq!(*log.borrow(), ["top"]);
+    }
+
+    #[test]
+    fn test_expire_releases_keys_after_timeout() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let keymap = logging_keymap(&log).timeout(Duration::from_millis(500));
+        let mut matcher = SequenceMatcher::default();
+
+        assert_eq!(feed(&mut matcher, &keymap, &[(key('g'), 0)]), ["pending"]);
+        assert!(matcher.expire(Duration::from_millis(500)).is_empty());
+        assert_eq!(matcher.expire(Duration::from_millis(501)), [key('g')]);
+        assert!(matcher.is_idle());
+    }
+}
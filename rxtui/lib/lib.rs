@@ -332,6 +332,10 @@ mod buffer;
 /// Minimizes escape sequences and I/O operations for best performance.
 mod terminal;
 
+/// Render targets for frames, abstracting the terminal.
+/// Lets apps draw to other surfaces such as an in-memory buffer.
+pub mod backend;
+
 //--------------------------------------------------------------------------------------------------
 // Modules: Application
 //--------------------------------------------------------------------------------------------------
@@ -348,6 +352,9 @@ pub mod app;
 /// Defines colors, spacing, borders, and other visual properties.
 pub mod style;
 
+/// Semantic color palettes shared through the component context.
+pub mod theme;
+
 /// Bounds and rectangle operations for dirty region tracking.
 /// Provides types for tracking screen regions that need redrawing.
 pub mod bounds;
@@ -360,6 +367,9 @@ pub mod bounds;
 /// Provides an enum for representing both characters and special keys.
 pub mod key;
 
+/// Key bindings for multi-key sequences like "g g" or "ctrl+k ctrl+c".
+pub mod keymap;
+
 /// Utilities for terminal rendering, Unicode width calculations, and text wrapping.
 /// Provides helpers for display width, text manipulation, and wrapping algorithms.
 mod utils;
@@ -417,16 +427,33 @@ pub use rxtui_macros::{component, update, view};
 #[cfg(feature = "effects")]
 pub use rxtui_macros::effect;
 
-pub use app::{App, Context};
+pub use app::{App, Breakpoint, Breakpoints, Context, NonTtyMode, ToastKind, measure};
+pub use backend::{Backend, CrosstermBackend, PlainTextBackend, TestBackend};
 pub use bounds::Rect;
+pub use buffer::{Cell, CellStyle};
 pub use component::{Action, Component, Message, MessageExt, State};
 pub use components::TextInput;
-pub use key::{Key, KeyWithModifiers};
-pub use node::{Div, Node, RichText, Text, TextSpan};
+pub use diff::{PatchBackend, PatchOp};
+pub use key::{Key, KeyWithModifiers, MediaKey};
+pub use keymap::KeyMap;
+pub use node::{Div, Divider, Node, Propagation, RichText, Screen, Text, TextContent, TextSpan};
 pub use style::{
-    BorderEdges, BorderStyle, Color, Dimension, Direction, Overflow, Position, Spacing, Style,
-    TextStyle, TextWrap, WrapMode,
+    Animation, BorderEdges, BorderStyle, Color, Dimension, Direction, Grid, Overflow, Position,
+    ScrollbarPosition, ScrollbarStyle, Spacing, SpacingPercent, Style, TextStyle, TextWrap,
+    WrapMode,
+};
+pub use terminal::{DEFAULT_WRITE_BUFFER_SIZE, FlushStrategy, FrameStats};
+pub use theme::{Theme, ThemeMode};
+#[doc(hidden)]
+pub use utils::write_debug_log;
+pub use utils::{
+    DEFAULT_TAB_WIDTH, LogLevel, TextMetrics, WrapCacheStats, center_in, debug_log_path,
+    display_width, display_width_with_tabs, expand_tabs, pad_to_width, pad_to_width_ellipsis,
+    set_debug_log_path, text_metrics, truncate_to_width, wrap_cache_stats, wrap_text_cached,
+    wrap_text_with_tabs,
 };
+pub use vdom::VDom;
+pub use vnode::VNode;
 
 //--------------------------------------------------------------------------------------------------
 // Tests
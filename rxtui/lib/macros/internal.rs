@@ -197,6 +197,9 @@ macro_rules! text_wrap_value {
     (word_break) => {
         $crate::TextWrap::WordBreak
     };
+    (hyphenate) => {
+        $crate::TextWrap::Hyphenate
+    };
     ($wrap:expr) => {
         $wrap
     };
@@ -220,6 +223,24 @@ macro_rules! text_align_value {
     };
 }
 
+/// Converts vertical align values to VerticalAlign enum
+#[doc(hidden)]
+#[macro_export]
+macro_rules! vertical_align_value {
+    (top) => {
+        $crate::style::VerticalAlign::Top
+    };
+    (middle) => {
+        $crate::style::VerticalAlign::Middle
+    };
+    (bottom) => {
+        $crate::style::VerticalAlign::Bottom
+    };
+    ($align:expr) => {
+        $align
+    };
+}
+
 /// Converts position values to Position enum
 #[doc(hidden)]
 #[macro_export]
@@ -233,6 +254,9 @@ macro_rules! position_value {
     (fixed) => {
         $crate::Position::Fixed
     };
+    (sticky) => {
+        $crate::Position::Sticky
+    };
     ($pos:expr) => {
         $pos
     };
@@ -331,6 +355,9 @@ macro_rules! key_value {
     (delete) => {
         $crate::Key::Delete
     };
+    (insert) => {
+        $crate::Key::Insert
+    };
 
     // Arrow keys (lowercase)
     (up) => {
@@ -423,6 +450,9 @@ macro_rules! key_value {
     (Delete) => {
         $crate::Key::Delete
     };
+    (Insert) => {
+        $crate::Key::Insert
+    };
     (Up) => {
         $crate::Key::Up
     };
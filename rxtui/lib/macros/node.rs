@@ -84,12 +84,16 @@
 ///         pad_h: 1,              // Horizontal padding only
 ///         pad_v: 1,              // Vertical padding only
 ///         padding: (Spacing::horizontal(2)), // Direct Spacing expression
+///         m: 1,                  // Margin on all sides
+///         margin: (Spacing::vertical(1)), // Direct margin expression
 ///         gap: 1,                // Gap between children
 ///
 ///         // Layout
 ///         dir: horizontal,       // Direction (or use 'h')
 ///         wrap: wrap,           // Wrap mode (lowercase)
 ///         overflow: hidden,     // Overflow behavior (lowercase)
+///         memo: (rows, filter), // Skip diff and layout while deps are unchanged
+///         key: item.id,         // Match to the previous render by key, not position
 ///
 ///         // Positioning
 ///         pos: absolute,        // Position type (lowercase)
@@ -134,7 +138,9 @@
 ///         // Text alignment
 ///         text("Centered text", align: center),
 ///         text("Right aligned", align: right),
-///         text("Left aligned", align: left)
+///         text("Left aligned", align: left),
+///         // Vertical alignment in the container's free space
+///         text("Vertically centered", valign: middle)
 ///     ]
 /// }
 /// ```
@@ -374,6 +380,8 @@
 /// | `pad` | `padding` | Inner spacing (all sides) |
 /// | `pad_h` | `padding` | Horizontal padding only |
 /// | `pad_v` | `padding` | Vertical padding only |
+/// | `m` | `margin` | Outer spacing (all sides) |
+/// | `grow` | `flex_grow` | Share of leftover main-axis space |
 /// | `w` | `width` | Fixed width |
 /// | `h` | `height` | Fixed height |
 /// | `w_pct` | `width_percent` | Width as percentage (0.0 to 1.0) |
@@ -396,6 +404,7 @@
 /// | `@focus` | Gained focus | `@focus: handler` |
 /// | `@blur` | Lost focus | `@blur: handler` |
 /// | `@any_char` | Any character typed | `@any_char: \|c\| handler(c)` |
+/// | `@paste` | Text pasted while focused | `@paste: \|s\| handler(s)` |
 ///
 /// # Tips
 ///
@@ -892,6 +901,24 @@ macro_rules! tui_apply_props {
         $container.padding($padding)
     }};
 
+    // Margin (single value - all sides)
+    ($container:expr, m: $margin:expr, $($rest:tt)*) => {{
+        let c = $container.margin($crate::Spacing::all($margin));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, m: $margin:expr) => {{
+        $container.margin($crate::Spacing::all($margin))
+    }};
+
+    // Direct margin expression
+    ($container:expr, margin: ($margin:expr), $($rest:tt)*) => {{
+        let c = $container.margin($margin);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, margin: ($margin:expr)) => {{
+        $container.margin($margin)
+    }};
+
     // Width
     ($container:expr, w: $width:expr, $($rest:tt)*) => {{
         let c = $container.width($width);
@@ -1007,6 +1034,22 @@ macro_rules! tui_apply_props {
         $container.gap($gap)
     }};
 
+    // Grid
+    ($container:expr, grid: $grid:expr, $($rest:tt)*) => {{
+        let c = $container.grid($grid);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, grid: $grid:expr) => {{
+        $container.grid($grid)
+    }};
+    ($container:expr, grid_span: ($cols:expr, $rows:expr), $($rest:tt)*) => {{
+        let c = $container.grid_span($cols, $rows);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, grid_span: ($cols:expr, $rows:expr)) => {{
+        $container.grid_span($cols, $rows)
+    }};
+
     // Border color (renamed from border for clarity)
     ($container:expr, border_color: $color:tt, $($rest:tt)*) => {{
         let c = $container.border_color($crate::color_value!($color));
@@ -1261,6 +1304,24 @@ macro_rules! tui_apply_props {
         $container.focusable(true)
     }};
 
+    // Lazy with value
+    ($container:expr, lazy: $val:expr, $($rest:tt)*) => {{
+        let c = $container.lazy($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, lazy: $val:expr) => {{
+        $container.lazy($val)
+    }};
+
+    // Lazy shorthand
+    ($container:expr, lazy, $($rest:tt)*) => {{
+        let c = $container.lazy(true);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, lazy) => {{
+        $container.lazy(true)
+    }};
+
     // Show scrollbar with value
     ($container:expr, show_scrollbar: $val:expr, $($rest:tt)*) => {{
         let c = $container.show_scrollbar($val);
@@ -1270,6 +1331,87 @@ macro_rules! tui_apply_props {
         $container.show_scrollbar($val)
     }};
 
+    // Scroll indicators
+    ($container:expr, scroll_indicators: $val:expr, $($rest:tt)*) => {{
+        let c = $container.scroll_indicators($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, scroll_indicators: $val:expr) => {{
+        $container.scroll_indicators($val)
+    }};
+
+    // Memoization dependencies
+    ($container:expr, memo: $deps:expr, $($rest:tt)*) => {{
+        let c = $container.memo($deps);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, memo: $deps:expr) => {{
+        $container.memo($deps)
+    }};
+
+    // Identity among siblings
+    ($container:expr, key: $key:expr, $($rest:tt)*) => {{
+        let c = $container.key($key);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, key: $key:expr) => {{
+        $container.key($key)
+    }};
+
+    // Flex grow weight
+    ($container:expr, grow: $val:expr, $($rest:tt)*) => {{
+        let c = $container.flex_grow($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, grow: $val:expr) => {{
+        $container.flex_grow($val)
+    }};
+
+    // Fill glyph
+    ($container:expr, fill_char: $val:expr, $($rest:tt)*) => {{
+        let c = $container.fill_char($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, fill_char: $val:expr) => {{
+        $container.fill_char($val)
+    }};
+
+    // Background opacity
+    ($container:expr, opacity: $val:expr, $($rest:tt)*) => {{
+        let c = $container.opacity($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, opacity: $val:expr) => {{
+        $container.opacity($val)
+    }};
+
+    // Scrollbar glyphs, colors and placement
+    ($container:expr, scrollbar_style: $val:expr, $($rest:tt)*) => {{
+        let c = $container.scrollbar_style($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, scrollbar_style: $val:expr) => {{
+        $container.scrollbar_style($val)
+    }};
+
+    // Key sequences
+    ($container:expr, keymap: $val:expr, $($rest:tt)*) => {{
+        let c = $container.keymap($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, keymap: $val:expr) => {{
+        $container.keymap($val)
+    }};
+
+    // Background inheritance
+    ($container:expr, inherit_background: $val:expr, $($rest:tt)*) => {{
+        let c = $container.inherit_background($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, inherit_background: $val:expr) => {{
+        $container.inherit_background($val)
+    }};
+
     // Focus style
     ($container:expr, focus_style: ($style:expr), $($rest:tt)*) => {{
         let c = $container.focus_style($style);
@@ -1390,6 +1532,15 @@ macro_rules! tui_apply_props {
         $container.position($crate::Position::Absolute)
     }};
 
+    // Sticky positioning shorthand
+    ($container:expr, sticky, $($rest:tt)*) => {{
+        let c = $container.position($crate::Position::Sticky);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, sticky) => {{
+        $container.position($crate::Position::Sticky)
+    }};
+
     // Positioning offsets
     ($container:expr, top: $val:expr, $($rest:tt)*) => {{
         let c = $container.top($val);
@@ -1553,6 +1704,15 @@ macro_rules! tui_apply_props {
     ($container:expr, @any_char: $handler:expr) => {{
         $container.on_any_char($handler)
     }};
+
+    // @paste handler
+    ($container:expr, @paste: $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_paste($handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @paste: $handler:expr) => {{
+        $container.on_paste($handler)
+    }};
 }
 
 /// Build text with properties (internal)
@@ -1691,6 +1851,31 @@ macro_rules! tui_apply_text_props {
     ($text:expr, align: $align:tt) => {{
         $text.align($crate::text_align_value!($align))
     }};
+    ($text:expr, valign: $align:tt, $($rest:tt)*) => {{
+        let t = $text.vertical_align($crate::vertical_align_value!($align));
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, valign: $align:tt) => {{
+        $text.vertical_align($crate::vertical_align_value!($align))
+    }};
+
+    // Tab width
+    ($text:expr, tab_width: $width:expr, $($rest:tt)*) => {{
+        let t = $text.tab_width($width);
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, tab_width: $width:expr) => {{
+        $text.tab_width($width)
+    }};
+
+    // Animation
+    ($text:expr, animation: $animation:expr, $($rest:tt)*) => {{
+        let t = $text.animation($animation);
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, animation: $animation:expr) => {{
+        $text.animation($animation)
+    }};
 }
 
 /// Build RichText elements (internal)
@@ -1887,6 +2072,33 @@ macro_rules! tui_apply_richtext_props {
         $rt.align($crate::text_align_value!($align))
     }};
 
+    ($rt:expr, valign: $align:tt, $($rest:tt)*) => {{
+        let rt = $rt.vertical_align($crate::vertical_align_value!($align));
+        $crate::tui_apply_richtext_props!(rt, $($rest)*)
+    }};
+
+    ($rt:expr, valign: $align:tt) => {{
+        $rt.vertical_align($crate::vertical_align_value!($align))
+    }};
+
+    ($rt:expr, tab_width: $width:expr, $($rest:tt)*) => {{
+        let rt = $rt.tab_width($width);
+        $crate::tui_apply_richtext_props!(rt, $($rest)*)
+    }};
+
+    ($rt:expr, tab_width: $width:expr) => {{
+        $rt.tab_width($width)
+    }};
+
+    ($rt:expr, animation: $animation:expr, $($rest:tt)*) => {{
+        let rt = $rt.animation($animation);
+        $crate::tui_apply_richtext_props!(rt, $($rest)*)
+    }};
+
+    ($rt:expr, animation: $animation:expr) => {{
+        $rt.animation($animation)
+    }};
+
     ($rt:expr, italic_all) => {{
         $rt.italic_all()
     }};
@@ -1,10 +1,12 @@
 use crate::component::ComponentId;
 use crate::key::{Key, KeyWithModifiers};
+use crate::keymap::KeyMap;
 use crate::style::{
-    AlignItems, AlignSelf, Border, BorderEdges, BorderStyle, Color, Dimension, Direction,
-    JustifyContent, Overflow, Position, Spacing, Style, WrapMode,
+    AlignItems, AlignSelf, Border, BorderEdges, BorderStyle, Color, Dimension, Direction, Grid,
+    JustifyContent, Overflow, Position, ScrollbarStyle, Spacing, SpacingPercent, Style, WrapMode,
 };
 use std::fmt::Debug;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::marker::PhantomData;
 use std::rc::Rc;
 
@@ -18,6 +20,20 @@ pub type KeyHandler = (Key, Rc<dyn Fn()>, bool);
 /// Type alias for keyboard event handler with modifiers: (key_with_modifiers, handler, is_global)
 pub type KeyWithModifiersHandler = (KeyWithModifiers, Rc<dyn Fn()>, bool);
 
+/// Type alias for a drag handler receiving (x, y, parent_width, parent_height)
+pub type DragInParentHandler = Rc<dyn Fn(u16, u16, u16, u16)>;
+
+/// Whether a click keeps bubbling to the ancestors of the clicked div.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Propagation {
+    /// Let the ancestors' click handlers run too
+    #[default]
+    Continue,
+
+    /// Stop here; no ancestor sees the click
+    Stop,
+}
+
 /// A container that can hold child elements
 #[derive(Clone)]
 pub struct Div<T> {
@@ -41,6 +57,18 @@ pub struct Div<T> {
 
     /// Component path that owns this div (used for focus targeting)
     pub component_path: Option<ComponentId>,
+
+    /// Hash of the dependencies this subtree was memoized with
+    pub memo_key: Option<u64>,
+
+    /// Hash of the key identifying this div among its siblings
+    pub key: Option<u64>,
+
+    /// Whether children are only built while this div is on screen
+    pub lazy: bool,
+
+    /// Tree path of a lazy div, set during expansion to track its visibility
+    pub lazy_path: Option<ComponentId>,
 }
 
 /// Style configuration for a div in different states.
@@ -59,8 +87,8 @@ pub struct DivStyles {
 /// Event callbacks for a div.
 #[derive(Clone, Default)]
 pub struct EventCallbacks {
-    /// Click event handler
-    pub on_click: Option<Rc<dyn Fn()>>,
+    /// Click event handler, deciding whether the click bubbles to ancestors
+    pub on_click: Option<Rc<dyn Fn() -> Propagation>>,
 
     /// Keyboard event handlers: (key, handler, is_global)
     /// Global handlers work regardless of focus state
@@ -76,11 +104,30 @@ pub struct EventCallbacks {
     /// Handler for any key press (receives the full Key enum)
     pub on_any_key: Option<Rc<dyn Fn(Key)>>,
 
+    /// Handler for pasted text (receives the whole pasted block)
+    pub on_paste: Option<Rc<dyn Fn(String)>>,
+
     /// Called when div gains focus
     pub on_focus: Option<Rc<dyn Fn()>>,
 
     /// Called when div loses focus
     pub on_blur: Option<Rc<dyn Fn()>>,
+
+    /// Called when a mouse button is pressed on the div, with the position
+    /// relative to its top-left corner
+    pub on_mouse_down: Option<Rc<dyn Fn(u16, u16)>>,
+
+    /// Called as the mouse moves with a button held after pressing on the div,
+    /// with the position relative to it, clamped to its bounds
+    pub on_drag: Option<Rc<dyn Fn(u16, u16)>>,
+
+    /// Called as the mouse moves with a button held after pressing on the div,
+    /// with the position relative to its parent, clamped to the parent's bounds,
+    /// followed by the parent's width and height
+    pub on_drag_in_parent: Option<DragInParentHandler>,
+
+    /// Key sequences bound on this div, active while focus is inside it or nothing is focused
+    pub keymap: Option<KeyMap>,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -98,6 +145,10 @@ impl<T> Div<T> {
             focused: false,
             hovered: false,
             component_path: None,
+            memo_key: None,
+            key: None,
+            lazy: false,
+            lazy_path: None,
         }
     }
 
@@ -113,6 +164,40 @@ impl<T> Div<T> {
         self
     }
 
+    /// Memoizes this subtree on `deps`.
+    ///
+    /// While `deps` hash to the same value as in the previous render, diffing skips
+    /// the children and the previous layout is reused. The subtree must not depend
+    /// on anything outside `deps`, or it will show stale content.
+    pub fn memo(mut self, deps: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        deps.hash(&mut hasher);
+        self.memo_key = Some(hasher.finish());
+        self
+    }
+
+    /// Identifies this div among its siblings across renders.
+    ///
+    /// Children with keys are matched to the previous render by key instead of
+    /// position, so inserting or reordering items keeps each item's scroll
+    /// position and focus. Keys should be unique among siblings.
+    pub fn key(mut self, key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        self.key = Some(hasher.finish());
+        self
+    }
+
+    /// Builds and mounts the children only while this div is within the viewport.
+    ///
+    /// Offscreen children don't run their views or effects, and are unmounted,
+    /// with their effects cancelled, once they scroll or move out of view. The
+    /// div keeps its own size meanwhile, so give it one to reserve its space.
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
     /// Makes this div focusable
     pub fn focusable(mut self, focusable: bool) -> Self {
         self.focusable = focusable;
@@ -149,6 +234,24 @@ impl<T> Div<T> {
         self
     }
 
+    /// Sets the margin as fractions of the parent's content box
+    pub fn margin_percent(mut self, margin: SpacingPercent) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .margin_percent = Some(margin);
+        self
+    }
+
+    /// Sets the padding as fractions of the parent's content box
+    pub fn padding_percent(mut self, padding: SpacingPercent) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .padding_percent = Some(padding);
+        self
+    }
+
     /// Sets the gap between children
     pub fn gap(mut self, gap: u16) -> Self {
         self.styles.base.get_or_insert(Style::default()).gap = Some(gap);
@@ -161,6 +264,18 @@ impl<T> Div<T> {
         self
     }
 
+    /// Lays the children out in the cells of a grid instead of along the direction
+    pub fn grid(mut self, grid: Grid) -> Self {
+        self.styles.base.get_or_insert(Style::default()).grid = Some(grid);
+        self
+    }
+
+    /// Sets how many columns and rows this div covers when placed in a grid
+    pub fn grid_span(mut self, columns: u16, rows: u16) -> Self {
+        self.styles.base.get_or_insert(Style::default()).grid_span = Some((columns, rows));
+        self
+    }
+
     /// Sets the width
     pub fn width(mut self, width: u16) -> Self {
         self.styles.base.get_or_insert(Style::default()).width = Some(Dimension::Fixed(width));
@@ -316,6 +431,57 @@ impl<T> Div<T> {
         self
     }
 
+    /// Sets whether to show ▲/▼ indicators at the top and bottom edges while
+    /// more content is scrolled out of view
+    pub fn scroll_indicators(mut self, show: bool) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .scroll_indicators = Some(show);
+        self
+    }
+
+    /// Sets the flex-grow weight.
+    /// Leftover main-axis space is shared among growing children in proportion to
+    /// their weights; Auto-sized children grow with a weight of 1 by default.
+    pub fn flex_grow(mut self, grow: f32) -> Self {
+        self.styles.base.get_or_insert(Style::default()).flex_grow = Some(grow);
+        self
+    }
+
+    /// Fills the div's area with a one-column glyph instead of spaces, drawn in
+    /// the terminal's default foreground; wide glyphs fall back to spaces
+    pub fn fill_char(mut self, ch: char) -> Self {
+        self.styles.base.get_or_insert(Style::default()).fill_char = Some(ch);
+        self
+    }
+
+    /// Sets the background opacity from 0.0 to 1.0; translucent backgrounds are
+    /// blended over what is drawn underneath, e.g. to dim content behind a modal
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.styles.base.get_or_insert(Style::default()).opacity = Some(opacity);
+        self
+    }
+
+    /// Sets the glyphs, colors and placement of the scrollbar
+    pub fn scrollbar_style(mut self, scrollbar: ScrollbarStyle) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .scrollbar_style = Some(scrollbar);
+        self
+    }
+
+    /// Sets whether text inside this div inherits its background; pass false to
+    /// leave text cells without a background so the terminal's theme shows through
+    pub fn inherit_background(mut self, inherit: bool) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .inherit_background = Some(inherit);
+        self
+    }
+
     /// Sets position to absolute (for macro compatibility when used as flag)
     pub fn absolute_position(mut self) -> Self {
         self.styles.base.get_or_insert(Style::default()).position = Some(Position::Absolute);
@@ -426,6 +592,13 @@ impl<T> Div<T> {
         self
     }
 
+    /// Binds key sequences such as "g g"; they work while focus is inside this div
+    /// or nothing is focused
+    pub fn keymap(mut self, keymap: KeyMap) -> Self {
+        self.events.keymap = Some(keymap);
+        self
+    }
+
     /// Registers a global key handler (works even when not focused)
     pub fn on_key_global(mut self, key: Key, handler: impl Fn() + 'static) -> Self {
         self.events.on_key.push((key, Rc::new(handler), true));
@@ -476,12 +649,57 @@ impl<T> Div<T> {
         self
     }
 
-    /// Registers a click handler
+    /// Registers a handler for bracketed paste, called once with the whole pasted text
+    pub fn on_paste(mut self, handler: impl Fn(String) + 'static) -> Self {
+        self.events.on_paste = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a click handler.
+    ///
+    /// Clicks bubble from the clicked div outward, so ancestors' handlers run
+    /// afterwards; use [`Div::on_click_with_propagation`] to stop them.
     pub fn on_click(mut self, handler: impl Fn() + 'static) -> Self {
+        self.events.on_click = Some(Rc::new(move || {
+            handler();
+            Propagation::Continue
+        }));
+        self
+    }
+
+    /// Registers a click handler that decides whether the click reaches the
+    /// ancestors' handlers
+    pub fn on_click_with_propagation(
+        mut self,
+        handler: impl Fn() -> Propagation + 'static,
+    ) -> Self {
         self.events.on_click = Some(Rc::new(handler));
         self
     }
 
+    /// Registers a handler for mouse presses, receiving the column and row
+    /// relative to this div
+    pub fn on_mouse_down(mut self, handler: impl Fn(u16, u16) + 'static) -> Self {
+        self.events.on_mouse_down = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a handler for drags that started on this div, receiving the
+    /// column and row relative to this div, clamped to its bounds
+    pub fn on_drag(mut self, handler: impl Fn(u16, u16) + 'static) -> Self {
+        self.events.on_drag = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a handler for drags that started on this div, receiving the
+    /// column and row relative to the parent div, clamped to its bounds, and
+    /// the parent's width and height. Suits handles that move within their
+    /// container, such as a splitter bar.
+    pub fn on_drag_in_parent(mut self, handler: impl Fn(u16, u16, u16, u16) + 'static) -> Self {
+        self.events.on_drag_in_parent = Some(Rc::new(handler));
+        self
+    }
+
     /// Registers a focus handler
     pub fn on_focus(mut self, handler: impl Fn() + 'static) -> Self {
         self.events.on_focus = Some(Rc::new(handler));
@@ -507,6 +725,10 @@ impl<T> Div<T> {
             focused: self.focused,
             hovered: self.hovered,
             component_path: self.component_path,
+            memo_key: self.memo_key,
+            key: self.key,
+            lazy: self.lazy,
+            lazy_path: self.lazy_path,
         }
     }
 
@@ -540,6 +762,8 @@ impl<T: PartialEq> PartialEq for Div<T> {
             && self.focused == other.focused
             && self.hovered == other.hovered
             && self.component_path == other.component_path
+            && self.memo_key == other.memo_key
+            && self.key == other.key
     }
 }
 
@@ -570,8 +794,12 @@ impl Debug for EventCallbacks {
             )
             .field("on_any_char", &self.on_any_char.is_some())
             .field("on_any_key", &self.on_any_key.is_some())
+            .field("on_paste", &self.on_paste.is_some())
             .field("on_focus", &self.on_focus.is_some())
             .field("on_blur", &self.on_blur.is_some())
+            .field("on_mouse_down", &self.on_mouse_down.is_some())
+            .field("on_drag", &self.on_drag.is_some())
+            .field("on_drag_in_parent", &self.on_drag_in_parent.is_some())
             .finish()
     }
 }
@@ -585,6 +813,8 @@ impl<T: Debug> Debug for Div<T> {
             .field("focusable", &self.focusable)
             .field("focused", &self.focused)
             .field("hovered", &self.hovered)
+            .field("memo_key", &self.memo_key)
+            .field("key", &self.key)
             .finish()
     }
 }
@@ -0,0 +1,155 @@
+use crate::node::{Div, Node};
+use crate::style::{BorderEdges, BorderStyle, Color, Dimension, Direction};
+use crate::vnode::VNode;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A horizontal or vertical separator line.
+///
+/// The line is drawn with the edge glyphs of a [`BorderStyle`] and stretches to
+/// fill its parent across the line's length:
+///
+/// ```rust,ignore
+/// Div::new()
+///     .direction(Direction::Vertical)
+///     .child(Text::new("Title").into())
+///     .child(Divider::horizontal().color(Color::BrightBlack).into())
+///     .child(Text::new("Body").into())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Divider {
+    /// Orientation of the line
+    pub direction: Direction,
+
+    /// Glyph set used to draw the line
+    pub style: BorderStyle,
+
+    /// Line color
+    pub color: Color,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Divider {
+    /// Creates a full-width `─` line, one row tall
+    pub fn horizontal() -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            style: BorderStyle::Single,
+            color: Color::White,
+        }
+    }
+
+    /// Creates a full-height `│` line, one column wide
+    pub fn vertical() -> Self {
+        Self {
+            direction: Direction::Vertical,
+            ..Self::horizontal()
+        }
+    }
+
+    /// Sets the line color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the glyph set used to draw the line
+    pub fn style(mut self, style: BorderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Builds the div that draws the line as a single border edge
+    pub fn into_div<T>(self) -> Div<T> {
+        let (edges, width, height) = match self.direction {
+            Direction::Horizontal => (
+                BorderEdges::TOP,
+                Dimension::Percentage(1.0),
+                Dimension::Fixed(1),
+            ),
+            Direction::Vertical => (
+                BorderEdges::LEFT,
+                Dimension::Fixed(1),
+                Dimension::Percentage(1.0),
+            ),
+        };
+
+        Div::new()
+            .border_style_with_color(self.style, self.color)
+            .border_edges(edges)
+            .width_dim(width)
+            .height_dim(height)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl From<Divider> for Node {
+    fn from(divider: Divider) -> Self {
+        Node::Div(divider.into_div())
+    }
+}
+
+impl From<Divider> for VNode {
+    fn from(divider: Divider) -> Self {
+        VNode::Div(divider.into_div())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_buffer;
+    use crate::node::Text;
+
+    #[test]
+    fn test_horizontal_divider_fills_parent_width() {
+        let node: Node = Div::new()
+            .direction(Direction::Vertical)
+            .width(12)
+            .height(3)
+            .child(Text::new("top").into())
+            .child(Divider::horizontal().color(Color::Blue).into())
+            .child(Text::new("bottom").into())
+            .into();
+        let buffer = render_to_buffer(node, 20, 5);
+
+        for x in 0..12 {
+            let cell = buffer.get_cell(x, 1).unwrap();
+            assert_eq!(cell.char, '─', "column {x}");
+            assert_eq!(cell.fg, Some(Color::Blue));
+        }
+        assert_eq!(buffer.get_cell(12, 1).unwrap().char, ' ');
+        assert_eq!(buffer.get_cell(0, 2).unwrap().char, 'b');
+    }
+
+    #[test]
+    fn test_vertical_divider_uses_border_style_glyph() {
+        let node: Node = Div::new()
+            .direction(Direction::Horizontal)
+            .width(5)
+            .height(3)
+            .child(Text::new("a").into())
+            .child(Divider::vertical().style(BorderStyle::Double).into())
+            .child(Text::new("b").into())
+            .into();
+        let buffer = render_to_buffer(node, 10, 4);
+
+        for y in 0..3 {
+            assert_eq!(buffer.get_cell(1, y).unwrap().char, '║', "row {y}");
+        }
+        assert_eq!(buffer.get_cell(1, 3).unwrap().char, ' ');
+        assert_eq!(buffer.get_cell(2, 0).unwrap().char, 'b');
+    }
+}
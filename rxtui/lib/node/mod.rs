@@ -2,12 +2,19 @@ use crate::component::Component;
 use std::sync::Arc;
 
 pub mod div;
+pub mod divider;
 pub mod rich_text;
+pub mod screen;
 pub mod text;
 
-pub use div::{Div, DivStyles, EventCallbacks, KeyHandler, KeyWithModifiersHandler};
+pub use div::{
+    Div, DivStyles, DragInParentHandler, EventCallbacks, KeyHandler, KeyWithModifiersHandler,
+    Propagation,
+};
+pub use divider::Divider;
 pub use rich_text::{RichText, TextSpan};
-pub use text::Text;
+pub use screen::Screen;
+pub use text::{Text, TextContent};
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -27,6 +34,10 @@ pub enum Node {
 
     /// Rich text with multiple styled segments
     RichText(RichText),
+
+    /// Sibling nodes grouped without a wrapper; they take the fragment's place
+    /// in the parent's children and the fragment gets no box of its own
+    Fragment(Vec<Node>),
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -51,6 +62,12 @@ impl Node {
     pub fn rich_text() -> Node {
         Node::RichText(RichText::new())
     }
+
+    /// Creates a fragment grouping the given nodes without a wrapper div.
+    #[inline]
+    pub fn fragment(children: Vec<Node>) -> Node {
+        Node::Fragment(children)
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -58,16 +75,34 @@ impl Node {
 //--------------------------------------------------------------------------------------------------
 
 impl Node {
-    /// Adds a single child (only valid for Div variant).
+    /// Adds a single child (only valid for Div and Fragment variants).
     #[inline]
     pub fn child(mut self, child: impl Into<Node>) -> Self {
-        if let Node::Div(ref mut div) = self {
-            div.children.push(child.into());
+        match self {
+            Node::Div(ref mut div) => div.children.push(child.into()),
+            Node::Fragment(ref mut children) => children.push(child.into()),
+            _ => {}
         }
         self
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Replaces each fragment in a list of children with its own children, at any depth.
+pub(crate) fn flatten_fragments(children: Vec<Node>) -> Vec<Node> {
+    let mut flat = Vec::with_capacity(children.len());
+    for child in children {
+        match child {
+            Node::Fragment(inner) => flat.extend(flatten_fragments(inner)),
+            child => flat.push(child),
+        }
+    }
+    flat
+}
+
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
@@ -79,6 +114,7 @@ impl Clone for Node {
             Node::Div(div) => Node::Div(div.clone()),
             Node::Text(text) => Node::Text(text.clone()),
             Node::RichText(rich) => Node::RichText(rich.clone()),
+            Node::Fragment(children) => Node::Fragment(children.clone()),
         }
     }
 }
@@ -90,6 +126,7 @@ impl std::fmt::Debug for Node {
             Node::Div(div) => write!(f, "Node::Div({div:?})"),
             Node::Text(text) => write!(f, "Node::Text({text:?})"),
             Node::RichText(rich) => write!(f, "Node::RichText({rich:?})"),
+            Node::Fragment(children) => write!(f, "Node::Fragment({children:?})"),
         }
     }
 }
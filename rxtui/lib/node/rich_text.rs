@@ -1,4 +1,5 @@
-use crate::style::{TextAlign, TextStyle};
+use crate::style::{Animation, TextAlign, TextStyle, VerticalAlign};
+use crate::utils::next_grapheme_boundary;
 use crate::{Color, TextWrap};
 
 //--------------------------------------------------------------------------------------------------
@@ -55,15 +56,16 @@ impl RichText {
 
         // Add cursor character or space at end
         if cursor_pos < char_count {
-            // Cursor on a character
+            // Cursor covers the whole grapheme cluster (e.g. a ZWJ emoji sequence)
+            let cursor_end = next_grapheme_boundary(text, cursor_pos);
             spans.push(TextSpan {
-                content: chars[cursor_pos].to_string(),
+                content: chars[cursor_pos..cursor_end].iter().collect(),
                 style: Some(cursor_style.clone()),
                 is_cursor: true, // Mark as cursor span
             });
             // Add text after cursor
-            if cursor_pos + 1 < char_count {
-                let after: String = chars[cursor_pos + 1..].iter().collect();
+            if cursor_end < char_count {
+                let after: String = chars[cursor_end..].iter().collect();
                 spans.push(TextSpan {
                     content: after,
                     style: None,
@@ -82,6 +84,70 @@ impl RichText {
         Self { spans, style: None }
     }
 
+    /// Creates RichText with the `start..end` char range highlighted
+    /// Used internally by TextInput component to show selections
+    pub fn with_selection(
+        text: &str,
+        start: usize,
+        end: usize,
+        selection_style: TextStyle,
+    ) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let end = end.min(chars.len());
+        let start = start.min(end);
+
+        let ranges = [
+            (&chars[..start], None),
+            (&chars[start..end], Some(selection_style)),
+            (&chars[end..], None),
+        ];
+        let spans = ranges
+            .into_iter()
+            .filter(|(range, _)| !range.is_empty())
+            .map(|(range, style)| TextSpan {
+                content: range.iter().collect(),
+                style,
+                is_cursor: false,
+            })
+            .collect();
+
+        Self { spans, style: None }
+    }
+
+    /// Creates RichText that highlights every case-insensitive match of `query`
+    /// with a `highlight_color` background, e.g. to mark search results
+    pub fn from_match(text: &str, query: &str, highlight_color: Color) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let query: Vec<char> = query.chars().collect();
+        let matches_at = |start: usize| {
+            chars[start..start + query.len()]
+                .iter()
+                .zip(&query)
+                .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+        };
+
+        let mut rich = Self::new();
+        let mut plain_start = 0;
+        let mut i = 0;
+        while !query.is_empty() && i + query.len() <= chars.len() {
+            if !matches_at(i) {
+                i += 1;
+                continue;
+            }
+            if plain_start < i {
+                rich = rich.text(chars[plain_start..i].iter().collect::<String>());
+            }
+            let end = i + query.len();
+            rich = rich.highlight(chars[i..end].iter().collect::<String>(), highlight_color);
+            plain_start = end;
+            i = end;
+        }
+        if plain_start < chars.len() || rich.spans.is_empty() {
+            rich = rich.text(chars[plain_start..].iter().collect::<String>());
+        }
+        rich
+    }
+
     /// Adds a plain text span
     pub fn text(mut self, content: impl Into<String>) -> Self {
         self.spans.push(TextSpan {
@@ -131,6 +197,33 @@ impl RichText {
         self
     }
 
+    /// Adds a span with a background color, e.g. to mark a search match
+    pub fn highlight(mut self, content: impl Into<String>, color: Color) -> Self {
+        self.spans.push(TextSpan {
+            content: content.into(),
+            style: Some(TextStyle {
+                background: Some(color),
+                ..Default::default()
+            }),
+            is_cursor: false,
+        });
+        self
+    }
+
+    /// Adds a span that links to `url`, shown as a clickable OSC 8 hyperlink in
+    /// terminals that support them and as plain text elsewhere
+    pub fn link(mut self, content: impl Into<String>, url: impl Into<String>) -> Self {
+        self.spans.push(TextSpan {
+            content: content.into(),
+            style: Some(TextStyle {
+                link: Some(url.into()),
+                ..Default::default()
+            }),
+            is_cursor: false,
+        });
+        self
+    }
+
     /// Adds a text span with custom style
     pub fn styled(mut self, content: impl Into<String>, style: TextStyle) -> Self {
         self.spans.push(TextSpan {
@@ -153,6 +246,20 @@ impl RichText {
         self
     }
 
+    /// Sets the vertical alignment within the container's free space
+    pub fn vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.style
+            .get_or_insert(TextStyle::default())
+            .vertical_align = Some(align);
+        self
+    }
+
+    /// Sets the number of columns between tab stops (default: 4)
+    pub fn tab_width(mut self, width: u8) -> Self {
+        self.style.get_or_insert(TextStyle::default()).tab_width = Some(width);
+        self
+    }
+
     /// Sets the color for all spans that don't already have a color
     pub fn color(mut self, color: Color) -> Self {
         for span in &mut self.spans {
@@ -175,6 +282,17 @@ impl RichText {
         self
     }
 
+    /// Animates all spans that don't already have an animation
+    pub fn animation(mut self, animation: Animation) -> Self {
+        for span in &mut self.spans {
+            let style = span.style.get_or_insert(TextStyle::default());
+            if style.animation.is_none() {
+                style.animation = Some(animation.clone());
+            }
+        }
+        self
+    }
+
     /// Makes all spans bold
     pub fn bold_all(mut self) -> Self {
         for span in &mut self.spans {
@@ -420,4 +538,41 @@ mod tests {
         assert!(rich.is_empty());
         assert_eq!(rich.content(), "");
     }
+
+    #[test]
+    fn test_from_match_alternates_plain_and_highlighted_spans() {
+        let rich = RichText::from_match(
+            "Find the needle, then NEEDLE again",
+            "needle",
+            Color::Yellow,
+        );
+        let highlighted = Some(TextStyle {
+            background: Some(Color::Yellow),
+            ..Default::default()
+        });
+
+        let spans: Vec<_> = rich
+            .spans
+            .iter()
+            .map(|span| (span.content.as_str(), span.style.clone()))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("Find the ", None),
+                ("needle", highlighted.clone()),
+                (", then ", None),
+                ("NEEDLE", highlighted),
+                (" again", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_match_without_match_is_single_plain_span() {
+        let rich = RichText::from_match("nothing to see", "needle", Color::Yellow);
+        assert_eq!(rich.spans.len(), 1);
+        assert_eq!(rich.spans[0].content, "nothing to see");
+        assert_eq!(rich.spans[0].style, None);
+    }
 }
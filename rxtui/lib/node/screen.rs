@@ -0,0 +1,132 @@
+use crate::node::{Div, Node};
+use crate::style::{Dimension, Direction};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Full-size layout with a fixed header and footer around the main content.
+///
+/// The header and footer keep their given heights and the content gets
+/// whatever space is left between them, so the bars stay in place as the
+/// terminal is resized:
+///
+/// ```rust,ignore
+/// Screen::new(node! { div [ text("body") ] })
+///     .header(1, node! { div(bg: blue) [ text("My App") ] })
+///     .footer(1, node! { div [ text("q: quit") ] })
+///     .into()
+/// ```
+#[derive(Debug)]
+pub struct Screen {
+    /// Top bar and its height in rows
+    header: Option<(u16, Node)>,
+
+    /// Main content, sized to the remaining space
+    content: Node,
+
+    /// Bottom bar and its height in rows
+    footer: Option<(u16, Node)>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Screen {
+    /// Creates a screen with only the main content
+    pub fn new(content: impl Into<Node>) -> Self {
+        Self {
+            header: None,
+            content: content.into(),
+            footer: None,
+        }
+    }
+
+    /// Reserves `height` rows at the top for the header
+    pub fn header(mut self, height: u16, header: impl Into<Node>) -> Self {
+        self.header = Some((height, header.into()));
+        self
+    }
+
+    /// Reserves `height` rows at the bottom for the footer
+    pub fn footer(mut self, height: u16, footer: impl Into<Node>) -> Self {
+        self.footer = Some((height, footer.into()));
+        self
+    }
+
+    /// Builds the vertical div holding the header, content and footer regions
+    pub fn into_div(self) -> Div<Node> {
+        let region = |height: Dimension, node: Node| -> Node {
+            Div::new()
+                .width_dim(Dimension::Percentage(1.0))
+                .height_dim(height)
+                .child(node)
+                .into()
+        };
+
+        let mut div = Div::new()
+            .direction(Direction::Vertical)
+            .width_dim(Dimension::Percentage(1.0))
+            .height_dim(Dimension::Percentage(1.0));
+        if let Some((height, header)) = self.header {
+            div = div.child(region(Dimension::Fixed(height), header));
+        }
+        div = div.child(region(Dimension::Auto, self.content));
+        if let Some((height, footer)) = self.footer {
+            div = div.child(region(Dimension::Fixed(height), footer));
+        }
+        div
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl From<Screen> for Node {
+    fn from(screen: Screen) -> Self {
+        Node::Div(screen.into_div())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{layout_node, render_to_buffer};
+    use crate::node::Text;
+
+    fn region_heights(screen: Screen, width: u16, height: u16) -> Vec<u16> {
+        let vdom = layout_node(screen.into(), width, height);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        let root = root.borrow();
+        root.children.iter().map(|c| c.borrow().height).collect()
+    }
+
+    #[test]
+    fn test_content_gets_space_between_header_and_footer() {
+        let screen = Screen::new(Text::new("body"))
+            .header(2, Text::new("title"))
+            .footer(1, Text::new("status"));
+        assert_eq!(region_heights(screen, 20, 10), [2, 7, 1]);
+
+        let screen = Screen::new(Text::new("body")).footer(3, Text::new("status"));
+        assert_eq!(region_heights(screen, 20, 10), [7, 3]);
+    }
+
+    #[test]
+    fn test_footer_is_pinned_to_the_bottom_row() {
+        let screen = Screen::new(Text::new("body"))
+            .header(1, Text::new("title"))
+            .footer(1, Text::new("status"));
+        let buffer = render_to_buffer(screen.into(), 10, 6);
+
+        assert_eq!(buffer.get_cell(0, 0).unwrap().char, 't');
+        assert_eq!(buffer.get_cell(0, 1).unwrap().char, 'b');
+        assert_eq!(buffer.get_cell(0, 5).unwrap().char, 's');
+    }
+}
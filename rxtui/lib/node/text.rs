@@ -1,5 +1,9 @@
-use crate::style::{TextAlign, TextStyle};
+use crate::style::{Animation, TextAlign, TextStyle, VerticalAlign};
 use crate::{Color, TextWrap};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -8,10 +12,27 @@ use crate::{Color, TextWrap};
 /// Text content with styling
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Text {
-    pub content: String,
+    pub content: TextContent,
     pub style: Option<TextStyle>,
 }
 
+/// String content of a [`Text`] node.
+///
+/// Static and shared content is cloned without copying the string, and the
+/// diff treats two nodes pointing at the same string as unchanged without
+/// comparing them character by character.
+#[derive(Clone)]
+pub enum TextContent {
+    /// A string literal or other `'static` string
+    Static(&'static str),
+
+    /// A reference-counted string shared between nodes
+    Shared(Arc<str>),
+
+    /// An owned string
+    Owned(String),
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -20,11 +41,32 @@ impl Text {
     /// Creates a new Text with the given content
     pub fn new(content: impl Into<String>) -> Self {
         Self {
-            content: content.into(),
+            content: TextContent::Owned(content.into()),
+            style: None,
+        }
+    }
+
+    /// Creates a Text from a `'static` string without allocating
+    pub const fn from_static(content: &'static str) -> Self {
+        Self {
+            content: TextContent::Static(content),
+            style: None,
+        }
+    }
+
+    /// Creates a Text that shares `content` with other nodes instead of copying it
+    pub fn shared(content: impl Into<Arc<str>>) -> Self {
+        Self {
+            content: TextContent::Shared(content.into()),
             style: None,
         }
     }
 
+    /// Returns the text as a string slice
+    pub fn content(&self) -> &str {
+        self.content.as_str()
+    }
+
     /// Sets the text color
     pub fn color(mut self, color: Color) -> Self {
         self.style.get_or_insert(TextStyle::default()).color = Some(color);
@@ -72,12 +114,158 @@ impl Text {
         self.style.get_or_insert(TextStyle::default()).align = Some(align);
         self
     }
+
+    /// Sets the vertical alignment within the container's free space
+    pub fn vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.style
+            .get_or_insert(TextStyle::default())
+            .vertical_align = Some(align);
+        self
+    }
+
+    /// Sets the number of columns between tab stops (default: 4)
+    pub fn tab_width(mut self, width: u8) -> Self {
+        self.style.get_or_insert(TextStyle::default()).tab_width = Some(width);
+        self
+    }
+
+    /// Animates the text with the app's frame counter, e.g. to make it blink
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.style.get_or_insert(TextStyle::default()).animation = Some(animation);
+        self
+    }
+}
+
+impl TextContent {
+    /// Returns the content as a string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            TextContent::Static(content) => content,
+            TextContent::Shared(content) => content,
+            TextContent::Owned(content) => content,
+        }
+    }
+
+    /// Returns the content as a mutable `String`, copying static or shared
+    /// content into an owned string first
+    pub fn to_mut(&mut self) -> &mut String {
+        if !matches!(self, TextContent::Owned(_)) {
+            *self = TextContent::Owned(self.as_str().to_owned());
+        }
+        match self {
+            TextContent::Owned(content) => content,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns true if both point at the same static or shared string.
+    ///
+    /// This never compares characters, so owned content is never the same.
+    pub fn ptr_eq(&self, other: &TextContent) -> bool {
+        match (self, other) {
+            (TextContent::Static(a), TextContent::Static(b)) => std::ptr::eq(*a, *b),
+            (TextContent::Shared(a), TextContent::Shared(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
 
+impl Default for TextContent {
+    fn default() -> Self {
+        TextContent::Static("")
+    }
+}
+
+impl Deref for TextContent {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for TextContent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for TextContent {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<str> for TextContent {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<String> for TextContent {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TextContent {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for TextContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for TextContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for TextContent {
+    fn from(content: String) -> Self {
+        TextContent::Owned(content)
+    }
+}
+
+impl From<&'static str> for TextContent {
+    fn from(content: &'static str) -> Self {
+        TextContent::Static(content)
+    }
+}
+
+impl From<Arc<str>> for TextContent {
+    fn from(content: Arc<str>) -> Self {
+        TextContent::Shared(content)
+    }
+}
+
+impl From<Cow<'static, str>> for TextContent {
+    fn from(content: Cow<'static, str>) -> Self {
+        match content {
+            Cow::Borrowed(content) => TextContent::Static(content),
+            Cow::Owned(content) => TextContent::Owned(content),
+        }
+    }
+}
+
+impl From<TextContent> for String {
+    fn from(content: TextContent) -> Self {
+        match content {
+            TextContent::Owned(content) => content,
+            content => content.as_str().to_owned(),
+        }
+    }
+}
+
 impl From<String> for Text {
     fn from(content: String) -> Self {
         Self::new(content)
@@ -89,3 +277,49 @@ impl From<&str> for Text {
         Self::new(content)
     }
 }
+
+impl From<Arc<str>> for Text {
+    fn from(content: Arc<str>) -> Self {
+        Self::shared(content)
+    }
+}
+
+impl From<Cow<'static, str>> for Text {
+    fn from(content: Cow<'static, str>) -> Self {
+        Self {
+            content: content.into(),
+            style: None,
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_converts_to_and_from_string() {
+        let mut text = Text::from_static("draft");
+        assert_eq!(text.content(), "draft");
+        assert_eq!(text.content, "draft".to_string());
+
+        text.content = format!("{} 2", text.content).into();
+        let content: String = text.content.clone().into();
+        assert_eq!(content, "draft 2");
+    }
+
+    #[test]
+    fn test_to_mut_copies_shared_content_before_editing() {
+        let shared: Arc<str> = Arc::from("saved");
+        let mut text = Text::shared(shared.clone());
+        text.content.to_mut().push('!');
+
+        assert_eq!(text.content(), "saved!");
+        assert_eq!(&*shared, "saved");
+        assert!(matches!(text.content, TextContent::Owned(_)));
+    }
+}
@@ -9,7 +9,7 @@
 //! ```
 
 // Core app types
-pub use crate::app::{App, Context};
+pub use crate::app::{App, Context, ToastKind, measure};
 
 // Component system
 pub use crate::component::{Action, Message, MessageExt, State};
@@ -27,7 +27,7 @@ pub use crate::effect;
 pub use crate::{component, update, view};
 
 // UI elements
-pub use crate::node::{Div, Node, RichText, Text};
+pub use crate::node::{Div, Divider, Node, RichText, Text};
 
 // Components
 pub use crate::components::TextInput;
@@ -35,8 +35,11 @@ pub use crate::components::TextInput;
 // Style types
 pub use crate::style::*;
 
+pub use crate::theme::{Theme, ThemeMode};
+
 // Key handling
-pub use crate::key::{Key, KeyWithModifiers};
+pub use crate::key::{Key, KeyWithModifiers, MediaKey};
+pub use crate::keymap::KeyMap;
 
 // Layout types
 pub use crate::bounds::Rect;
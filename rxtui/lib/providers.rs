@@ -57,6 +57,22 @@ pub trait EffectsProvider {
     }
 }
 
+/// Internal trait for the Component macro system to handle optional lifecycle hooks.
+///
+/// DO NOT implement or use this trait directly - it's automatically handled by the macro system.
+/// This uses Rust's method resolution order where inherent methods shadow trait methods,
+/// allowing #[component] to forward to `on_mount` and `on_unmount` methods when defined.
+#[doc(hidden)]
+pub trait LifecycleProvider {
+    /// Internal method that does nothing by default.
+    /// This is shadowed by an inherent method when #[component] finds `on_mount`.
+    fn __component_on_mount_impl(&self, _ctx: &Context) {}
+
+    /// Internal method that does nothing by default.
+    /// This is shadowed by an inherent method when #[component] finds `on_unmount`.
+    fn __component_on_unmount_impl(&self, _ctx: &Context) {}
+}
+
 //--------------------------------------------------------------------------------------------------
 // Blanket Implementations
 //--------------------------------------------------------------------------------------------------
@@ -65,3 +81,4 @@ pub trait EffectsProvider {
 impl<T> UpdateProvider for T {}
 impl<T> ViewProvider for T {}
 impl<T> EffectsProvider for T {}
+impl<T> LifecycleProvider for T {}
@@ -26,7 +26,8 @@
 mod node;
 mod tree;
 
-pub use node::{RenderNode, RenderNodeType};
+pub(crate) use node::LayoutMode;
+pub use node::{RenderNode, RenderNodeType, ScrollbarRegion};
 pub use tree::RenderTree;
 
 #[cfg(test)]
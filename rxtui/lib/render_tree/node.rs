@@ -1,13 +1,13 @@
 use crate::bounds::Rect;
 use crate::component::ComponentId;
 use crate::key::Key;
-use crate::node::{DivStyles, EventCallbacks, TextSpan};
+use crate::node::{DivStyles, EventCallbacks, Propagation, TextContent, TextSpan};
 use crate::style::{
-    AlignItems, AlignSelf, Color, Dimension, Direction, JustifyContent, Overflow, Position,
-    Spacing, Style, TextStyle, TextWrap,
+    AlignItems, AlignSelf, Border, Color, Dimension, Direction, Grid, JustifyContent, Overflow,
+    Position, Spacing, Style, TextStyle, TextWrap, VerticalAlign,
 };
-use crate::utils::{display_width, wrap_text};
-use std::cell::RefCell;
+use crate::utils::{display_width, wrap_text_cached};
+use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 
 //--------------------------------------------------------------------------------------------------
@@ -57,6 +57,9 @@ pub struct RenderNode {
     /// Full text style (only used for text nodes)
     pub text_style: Option<TextStyle>,
 
+    /// Content the text node was built from, before tab expansion
+    pub text_source: Option<TextContent>,
+
     /// Child nodes to render inside this node
     pub children: Vec<Rc<RefCell<RenderNode>>>,
 
@@ -101,6 +104,81 @@ pub struct RenderNode {
 
     /// Component path that produced this node (used for focus targeting)
     pub component_path: Option<ComponentId>,
+
+    /// Memoization key copied from the div; unchanged keys skip diffing the subtree
+    pub memo_key: Option<u64>,
+
+    /// Key copied from the div, matching it to its previous render among siblings
+    pub key: Option<u64>,
+
+    /// Tree path of a lazy div, used to track whether it is on screen
+    pub lazy_path: Option<ComponentId>,
+
+    /// Layout of a memoized subtree from the last pass, reused while its inputs match
+    pub memo_layout: Option<MemoLayout>,
+
+    /// Screen area of the scrollbar as last drawn, used to hit-test the thumb
+    pub scrollbar: Cell<Option<ScrollbarRegion>>,
+}
+
+/// Screen area of a drawn vertical scrollbar, recorded by the renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollbarRegion {
+    /// Column the scrollbar is drawn in
+    pub x: u16,
+
+    /// Row of the top of the track
+    pub y: u16,
+
+    /// Height of the track
+    pub height: u16,
+
+    /// Row of the top of the thumb
+    pub thumb_y: u16,
+
+    /// Height of the thumb
+    pub thumb_height: u16,
+}
+
+/// Inputs and results of a memoized node's last layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoLayout {
+    /// Parent content size the layout was resolved against
+    pub parent_size: (u16, u16),
+
+    /// Size assigned by the parent before layout
+    pub assigned_size: (u16, u16),
+
+    /// Position the subtree was laid out at
+    pub position: (u16, u16),
+
+    /// Size the node resolved to
+    pub size: (u16, u16),
+}
+
+/// How child subtrees are laid out once their sizes and positions are set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum LayoutMode {
+    /// One subtree after another on the calling thread
+    #[default]
+    Serial,
+
+    /// Sibling subtrees concurrently on the rayon thread pool, given the
+    /// `parallel` feature; serial without it
+    Parallel,
+}
+
+/// A child subtree handed to another thread during parallel layout.
+#[cfg(feature = "parallel")]
+struct Subtree<'a>(&'a RefCell<RenderNode>);
+
+/// Cells a child covers in its parent's grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GridArea {
+    column: usize,
+    row: usize,
+    columns: usize,
+    rows: usize,
 }
 
 /// Types of nodes that can be rendered.
@@ -126,6 +204,40 @@ pub enum RenderNodeType {
 // Helper Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Lays out the subtrees of `children`, each job giving a child's index and the
+/// parent size its percentages resolve against.
+///
+/// Each child already has its size and position, and layout only reaches into
+/// its own subtree, so the jobs don't depend on each other and in
+/// [`LayoutMode::Parallel`] run concurrently.
+fn layout_subtrees(
+    children: &[Rc<RefCell<RenderNode>>],
+    jobs: &[(usize, u16, u16)],
+    mode: LayoutMode,
+) {
+    #[cfg(feature = "parallel")]
+    if mode == LayoutMode::Parallel && jobs.len() > 1 {
+        use rayon::prelude::*;
+
+        let subtrees: Vec<_> = jobs
+            .iter()
+            .map(|&(index, width, height)| (Subtree(&children[index]), width, height))
+            .collect();
+        subtrees
+            .into_par_iter()
+            .for_each(|(subtree, width, height)| {
+                subtree.0.borrow_mut().layout_with_mode(width, height, mode);
+            });
+        return;
+    }
+
+    for &(index, width, height) in jobs {
+        children[index]
+            .borrow_mut()
+            .layout_with_mode(width, height, mode);
+    }
+}
+
 /// Calculate offset and item spacing based on JustifyContent mode
 fn calculate_justify_offsets(
     justify: JustifyContent,
@@ -164,6 +276,119 @@ fn calculate_justify_offsets(
     }
 }
 
+/// Places children with the given `(columns, rows)` spans into a grid of
+/// `column_count` columns, in order and skipping cells already taken.
+///
+/// Spans wider than the grid are narrowed to fit, and rows are added as needed.
+fn place_grid_areas(spans: &[(u16, u16)], column_count: usize) -> Vec<GridArea> {
+    let column_count = column_count.max(1);
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let (mut row, mut column) = (0, 0);
+
+    spans
+        .iter()
+        .map(|&(columns, rows)| {
+            let columns = (columns.max(1) as usize).min(column_count);
+            let rows = rows.max(1) as usize;
+
+            // Advance the cursor to the first spot where the whole span is free
+            loop {
+                if column + columns > column_count {
+                    (row, column) = (row + 1, 0);
+                    continue;
+                }
+                let free = (row..row + rows).all(|r| {
+                    (column..column + columns)
+                        .all(|c| !occupied.get(r).is_some_and(|cells| cells[c]))
+                });
+                if free {
+                    break;
+                }
+                column += 1;
+            }
+
+            if occupied.len() < row + rows {
+                occupied.resize(row + rows, vec![false; column_count]);
+            }
+            for cells in &mut occupied[row..row + rows] {
+                cells[column..column + columns].fill(true);
+            }
+
+            let area = GridArea {
+                column,
+                row,
+                columns,
+                rows,
+            };
+            column += columns;
+            area
+        })
+        .collect()
+}
+
+/// Resolves the sizes of `count` grid tracks.
+///
+/// `content` holds the size each track needs for its single-track children, and
+/// tracks past the end of `tracks` are sized to that content. Without an
+/// `available` length only fixed tracks keep their size and the rest fit their
+/// content; with one, auto tracks also share whatever space remains.
+fn resolve_grid_tracks(
+    tracks: &[Dimension],
+    content: &[u16],
+    available: Option<u16>,
+    gap: u16,
+) -> Vec<u16> {
+    let track = |index: usize| tracks.get(index).copied().unwrap_or(Dimension::Content);
+    let mut sizes: Vec<u16> = (0..content.len())
+        .map(|index| match (track(index), available) {
+            (Dimension::Fixed(size), _) => size,
+            (Dimension::Percentage(pct), Some(available)) => (available as f32 * pct) as u16,
+            _ => content[index],
+        })
+        .collect();
+
+    if let Some(available) = available {
+        let auto: Vec<usize> = (0..content.len())
+            .filter(|&index| track(index) == Dimension::Auto)
+            .collect();
+        if !auto.is_empty() {
+            let gaps = gap.saturating_mul(content.len().saturating_sub(1) as u16);
+            let used: u16 = (0..content.len())
+                .filter(|index| !auto.contains(index))
+                .map(|index| sizes[index])
+                .fold(gaps, u16::saturating_add);
+            let remaining = available.saturating_sub(used);
+            let share = remaining / auto.len() as u16;
+            let extra = (remaining % auto.len() as u16) as usize;
+            for (i, &index) in auto.iter().enumerate() {
+                let share = share + u16::from(i < extra);
+                sizes[index] = sizes[index].max(share);
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the length covered by `count` tracks starting at `start`, including
+/// the gaps between them.
+fn grid_span_length(sizes: &[u16], start: usize, count: usize, gap: u16) -> u16 {
+    sizes[start..start + count]
+        .iter()
+        .fold(gap.saturating_mul(count as u16 - 1), |total, &size| {
+            total.saturating_add(size)
+        })
+}
+
+/// Returns the offset of track `index` from the start of the grid.
+fn grid_track_offset(sizes: &[u16], index: usize, gap: u16) -> u16 {
+    sizes[..index]
+        .iter()
+        .fold(gap.saturating_mul(index as u16), |total, &size| {
+            total.saturating_add(size)
+        })
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -182,6 +407,7 @@ impl RenderNode {
             style: None,
             text_color: None,
             text_style: None,
+            text_source: None,
             children: Vec::new(),
             parent: None,
             styles: DivStyles::default(),
@@ -197,6 +423,11 @@ impl RenderNode {
             content_height: 0,
             scrollable: false,
             component_path: None,
+            memo_key: None,
+            key: None,
+            lazy_path: None,
+            memo_layout: None,
+            scrollbar: Cell::new(None),
         }
     }
 
@@ -350,6 +581,47 @@ impl RenderNode {
         self.scroll_y = y.min(max_scroll_y);
     }
 
+    /// Returns the resolved outer margin of this node.
+    pub fn margin(&self) -> Spacing {
+        self.style
+            .as_ref()
+            .and_then(|s| s.margin)
+            .unwrap_or(Spacing::all(0))
+    }
+
+    /// Returns the space between this node's edges and its content box: the
+    /// padding plus a cell for each enabled border edge.
+    pub fn content_insets(&self) -> Spacing {
+        let style = self.style.as_ref();
+        let padding = style.and_then(|s| s.padding).unwrap_or(Spacing::all(0));
+        let border = style
+            .and_then(|s| s.border.as_ref())
+            .map_or(Spacing::all(0), Border::insets);
+        Spacing::new(
+            padding.top + border.top,
+            padding.right + border.right,
+            padding.bottom + border.bottom,
+            padding.left + border.left,
+        )
+    }
+
+    /// Returns the vertical alignment of a text node, if set.
+    fn vertical_align(&self) -> Option<VerticalAlign> {
+        self.text_style.as_ref().and_then(|s| s.vertical_align)
+    }
+
+    /// Resolves percentage padding and margin against the parent's content box.
+    fn resolve_percent_spacing(&mut self, parent_width: u16, parent_height: u16) {
+        if let Some(style) = &mut self.style {
+            if let Some(padding) = style.padding_percent {
+                style.padding = Some(padding.resolve(parent_width, parent_height));
+            }
+            if let Some(margin) = style.margin_percent {
+                style.margin = Some(margin.resolve(parent_width, parent_height));
+            }
+        }
+    }
+
     /// Returns the maximum scrollable range for vertical axis.
     pub fn get_max_scroll_y(&self) -> u16 {
         self.content_height.saturating_sub(self.height)
@@ -369,12 +641,12 @@ impl RenderNode {
         max_passes: usize,
         hint: Option<(u16, u16)>,
     ) -> (u16, u16) {
-        let mut size = self.calculate_intrinsic_size_single_pass(hint);
+        let mut size = self.constrain_size(self.calculate_intrinsic_size_single_pass(hint));
         let mut prev_size = size;
 
         for _pass in 1..max_passes {
             // Use previous size as hint for next pass
-            size = self.calculate_intrinsic_size_single_pass(Some(prev_size));
+            size = self.constrain_size(self.calculate_intrinsic_size_single_pass(Some(prev_size)));
 
             // Check for convergence
             if size == prev_size {
@@ -386,6 +658,24 @@ impl RenderNode {
         size
     }
 
+    /// Clamps a size to the style's min/max width and height.
+    ///
+    /// Max wins over min when they conflict. A content-sized container with a
+    /// max height stops growing at the cap and scrolls the rest of its content.
+    pub fn constrain_size(&self, (width, height): (u16, u16)) -> (u16, u16) {
+        let Some(style) = &self.style else {
+            return (width, height);
+        };
+        let clamp = |value: u16, min: Option<u16>, max: Option<u16>| {
+            let value = min.map_or(value, |min| value.max(min));
+            max.map_or(value, |max| value.min(max))
+        };
+        (
+            clamp(width, style.min_width, style.max_width),
+            clamp(height, style.min_height, style.max_height),
+        )
+    }
+
     /// Single pass of intrinsic size calculation.
     /// Uses hint for resolving percentages and simulating wrapping.
     fn calculate_intrinsic_size_single_pass(&self, hint: Option<(u16, u16)>) -> (u16, u16) {
@@ -413,7 +703,7 @@ impl RenderNode {
 
                     if let Some(width) = wrap_width {
                         // Apply wrapping at the determined width to get accurate height
-                        let wrapped_lines = wrap_text(text, width, wrap_mode);
+                        let wrapped_lines = wrap_text_cached(text, width, wrap_mode);
                         let height = wrapped_lines.len() as u16;
                         let actual_width = wrapped_lines
                             .iter()
@@ -465,20 +755,20 @@ impl RenderNode {
                 let direction = style
                     .and_then(|s| s.direction)
                     .unwrap_or(Direction::Vertical);
-                let padding = style.and_then(|s| s.padding).unwrap_or(Spacing::all(0));
-                let border_size = if style
-                    .and_then(|s| s.border.as_ref())
-                    .is_some_and(|b| b.enabled)
-                {
-                    2 // 1 cell on each side
-                } else {
-                    0
-                };
+                let insets = self.content_insets();
 
                 // Check for wrapping mode and constraints
                 let wrap_mode = style.and_then(|s| s.wrap);
                 let gap = style.and_then(|s| s.gap).unwrap_or(0);
 
+                if let Some(grid) = style.and_then(|s| s.grid.as_ref()) {
+                    let (width, height) = self.calculate_grid_intrinsic_size(grid, gap);
+                    return (
+                        width.saturating_add(insets.left + insets.right),
+                        height.saturating_add(insets.top + insets.bottom),
+                    );
+                }
+
                 // Check if we should simulate wrapping
                 let should_wrap = if let Some(crate::style::WrapMode::Wrap) = wrap_mode {
                     match direction {
@@ -501,16 +791,10 @@ impl RenderNode {
 
                 if should_wrap {
                     // Simulate wrapping layout to calculate intrinsic size
-                    self.calculate_wrapped_intrinsic_size(
-                        direction,
-                        padding,
-                        border_size,
-                        gap,
-                        hint,
-                    )
+                    self.calculate_wrapped_intrinsic_size(direction, insets, gap, hint)
                 } else {
                     // Standard layout calculation (no wrapping)
-                    self.calculate_standard_intrinsic_size(direction, padding, border_size, hint)
+                    self.calculate_standard_intrinsic_size(direction, insets, hint)
                 }
             }
         }
@@ -520,8 +804,7 @@ impl RenderNode {
     fn calculate_standard_intrinsic_size(
         &self,
         direction: Direction,
-        padding: Spacing,
-        border_size: u16,
+        insets: Spacing,
         hint: Option<(u16, u16)>,
     ) -> (u16, u16) {
         let mut total_width = 0u16;
@@ -534,22 +817,18 @@ impl RenderNode {
             match (style.width, style.height) {
                 (Some(Dimension::Fixed(w)), Some(Dimension::Fixed(h))) => {
                     // Both dimensions fixed: pass content area as hint
-                    let content_width =
-                        w.saturating_sub(padding.left + padding.right + border_size);
-                    let content_height =
-                        h.saturating_sub(padding.top + padding.bottom + border_size);
+                    let content_width = w.saturating_sub(insets.left + insets.right);
+                    let content_height = h.saturating_sub(insets.top + insets.bottom);
                     Some((content_width, content_height))
                 }
                 (Some(Dimension::Fixed(w)), _) => {
                     // Width fixed: pass content width, keep height from original hint
-                    let content_width =
-                        w.saturating_sub(padding.left + padding.right + border_size);
+                    let content_width = w.saturating_sub(insets.left + insets.right);
                     Some((content_width, hint.map(|(_, h)| h).unwrap_or(0)))
                 }
                 (_, Some(Dimension::Fixed(h))) => {
                     // Height fixed: pass content height, keep width from original hint
-                    let content_height =
-                        h.saturating_sub(padding.top + padding.bottom + border_size);
+                    let content_height = h.saturating_sub(insets.top + insets.bottom);
                     Some((hint.map(|(w, _)| w).unwrap_or(0), content_height))
                 }
                 _ => hint, // No fixed dimensions, pass hint through
@@ -588,7 +867,14 @@ impl RenderNode {
                     }
                 }
 
-                (width, height)
+                let (width, height) = child_ref.constrain_size((width, height));
+
+                // Margins take up space around the child
+                let margin = child_ref.margin();
+                (
+                    width.saturating_add(margin.left + margin.right),
+                    height.saturating_add(margin.top + margin.bottom),
+                )
             };
 
             match direction {
@@ -613,13 +899,9 @@ impl RenderNode {
             Direction::Vertical => total_height,
         };
 
-        let final_width = content_width
-            .saturating_add(padding.left + padding.right)
-            .saturating_add(border_size);
+        let final_width = content_width.saturating_add(insets.left + insets.right);
 
-        let final_height = content_height
-            .saturating_add(padding.top + padding.bottom)
-            .saturating_add(border_size);
+        let final_height = content_height.saturating_add(insets.top + insets.bottom);
 
         (final_width, final_height)
     }
@@ -628,8 +910,7 @@ impl RenderNode {
     fn calculate_wrapped_intrinsic_size(
         &self,
         direction: Direction,
-        padding: Spacing,
-        border_size: u16,
+        insets: Spacing,
         gap: u16,
         hint: Option<(u16, u16)>,
     ) -> (u16, u16) {
@@ -638,7 +919,7 @@ impl RenderNode {
             Direction::Horizontal => {
                 // For horizontal wrap, we need fixed width
                 if let Some(Dimension::Fixed(w)) = self.style.as_ref().and_then(|s| s.width) {
-                    w.saturating_sub(padding.left + padding.right + border_size)
+                    w.saturating_sub(insets.left + insets.right)
                 } else {
                     // Shouldn't happen due to should_wrap check, but fallback to hint or large value
                     hint.map(|(w, _)| w).unwrap_or(u16::MAX)
@@ -647,7 +928,7 @@ impl RenderNode {
             Direction::Vertical => {
                 // For vertical wrap, we need fixed height
                 if let Some(Dimension::Fixed(h)) = self.style.as_ref().and_then(|s| s.height) {
-                    h.saturating_sub(padding.top + padding.bottom + border_size)
+                    h.saturating_sub(insets.top + insets.bottom)
                 } else {
                     // Shouldn't happen due to should_wrap check, but fallback to hint or large value
                     hint.map(|(_, h)| h).unwrap_or(u16::MAX)
@@ -734,13 +1015,9 @@ impl RenderNode {
                     .sum::<u16>()
                     + (rows.len().saturating_sub(1) as u16 * gap);
 
-                let final_width = total_width
-                    .saturating_add(padding.left + padding.right)
-                    .saturating_add(border_size);
+                let final_width = total_width.saturating_add(insets.left + insets.right);
 
-                let final_height = total_height
-                    .saturating_add(padding.top + padding.bottom)
-                    .saturating_add(border_size);
+                let final_height = total_height.saturating_add(insets.top + insets.bottom);
 
                 (final_width, final_height)
             }
@@ -778,13 +1055,9 @@ impl RenderNode {
                     + (columns.len().saturating_sub(1) as u16 * gap);
                 let total_height = constraint; // Height is fixed
 
-                let final_width = total_width
-                    .saturating_add(padding.left + padding.right)
-                    .saturating_add(border_size);
+                let final_width = total_width.saturating_add(insets.left + insets.right);
 
-                let final_height = total_height
-                    .saturating_add(padding.top + padding.bottom)
-                    .saturating_add(border_size);
+                let final_height = total_height.saturating_add(insets.top + insets.bottom);
 
                 (final_width, final_height)
             }
@@ -803,7 +1076,7 @@ impl RenderNode {
                     && available_width > 0
                 {
                     // Apply wrapping
-                    let wrapped_lines = wrap_text(text, available_width, wrap_mode);
+                    let wrapped_lines = wrap_text_cached(text, available_width, wrap_mode);
 
                     // Update node type and dimensions
                     self.node_type = RenderNodeType::TextWrapped(wrapped_lines.clone());
@@ -837,13 +1110,23 @@ impl RenderNode {
                         .collect();
 
                     // Apply wrapping to the full text
-                    let wrapped_lines = wrap_text(&full_text, available_width, wrap_mode);
+                    let wrapped_lines = wrap_text_cached(&full_text, available_width, wrap_mode);
+                    let multiline = wrapped_lines.len() > 1;
+
+                    let full_chars: Vec<char> = full_text.chars().collect();
 
                     // Build wrapped lines with correct span information
                     let mut wrapped_styled_lines = Vec::new();
                     let mut char_offset = 0;
 
                     for line in wrapped_lines {
+                        // Explicit line breaks end a line without showing up in it
+                        match full_chars.get(char_offset..char_offset + 2) {
+                            Some(['\r', '\n']) => char_offset += 2,
+                            _ if full_chars.get(char_offset) == Some(&'\n') => char_offset += 1,
+                            _ => {}
+                        }
+
                         let mut line_spans = Vec::new();
                         let mut current_span_idx = None;
                         let mut current_content = String::new();
@@ -852,6 +1135,14 @@ impl RenderNode {
 
                         // Process each character in the line
                         for ch in line.chars() {
+                            // Hyphens added by hyphenation stay with the span they break
+                            if ch == '-'
+                                && full_chars.get(char_offset) != Some(&'-')
+                                && !current_content.is_empty()
+                            {
+                                current_content.push(ch);
+                                continue;
+                            }
                             if char_offset < char_to_span.len() {
                                 let (span_idx, style, is_cursor) = &char_to_span[char_offset];
 
@@ -889,7 +1180,8 @@ impl RenderNode {
                             });
                         }
 
-                        if !line_spans.is_empty() {
+                        // Blank lines between explicit line breaks are kept
+                        if !line_spans.is_empty() || multiline {
                             wrapped_styled_lines.push(line_spans);
                         }
                     }
@@ -934,11 +1226,81 @@ impl RenderNode {
     ///
     /// This method resolves percentage-based dimensions before laying out children.
     pub fn layout_with_parent(&mut self, parent_width: u16, parent_height: u16) {
+        self.layout_with_mode(parent_width, parent_height, LayoutMode::default());
+    }
+
+    /// Lays out this node against its parent size, its child subtrees as `mode` says.
+    pub(crate) fn layout_with_mode(
+        &mut self,
+        parent_width: u16,
+        parent_height: u16,
+        mode: LayoutMode,
+    ) {
+        if self.memo_key.is_none() {
+            self.layout_unmemoized(parent_width, parent_height, mode);
+            return;
+        }
+
+        // Memoized subtrees keep their layout while the parent constraints are unchanged,
+        // only moving along with the node when siblings shift it
+        let parent_size = (parent_width, parent_height);
+        let assigned_size = (self.width, self.height);
+        if let Some(memo) = self.memo_layout
+            && memo.parent_size == parent_size
+            && memo.assigned_size == assigned_size
+        {
+            let dx = self.x as i32 - memo.position.0 as i32;
+            let dy = self.y as i32 - memo.position.1 as i32;
+            (self.width, self.height) = memo.size;
+            if dx != 0 || dy != 0 {
+                self.translate_children(dx, dy);
+                self.memo_layout = Some(MemoLayout {
+                    position: (self.x, self.y),
+                    ..memo
+                });
+            }
+            return;
+        }
+
+        self.layout_unmemoized(parent_width, parent_height, mode);
+        self.memo_layout = Some(MemoLayout {
+            parent_size,
+            assigned_size,
+            position: (self.x, self.y),
+            size: (self.width, self.height),
+        });
+    }
+
+    /// Moves every descendant by the given offset without laying it out again.
+    fn translate_children(&mut self, dx: i32, dy: i32) {
+        for child in &self.children {
+            let mut child_ref = child.borrow_mut();
+            child_ref.x = (child_ref.x as i32 + dx).clamp(0, u16::MAX as i32) as u16;
+            child_ref.y = (child_ref.y as i32 + dy).clamp(0, u16::MAX as i32) as u16;
+            let position = (child_ref.x, child_ref.y);
+            if let Some(memo) = &mut child_ref.memo_layout {
+                memo.position = position;
+            }
+            child_ref.translate_children(dx, dy);
+        }
+    }
+
+    /// Resolves this node's dimensions and lays out its children.
+    fn layout_unmemoized(&mut self, parent_width: u16, parent_height: u16, mode: LayoutMode) {
         // First, calculate intrinsic size if we need it
         let (intrinsic_width, intrinsic_height) = self.calculate_intrinsic_size();
 
+        let grown_by_parent = self.parent.is_some()
+            && self
+                .style
+                .as_ref()
+                .and_then(|s| s.flex_grow)
+                .is_some_and(|grow| grow > 0.0);
+
         // Resolve percentage and fixed dimensions first (auto handled in layout_children_with_parent)
-        if let Some(style) = &self.style {
+        if grown_by_parent {
+            // Growing children keep the size their parent distributed to them
+        } else if let Some(style) = &self.style {
             // Resolve width
             match style.width {
                 Some(Dimension::Percentage(pct)) => {
@@ -1008,6 +1370,8 @@ impl RenderNode {
             self.height = intrinsic_height.min(parent_height);
         }
 
+        (self.width, self.height) = self.constrain_size((self.width, self.height));
+
         // Apply text wrapping if this is a text node with wrapping enabled
         // Use the node's own width (which may have been set to Fixed) as the constraint
         // Note: Skip if already wrapped (TextWrapped or RichTextWrapped)
@@ -1033,7 +1397,24 @@ impl RenderNode {
             .as_ref()
             .and_then(|s| s.direction)
             .unwrap_or(Direction::Vertical);
-        self.layout_children_with_parent(direction);
+        self.layout_children_with_parent(direction, mode);
+
+        // Wrapped text only knows its line count once its width is final, so a
+        // content-based height is measured again after the children are laid out
+        let content_height = !grown_by_parent
+            && matches!(self.node_type, RenderNodeType::Element)
+            && matches!(
+                self.style.as_ref().and_then(|s| s.height),
+                None | Some(Dimension::Content)
+            );
+        if content_height {
+            let (_, measured_height) = self.calculate_intrinsic_size();
+            let (_, height) = self.constrain_size((self.width, measured_height.min(parent_height)));
+            if height > self.height {
+                self.height = height;
+                self.layout_children_with_parent(direction, mode);
+            }
+        }
     }
 
     /// Lays out child nodes according to the specified direction.
@@ -1058,23 +1439,8 @@ impl RenderNode {
     ///   x=0    x=6    x=12
     /// ```
     fn layout_children(&mut self, direction: Direction) {
-        let padding = self
-            .style
-            .as_ref()
-            .and_then(|s| s.padding)
-            .unwrap_or(Spacing::all(0));
-
-        // Check if border is enabled and adjust content area accordingly
-        let border_offset = if self
-            .style
-            .as_ref()
-            .and_then(|s| s.border.as_ref())
-            .is_some_and(|b| b.enabled)
-        {
-            1
-        } else {
-            0
-        };
+        // Padding plus the cells taken by enabled border edges
+        let insets = self.content_insets();
 
         let mut offset = 0u16;
 
@@ -1083,17 +1449,11 @@ impl RenderNode {
 
             match direction {
                 Direction::Vertical => {
-                    child_ref.set_position(
-                        self.x + padding.left + border_offset,
-                        self.y + padding.top + border_offset + offset,
-                    );
+                    child_ref.set_position(self.x + insets.left, self.y + insets.top + offset);
                     offset += child_ref.height;
                 }
                 Direction::Horizontal => {
-                    child_ref.set_position(
-                        self.x + padding.left + border_offset + offset,
-                        self.y + padding.top + border_offset,
-                    );
+                    child_ref.set_position(self.x + insets.left + offset, self.y + insets.top);
                     offset += child_ref.width;
                 }
             }
@@ -1108,12 +1468,12 @@ impl RenderNode {
         direction: Direction,
         content_width: u16,
         content_height: u16,
-        padding: Spacing,
-        border_offset: u16,
+        insets: Spacing,
         gap: u16,
+        mode: LayoutMode,
     ) {
-        let start_x = self.x + padding.left + border_offset;
-        let start_y = self.y + padding.top + border_offset;
+        let start_x = self.x + insets.left;
+        let start_y = self.y + insets.top;
 
         // Get alignment settings
         let justify_content = self
@@ -1147,10 +1507,10 @@ impl RenderNode {
                 let mut row_start_index = 0;
 
                 // Resolve all child dimensions first
-                for child in &self.children {
-                    let mut child_ref = child.borrow_mut();
-                    child_ref.layout_with_parent(content_width, content_height);
-                }
+                let subtrees: Vec<_> = (0..self.children.len())
+                    .map(|index| (index, content_width, content_height))
+                    .collect();
+                layout_subtrees(&self.children, &subtrees, mode);
 
                 // Group children into rows
                 for (i, child) in self.children.iter().enumerate() {
@@ -1307,10 +1667,10 @@ impl RenderNode {
                 let mut col_start_index = 0;
 
                 // Resolve all child dimensions first
-                for child in &self.children {
-                    let mut child_ref = child.borrow_mut();
-                    child_ref.layout_with_parent(content_width, content_height);
-                }
+                let subtrees: Vec<_> = (0..self.children.len())
+                    .map(|index| (index, content_width, content_height))
+                    .collect();
+                layout_subtrees(&self.children, &subtrees, mode);
 
                 // Group children into columns
                 for (i, child) in self.children.iter().enumerate() {
@@ -1456,67 +1816,201 @@ impl RenderNode {
             let mut child_ref = child.borrow_mut();
             if let Some(child_style) = &child_ref.style {
                 let child_direction = child_style.direction.unwrap_or(Direction::Vertical);
-                child_ref.layout_children_with_parent(child_direction);
+                child_ref.layout_children_with_parent(child_direction, mode);
             } else {
-                child_ref.layout_children_with_parent(Direction::Vertical);
+                child_ref.layout_children_with_parent(Direction::Vertical, mode);
             }
         }
     }
 
-    /// Lays out child nodes with parent dimension context for percentage resolution.
-    pub(crate) fn layout_children_with_parent(&mut self, direction: Direction) {
-        let padding = self
-            .style
-            .as_ref()
-            .and_then(|s| s.padding)
-            .unwrap_or(Spacing::all(0));
+    /// Places the children into grid cells, returning each child's area and the
+    /// size of every column and row's content.
+    fn measure_grid(&self, grid: &Grid) -> (Vec<GridArea>, Vec<u16>, usize) {
+        let spans: Vec<(u16, u16)> = self
+            .children
+            .iter()
+            .map(|child| {
+                let child = child.borrow();
+                child
+                    .style
+                    .as_ref()
+                    .and_then(|s| s.grid_span)
+                    .unwrap_or((1, 1))
+            })
+            .collect();
+        let areas = place_grid_areas(&spans, grid.columns.len());
+        let row_count = areas
+            .iter()
+            .map(|area| area.row + area.rows)
+            .max()
+            .unwrap_or(0)
+            .max(grid.rows.len());
+
+        let mut column_content = vec![0; grid.columns.len().max(1)];
+        for (child, area) in self.children.iter().zip(&areas) {
+            if area.columns == 1 {
+                let (width, _) = child.borrow().calculate_intrinsic_size();
+                column_content[area.column] = column_content[area.column].max(width);
+            }
+        }
 
-        // Check if border is enabled and adjust content area accordingly
-        let border_offset = if self
-            .style
-            .as_ref()
-            .and_then(|s| s.border.as_ref())
-            .is_some_and(|b| b.enabled)
-        {
-            1
-        } else {
-            0
+        (areas, column_content, row_count)
+    }
+
+    /// Calculates the size of a grid's tracks and gaps when sized by its content.
+    fn calculate_grid_intrinsic_size(&self, grid: &Grid, gap: u16) -> (u16, u16) {
+        let (areas, column_content, row_count) = self.measure_grid(grid);
+        let columns = resolve_grid_tracks(&grid.columns, &column_content, None, gap);
+
+        let mut row_content = vec![0; row_count];
+        for (child, area) in self.children.iter().zip(&areas) {
+            if area.rows == 1 {
+                let (_, height) = child.borrow().calculate_intrinsic_size();
+                row_content[area.row] = row_content[area.row].max(height);
+            }
+        }
+        let rows = resolve_grid_tracks(&grid.rows, &row_content, None, gap);
+
+        let length = |sizes: &[u16]| match sizes.len() {
+            0 => 0,
+            count => grid_span_length(sizes, 0, count, gap),
         };
+        (length(&columns), length(&rows))
+    }
+
+    /// Lays out child nodes in the cells of a grid.
+    ///
+    /// ```text
+    /// ┌────────┬──────────────┐
+    /// │ span 2 columns        │  ← row 0
+    /// ├────────┼──────────────┤
+    /// │ Fixed  │ Auto         │  ← row 1
+    /// └────────┴──────────────┘
+    /// ```
+    ///
+    /// Columns are resolved first so each child can be measured at its cell
+    /// width, which then sizes the content-based rows.
+    fn layout_children_grid(
+        &mut self,
+        grid: &Grid,
+        content_width: u16,
+        content_height: u16,
+        insets: Spacing,
+        gap: u16,
+        mode: LayoutMode,
+    ) {
+        let start_x = self.x + insets.left;
+        let start_y = self.y + insets.top;
+
+        let (areas, column_content, row_count) = self.measure_grid(grid);
+        let columns = resolve_grid_tracks(&grid.columns, &column_content, Some(content_width), gap);
+
+        // Measure each child at its cell width to find the rows' content heights
+        let mut subtrees = Vec::new();
+        for (index, (child, area)) in self.children.iter().zip(&areas).enumerate() {
+            let mut child_ref = child.borrow_mut();
+            let cell_width = grid_span_length(&columns, area.column, area.columns, gap);
+            if matches!(
+                child_ref.style.as_ref().and_then(|s| s.width),
+                Some(Dimension::Auto)
+            ) {
+                child_ref.width = cell_width;
+            }
+            subtrees.push((index, cell_width, content_height));
+        }
+        layout_subtrees(&self.children, &subtrees, mode);
+
+        let mut row_content = vec![0; row_count];
+        for (child, area) in self.children.iter().zip(&areas) {
+            if area.rows == 1 {
+                row_content[area.row] = row_content[area.row].max(child.borrow().height);
+            }
+        }
+        let rows = resolve_grid_tracks(&grid.rows, &row_content, Some(content_height), gap);
+
+        let mut subtrees = Vec::new();
+        for (index, (child, area)) in self.children.iter().zip(&areas).enumerate() {
+            let mut child_ref = child.borrow_mut();
+            let cell_width = grid_span_length(&columns, area.column, area.columns, gap);
+            let cell_height = grid_span_length(&rows, area.row, area.rows, gap);
+            child_ref.set_position(
+                start_x.saturating_add(grid_track_offset(&columns, area.column, gap)),
+                start_y.saturating_add(grid_track_offset(&rows, area.row, gap)),
+            );
+
+            let style = child_ref.style.as_ref();
+            let auto_width = matches!(style.and_then(|s| s.width), Some(Dimension::Auto));
+            let auto_height = matches!(style.and_then(|s| s.height), Some(Dimension::Auto));
+            if auto_width {
+                child_ref.width = cell_width;
+            }
+            if auto_height {
+                child_ref.height = cell_height;
+            }
+            subtrees.push((index, cell_width, cell_height));
+        }
+        layout_subtrees(&self.children, &subtrees, mode);
+
+        self.calculate_content_dimensions();
+    }
+
+    /// Lays out child nodes with parent dimension context for percentage resolution.
+    ///
+    /// Content-sized children in a column are measured before their text is wrapped
+    /// to its final width. When one of them turns out taller, the children are laid
+    /// out once more with the wrapped heights, so later siblings move past it.
+    pub(crate) fn layout_children_with_parent(&mut self, direction: Direction, mode: LayoutMode) {
+        if self.layout_children_pass(direction, mode) {
+            self.layout_children_pass(direction, mode);
+        }
+    }
+
+    /// Single layout pass over the children. Returns whether a content-sized child
+    /// in a column grew past the height it was measured at.
+    fn layout_children_pass(&mut self, direction: Direction, mode: LayoutMode) -> bool {
+        // Padding plus the cells taken by enabled border edges
+        let insets = self.content_insets();
 
         // Calculate content box dimensions (after padding and border)
-        let content_width = self
-            .width
-            .saturating_sub(padding.left + padding.right + (border_offset * 2));
-        let content_height = self
-            .height
-            .saturating_sub(padding.top + padding.bottom + (border_offset * 2));
+        let content_width = self.width.saturating_sub(insets.left + insets.right);
+        let content_height = self.height.saturating_sub(insets.top + insets.bottom);
 
         // Check if wrapping is enabled
         let wrap_mode = self.style.as_ref().and_then(|s| s.wrap);
         let gap = self.style.as_ref().and_then(|s| s.gap).unwrap_or(0);
 
+        if let Some(grid) = self.style.as_ref().and_then(|s| s.grid.clone()) {
+            self.layout_children_grid(&grid, content_width, content_height, insets, gap, mode);
+            return false;
+        }
+
         // If wrapping is enabled, use wrapping layout
         if let Some(crate::style::WrapMode::Wrap) = wrap_mode {
             self.layout_children_with_wrap(
                 direction,
                 content_width,
                 content_height,
-                padding,
-                border_offset,
+                insets,
                 gap,
+                mode,
             );
-            return;
+            return false;
         }
 
         // First pass: Identify child types and calculate fixed/percentage sizes
         let mut absolute_children = Vec::new();
         let mut auto_children = Vec::new();
+        let mut grow_children = Vec::new();
         let mut used_space = 0u16;
         let mut child_sizes = Vec::new();
+        let mut child_margins = Vec::new();
 
         for (index, child) in self.children.iter().enumerate() {
             let mut child_ref = child.borrow_mut();
 
+            // Percentage spacing is relative to this node's content box
+            child_ref.resolve_percent_spacing(content_width, content_height);
+
             // Extract position info from style
             let (position_type, z_index) = if let Some(style) = &child_ref.style {
                 (
@@ -1537,9 +2031,18 @@ impl RenderNode {
             ) {
                 absolute_children.push(index);
                 child_sizes.push(0);
+                child_margins.push(Spacing::all(0));
                 continue;
             }
 
+            // Margins reserve space along the main axis before sizing
+            let margin = child_ref.margin();
+            child_margins.push(margin);
+            used_space = used_space.saturating_add(match direction {
+                Direction::Vertical => margin.top + margin.bottom,
+                Direction::Horizontal => margin.left + margin.right,
+            });
+
             // Apply text wrapping early for text/richtext nodes if they have wrapping enabled
             // This must happen before size calculation to get correct heights
             if matches!(
@@ -1554,10 +2057,10 @@ impl RenderNode {
                     match style.width {
                         Some(Dimension::Fixed(w)) => w,
                         Some(Dimension::Percentage(pct)) => (content_width as f32 * pct) as u16,
-                        _ => content_width,
+                        _ => content_width.saturating_sub(margin.left + margin.right),
                     }
                 } else {
-                    content_width
+                    content_width.saturating_sub(margin.left + margin.right)
                 };
                 child_ref.apply_text_wrapping(wrap_width);
             }
@@ -1568,6 +2071,15 @@ impl RenderNode {
                 Direction::Horizontal => child_ref.style.as_ref().and_then(|s| s.width),
             };
 
+            // Auto children grow with weight 1 unless told otherwise; other
+            // children only grow when given an explicit weight
+            let flex_grow = child_ref.style.as_ref().and_then(|s| s.flex_grow);
+            match (dimension, flex_grow) {
+                (Some(Dimension::Auto), grow) => grow_children.push((index, grow.unwrap_or(1.0))),
+                (_, Some(grow)) if grow > 0.0 => grow_children.push((index, grow)),
+                _ => {}
+            }
+
             let child_size = match dimension {
                 Some(Dimension::Fixed(size)) => {
                     used_space = used_space.saturating_add(size);
@@ -1678,14 +2190,24 @@ impl RenderNode {
             Direction::Horizontal => content_width.saturating_sub(used_space),
         };
 
-        let auto_size = if !auto_children.is_empty() {
-            available_space / auto_children.len() as u16
-        } else {
-            0
+        // Leftover space is shared in proportion to each child's grow weight
+        let total_grow: f32 = grow_children.iter().map(|(_, grow)| grow).sum();
+        let grow_share = |grow: f32| -> u16 {
+            if total_grow > 0.0 {
+                (available_space as f32 * grow / total_grow) as u16
+            } else {
+                0
+            }
         };
 
-        // Update auto-sized children
-        for &index in &auto_children {
+        // Update growing children
+        for &(index, grow) in &grow_children {
+            // Sized children grow beyond their base size
+            if !auto_children.contains(&index) {
+                child_sizes[index] = child_sizes[index].saturating_add(grow_share(grow));
+                continue;
+            }
+
             let is_text = {
                 let child_ref = self.children[index].borrow();
                 matches!(
@@ -1698,7 +2220,7 @@ impl RenderNode {
             };
             // Skip text nodes as they already have their size
             if !is_text {
-                child_sizes[index] = auto_size;
+                child_sizes[index] = grow_share(grow);
             }
         }
 
@@ -1718,7 +2240,16 @@ impl RenderNode {
             .map(|(_, size)| *size)
             .sum();
 
-        let total_used_space = total_children_size + total_gaps;
+        // Margins along the main axis
+        let total_margins: u16 = child_margins
+            .iter()
+            .map(|margin| match direction {
+                Direction::Vertical => margin.top + margin.bottom,
+                Direction::Horizontal => margin.left + margin.right,
+            })
+            .sum();
+
+        let total_used_space = total_children_size + total_gaps + total_margins;
 
         // Get justify content setting
         let justify_content = self
@@ -1749,7 +2280,16 @@ impl RenderNode {
             }
         };
 
-        // Third pass: Position and layout all children
+        // A vertically aligned text in a column takes the space the column leaves free
+        let mut free_main_space = match direction {
+            Direction::Vertical if justify_content == JustifyContent::Start => {
+                content_height.saturating_sub(total_used_space)
+            }
+            _ => 0,
+        };
+
+        // Third pass: Position all children, then lay out their subtrees
+        let mut subtrees = Vec::new();
         for (index, child) in self.children.iter().enumerate() {
             let mut child_ref = child.borrow_mut();
 
@@ -1759,8 +2299,11 @@ impl RenderNode {
             }
 
             // Set child dimensions based on calculated sizes
+            let margin = child_margins[index];
             match direction {
                 Direction::Vertical => {
+                    // Cross-axis margins shrink the width available to the child
+                    let content_width = content_width.saturating_sub(margin.left + margin.right);
                     child_ref.height = child_sizes[index];
                     // Set width for the child (respecting its own width setting)
                     if let Some(style) = &child_ref.style {
@@ -1899,21 +2442,37 @@ impl RenderNode {
                         AlignSelf::End => AlignItems::End,
                     };
 
+                    let start_x = self.x + insets.left + margin.left;
                     let x_position = match effective_align {
-                        AlignItems::Start => self.x + padding.left + border_offset,
+                        AlignItems::Start => start_x,
                         AlignItems::Center => {
                             let child_space = content_width.saturating_sub(child_ref.width);
-                            self.x + padding.left + border_offset + (child_space / 2)
+                            start_x + (child_space / 2)
                         }
                         AlignItems::End => {
                             let child_space = content_width.saturating_sub(child_ref.width);
-                            self.x + padding.left + border_offset + child_space
+                            start_x + child_space
                         }
                     };
 
-                    child_ref
-                        .set_position(x_position, self.y + padding.top + border_offset + offset);
-                    offset += child_sizes[index];
+                    // Offset vertically aligned text within the free space
+                    let mut space_after = 0;
+                    if let Some(valign) = child_ref.vertical_align()
+                        && free_main_space > 0
+                    {
+                        let space_before = match valign {
+                            VerticalAlign::Top => 0,
+                            VerticalAlign::Middle => free_main_space / 2,
+                            VerticalAlign::Bottom => free_main_space,
+                        };
+                        offset += space_before;
+                        space_after = free_main_space - space_before;
+                        free_main_space = 0;
+                    }
+
+                    offset += margin.top;
+                    child_ref.set_position(x_position, self.y + insets.top + offset);
+                    offset += child_sizes[index] + margin.bottom + space_after;
                     // Add spacing after each child based on justify mode
                     // For SpaceBetween, add spacing after all children except the last
                     // For SpaceAround and SpaceEvenly, add spacing after all children
@@ -1941,6 +2500,8 @@ impl RenderNode {
                     }
                 }
                 Direction::Horizontal => {
+                    // Cross-axis margins shrink the height available to the child
+                    let content_height = content_height.saturating_sub(margin.top + margin.bottom);
                     // Set width from calculated size (includes auto-sizing)
                     child_ref.width = child_sizes[index];
 
@@ -1996,28 +2557,33 @@ impl RenderNode {
                         .and_then(|s| s.align_self)
                         .unwrap_or(AlignSelf::Auto);
 
-                    let effective_align = match child_align {
-                        AlignSelf::Auto => align_items,
-                        AlignSelf::Start => AlignItems::Start,
-                        AlignSelf::Center => AlignItems::Center,
-                        AlignSelf::End => AlignItems::End,
+                    let effective_align = match (child_align, child_ref.vertical_align()) {
+                        // Text's own vertical alignment acts like align_self
+                        (AlignSelf::Auto, Some(VerticalAlign::Top)) => AlignItems::Start,
+                        (AlignSelf::Auto, Some(VerticalAlign::Middle)) => AlignItems::Center,
+                        (AlignSelf::Auto, Some(VerticalAlign::Bottom)) => AlignItems::End,
+                        (AlignSelf::Auto, None) => align_items,
+                        (AlignSelf::Start, _) => AlignItems::Start,
+                        (AlignSelf::Center, _) => AlignItems::Center,
+                        (AlignSelf::End, _) => AlignItems::End,
                     };
 
+                    let start_y = self.y + insets.top + margin.top;
                     let y_position = match effective_align {
-                        AlignItems::Start => self.y + padding.top + border_offset,
+                        AlignItems::Start => start_y,
                         AlignItems::Center => {
                             let child_space = content_height.saturating_sub(child_ref.height);
-                            self.y + padding.top + border_offset + (child_space / 2)
+                            start_y + (child_space / 2)
                         }
                         AlignItems::End => {
                             let child_space = content_height.saturating_sub(child_ref.height);
-                            self.y + padding.top + border_offset + child_space
+                            start_y + child_space
                         }
                     };
 
-                    child_ref
-                        .set_position(self.x + padding.left + border_offset + offset, y_position);
-                    offset += child_sizes[index];
+                    offset += margin.left;
+                    child_ref.set_position(self.x + insets.left + offset, y_position);
+                    offset += child_sizes[index] + margin.right;
                     // Add spacing after each child based on justify mode
                     // For SpaceBetween, add spacing after all children except the last
                     // For SpaceAround and SpaceEvenly, add spacing after all children
@@ -2046,12 +2612,35 @@ impl RenderNode {
                 }
             }
 
-            // Layout child's children
-            child_ref.layout_with_parent(content_width, content_height);
+            // Cross-axis margins are taken out of the space the child's percentages
+            // resolve against
+            subtrees.push(match direction {
+                Direction::Vertical => (
+                    index,
+                    content_width.saturating_sub(margin.left + margin.right),
+                    content_height,
+                ),
+                Direction::Horizontal => (
+                    index,
+                    content_width,
+                    content_height.saturating_sub(margin.top + margin.bottom),
+                ),
+            });
         }
+        layout_subtrees(&self.children, &subtrees, mode);
+
+        let child_grew = direction == Direction::Vertical
+            && subtrees.iter().any(|&(index, ..)| {
+                let child_ref = self.children[index].borrow();
+                let content_sized = matches!(
+                    child_ref.style.as_ref().and_then(|s| s.height),
+                    None | Some(Dimension::Content)
+                );
+                content_sized && child_ref.height > child_sizes[index]
+            });
 
         // Second pass: position absolute/fixed children
-        for index in absolute_children {
+        for &index in &absolute_children {
             let child = &self.children[index];
             let mut child_ref = child.borrow_mut();
 
@@ -2078,11 +2667,15 @@ impl RenderNode {
                 }
                 _ => {} // Already handled
             }
-
-            // Layout the absolutely positioned child
-            child_ref.layout_with_parent(content_width, content_height);
         }
 
+        // Layout the absolutely positioned children
+        let subtrees: Vec<_> = absolute_children
+            .iter()
+            .map(|&index| (index, content_width, content_height))
+            .collect();
+        layout_subtrees(&self.children, &subtrees, mode);
+
         // Track content dimensions for scrolling
         self.calculate_content_dimensions();
 
@@ -2101,6 +2694,8 @@ impl RenderNode {
                 }
             }
         }
+
+        child_grew
     }
 
     /// Calculates the actual content dimensions (may exceed container bounds).
@@ -2113,24 +2708,8 @@ impl RenderNode {
             return;
         }
 
-        // Get padding values to account for them in content dimensions
-        let padding = self
-            .style
-            .as_ref()
-            .and_then(|s| s.padding)
-            .unwrap_or(Spacing::all(0));
-
-        // Check if border is enabled
-        let border_offset = if self
-            .style
-            .as_ref()
-            .and_then(|s| s.border.as_ref())
-            .is_some_and(|b| b.enabled)
-        {
-            1
-        } else {
-            0
-        };
+        // Padding plus the cells taken by enabled border edges
+        let insets = self.content_insets();
 
         // Find the maximum extent of all children
         let mut max_x = 0u16;
@@ -2146,8 +2725,10 @@ impl RenderNode {
                 continue;
             }
 
-            let child_right = child_ref.x + child_ref.width;
-            let child_bottom = child_ref.y + child_ref.height;
+            // Trailing margins are part of the scrollable extent
+            let margin = child_ref.margin();
+            let child_right = child_ref.x + child_ref.width + margin.right;
+            let child_bottom = child_ref.y + child_ref.height + margin.bottom;
 
             // Update max extents relative to this node's position
             if child_ref.x >= self.x && child_right > self.x {
@@ -2161,10 +2742,10 @@ impl RenderNode {
         // Add padding to the content dimensions if children extend beyond the container
         // This ensures scrollable content includes padding after the last child
         if max_x > self.width {
-            max_x = max_x + padding.right + border_offset;
+            max_x += insets.right;
         }
         if max_y > self.height {
-            max_y = max_y + padding.bottom + border_offset;
+            max_y += insets.bottom;
         }
 
         // Content dimensions are the maximum of container size and children extent with padding
@@ -2210,11 +2791,45 @@ impl RenderNode {
 
     /// Handles a click event on this node.
     ///
-    /// Calls the registered click handler if one exists.
-    pub fn handle_click(&self) {
-        if let Some(on_click) = &self.events.on_click {
-            on_click();
+    /// Calls the registered click handler if one exists and returns whether the
+    /// click should bubble on to the ancestors.
+    pub fn handle_click(&self) -> Propagation {
+        match &self.events.on_click {
+            Some(on_click) => on_click(),
+            None => Propagation::Continue,
+        }
+    }
+
+    /// Handles a mouse press at a position relative to this node.
+    pub fn handle_mouse_down(&self, x: u16, y: u16) {
+        if let Some(on_mouse_down) = &self.events.on_mouse_down {
+            on_mouse_down(x, y);
+        }
+    }
+
+    /// Handles a drag at a position relative to this node.
+    pub fn handle_drag(&self, x: u16, y: u16) {
+        if let Some(on_drag) = &self.events.on_drag {
+            on_drag(x, y);
+        }
+    }
+
+    /// Handles a drag with the position relative to this node's parent.
+    pub fn handle_drag_in_parent(&self, x: u16, y: u16, width: u16, height: u16) {
+        if let Some(on_drag_in_parent) = &self.events.on_drag_in_parent {
+            on_drag_in_parent(x, y, width, height);
+        }
+    }
+
+    /// Handles pasted text on this node.
+    ///
+    /// Returns true if a paste handler was registered and called.
+    pub fn handle_paste(&self, text: String) -> bool {
+        if let Some(on_paste) = &self.events.on_paste {
+            on_paste(text);
+            return true;
         }
+        false
     }
 
     /// Handles a key press event on this node.
@@ -2257,14 +2872,20 @@ impl RenderNode {
 
     /// Checks if a handler is registered for the pressed key with modifiers
     /// and calls it if found. Only processes non-global handlers.
-    pub fn handle_key_with_modifiers(&self, key_with_modifiers: crate::key::KeyWithModifiers) {
+    ///
+    /// Returns true if a handler was called.
+    pub fn handle_key_with_modifiers(
+        &self,
+        key_with_modifiers: crate::key::KeyWithModifiers,
+    ) -> bool {
         // Check specific key with modifiers handlers
         for (k, handler, is_global) in &self.events.on_key_with_modifiers {
             if *k == key_with_modifiers && !is_global {
                 handler();
-                break;
+                return true;
             }
         }
+        false
     }
 
     /// Checks if a global handler is registered for the pressed key with modifiers and calls it.
@@ -2281,3 +2902,32 @@ impl RenderNode {
         }
     }
 }
+
+impl ScrollbarRegion {
+    /// Returns true if the point lies on the thumb
+    pub fn thumb_contains(&self, x: u16, y: u16) -> bool {
+        x == self.x && y >= self.thumb_y && y < self.thumb_y + self.thumb_height
+    }
+
+    /// Returns the scroll position that puts the top of the thumb at `thumb_top`
+    pub fn scroll_for_thumb_at(&self, thumb_top: i32, max_scroll: u16) -> u16 {
+        let travel = self.height.saturating_sub(self.thumb_height);
+        if travel == 0 {
+            return 0;
+        }
+        let offset = (thumb_top - self.y as i32).clamp(0, travel as i32);
+        (offset as f32 / travel as f32 * max_scroll as f32).round() as u16
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+// SAFETY: laying out a subtree only touches the nodes inside it. It borrows their
+// `RefCell`s and writes plain fields, without cloning or dropping an `Rc`, following
+// parent links or calling event callbacks. The subtrees of one parallel pass are
+// disjoint and their parent stays borrowed until all of them are done, so no node is
+// reached from two threads. The text wrap cache used along the way is thread-local.
+#[cfg(feature = "parallel")]
+unsafe impl Send for Subtree<'_> {}
@@ -0,0 +1,45 @@
+use crate::components::layout_node;
+use crate::node::{Div, Node, Text};
+use crate::style::{Color, Direction, Overflow, Spacing};
+use serde_json::json;
+
+#[test]
+fn test_debug_json_reports_geometry_style_and_scroll() {
+    let node: Node = Div::new()
+        .direction(Direction::Vertical)
+        .padding(Spacing::all(1))
+        .background(Color::Blue)
+        .width(12)
+        .height(5)
+        .child(Text::new("hi").into())
+        .child(
+            Div::new()
+                .overflow(Overflow::Scroll)
+                .height(1)
+                .children(vec![Text::new("a").into(), Text::new("b").into()])
+                .direction(Direction::Vertical)
+                .into(),
+        )
+        .into();
+    let vdom = layout_node(node, 40, 10);
+    let json = vdom.get_render_tree().to_debug_json();
+
+    assert_eq!(json["type"], "div");
+    assert_eq!(
+        [&json["x"], &json["y"], &json["width"], &json["height"]],
+        [0, 0, 12, 5]
+    );
+    assert_eq!(json["style"]["background"], "Blue");
+    assert_eq!(json["style"]["padding"], json!([1, 1, 1, 1]));
+
+    let text = &json["children"][0];
+    assert_eq!(text["type"], "text");
+    assert_eq!(text["text"], "hi");
+    assert_eq!([&text["x"], &text["y"], &text["width"]], [1, 1, 2]);
+
+    let scroller = &json["children"][1];
+    assert_eq!(scroller["y"], 2);
+    assert_eq!(scroller["scroll"]["scrollable"], true);
+    assert_eq!(scroller["scroll"]["content_height"], 2);
+    assert_eq!(scroller["children"].as_array().unwrap().len(), 2);
+}
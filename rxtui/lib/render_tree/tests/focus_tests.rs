@@ -0,0 +1,72 @@
+use crate::render_tree::{RenderNode, RenderTree};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Builds a tree with two focusable inputs whose callbacks log into `log`.
+fn two_inputs(
+    log: &Rc<RefCell<Vec<String>>>,
+) -> (RenderTree, Rc<RefCell<RenderNode>>, Rc<RefCell<RenderNode>>) {
+    let root = Rc::new(RefCell::new(RenderNode::element()));
+    let input = |name: &'static str| {
+        let mut node = RenderNode::element();
+        node.focusable = true;
+        node.events.on_focus = Some(Rc::new({
+            let log = log.clone();
+            move || log.borrow_mut().push(format!("focus {name}"))
+        }));
+        node.events.on_blur = Some(Rc::new({
+            let log = log.clone();
+            move || log.borrow_mut().push(format!("blur {name}"))
+        }));
+        let node = Rc::new(RefCell::new(node));
+        RenderNode::add_child_with_parent(&root, node.clone());
+        node
+    };
+    let first = input("first");
+    let second = input("second");
+
+    let mut tree = RenderTree::new();
+    tree.set_root(root);
+    (tree, first, second)
+}
+
+#[test]
+fn test_moving_focus_blurs_old_before_focusing_new() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (tree, first, second) = two_inputs(&log);
+
+    tree.set_focused_node(Some(first.clone()));
+    tree.set_focused_node(Some(second.clone()));
+    assert_eq!(*log.borrow(), ["focus first", "blur first", "focus second"]);
+    assert!(!first.borrow().focused);
+    assert!(second.borrow().focused);
+
+    // Refocusing the focused node fires nothing
+    log.borrow_mut().clear();
+    tree.set_focused_node(Some(second.clone()));
+    assert!(log.borrow().is_empty());
+
+    // Clearing focus only blurs
+    tree.set_focused_node(None);
+    assert_eq!(*log.borrow(), ["blur second"]);
+}
+
+#[test]
+fn test_tab_navigation_fires_focus_callbacks() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (tree, _, _) = two_inputs(&log);
+
+    tree.focus_next();
+    tree.focus_next();
+    tree.focus_prev();
+    assert_eq!(
+        *log.borrow(),
+        [
+            "focus first",
+            "blur first",
+            "focus second",
+            "blur second",
+            "focus first",
+        ]
+    );
+}
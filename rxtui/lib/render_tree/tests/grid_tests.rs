@@ -0,0 +1,130 @@
+use crate::components::layout_node;
+use crate::node::{Div, Node, Text};
+use crate::style::{Dimension, Grid};
+
+/// Lays out a grid div and returns each child's `(x, y, width, height)`.
+fn layout_grid(grid: Div<Node>, width: u16, height: u16) -> Vec<(u16, u16, u16, u16)> {
+    let node: Node = grid.into();
+    let vdom = layout_node(node, width, height);
+    let root = vdom.get_render_tree().root.clone().unwrap();
+    let root = root.borrow();
+    root.children
+        .iter()
+        .map(|child| {
+            let child = child.borrow();
+            (child.x, child.y, child.width, child.height)
+        })
+        .collect()
+}
+
+/// An empty div that fills its grid cell.
+fn cell() -> Div<Node> {
+    Div::new()
+        .width_dim(Dimension::Auto)
+        .height_dim(Dimension::Auto)
+}
+
+#[test]
+fn test_grid_places_children_in_row_major_order() {
+    let grid = Div::new()
+        .grid(Grid::new(3, 2))
+        .width(30)
+        .height(10)
+        .children((0..6).map(|_| cell().into()).collect());
+
+    assert_eq!(
+        layout_grid(grid, 40, 20),
+        [
+            (0, 0, 10, 5),
+            (10, 0, 10, 5),
+            (20, 0, 10, 5),
+            (0, 5, 10, 5),
+            (10, 5, 10, 5),
+            (20, 5, 10, 5),
+        ]
+    );
+}
+
+#[test]
+fn test_grid_respects_track_sizes_and_gap() {
+    let grid = Div::new()
+        .grid(Grid::tracks(
+            vec![
+                Dimension::Fixed(4),
+                Dimension::Auto,
+                Dimension::Percentage(0.25),
+            ],
+            vec![Dimension::Content, Dimension::Fixed(3)],
+        ))
+        .gap(1)
+        .width(40)
+        .height(10)
+        .children(vec![
+            cell().into(),
+            Text::new("wide").into(),
+            cell().into(),
+            cell().into(),
+        ]);
+
+    // 40 less two gaps, the fixed 4 and a 10 column percentage leaves 24
+    assert_eq!(
+        layout_grid(grid, 40, 20),
+        [(0, 0, 4, 1), (5, 0, 4, 1), (30, 0, 10, 1), (0, 2, 4, 3),]
+    );
+}
+
+#[test]
+fn test_grid_span_covers_multiple_cells() {
+    let grid = Div::new()
+        .grid(Grid::tracks(
+            vec![Dimension::Fixed(5); 3],
+            vec![Dimension::Fixed(2); 2],
+        ))
+        .children(vec![
+            cell().grid_span(2, 1).into(),
+            cell().grid_span(1, 2).into(),
+            cell().into(),
+            cell().into(),
+        ]);
+
+    assert_eq!(
+        layout_grid(grid, 40, 20),
+        [(0, 0, 10, 2), (10, 0, 5, 4), (0, 2, 5, 2), (5, 2, 5, 2)]
+    );
+}
+
+#[test]
+fn test_grid_wraps_extra_children_into_new_rows() {
+    let grid = Div::new()
+        .grid(Grid::tracks(
+            vec![Dimension::Fixed(3); 2],
+            vec![Dimension::Fixed(1)],
+        ))
+        .children(vec![
+            Text::new("a").into(),
+            Text::new("b").into(),
+            Text::new("c").into(),
+            Div::new().height(2).child(Text::new("d").into()).into(),
+        ]);
+
+    let cells = layout_grid(grid, 40, 20);
+    assert_eq!(&cells[..2], [(0, 0, 1, 1), (3, 0, 1, 1)]);
+    // Rows beyond the template size to their tallest child
+    assert_eq!((cells[2].0, cells[2].1), (0, 1));
+    assert_eq!((cells[3].0, cells[3].1), (3, 1));
+    assert_eq!(cells[3].3, 2);
+}
+
+#[test]
+fn test_grid_sizes_to_content_without_dimensions() {
+    let grid = Div::new()
+        .grid(Grid::new(2, 1))
+        .gap(1)
+        .children(vec![Text::new("abc").into(), Text::new("de").into()]);
+    let node: Node = grid.into();
+    let vdom = layout_node(node, 40, 20);
+    let root = vdom.get_render_tree().root.clone().unwrap();
+    let root = root.borrow();
+
+    assert_eq!((root.width, root.height), (6, 1));
+}
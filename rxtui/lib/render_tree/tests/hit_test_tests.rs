@@ -0,0 +1,122 @@
+use crate::components::layout_node;
+use crate::node::{Div, Text};
+use crate::render_tree::RenderNode;
+use crate::style::Spacing;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Returns the `index`-th child of `node`
+fn child(node: &Rc<RefCell<RenderNode>>, index: usize) -> Rc<RefCell<RenderNode>> {
+    node.borrow().children[index].clone()
+}
+
+#[test]
+fn test_hit_test_path_lists_target_then_ancestors() {
+    // root > panel > [header, button > text]
+    let button = Div::new().width(6).height(1).child(Text::new("OK").into());
+    let panel = Div::new()
+        .width(10)
+        .height(4)
+        .padding(Spacing::all(1))
+        .child(Div::new().width(8).height(1).into())
+        .child(button.into());
+    let vdom = layout_node(
+        Div::new().width(20).height(6).child(panel.into()).into(),
+        20,
+        6,
+    );
+    let tree = vdom.get_render_tree();
+
+    let root = tree.root.clone().unwrap();
+    let panel = child(&root, 0);
+    let button = child(&panel, 1);
+
+    // Clicking the button's text targets the button itself
+    let path = tree.hit_test_path(1, 2);
+    assert_eq!(path.len(), 3);
+    assert!(Rc::ptr_eq(&path[0], &button));
+    assert!(Rc::ptr_eq(&path[1], &panel));
+    assert!(Rc::ptr_eq(&path[2], &root));
+    assert!(Rc::ptr_eq(&tree.find_node_at(1, 2).unwrap(), &button));
+
+    // Panel padding belongs to the panel
+    let path = tree.hit_test_path(0, 0);
+    assert_eq!(path.len(), 2);
+    assert!(Rc::ptr_eq(&path[0], &panel));
+
+    // Empty root space hits only the root, and outside the root nothing
+    let path = tree.hit_test_path(19, 5);
+    assert_eq!(path.len(), 1);
+    assert!(tree.hit_test_path(25, 2).is_empty());
+}
+
+#[test]
+fn test_hit_test_prefers_higher_z_index_sibling() {
+    // Two overlapping absolute siblings; the first one added is raised above
+    let raised = Div::new()
+        .absolute_position()
+        .top(0)
+        .left(0)
+        .width(6)
+        .height(2)
+        .z_index(5);
+    let lowered = Div::new()
+        .absolute_position()
+        .top(1)
+        .left(2)
+        .width(6)
+        .height(2);
+    let vdom = layout_node(
+        Div::new()
+            .width(20)
+            .height(6)
+            .child(raised.into())
+            .child(lowered.into())
+            .into(),
+        20,
+        6,
+    );
+    let tree = vdom.get_render_tree();
+    let root = tree.root.clone().unwrap();
+    let raised = child(&root, 0);
+    let lowered = child(&root, 1);
+
+    // The overlap goes to the raised sibling, as drawn
+    assert!(Rc::ptr_eq(&tree.find_node_at(3, 1).unwrap(), &raised));
+    assert!(Rc::ptr_eq(&tree.find_node_at(7, 2).unwrap(), &lowered));
+}
+
+#[test]
+fn test_hit_test_prefers_later_sibling_at_equal_z_index() {
+    let sibling = |left| {
+        Div::new()
+            .absolute_position()
+            .top(0)
+            .left(left)
+            .width(6)
+            .height(2)
+            .into()
+    };
+    let vdom = layout_node(
+        Div::new()
+            .width(20)
+            .height(6)
+            .child(sibling(0))
+            .child(sibling(3))
+            .into(),
+        20,
+        6,
+    );
+    let tree = vdom.get_render_tree();
+    let root = tree.root.clone().unwrap();
+
+    // Later siblings are drawn over earlier ones
+    assert!(Rc::ptr_eq(
+        &tree.find_node_at(4, 0).unwrap(),
+        &child(&root, 1)
+    ));
+    assert!(Rc::ptr_eq(
+        &tree.find_node_at(1, 0).unwrap(),
+        &child(&root, 0)
+    ));
+}
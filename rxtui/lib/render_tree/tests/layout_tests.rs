@@ -1,5 +1,8 @@
 use crate::render_tree::RenderNode;
-use crate::style::{Border, BorderStyle, Color, Dimension, Direction, Spacing, Style};
+use crate::style::{
+    Border, BorderStyle, Color, Dimension, Direction, Spacing, SpacingPercent, Style, TextStyle,
+    VerticalAlign,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -424,3 +427,508 @@ fn test_no_space_for_auto() {
         "Auto child should get 0 width when no space available"
     );
 }
+
+#[test]
+fn test_margin_offsets_child_and_siblings() {
+    // Create a vertical parent with fixed dimensions
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Vertical),
+        width: Some(Dimension::Fixed(20)),
+        height: Some(Dimension::Fixed(10)),
+        ..Default::default()
+    });
+
+    // Create a full-width child with margin on every side
+    let mut child1 = RenderNode::element();
+    child1.style = Some(Style {
+        width: Some(Dimension::Percentage(1.0)),
+        height: Some(Dimension::Fixed(2)),
+        margin: Some(Spacing {
+            top: 1,
+            right: 3,
+            bottom: 2,
+            left: 2,
+        }),
+        ..Default::default()
+    });
+
+    // Create a sibling that fills the remaining height
+    let mut child2 = RenderNode::element();
+    child2.style = Some(Style {
+        height: Some(Dimension::Auto),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let child1_rc = Rc::new(RefCell::new(child1));
+    let child2_rc = Rc::new(RefCell::new(child2));
+    RenderNode::add_child_with_parent(&parent_rc, child1_rc.clone());
+    RenderNode::add_child_with_parent(&parent_rc, child2_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // Margins offset the child and shrink its cross-axis size
+    let child1 = child1_rc.borrow();
+    assert_eq!((child1.x, child1.y), (2, 1));
+    assert_eq!((child1.width, child1.height), (15, 2));
+
+    // The sibling starts after the bottom margin and auto sizing excludes all margins
+    let child2 = child2_rc.borrow();
+    assert_eq!(
+        child2.y, 5,
+        "Sibling should start after child height and margins"
+    );
+    assert_eq!(child2.height, 5, "Auto height should be 10 - 2 - 1 - 2");
+}
+
+#[test]
+fn test_resolved_percentage_spacing_is_not_diffed_as_a_change() {
+    use crate::diff::diff;
+    use crate::node::Div;
+    use crate::vdom::VDom;
+    use crate::vnode::VNode;
+
+    let child: VNode = Div::new()
+        .width(10)
+        .height(4)
+        .margin_percent(SpacingPercent::all(0.1))
+        .padding_percent(SpacingPercent::horizontal(0.05))
+        .into();
+    let node: VNode = Div::new().width(40).height(10).child(child).into();
+
+    let mut vdom = VDom::new();
+    vdom.render(node.clone());
+    vdom.layout(100, 50);
+
+    // Layout wrote the resolved spacing into the render node's style, which the
+    // same percentages must not count as a change against
+    let root = vdom.get_render_tree().root.clone().unwrap();
+    assert!(diff(&root, &node).is_empty());
+}
+
+#[test]
+fn test_percentage_spacing_resolves_against_parent() {
+    // Create a horizontal parent with fixed dimensions
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Horizontal),
+        width: Some(Dimension::Fixed(40)),
+        height: Some(Dimension::Fixed(10)),
+        ..Default::default()
+    });
+
+    // Create a child with 10% margin and 5% horizontal padding
+    let mut child = RenderNode::element();
+    child.style = Some(Style {
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(4)),
+        margin_percent: Some(SpacingPercent::all(0.1)),
+        padding_percent: Some(SpacingPercent::horizontal(0.05)),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let child_rc = Rc::new(RefCell::new(child));
+    RenderNode::add_child_with_parent(&parent_rc, child_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // Horizontal percentages use the parent width, vertical ones the parent height
+    let child = child_rc.borrow();
+    assert_eq!((child.x, child.y), (4, 1));
+    let style = child.style.as_ref().unwrap();
+    assert_eq!(
+        style.margin,
+        Some(Spacing {
+            top: 1,
+            right: 4,
+            bottom: 1,
+            left: 4,
+        })
+    );
+    assert_eq!(style.padding, Some(Spacing::horizontal(2)));
+}
+
+#[test]
+fn test_flex_grow_weights_split_remaining_space() {
+    // Create a horizontal parent with 60 cells to share
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Horizontal),
+        width: Some(Dimension::Fixed(60)),
+        height: Some(Dimension::Fixed(5)),
+        ..Default::default()
+    });
+
+    // Create two auto children with grow weights 1 and 2
+    let mut child1 = RenderNode::element();
+    child1.style = Some(Style {
+        width: Some(Dimension::Auto),
+        flex_grow: Some(1.0),
+        ..Default::default()
+    });
+
+    let mut child2 = RenderNode::element();
+    child2.style = Some(Style {
+        width: Some(Dimension::Auto),
+        flex_grow: Some(2.0),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let child1_rc = Rc::new(RefCell::new(child1));
+    let child2_rc = Rc::new(RefCell::new(child2));
+    RenderNode::add_child_with_parent(&parent_rc, child1_rc.clone());
+    RenderNode::add_child_with_parent(&parent_rc, child2_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    assert_eq!(child1_rc.borrow().width, 20, "Weight 1 should get a third");
+    assert_eq!(
+        child2_rc.borrow().width,
+        40,
+        "Weight 2 should get two thirds"
+    );
+    assert_eq!(child2_rc.borrow().x, 20);
+}
+
+#[test]
+fn test_flex_grow_extends_fixed_child() {
+    // Create a vertical parent: a fixed header that also grows, and an auto body
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Vertical),
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(20)),
+        ..Default::default()
+    });
+
+    // Fixed 2 rows plus a third of the leftover rows
+    let mut header = RenderNode::element();
+    header.style = Some(Style {
+        height: Some(Dimension::Fixed(2)),
+        flex_grow: Some(1.0),
+        ..Default::default()
+    });
+
+    // Auto body taking the other two thirds
+    let mut body = RenderNode::element();
+    body.style = Some(Style {
+        height: Some(Dimension::Auto),
+        flex_grow: Some(2.0),
+        ..Default::default()
+    });
+
+    // Fixed without a weight keeps its size
+    let mut footer = RenderNode::element();
+    footer.style = Some(Style {
+        height: Some(Dimension::Fixed(2)),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let header_rc = Rc::new(RefCell::new(header));
+    let body_rc = Rc::new(RefCell::new(body));
+    let footer_rc = Rc::new(RefCell::new(footer));
+    RenderNode::add_child_with_parent(&parent_rc, header_rc.clone());
+    RenderNode::add_child_with_parent(&parent_rc, body_rc.clone());
+    RenderNode::add_child_with_parent(&parent_rc, footer_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // 20 - 2 - 2 = 16 leftover rows split 1:2
+    assert_eq!(header_rc.borrow().height, 2 + 5);
+    assert_eq!(body_rc.borrow().height, 10);
+    assert_eq!(footer_rc.borrow().height, 2);
+    assert_eq!(footer_rc.borrow().y, 17);
+}
+
+#[test]
+fn test_vertical_align_centers_text_in_column() {
+    // A one-line label in a 5-row box, like a button
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(5)),
+        ..Default::default()
+    });
+
+    let mut label = RenderNode::text("OK");
+    label.text_style = Some(TextStyle {
+        vertical_align: Some(VerticalAlign::Middle),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let label_rc = Rc::new(RefCell::new(label));
+    RenderNode::add_child_with_parent(&parent_rc, label_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    assert_eq!(label_rc.borrow().y, 2);
+    assert_eq!(label_rc.borrow().height, 1);
+}
+
+#[test]
+fn test_vertical_align_keeps_siblings_after_text() {
+    // Column of 6 rows: aligned text followed by a fixed 1-row footer
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(6)),
+        ..Default::default()
+    });
+
+    let mut label = RenderNode::text("OK");
+    label.text_style = Some(TextStyle {
+        vertical_align: Some(VerticalAlign::Bottom),
+        ..Default::default()
+    });
+
+    let mut footer = RenderNode::element();
+    footer.style = Some(Style {
+        height: Some(Dimension::Fixed(1)),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let label_rc = Rc::new(RefCell::new(label));
+    let footer_rc = Rc::new(RefCell::new(footer));
+    RenderNode::add_child_with_parent(&parent_rc, label_rc.clone());
+    RenderNode::add_child_with_parent(&parent_rc, footer_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // All 4 free rows go above the text, the footer stays at the bottom
+    assert_eq!(label_rc.borrow().y, 4);
+    assert_eq!(footer_rc.borrow().y, 5);
+}
+
+#[test]
+fn test_vertical_align_in_row_acts_like_align_self() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Horizontal),
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(5)),
+        ..Default::default()
+    });
+
+    let mut label = RenderNode::text("OK");
+    label.text_style = Some(TextStyle {
+        vertical_align: Some(VerticalAlign::Middle),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let label_rc = Rc::new(RefCell::new(label));
+    RenderNode::add_child_with_parent(&parent_rc, label_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    assert_eq!(label_rc.borrow().y, 2);
+}
+
+#[test]
+fn test_partial_border_reserves_only_enabled_edges() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(20)),
+        height: Some(Dimension::Fixed(10)),
+        border: Some(Border::with_edges(
+            BorderStyle::Single,
+            Color::Red,
+            crate::style::BorderEdges::TOP,
+        )),
+        ..Default::default()
+    });
+
+    let mut child = RenderNode::element();
+    child.style = Some(Style {
+        width: Some(Dimension::Percentage(1.0)),
+        height: Some(Dimension::Percentage(1.0)),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let child_rc = Rc::new(RefCell::new(child));
+    RenderNode::add_child_with_parent(&parent_rc, child_rc.clone());
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // Only the top row goes to the border
+    let child_ref = child_rc.borrow();
+    assert_eq!((child_ref.x, child_ref.y), (0, 1));
+    assert_eq!((child_ref.width, child_ref.height), (20, 9));
+}
+
+#[test]
+fn test_partial_border_intrinsic_size() {
+    use crate::components::layout_node;
+    use crate::node::{Div, Node, Text};
+    use crate::style::BorderEdges;
+
+    let node: Node = Div::new()
+        .border_edges(BorderEdges::LEFT | BorderEdges::BOTTOM)
+        .child(Text::new("abc").into())
+        .into();
+    let vdom = layout_node(node, 40, 10);
+    let root = vdom.get_render_tree().root.clone().unwrap();
+    let root = root.borrow();
+
+    assert_eq!((root.width, root.height), (4, 2));
+    let text = root.children[0].borrow();
+    assert_eq!((text.x, text.y), (1, 0));
+}
+
+#[test]
+fn test_overflow_diagnostics_report_unclipped_children() {
+    use crate::components::layout_node;
+    use crate::node::{Div, Node};
+    use crate::style::Overflow;
+
+    let row = |overflow: Option<Overflow>| -> Node {
+        let mut div = Div::new()
+            .width(10)
+            .height(3)
+            .padding(Spacing::all(1))
+            .child(Div::new().width(12).height(1).into());
+        if let Some(overflow) = overflow {
+            div = div.overflow(overflow);
+        }
+        div.into()
+    };
+    let node: Node = Div::new()
+        .direction(Direction::Vertical)
+        .child(row(None))
+        .child(row(Some(Overflow::Hidden)))
+        .into();
+    let vdom = layout_node(node, 40, 10);
+
+    let diagnostics = vdom.get_render_tree().overflow_diagnostics();
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].path, [0, 0]);
+    assert_eq!(
+        (diagnostics[0].overflow_x, diagnostics[0].overflow_y),
+        (4, 0)
+    );
+}
+
+/// Card with a border, padding and a wrapping body
+fn card(index: u16) -> crate::vnode::VNode {
+    use crate::node::{Div, Text};
+    use crate::style::TextWrap;
+
+    Div::new()
+        .border(BorderStyle::Rounded)
+        .padding(Spacing::all(1))
+        .child(Text::new(format!("card {index}")).into())
+        .child(
+            Text::new("a longer body that wraps inside the card")
+                .wrap(TextWrap::Word)
+                .into(),
+        )
+        .into()
+}
+
+/// Tree mixing rows, wrapping, a grid and absolute positioning, built into
+/// render nodes
+fn sample_tree() -> Rc<RefCell<RenderNode>> {
+    use crate::node::Div;
+    use crate::style::{Grid, WrapMode};
+    use crate::vdom::VDom;
+
+    let row = Div::new()
+        .direction(Direction::Horizontal)
+        .gap(1)
+        .child(
+            Div::new()
+                .width_percent(0.3)
+                .children((0..3).map(card).collect())
+                .into(),
+        )
+        .child(
+            Div::new()
+                .flex_grow(1.0)
+                .children((3..6).map(card).collect())
+                .into(),
+        )
+        .child(Div::new().width(12).memo(7).child(card(6)).into());
+    let wrapped = Div::new()
+        .direction(Direction::Horizontal)
+        .wrap(WrapMode::Wrap)
+        .children((7..12).map(card).collect());
+    let grid = Div::new()
+        .grid(Grid::new(3, 2))
+        .children((12..18).map(card).collect());
+    let sample = Div::new()
+        .width_percent(1.0)
+        .child(row.into())
+        .child(wrapped.into())
+        .child(grid.into())
+        .child(Div::new().absolute(5, 2).child(card(18)).into());
+
+    let mut vdom = VDom::new();
+    vdom.render(sample.into());
+    vdom.get_render_tree().root.clone().unwrap()
+}
+
+#[test]
+fn test_sibling_subtrees_share_no_nodes() {
+    // Parallel layout hands each child subtree to its own thread, so no node may
+    // be reachable from two of them or owned by anything but its parent
+    fn collect(node: &Rc<RefCell<RenderNode>>, seen: &mut Vec<*const RefCell<RenderNode>>) {
+        seen.push(Rc::as_ptr(node));
+        for child in &node.borrow().children {
+            assert_eq!(Rc::strong_count(child), 1);
+            collect(child, seen);
+        }
+    }
+
+    let root = sample_tree();
+    let mut seen = Vec::new();
+    collect(&root, &mut seen);
+
+    let count = seen.len();
+    seen.sort();
+    seen.dedup();
+    assert!(count > 50, "{count}");
+    assert_eq!(seen.len(), count);
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn test_parallel_layout_matches_serial_layout() {
+    use crate::render_tree::RenderTree;
+    use crate::render_tree::node::LayoutMode;
+
+    /// Lays out a fresh sample tree and returns every node's geometry, depth first
+    fn geometry(mode: LayoutMode) -> Vec<(u16, u16, u16, u16, u16, u16)> {
+        fn collect(node: &RenderNode, out: &mut Vec<(u16, u16, u16, u16, u16, u16)>) {
+            out.push((
+                node.x,
+                node.y,
+                node.width,
+                node.height,
+                node.content_width,
+                node.content_height,
+            ));
+            for child in &node.children {
+                collect(&child.borrow(), out);
+            }
+        }
+
+        let root = sample_tree();
+        let mut tree = RenderTree::new();
+        tree.set_root(root.clone());
+        tree.layout_with_mode(80, 60, mode);
+
+        let mut out = Vec::new();
+        collect(&root.borrow(), &mut out);
+        out
+    }
+
+    let serial = geometry(LayoutMode::Serial);
+    assert!(serial.len() > 50, "{}", serial.len());
+    assert_eq!(geometry(LayoutMode::Parallel), serial);
+}
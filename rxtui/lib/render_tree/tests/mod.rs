@@ -1,3 +1,8 @@
+#[cfg(feature = "serde")]
+mod debug_json_tests;
+mod focus_tests;
+mod grid_tests;
+mod hit_test_tests;
 mod layout_tests;
 mod rich_text_tests;
 mod sizing_tests;
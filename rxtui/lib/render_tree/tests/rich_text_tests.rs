@@ -319,3 +319,57 @@ fn test_wrapped_richtext_height_in_vertical_layout() {
         _ => panic!("Expected RichTextWrapped after layout"),
     }
 }
+
+#[test]
+fn test_rich_text_hyphenation_keeps_span_styles() {
+    let rich = RichText::new()
+        .colored("colorful", Color::Red)
+        .text("plain")
+        .wrap(TextWrap::Hyphenate);
+
+    let mut render_node = RenderNode::new(RenderNodeType::RichText(rich.spans.clone()));
+    render_node.text_style = rich.style.clone();
+    render_node.apply_text_wrapping(6);
+
+    let RenderNodeType::RichTextWrapped(lines) = &render_node.node_type else {
+        panic!("Expected RichTextWrapped node type");
+    };
+    let text: Vec<String> = lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.content.as_str()).collect())
+        .collect();
+    assert_eq!(text, ["color-", "fulpl-", "ain"]);
+
+    // The added hyphen takes the style of the character it follows
+    assert_eq!(lines[1][0].content, "ful");
+    assert_eq!(lines[1][1].content, "pl-");
+    assert!(lines[1][1].style.is_none());
+    assert!(lines[2][0].style.is_none());
+    assert_eq!(lines[0][0].style.as_ref().unwrap().color, Some(Color::Red));
+}
+
+#[test]
+fn test_rich_text_wrapping_breaks_at_newlines() {
+    let rich = RichText::new()
+        .text("ab\n\n")
+        .colored("cd", Color::Green)
+        .wrap(TextWrap::Word);
+
+    let mut render_node = RenderNode::new(RenderNodeType::RichText(rich.spans.clone()));
+    render_node.text_style = rich.style.clone();
+    render_node.apply_text_wrapping(10);
+
+    let RenderNodeType::RichTextWrapped(lines) = &render_node.node_type else {
+        panic!("Expected RichTextWrapped node type");
+    };
+    let text: Vec<String> = lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.content.as_str()).collect())
+        .collect();
+    assert_eq!(text, ["ab", "", "cd"]);
+    assert_eq!(
+        lines[2][0].style.as_ref().unwrap().color,
+        Some(Color::Green)
+    );
+    assert_eq!(render_node.height, 3);
+}
@@ -1,5 +1,5 @@
 use crate::render_tree::{RenderNode, RenderNodeType};
-use crate::style::{Border, BorderStyle, Color, Dimension, Direction, Spacing, Style};
+use crate::style::{Border, BorderStyle, Color, Dimension, Direction, Overflow, Spacing, Style};
 use crate::utils::display_width;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -322,3 +322,60 @@ fn test_complex_nested_convergence() {
     //     text_width, child_ref.width, container_ref.width, root_ref.width
     // );
 }
+
+#[test]
+fn test_content_height_capped_by_max_height_scrolls() {
+    // A dropdown-style list: sized to its items, but never taller than 5 rows
+    let mut root = RenderNode::element();
+    root.style = Some(Style {
+        direction: Some(Direction::Vertical),
+        ..Default::default()
+    });
+
+    let mut list = RenderNode::element();
+    list.style = Some(Style {
+        height: Some(Dimension::Content),
+        max_height: Some(5),
+        overflow: Some(Overflow::Scroll),
+        ..Default::default()
+    });
+
+    let root_rc = Rc::new(RefCell::new(root));
+    let list_rc = Rc::new(RefCell::new(list));
+    for i in 0..10 {
+        let item = Rc::new(RefCell::new(RenderNode::text(format!("item {i}"))));
+        RenderNode::add_child_with_parent(&list_rc, item);
+    }
+    let footer_rc = Rc::new(RefCell::new(RenderNode::text("footer")));
+    RenderNode::add_child_with_parent(&root_rc, list_rc.clone());
+    RenderNode::add_child_with_parent(&root_rc, footer_rc.clone());
+
+    root_rc.borrow_mut().layout_with_parent(20, 20);
+
+    let list_ref = list_rc.borrow();
+    assert_eq!(list_ref.height, 5);
+    assert_eq!(list_ref.content_height, 10);
+    assert!(list_ref.scrollable);
+    assert_eq!(list_ref.get_max_scroll_y(), 5);
+
+    // Siblings are placed after the capped height, and the root shrinks with it
+    assert_eq!(footer_rc.borrow().y, 5);
+    assert_eq!(root_rc.borrow().height, 6);
+}
+
+#[test]
+fn test_min_size_grows_small_content() {
+    let mut node = RenderNode::element();
+    node.style = Some(Style {
+        min_width: Some(8),
+        min_height: Some(3),
+        ..Default::default()
+    });
+    let node_rc = Rc::new(RefCell::new(node));
+    RenderNode::add_child_with_parent(&node_rc, Rc::new(RefCell::new(RenderNode::text("hi"))));
+
+    node_rc.borrow_mut().layout_with_parent(20, 20);
+
+    let node_ref = node_rc.borrow();
+    assert_eq!((node_ref.width, node_ref.height), (8, 3));
+}
@@ -386,3 +386,35 @@ fn test_multiple_wrapped_texts_horizontal() {
     assert_eq!(text1_ref.x, 0, "Text1 should be at x=0");
     assert_eq!(text2_ref.x, 20, "Text2 should be at x=20");
 }
+
+#[test]
+fn test_content_height_parent_grows_with_late_wrapped_text() {
+    use crate::components::layout_node;
+    use crate::node::{Div, Node, Text};
+
+    // The parent's width is only known from the root, so its text wraps after
+    // the parent first measured itself
+    let node: Node = Div::new()
+        .width(5)
+        .direction(Direction::Vertical)
+        .child(
+            Div::new()
+                .height_content()
+                .child(Text::new("one two three").wrap(TextWrap::Word).into())
+                .into(),
+        )
+        .child(Text::new("after").into())
+        .into();
+    let vdom = layout_node(node, 10, 10);
+    let root = vdom.get_render_tree().root.clone().unwrap();
+    let root = root.borrow();
+
+    let parent = root.children[0].borrow();
+    assert_eq!((parent.width, parent.height), (5, 3));
+    let text = parent.children[0].borrow();
+    assert!(matches!(&text.node_type, RenderNodeType::TextWrapped(lines) if lines.len() == 3));
+    assert_eq!(text.height, 3);
+
+    // Siblings move down past the extra lines
+    assert_eq!(root.children[1].borrow().y, 3);
+}
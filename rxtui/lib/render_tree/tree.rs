@@ -1,13 +1,17 @@
 use crate::bounds::Rect;
 use crate::component::ComponentId;
-use crate::render_tree::node::{RenderNode, RenderNodeType};
-use crate::style::{Dimension, Direction, Overflow};
+use crate::key::{Key, KeyWithModifiers};
+use crate::keymap::{KeyMap, SequenceMatch, SequenceMatcher};
+use crate::render_tree::node::{LayoutMode, RenderNode, RenderNodeType};
+use crate::style::{Dimension, Direction, Overflow, Position, TextStyle};
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::sync::{
     Arc,
     atomic::{AtomicBool, Ordering},
 };
+use std::time::Duration;
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -27,8 +31,43 @@ pub struct RenderTree {
     /// The currently hovered node (uses RefCell for interior mutability)
     hovered_node: RefCell<Option<Rc<RefCell<RenderNode>>>>,
 
+    /// Node a mouse button was pressed on, which receives drags until release
+    pressed_node: RefCell<Option<Rc<RefCell<RenderNode>>>>,
+
+    /// Scrollable node whose thumb is held, with the row offset it was grabbed at
+    scrollbar_drag: RefCell<Option<(Rc<RefCell<RenderNode>>, u16)>>,
+
+    /// Key sequence in progress for the key maps in the tree
+    sequence_matcher: RefCell<SequenceMatcher>,
+
+    /// Key maps in effect for the current focus, until the tree or focus changes
+    active_keymaps: RefCell<Option<ActiveKeymaps>>,
+
     /// Tracks whether a focus clear has been requested this frame
     pending_focus_clear: Arc<AtomicBool>,
+
+    /// How [`RenderTree::layout`] lays out sibling subtrees
+    layout_mode: LayoutMode,
+}
+
+/// Key maps collected for a focused node, or for the whole tree without focus.
+struct ActiveKeymaps {
+    focus: Option<Rc<RefCell<RenderNode>>>,
+    keymaps: Vec<KeyMap>,
+}
+
+/// A child that extends past its parent's content box while the parent leaves
+/// `overflow` unset, so the excess spills out or is cut off by an ancestor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowDiagnostic {
+    /// Child indices from the root to the overflowing node
+    pub path: Vec<usize>,
+
+    /// Columns the node extends past the right edge of the content box
+    pub overflow_x: u16,
+
+    /// Rows the node extends past the bottom edge of the content box
+    pub overflow_y: u16,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -42,7 +81,12 @@ impl RenderTree {
             root: None,
             focused_node: RefCell::new(None),
             hovered_node: RefCell::new(None),
+            pressed_node: RefCell::new(None),
+            scrollbar_drag: RefCell::new(None),
+            sequence_matcher: RefCell::new(SequenceMatcher::default()),
+            active_keymaps: RefCell::new(None),
             pending_focus_clear: Arc::new(AtomicBool::new(false)),
+            layout_mode: LayoutMode::default(),
         }
     }
 
@@ -192,6 +236,212 @@ impl RenderTree {
         }
     }
 
+    /// Returns the tree as nested JSON for debugging layout.
+    ///
+    /// Each node has its `type`, `x`, `y`, `width` and `height`, a `style`
+    /// summary of the set background, direction, padding, margin, overflow and
+    /// border, its `scroll` state, any text, and its `children`.
+    #[cfg(feature = "serde")]
+    pub fn to_debug_json(&self) -> serde_json::Value {
+        match &self.root {
+            Some(root) => Self::debug_json_node(&root.borrow()),
+            None => serde_json::Value::Null,
+        }
+    }
+
+    /// Builds the debug JSON for a node and its children.
+    #[cfg(feature = "serde")]
+    fn debug_json_node(node: &RenderNode) -> serde_json::Value {
+        use serde_json::{Map, Value, json};
+
+        let spans_text = |spans: &[crate::node::TextSpan]| -> String {
+            spans.iter().map(|span| span.content.as_str()).collect()
+        };
+        let (kind, text) = match &node.node_type {
+            RenderNodeType::Element => ("div", Value::Null),
+            RenderNodeType::Text(content) => ("text", json!(content)),
+            RenderNodeType::TextWrapped(lines) => ("text_wrapped", json!(lines)),
+            RenderNodeType::RichText(spans) => ("rich_text", json!(spans_text(spans))),
+            RenderNodeType::RichTextWrapped(lines) => (
+                "rich_text_wrapped",
+                json!(
+                    lines
+                        .iter()
+                        .map(|line| spans_text(line))
+                        .collect::<Vec<_>>()
+                ),
+            ),
+        };
+
+        let mut style = Map::new();
+        if let Some(node_style) = &node.style {
+            let spacing = |spacing: &crate::style::Spacing| {
+                json!([spacing.top, spacing.right, spacing.bottom, spacing.left])
+            };
+            if let Some(background) = node_style.background {
+                style.insert("background".into(), json!(format!("{background:?}")));
+            }
+            if let Some(direction) = node_style.direction {
+                style.insert("direction".into(), json!(format!("{direction:?}")));
+            }
+            if let Some(padding) = &node_style.padding {
+                style.insert("padding".into(), spacing(padding));
+            }
+            if let Some(margin) = &node_style.margin {
+                style.insert("margin".into(), spacing(margin));
+            }
+            if let Some(overflow) = node_style.overflow {
+                style.insert("overflow".into(), json!(format!("{overflow:?}")));
+            }
+            if let Some(border) = &node_style.border
+                && border.enabled
+            {
+                style.insert("border".into(), json!(format!("{:?}", border.style)));
+            }
+        }
+        if let Some(color) = node.text_color {
+            style.insert("color".into(), json!(format!("{color:?}")));
+        }
+
+        let mut value = json!({
+            "type": kind,
+            "x": node.x,
+            "y": node.y,
+            "width": node.width,
+            "height": node.height,
+            "style": style,
+            "scroll": {
+                "scrollable": node.scrollable,
+                "scroll_y": node.scroll_y,
+                "content_width": node.content_width,
+                "content_height": node.content_height,
+            },
+            "focused": node.focused,
+            "children": node
+                .children
+                .iter()
+                .map(|child| Self::debug_json_node(&child.borrow()))
+                .collect::<Vec<_>>(),
+        });
+        if !text.is_null() {
+            value["text"] = text;
+        }
+        value
+    }
+
+    /// Returns the laid out nodes that overflow their parent's content box
+    /// without the parent setting `overflow`, in tree order.
+    ///
+    /// Absolutely and fixed positioned children are left out, since they are
+    /// expected to sit outside the parent's flow.
+    pub fn overflow_diagnostics(&self) -> Vec<OverflowDiagnostic> {
+        let mut diagnostics = Vec::new();
+        if let Some(root) = &self.root {
+            Self::collect_overflow(&root.borrow(), &mut Vec::new(), &mut diagnostics);
+        }
+        diagnostics
+    }
+
+    /// Checks the children of `node`, at `path`, against its content box.
+    fn collect_overflow(
+        node: &RenderNode,
+        path: &mut Vec<usize>,
+        diagnostics: &mut Vec<OverflowDiagnostic>,
+    ) {
+        let style = node.style.as_ref();
+        if style
+            .and_then(|s| s.overflow)
+            .is_none_or(|o| o == Overflow::None)
+        {
+            let insets = node.content_insets();
+            let content_right = (node.x + node.width).saturating_sub(insets.right);
+            let content_bottom = (node.y + node.height).saturating_sub(insets.bottom);
+
+            for (index, child) in node.children.iter().enumerate() {
+                let child = child.borrow();
+                let positioned = child
+                    .style
+                    .as_ref()
+                    .and_then(|s| s.position)
+                    .is_some_and(|p| matches!(p, Position::Absolute | Position::Fixed));
+                if positioned {
+                    continue;
+                }
+                let overflow_x = (child.x + child.width).saturating_sub(content_right);
+                let overflow_y = (child.y + child.height).saturating_sub(content_bottom);
+                if overflow_x > 0 || overflow_y > 0 {
+                    let mut child_path = path.clone();
+                    child_path.push(index);
+                    diagnostics.push(OverflowDiagnostic {
+                        path: child_path,
+                        overflow_x,
+                        overflow_y,
+                    });
+                }
+            }
+        }
+
+        for (index, child) in node.children.iter().enumerate() {
+            path.push(index);
+            Self::collect_overflow(&child.borrow(), path, diagnostics);
+            path.pop();
+        }
+    }
+
+    /// Returns the tree paths of the lazy divs that show on screen within
+    /// `viewport`, after scroll offsets and overflow clipping.
+    ///
+    /// Empty lazy divs count as one cell, so content-sized ones get built once
+    /// their position is in view.
+    pub fn visible_lazy_paths(&self, viewport: Rect) -> HashSet<ComponentId> {
+        let mut paths = HashSet::new();
+        if let Some(root) = &self.root {
+            Self::collect_visible_lazy(&root.borrow(), viewport, 0, &mut paths);
+        }
+        paths
+    }
+
+    /// Adds the lazy divs at or below `node` that intersect `clip`.
+    fn collect_visible_lazy(
+        node: &RenderNode,
+        clip: Rect,
+        scroll_offset: u16,
+        paths: &mut HashSet<ComponentId>,
+    ) {
+        // Signed, so a node scrolled above the screen is cut off instead of
+        // clamping onto the top row
+        let top = node.y as i32 - scroll_offset as i32;
+        let on_screen = |width: u16, height: u16| {
+            let bottom = (top + height as i32).max(0);
+            let top = top.max(0);
+            Rect::new(node.x, top as u16, width, (bottom - top).max(0) as u16)
+        };
+        let bounds = on_screen(node.width, node.height);
+
+        if let Some(path) = &node.lazy_path {
+            let footprint = on_screen(node.width.max(1), node.height.max(1));
+            if footprint.intersects(&clip) {
+                paths.insert(path.clone());
+            }
+        }
+
+        let child_clip = match node.style.as_ref().and_then(|s| s.overflow) {
+            Some(Overflow::Hidden | Overflow::Scroll | Overflow::Auto) => {
+                bounds.intersection(&clip)
+            }
+            _ => clip,
+        };
+        let child_offset = if node.scrollable {
+            scroll_offset + node.scroll_y
+        } else {
+            scroll_offset
+        };
+
+        for child in &node.children {
+            Self::collect_visible_lazy(&child.borrow(), child_clip, child_offset, paths);
+        }
+    }
+
     /// Sets the root node of the render tree.
     pub fn set_root(&mut self, root: Rc<RefCell<RenderNode>>) {
         self.root = Some(root);
@@ -201,7 +451,25 @@ impl RenderTree {
     ///
     /// Respects the root node's specified dimensions if set, otherwise
     /// uses the viewport size. Clamps dimensions to viewport bounds.
+    ///
+    /// Sibling subtrees are laid out concurrently once
+    /// [`LayoutMode::Parallel`] is set with [`RenderTree::set_layout_mode`].
     pub fn layout(&mut self, viewport_width: u16, viewport_height: u16) {
+        self.layout_with_mode(viewport_width, viewport_height, self.layout_mode);
+    }
+
+    /// Sets how [`RenderTree::layout`] lays out sibling subtrees
+    pub(crate) fn set_layout_mode(&mut self, mode: LayoutMode) {
+        self.layout_mode = mode;
+    }
+
+    /// Performs layout for the entire tree, laying out child subtrees as `mode` says.
+    pub(crate) fn layout_with_mode(
+        &mut self,
+        viewport_width: u16,
+        viewport_height: u16,
+        mode: LayoutMode,
+    ) {
         if let Some(root) = &self.root {
             let mut root_ref = root.borrow_mut();
             root_ref.set_position(0, 0);
@@ -266,13 +534,18 @@ impl RenderTree {
                 root_ref.height = intrinsic_height.min(viewport_height);
             }
 
+            // Min/max constraints apply within the viewport
+            let (width, height) = root_ref.constrain_size((root_ref.width, root_ref.height));
+            root_ref.width = width.min(viewport_width);
+            root_ref.height = height.min(viewport_height);
+
             // Layout children with root's resolved dimensions
             let direction = root_ref
                 .style
                 .as_ref()
                 .and_then(|s| s.direction)
                 .unwrap_or(Direction::Vertical);
-            root_ref.layout_children_with_parent(direction);
+            root_ref.layout_children_with_parent(direction, mode);
         }
     }
 
@@ -281,28 +554,39 @@ impl RenderTree {
     /// Used for mouse event handling. Returns the deepest node
     /// in the tree that contains the given point.
     pub fn find_node_at(&self, x: u16, y: u16) -> Option<Rc<RefCell<RenderNode>>> {
-        if let Some(root) = &self.root {
+        self.hit_test_path(x, y).into_iter().next()
+    }
+
+    /// Returns the node at the given terminal coordinates followed by each of
+    /// its ancestors up to the root.
+    ///
+    /// The first entry is the node [`find_node_at`](Self::find_node_at) returns;
+    /// the path is empty if no node contains the point. Used to bubble events
+    /// from the target outward.
+    pub fn hit_test_path(&self, x: u16, y: u16) -> Vec<Rc<RefCell<RenderNode>>> {
+        match &self.root {
             // Start with no clipping and no scroll offset
-            Self::find_node_at_recursive(root, x, y, None, 0)
-        } else {
-            None
+            Some(root) => Self::hit_test_recursive(root, x, y, None, 0).unwrap_or_default(),
+            None => Vec::new(),
         }
     }
 
-    /// Recursively searches for a node containing the given point.
+    /// Recursively searches for a node containing the given point, returning
+    /// the path from that node up to `node`.
     ///
-    /// Performs depth-first search, checking children before parents
-    /// to ensure the topmost (visually) node is returned.
+    /// Performs depth-first search, checking children before parents and
+    /// siblings in reverse z-order to ensure the topmost (visually) node is
+    /// returned.
     /// Respects overflow clipping - nodes with overflow:hidden will
     /// clip their children's click areas.
     /// Text nodes are transparent to clicks and pass events to their parent.
-    fn find_node_at_recursive(
+    fn hit_test_recursive(
         node: &Rc<RefCell<RenderNode>>,
         x: u16,
         y: u16,
         clip_rect: Option<Rect>,
         parent_scroll_offset: i16,
-    ) -> Option<Rc<RefCell<RenderNode>>> {
+    ) -> Option<Vec<Rc<RefCell<RenderNode>>>> {
         let node_ref = node.borrow();
 
         // Calculate the actual rendered position with parent scroll offset
@@ -353,24 +637,26 @@ impl RenderTree {
             parent_scroll_offset
         };
 
+        // Check children in reverse drawing order so the one drawn on top wins:
+        // highest z-index first, and later siblings before earlier ones
+        let mut children: Vec<_> = node_ref.children.iter().collect();
+        children.sort_by_key(|child| child.borrow().z_index);
+
         // Always check children first, even if this node isn't clickable
         // This is important for overflow:none where children can extend outside
-        for child in &node_ref.children {
-            if let Some(found) =
-                Self::find_node_at_recursive(child, x, y, child_clip, child_scroll_offset)
+        for child in children.into_iter().rev() {
+            if let Some(mut path) =
+                Self::hit_test_recursive(child, x, y, child_clip, child_scroll_offset)
             {
-                // Check if the found child is a text node
-                let found_ref = found.borrow();
+                // Text nodes are transparent to clicks, don't return them
                 if matches!(
-                    found_ref.node_type,
+                    path[0].borrow().node_type,
                     RenderNodeType::Text(_) | RenderNodeType::TextWrapped(_)
                 ) {
-                    // Text nodes are transparent to clicks, don't return them
-                    drop(found_ref);
                     continue;
                 }
-                drop(found_ref);
-                return Some(found);
+                path.push(node.clone());
+                return Some(path);
             }
         }
 
@@ -383,7 +669,7 @@ impl RenderTree {
             )
         {
             drop(node_ref); // Release borrow before cloning
-            return Some(node.clone());
+            return Some(vec![node.clone()]);
         }
 
         None
@@ -464,6 +750,34 @@ impl RenderTree {
         }
     }
 
+    /// Returns true if any text in the tree is animated and needs redrawing
+    /// every frame.
+    pub fn has_animations(&self) -> bool {
+        self.root
+            .as_ref()
+            .is_some_and(|root| Self::has_animations_recursive(&root.borrow()))
+    }
+
+    /// Recursively checks nodes and their text spans for animations.
+    fn has_animations_recursive(node: &RenderNode) -> bool {
+        let animated =
+            |style: &Option<TextStyle>| style.as_ref().is_some_and(|s| s.animation.is_some());
+        let spans_animated = match &node.node_type {
+            RenderNodeType::RichText(spans) => spans.iter().any(|span| animated(&span.style)),
+            RenderNodeType::RichTextWrapped(lines) => {
+                lines.iter().flatten().any(|span| animated(&span.style))
+            }
+            _ => false,
+        };
+
+        animated(&node.text_style)
+            || spans_animated
+            || node
+                .children
+                .iter()
+                .any(|child| Self::has_animations_recursive(&child.borrow()))
+    }
+
     //--------------------------------------------------------------------------------------------------
     // Focus Management
     //--------------------------------------------------------------------------------------------------
@@ -646,6 +960,139 @@ impl RenderTree {
         *self.hovered_node.borrow_mut() = node;
     }
 
+    /// Gets the node the held mouse button was pressed on.
+    pub fn get_pressed_node(&self) -> Option<Rc<RefCell<RenderNode>>> {
+        self.pressed_node.borrow().clone()
+    }
+
+    /// Sets the node that receives drag events until the button is released.
+    pub fn set_pressed_node(&self, node: Option<Rc<RefCell<RenderNode>>>) {
+        *self.pressed_node.borrow_mut() = node;
+    }
+
+    /// Gets the node whose scrollbar thumb is held and the row offset within the thumb.
+    pub fn get_scrollbar_drag(&self) -> Option<(Rc<RefCell<RenderNode>>, u16)> {
+        self.scrollbar_drag.borrow().clone()
+    }
+
+    /// Sets the node whose scrollbar thumb follows drags until the button is released.
+    pub fn set_scrollbar_drag(&self, drag: Option<(Rc<RefCell<RenderNode>>, u16)>) {
+        *self.scrollbar_drag.borrow_mut() = drag;
+    }
+
+    /// Feeds a key pressed at `now` to the key maps on the focus path: those of
+    /// the focused node and its ancestors, or of every node if nothing is focused.
+    ///
+    /// Returns the keys a broken sequence held back, to be handled as regular key
+    /// presses before the result for `key`. Characters typed into a focused node
+    /// that takes text input only continue a sequence, never start one.
+    pub(crate) fn match_key_sequence(
+        &self,
+        key: KeyWithModifiers,
+        now: Duration,
+    ) -> (Vec<KeyWithModifiers>, SequenceMatch) {
+        let focused = self.get_focused_node();
+        let typing = matches!(key.key, Key::Char(_))
+            && !(key.ctrl || key.alt || key.meta)
+            && focused
+                .as_ref()
+                .is_some_and(|node| node.borrow().events.on_any_char.is_some());
+        let mut matcher = self.sequence_matcher.borrow_mut();
+        if typing && matcher.is_idle() {
+            return (Vec::new(), SequenceMatch::Unbound);
+        }
+
+        let cache = self.active_keymaps(focused);
+        let keymaps = &cache.as_ref().unwrap().keymaps;
+        if keymaps.is_empty() && matcher.is_idle() {
+            return (Vec::new(), SequenceMatch::Unbound);
+        }
+        matcher.feed(keymaps, key, now, !typing)
+    }
+
+    /// Returns true if pressing `key` would reach a component: a key map on the
+    /// focus path starts a sequence with it, or a key handler would receive it.
+    pub(crate) fn binds_key(&self, key: KeyWithModifiers) -> bool {
+        let focused = self.get_focused_node();
+        let handles = |node: &Rc<RefCell<RenderNode>>, any: bool| {
+            node.borrow()
+                .events
+                .on_key_with_modifiers
+                .iter()
+                .any(|(bound, _, is_global)| *bound == key && (*is_global || any))
+        };
+
+        let in_keymap = self
+            .active_keymaps(focused.clone())
+            .as_ref()
+            .unwrap()
+            .keymaps
+            .iter()
+            .any(|keymap| keymap.starts_with(key));
+        let in_focus = focused.as_ref().is_some_and(|node| handles(node, true));
+        // Without focus, keys are broadcast to every handler
+        let in_tree = self
+            .root
+            .as_ref()
+            .is_some_and(|root| any_node(root, &|node| handles(node, focused.is_none())));
+        in_keymap || in_focus || in_tree
+    }
+
+    /// Returns the cached key maps for `focused`, collecting them again if the
+    /// focus changed since they were cached.
+    fn active_keymaps(
+        &self,
+        focused: Option<Rc<RefCell<RenderNode>>>,
+    ) -> std::cell::RefMut<'_, Option<ActiveKeymaps>> {
+        let mut cache = self.active_keymaps.borrow_mut();
+        let stale = cache
+            .as_ref()
+            .is_none_or(|active| match (&active.focus, &focused) {
+                (Some(old), Some(new)) => !Rc::ptr_eq(old, new),
+                (old, new) => old.is_some() != new.is_some(),
+            });
+        if stale {
+            *cache = Some(ActiveKeymaps {
+                keymaps: self.collect_active_keymaps(focused.as_ref()),
+                focus: focused,
+            });
+        }
+        cache
+    }
+
+    /// Gives up on a key sequence that has waited past its timeout at `now`,
+    /// returning the keys it held back.
+    pub(crate) fn expire_key_sequence(&self, now: Duration) -> Vec<KeyWithModifiers> {
+        self.sequence_matcher.borrow_mut().expire(now)
+    }
+
+    /// Drops the cached key maps after the tree changed.
+    pub(crate) fn invalidate_keymaps(&self) {
+        self.active_keymaps.borrow_mut().take();
+    }
+
+    /// Collects the key maps from `focused` up to the root, innermost first, or
+    /// from the whole tree when nothing is focused.
+    fn collect_active_keymaps(&self, focused: Option<&Rc<RefCell<RenderNode>>>) -> Vec<KeyMap> {
+        let mut keymaps = Vec::new();
+        match focused {
+            Some(node) => {
+                let mut current = Some(node.clone());
+                while let Some(node) = current {
+                    let node_ref = node.borrow();
+                    keymaps.extend(node_ref.events.keymap.clone());
+                    current = node_ref.parent.as_ref().and_then(|parent| parent.upgrade());
+                }
+            }
+            None => {
+                if let Some(root) = &self.root {
+                    collect_keymaps(root, &mut keymaps);
+                }
+            }
+        }
+        keymaps
+    }
+
     /// Moves focus to the next focusable element.
     pub fn focus_next(&self) {
         let focusable = self.collect_focusable_nodes();
@@ -703,6 +1150,34 @@ impl RenderTree {
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Collects the key maps bound in a subtree, in tree order
+fn collect_keymaps(node: &Rc<RefCell<RenderNode>>, keymaps: &mut Vec<KeyMap>) {
+    let node_ref = node.borrow();
+    if let Some(keymap) = &node_ref.events.keymap {
+        keymaps.push(keymap.clone());
+    }
+    for child in &node_ref.children {
+        collect_keymaps(child, keymaps);
+    }
+}
+
+/// Returns true if `predicate` holds for a node in the subtree
+fn any_node(
+    node: &Rc<RefCell<RenderNode>>,
+    predicate: &impl Fn(&Rc<RefCell<RenderNode>>) -> bool,
+) -> bool {
+    predicate(node)
+        || node
+            .borrow()
+            .children
+            .iter()
+            .any(|child| any_node(child, predicate))
+}
+
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
@@ -19,6 +19,32 @@
 //! ```
 
 use bitflags::bitflags;
+use std::ops::Range;
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// RGB values of the 16 named colors, in ANSI order, taken from xterm's defaults
+const NAMED_COLOR_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -89,6 +115,31 @@ pub struct Spacing {
     pub left: u16,
 }
 
+/// Spacing for all four sides as fractions of the parent's content box.
+///
+/// Left and right are relative to the parent's content width, top and bottom
+/// to its content height. Resolved to cells during layout; when set, it takes
+/// precedence over the cell-based value of the same property.
+///
+/// ```text
+/// SpacingPercent::all(0.1) inside a 40x10 parent resolves to:
+///   left/right: 4 cells, top/bottom: 1 cell
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpacingPercent {
+    /// Fraction of the parent height above the element
+    pub top: f32,
+
+    /// Fraction of the parent width right of the element
+    pub right: f32,
+
+    /// Fraction of the parent height below the element
+    pub bottom: f32,
+
+    /// Fraction of the parent width left of the element
+    pub left: f32,
+}
+
 /// Terminal color definitions.
 ///
 /// Supports both standard 16-color palette and 24-bit RGB colors.
@@ -224,10 +275,53 @@ pub enum TextAlign {
     Right,
 }
 
+/// Vertical alignment modes for positioning text within its container's height.
+///
+/// Text is offset within the space its container leaves free, so a single line
+/// can be centered in a taller box such as a button.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum VerticalAlign {
+    /// Align text to the top edge (default)
+    #[default]
+    Top,
+
+    /// Center text vertically
+    Middle,
+
+    /// Align text to the bottom edge
+    Bottom,
+}
+
+/// Clock-driven text animations.
+///
+/// Animations follow the time since the app started instead of relying on the
+/// terminal's own blink attribute, which many terminals ignore, so their speed
+/// doesn't depend on how often frames are drawn. Headless apps only see them
+/// move when the clock is advanced.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Animation {
+    /// Alternates between shown and hidden, holding each state for `period`
+    Blink { period: Duration },
+
+    /// Steps through `colors`, holding each one for `period`
+    ColorCycle {
+        colors: Vec<Color>,
+        period: Duration,
+    },
+
+    /// Sweeps a band of `width` cells drawn in `highlight` across the text from
+    /// left to right, moving one cell every `period`
+    Shimmer {
+        highlight: Color,
+        width: u16,
+        period: Duration,
+    },
+}
+
 /// Text wrapping modes for controlling how text breaks across lines.
 ///
 /// Determines how text content wraps when it exceeds its container width.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TextWrap {
     /// No wrapping - text overflows or is clipped (default)
     None,
@@ -243,6 +337,10 @@ pub enum TextWrap {
     /// Break at word boundaries, but break words if necessary
     /// Ensures text never exceeds the specified width
     WordBreak,
+
+    /// Like `WordBreak`, but ends each line a word is broken on with a `-`
+    /// Short words are broken without one
+    Hyphenate,
 }
 
 /// Element wrapping modes for controlling how children wrap.
@@ -276,6 +374,10 @@ pub enum Position {
     /// Element is positioned relative to the viewport
     /// Similar to absolute but always relative to the terminal window
     Fixed,
+
+    /// Element is laid out in normal flow but pinned to the top of the scrolled
+    /// viewport while its parent is in view, then scrolls off with the parent
+    Sticky,
 }
 
 /// Controls how content is distributed along the main axis.
@@ -401,6 +503,39 @@ pub enum BorderStyle {
     Dashed,
 }
 
+/// Side of an element the vertical scrollbar is drawn on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollbarPosition {
+    /// Along the right edge
+    #[default]
+    Right,
+
+    /// Along the left edge
+    Left,
+}
+
+/// Glyphs, colors and placement of a scrollbar.
+///
+/// Glyphs must be one column wide; wider characters fall back to the default
+/// track (`│`) or thumb (`█`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollbarStyle {
+    /// Character drawn along the track
+    pub track_char: char,
+
+    /// Character drawn for the thumb
+    pub thumb_char: char,
+
+    /// Color of the track
+    pub track_color: Color,
+
+    /// Color of the thumb
+    pub thumb_color: Color,
+
+    /// Edge the scrollbar is drawn along
+    pub position: ScrollbarPosition,
+}
+
 /// Border configuration for UI elements.
 ///
 /// Defines border styling including whether borders are shown
@@ -421,6 +556,33 @@ pub struct Border {
     pub edges: BorderEdges,
 }
 
+/// Column and row tracks of a grid layout.
+///
+/// Children fill the cells in order, left to right and then top to bottom,
+/// skipping cells taken by an earlier child's span. Children beyond the last
+/// row add rows sized to their content. Track sizes work like element sizes:
+/// fixed and percentage tracks are resolved first, content tracks fit their
+/// widest (or tallest) child, and auto tracks share what is left.
+///
+/// ```rust,ignore
+/// Div::new()
+///     .grid(Grid::tracks(
+///         vec![Dimension::Fixed(10), Dimension::Auto, Dimension::Auto],
+///         vec![Dimension::Fixed(1), Dimension::Auto],
+///     ))
+///     .gap(1)
+///     .child(Div::new().grid_span(3, 1).child(Text::new("Header").into()).into())
+///     .children(cells)
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    /// Width of each column
+    pub columns: Vec<Dimension>,
+
+    /// Height of each explicit row
+    pub rows: Vec<Dimension>,
+}
+
 /// Complete style definition for a UI element.
 ///
 /// Combines colors, layout, and spacing properties.
@@ -430,6 +592,10 @@ pub struct Style {
     /// Background fill color
     pub background: Option<Color>,
 
+    /// Glyph tiled across the element's area instead of spaces; must be one
+    /// column wide, wider glyphs fall back to spaces
+    pub fill_char: Option<char>,
+
     /// Layout direction for children
     pub direction: Option<Direction>,
 
@@ -469,12 +635,18 @@ pub struct Style {
     /// Wrapping mode for child elements
     pub wrap: Option<WrapMode>,
 
-    /// Gap between wrapped rows/columns
+    /// Gap between wrapped rows/columns, or between grid tracks
     pub gap: Option<u16>,
 
     /// Outer spacing around element
     pub margin: Option<Spacing>,
 
+    /// Outer spacing relative to the parent's content box (overrides `margin`)
+    pub margin_percent: Option<SpacingPercent>,
+
+    /// Inner spacing relative to the parent's content box (overrides `padding`)
+    pub padding_percent: Option<SpacingPercent>,
+
     /// Minimum width constraint
     pub min_width: Option<u16>,
 
@@ -499,6 +671,9 @@ pub struct Style {
     /// Whether to show scrollbar for scrollable content
     pub show_scrollbar: Option<bool>,
 
+    /// Whether to show ▲/▼ hints at the edges when more content is scrolled out of view
+    pub scroll_indicators: Option<bool>,
+
     /// Controls how content is distributed along the main axis
     pub justify_content: Option<JustifyContent>,
 
@@ -507,6 +682,27 @@ pub struct Style {
 
     /// Allows this element to override parent's align_items
     pub align_self: Option<AlignSelf>,
+
+    /// Share of leftover main-axis space this element grows into, relative to its siblings
+    pub flex_grow: Option<f32>,
+
+    /// Opacity of the background from 0.0 to 1.0, blended over the cells
+    /// already drawn underneath (truecolor only)
+    pub opacity: Option<f32>,
+
+    /// Whether text inside this element picks up the background of the element
+    /// and its ancestors (defaults to true); when false, text cells keep their own
+    /// background or none, letting the terminal's theme show through
+    pub inherit_background: Option<bool>,
+
+    /// Glyphs, colors and placement of the scrollbar
+    pub scrollbar_style: Option<ScrollbarStyle>,
+
+    /// Lays the children out in grid cells instead of along `direction`
+    pub grid: Option<Grid>,
+
+    /// Number of columns and rows this element covers inside a grid
+    pub grid_span: Option<(u16, u16)>,
 }
 
 /// Style properties specific to text elements.
@@ -538,6 +734,18 @@ pub struct TextStyle {
 
     /// Text alignment within container
     pub align: Option<TextAlign>,
+
+    /// Vertical alignment within the container's free space
+    pub vertical_align: Option<VerticalAlign>,
+
+    /// Columns between tab stops that tabs expand to (default: 4)
+    pub tab_width: Option<u8>,
+
+    /// Clock-driven animation such as blinking or cycling colors
+    pub animation: Option<Animation>,
+
+    /// URL the text links to, emitted as an OSC 8 hyperlink where supported
+    pub link: Option<String>,
 }
 
 /// Builder for creating styles with a fluent API.
@@ -631,6 +839,25 @@ impl Color {
         }
     }
 
+    /// Mixes this color over `under` at the given opacity (0.0 keeps `under`,
+    /// 1.0 keeps this color).
+    ///
+    /// Only RGB colors can be mixed; with a named color the more opaque side
+    /// wins instead.
+    pub fn blend(self, under: Color, opacity: f32) -> Color {
+        let opacity = opacity.clamp(0.0, 1.0);
+        match (self, under) {
+            (Color::Rgb(r, g, b), Color::Rgb(ur, ug, ub)) => {
+                let mix = |over: u8, under: u8| {
+                    (over as f32 * opacity + under as f32 * (1.0 - opacity)).round() as u8
+                };
+                Color::Rgb(mix(r, ur), mix(g, ug), mix(b, ub))
+            }
+            _ if opacity >= 0.5 => self,
+            _ => under,
+        }
+    }
+
     /// Creates a Color from a hex string, panicking on invalid input.
     ///
     /// This is a convenience method for use with compile-time constants
@@ -663,6 +890,79 @@ impl Color {
     pub fn rgb(r: u8, g: u8, b: u8) -> Self {
         Color::Rgb(r, g, b)
     }
+
+    /// Moves the color toward white by `amount` (0.0 unchanged, 1.0 white).
+    pub fn lighten(self, amount: f32) -> Color {
+        self.mix(Color::Rgb(255, 255, 255), amount)
+    }
+
+    /// Moves the color toward black by `amount` (0.0 unchanged, 1.0 black).
+    pub fn darken(self, amount: f32) -> Color {
+        self.mix(Color::Rgb(0, 0, 0), amount)
+    }
+
+    /// Interpolates between this color and `other` (0.0 keeps this color,
+    /// 1.0 gives `other`).
+    ///
+    /// Named colors are converted to RGB first, so the result is always RGB.
+    pub fn mix(self, other: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (r, g, b) = self.to_rgb();
+        let (or, og, ob) = other.to_rgb();
+        let channel = |from: u8, to: u8| {
+            (from as f32 + (to as f32 - from as f32) * t)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Color::Rgb(channel(r, or), channel(g, og), channel(b, ob))
+    }
+
+    /// Returns the gray with the same perceived brightness.
+    pub fn grayscale(self) -> Color {
+        let (r, g, b) = self.to_rgb();
+        let luma = (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32)
+            .round()
+            .clamp(0.0, 255.0) as u8;
+        Color::Rgb(luma, luma, luma)
+    }
+
+    /// Returns the red, green and blue channels of the color.
+    ///
+    /// RGB colors pass through unchanged. Named colors resolve to xterm's
+    /// default palette, which terminals may override:
+    ///
+    /// ```text
+    /// Black         (  0,   0,   0)    BrightBlack    (127, 127, 127)
+    /// Red           (205,   0,   0)    BrightRed      (255,   0,   0)
+    /// Green         (  0, 205,   0)    BrightGreen    (  0, 255,   0)
+    /// Yellow        (205, 205,   0)    BrightYellow   (255, 255,   0)
+    /// Blue          (  0,   0, 238)    BrightBlue     ( 92,  92, 255)
+    /// Magenta       (205,   0, 205)    BrightMagenta  (255,   0, 255)
+    /// Cyan          (  0, 205, 205)    BrightCyan     (  0, 255, 255)
+    /// White         (229, 229, 229)    BrightWhite    (255, 255, 255)
+    /// ```
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        let index = match self {
+            Color::Rgb(r, g, b) => return (r, g, b),
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 8,
+            Color::BrightRed => 9,
+            Color::BrightGreen => 10,
+            Color::BrightYellow => 11,
+            Color::BrightBlue => 12,
+            Color::BrightMagenta => 13,
+            Color::BrightCyan => 14,
+            Color::BrightWhite => 15,
+        };
+        NAMED_COLOR_RGB[index]
+    }
 }
 
 /// Parses a single hex digit into a u8 value.
@@ -675,6 +975,20 @@ fn parse_hex_digit(c: char) -> Result<u8, &'static str> {
     }
 }
 
+/// Returns how many whole `period`s fit in `elapsed`, treating a zero period as 1ms.
+fn steps(elapsed: Duration, period: Duration) -> u128 {
+    elapsed.as_millis() / period.as_millis().max(1)
+}
+
+/// Returns `ch` if it takes exactly one column, otherwise `fallback`.
+fn single_column(ch: char, fallback: char) -> char {
+    if crate::utils::char_width(ch) == 1 {
+        ch
+    } else {
+        fallback
+    }
+}
+
 impl Style {
     /// Creates the default focus style for focusable elements.
     ///
@@ -707,12 +1021,24 @@ impl Style {
                 if overlay.background.is_some() {
                     base.background = overlay.background;
                 }
+                if overlay.fill_char.is_some() {
+                    base.fill_char = overlay.fill_char;
+                }
                 if overlay.direction.is_some() {
                     base.direction = overlay.direction;
                 }
                 if overlay.padding.is_some() {
                     base.padding = overlay.padding;
                 }
+                if overlay.padding_percent.is_some() {
+                    base.padding_percent = overlay.padding_percent;
+                }
+                if overlay.margin.is_some() {
+                    base.margin = overlay.margin;
+                }
+                if overlay.margin_percent.is_some() {
+                    base.margin_percent = overlay.margin_percent;
+                }
                 if overlay.overflow.is_some() {
                     base.overflow = overlay.overflow;
                 }
@@ -752,6 +1078,27 @@ impl Style {
                 if overlay.show_scrollbar.is_some() {
                     base.show_scrollbar = overlay.show_scrollbar;
                 }
+                if overlay.scroll_indicators.is_some() {
+                    base.scroll_indicators = overlay.scroll_indicators;
+                }
+                if overlay.flex_grow.is_some() {
+                    base.flex_grow = overlay.flex_grow;
+                }
+                if overlay.opacity.is_some() {
+                    base.opacity = overlay.opacity;
+                }
+                if overlay.grid.is_some() {
+                    base.grid = overlay.grid;
+                }
+                if overlay.grid_span.is_some() {
+                    base.grid_span = overlay.grid_span;
+                }
+                if overlay.inherit_background.is_some() {
+                    base.inherit_background = overlay.inherit_background;
+                }
+                if overlay.scrollbar_style.is_some() {
+                    base.scrollbar_style = overlay.scrollbar_style;
+                }
                 if overlay.justify_content.is_some() {
                     base.justify_content = overlay.justify_content;
                 }
@@ -784,6 +1131,24 @@ impl Style {
         self
     }
 
+    /// Sets the inner padding as fractions of the parent's content box.
+    pub fn padding_percent(mut self, padding: SpacingPercent) -> Self {
+        self.padding_percent = Some(padding);
+        self
+    }
+
+    /// Sets the outer spacing that offsets the element within its parent.
+    pub fn margin(mut self, margin: Spacing) -> Self {
+        self.margin = Some(margin);
+        self
+    }
+
+    /// Sets the outer spacing as fractions of the parent's content box.
+    pub fn margin_percent(mut self, margin: SpacingPercent) -> Self {
+        self.margin_percent = Some(margin);
+        self
+    }
+
     /// Sets the overflow behavior.
     pub fn overflow(mut self, overflow: Overflow) -> Self {
         self.overflow = Some(overflow);
@@ -866,6 +1231,107 @@ impl Style {
         self.show_scrollbar = Some(show);
         self
     }
+
+    /// Sets whether to show ▲/▼ indicators when content is scrolled out of view.
+    pub fn scroll_indicators(mut self, show: bool) -> Self {
+        self.scroll_indicators = Some(show);
+        self
+    }
+
+    /// Sets the flex-grow weight used to share leftover main-axis space.
+    pub fn flex_grow(mut self, grow: f32) -> Self {
+        self.flex_grow = Some(grow);
+        self
+    }
+
+    /// Sets the background opacity, from 0.0 (invisible) to 1.0 (opaque).
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Lays the children out in the cells of a grid.
+    pub fn grid(mut self, grid: Grid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Sets how many columns and rows this element covers inside a grid.
+    pub fn grid_span(mut self, columns: u16, rows: u16) -> Self {
+        self.grid_span = Some((columns, rows));
+        self
+    }
+
+    /// Sets the glyph that fills the element's area, such as `░` or `·`.
+    pub fn fill_char(mut self, ch: char) -> Self {
+        self.fill_char = Some(ch);
+        self
+    }
+
+    /// Sets whether text inside the element inherits its background.
+    pub fn inherit_background(mut self, inherit: bool) -> Self {
+        self.inherit_background = Some(inherit);
+        self
+    }
+
+    /// Sets the glyphs, colors and placement of the scrollbar.
+    pub fn scrollbar_style(mut self, scrollbar: ScrollbarStyle) -> Self {
+        self.scrollbar_style = Some(scrollbar);
+        self
+    }
+}
+
+impl ScrollbarStyle {
+    /// Track glyph used when none (or a wide one) is set
+    pub const DEFAULT_TRACK: char = '│';
+
+    /// Thumb glyph used when none (or a wide one) is set
+    pub const DEFAULT_THUMB: char = '█';
+
+    /// Sets the track character and color
+    pub fn track(mut self, ch: char, color: Color) -> Self {
+        self.track_char = ch;
+        self.track_color = color;
+        self
+    }
+
+    /// Sets the thumb character and color
+    pub fn thumb(mut self, ch: char, color: Color) -> Self {
+        self.thumb_char = ch;
+        self.thumb_color = color;
+        self
+    }
+
+    /// Sets the edge the scrollbar is drawn along
+    pub fn position(mut self, position: ScrollbarPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Returns the track glyph, replacing characters wider than one column
+    pub fn track_glyph(&self) -> char {
+        single_column(self.track_char, Self::DEFAULT_TRACK)
+    }
+
+    /// Returns the thumb glyph, replacing characters wider than one column
+    pub fn thumb_glyph(&self) -> char {
+        single_column(self.thumb_char, Self::DEFAULT_THUMB)
+    }
+}
+
+impl Grid {
+    /// Creates a grid of equal columns and rows sharing the available space
+    pub fn new(columns: u16, rows: u16) -> Self {
+        Self {
+            columns: vec![Dimension::Auto; columns.max(1) as usize],
+            rows: vec![Dimension::Auto; rows as usize],
+        }
+    }
+
+    /// Creates a grid with the given column and row sizes
+    pub fn tracks(columns: Vec<Dimension>, rows: Vec<Dimension>) -> Self {
+        Self { columns, rows }
+    }
 }
 
 impl Border {
@@ -898,6 +1364,23 @@ impl Border {
             edges,
         }
     }
+
+    /// Returns the cells this border takes from each side of the element.
+    ///
+    /// Only edges take space; corners drawn without their edges sit on top of
+    /// the content.
+    pub fn insets(&self) -> Spacing {
+        if !self.enabled {
+            return Spacing::all(0);
+        }
+        let side = |edge| u16::from(self.edges.contains(edge));
+        Spacing::new(
+            side(BorderEdges::TOP),
+            side(BorderEdges::RIGHT),
+            side(BorderEdges::BOTTOM),
+            side(BorderEdges::LEFT),
+        )
+    }
 }
 
 impl Style {
@@ -910,6 +1393,45 @@ impl Style {
     }
 }
 
+impl Animation {
+    /// Returns whether the text is shown at `elapsed`.
+    pub fn is_visible(&self, elapsed: Duration) -> bool {
+        match self {
+            Animation::Blink { period } => steps(elapsed, *period).is_multiple_of(2),
+            Animation::ColorCycle { .. } | Animation::Shimmer { .. } => true,
+        }
+    }
+
+    /// Returns the color the animation imposes at `elapsed`, if any.
+    pub fn color(&self, elapsed: Duration) -> Option<Color> {
+        match self {
+            Animation::Blink { .. } | Animation::Shimmer { .. } => None,
+            Animation::ColorCycle { colors, period } => {
+                if colors.is_empty() {
+                    return None;
+                }
+                let step = steps(elapsed, *period);
+                Some(colors[(step % colors.len() as u128) as usize])
+            }
+        }
+    }
+
+    /// Returns the columns of a `len` cells wide text that the shimmer band
+    /// covers at `elapsed`, if any.
+    ///
+    /// The band enters at the left edge and leaves past the right edge before
+    /// starting over.
+    pub fn highlight(&self, elapsed: Duration, len: usize) -> Option<Range<usize>> {
+        let Animation::Shimmer { width, period, .. } = self else {
+            return None;
+        };
+        let width = *width as usize;
+        let cycle = (len + width) as u128;
+        let position = (steps(elapsed, *period) % cycle.max(1)) as usize;
+        Some(position.saturating_sub(width)..position.min(len))
+    }
+}
+
 impl TextStyle {
     /// Merges two text styles, with the overlay style taking precedence.
     ///
@@ -946,6 +1468,18 @@ impl TextStyle {
                 if overlay.align.is_some() {
                     base.align = overlay.align;
                 }
+                if overlay.vertical_align.is_some() {
+                    base.vertical_align = overlay.vertical_align;
+                }
+                if overlay.tab_width.is_some() {
+                    base.tab_width = overlay.tab_width;
+                }
+                if overlay.animation.is_some() {
+                    base.animation = overlay.animation;
+                }
+                if overlay.link.is_some() {
+                    base.link = overlay.link;
+                }
                 Some(base)
             }
         }
@@ -964,6 +1498,10 @@ impl TextStyle {
                 strikethrough: None,
                 wrap: None,
                 align: None,
+                vertical_align: None,
+                tab_width: None,
+                animation: None,
+                link: None,
             },
         }
     }
@@ -1015,6 +1553,30 @@ impl TextStyle {
         self.align = Some(align);
         self
     }
+
+    /// Sets the vertical alignment.
+    pub fn vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.vertical_align = Some(align);
+        self
+    }
+
+    /// Sets the number of columns between tab stops.
+    pub fn tab_width(mut self, width: u8) -> Self {
+        self.tab_width = Some(width);
+        self
+    }
+
+    /// Sets a clock-driven animation.
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Makes the text a hyperlink to `url`.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
 }
 
 impl TextStyleBuilder {
@@ -1083,6 +1645,16 @@ impl TextStyleBuilder {
 }
 
 impl Spacing {
+    /// Creates spacing with a value for each side, clockwise from the top.
+    pub fn new(top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
     /// Creates spacing with the same value on all sides.
     ///
     /// ```text
@@ -1141,6 +1713,82 @@ impl Spacing {
             left: value,
         }
     }
+
+    /// Creates spacing with `horizontal` on left and right and `vertical` on
+    /// top and bottom.
+    pub fn symmetric(horizontal: u16, vertical: u16) -> Self {
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    /// Replaces the spacing above the content.
+    pub fn top(mut self, value: u16) -> Self {
+        self.top = value;
+        self
+    }
+
+    /// Replaces the spacing to the right of the content.
+    pub fn right(mut self, value: u16) -> Self {
+        self.right = value;
+        self
+    }
+
+    /// Replaces the spacing below the content.
+    pub fn bottom(mut self, value: u16) -> Self {
+        self.bottom = value;
+        self
+    }
+
+    /// Replaces the spacing to the left of the content.
+    pub fn left(mut self, value: u16) -> Self {
+        self.left = value;
+        self
+    }
+}
+
+impl SpacingPercent {
+    /// Creates percentage spacing with the same fraction on all sides.
+    pub fn all(value: f32) -> Self {
+        Self {
+            top: value,
+            right: value,
+            bottom: value,
+            left: value,
+        }
+    }
+
+    /// Creates percentage spacing only on top and bottom.
+    pub fn vertical(value: f32) -> Self {
+        Self {
+            top: value,
+            bottom: value,
+            ..Self::default()
+        }
+    }
+
+    /// Creates percentage spacing only on left and right.
+    pub fn horizontal(value: f32) -> Self {
+        Self {
+            right: value,
+            left: value,
+            ..Self::default()
+        }
+    }
+
+    /// Converts to cells for a parent content box of `width` x `height`.
+    pub fn resolve(&self, width: u16, height: u16) -> Spacing {
+        let cells = |fraction: f32, extent: u16| (extent as f32 * fraction.max(0.0)) as u16;
+        Spacing {
+            top: cells(self.top, height),
+            right: cells(self.right, width),
+            bottom: cells(self.bottom, height),
+            left: cells(self.left, width),
+        }
+    }
 }
 
 impl StyleBuilder {
@@ -1254,6 +1902,7 @@ impl Default for Style {
     fn default() -> Self {
         Self {
             background: None,
+            fill_char: None,
             direction: None,
             padding: None,
             overflow: None,
@@ -1269,6 +1918,8 @@ impl Default for Style {
             wrap: None,
             gap: None,
             margin: None,
+            margin_percent: None,
+            padding_percent: None,
             min_width: None,
             min_height: None,
             max_width: None,
@@ -1277,9 +1928,29 @@ impl Default for Style {
             x: None,
             y: None,
             show_scrollbar: None,
+            scroll_indicators: None,
             justify_content: None,
             align_items: None,
             align_self: None,
+            flex_grow: None,
+            opacity: None,
+            inherit_background: None,
+            scrollbar_style: None,
+            grid: None,
+            grid_span: None,
+        }
+    }
+}
+
+/// Default scrollbar: a dim `│` track with a `█` thumb on the right.
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        Self {
+            track_char: Self::DEFAULT_TRACK,
+            thumb_char: Self::DEFAULT_THUMB,
+            track_color: Color::BrightBlack,
+            thumb_color: Color::BrightBlack,
+            position: ScrollbarPosition::Right,
         }
     }
 }
@@ -1296,6 +1967,10 @@ impl Default for TextStyle {
             strikethrough: None,
             wrap: None,
             align: None,
+            vertical_align: None,
+            tab_width: None,
+            animation: None,
+            link: None,
         }
     }
 }
@@ -1308,6 +1983,119 @@ impl Default for TextStyle {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_animation_phases() {
+        let ms = Duration::from_millis;
+        let blink = Animation::Blink { period: ms(300) };
+        let visible: Vec<bool> = (0..7).map(|i| blink.is_visible(ms(i * 100))).collect();
+        assert_eq!(visible, [true, true, true, false, false, false, true]);
+        assert_eq!(blink.color(Duration::ZERO), None);
+
+        let cycle = Animation::ColorCycle {
+            colors: vec![Color::Red, Color::Green],
+            period: ms(200),
+        };
+        let colors: Vec<_> = (0..5).filter_map(|i| cycle.color(ms(i * 100))).collect();
+        assert_eq!(
+            colors,
+            [
+                Color::Red,
+                Color::Red,
+                Color::Green,
+                Color::Green,
+                Color::Red
+            ]
+        );
+        assert!(cycle.is_visible(ms(300)));
+
+        let shimmer = Animation::Shimmer {
+            highlight: Color::White,
+            width: 2,
+            period: ms(50),
+        };
+        let bands: Vec<_> = (0..7)
+            .filter_map(|i| shimmer.highlight(ms(i * 50), 4))
+            .collect();
+        assert_eq!(bands, [0..0, 0..1, 0..2, 1..3, 2..4, 3..4, 0..0]);
+        assert_eq!(cycle.highlight(Duration::ZERO, 4), None);
+    }
+
+    #[test]
+    fn test_to_rgb_resolves_named_colors() {
+        assert_eq!(Color::Black.to_rgb(), (0, 0, 0));
+        assert_eq!(Color::Red.to_rgb(), (205, 0, 0));
+        assert_eq!(Color::BrightBlue.to_rgb(), (92, 92, 255));
+        assert_eq!(Color::BrightWhite.to_rgb(), (255, 255, 255));
+
+        // RGB colors pass through unchanged
+        assert_eq!(Color::rgb(12, 34, 56).to_rgb(), (12, 34, 56));
+    }
+
+    #[test]
+    fn test_lighten_and_darken_move_toward_white_and_black() {
+        assert_eq!(
+            Color::rgb(100, 100, 100).lighten(0.5),
+            Color::rgb(178, 178, 178)
+        );
+        assert_eq!(
+            Color::rgb(100, 100, 100).darken(0.5),
+            Color::rgb(50, 50, 50)
+        );
+
+        // Amounts outside 0..=1 are clamped, keeping channels in range
+        assert_eq!(
+            Color::rgb(10, 200, 250).lighten(3.0),
+            Color::rgb(255, 255, 255)
+        );
+        assert_eq!(
+            Color::rgb(10, 200, 250).darken(-1.0),
+            Color::rgb(10, 200, 250)
+        );
+    }
+
+    #[test]
+    fn test_mix_and_grayscale() {
+        assert_eq!(
+            Color::rgb(0, 100, 200).mix(Color::rgb(100, 200, 0), 0.5),
+            Color::rgb(50, 150, 100)
+        );
+        // Named colors are mixed through their RGB values
+        assert_eq!(
+            Color::Black.mix(Color::BrightWhite, 1.0),
+            Color::rgb(255, 255, 255)
+        );
+
+        assert_eq!(Color::rgb(255, 0, 0).grayscale(), Color::rgb(76, 76, 76));
+    }
+
+    #[test]
+    fn test_spacing_constructors() {
+        assert_eq!(
+            Spacing::new(1, 2, 3, 4),
+            Spacing {
+                top: 1,
+                right: 2,
+                bottom: 3,
+                left: 4
+            }
+        );
+        assert_eq!(Spacing::horizontal(2), Spacing::new(0, 2, 0, 2));
+        assert_eq!(Spacing::vertical(2), Spacing::new(2, 0, 2, 0));
+        assert_eq!(Spacing::symmetric(3, 1), Spacing::new(1, 3, 1, 3));
+    }
+
+    #[test]
+    fn test_spacing_side_overrides_compose() {
+        let spacing = Spacing::all(1).top(0).left(4);
+        assert_eq!(spacing, Spacing::new(0, 1, 1, 4));
+
+        // Later overrides of the same side win
+        assert_eq!(
+            Spacing::symmetric(2, 2).right(5).right(6).bottom(0),
+            Spacing::new(2, 6, 0, 2)
+        );
+    }
+
     #[test]
     fn test_hex_color_parsing() {
         // Test 1-digit hex (grayscale)
@@ -6,16 +6,40 @@
 
 use crate::buffer::{Cell, CellStyle, CellUpdate};
 use crate::style::Color;
+use crate::utils::{char_width, display_width};
 use crossterm::{
-    ExecutableCommand, cursor,
+    QueueableCommand, cursor,
     style::{Attribute, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
 };
-use std::io::{self, Write};
+use std::io::{self, BufWriter, Write};
+use std::sync::Arc;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Default size in bytes of the buffer terminal output is collected in.
+pub const DEFAULT_WRITE_BUFFER_SIZE: usize = 8 * 1024;
 
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// When buffered terminal output is flushed.
+///
+/// Flushing once per frame sends the fewest, largest writes, which suits local
+/// terminals. Over a slow link such as SSH, flushing each run of cells as it is
+/// written lets the top of the screen appear before the rest of the frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlushStrategy {
+    /// Flush once after the whole frame is written (default)
+    #[default]
+    PerFrame,
+
+    /// Flush after every run of cells
+    Immediate,
+}
+
 /// Optimized terminal renderer that tracks terminal state to minimize commands.
 ///
 /// By tracking the current cursor position, colors, and attributes, we can
@@ -35,8 +59,11 @@ use std::io::{self, Write};
 /// └─────────────────────────────────────┘
 /// ```
 pub struct TerminalRenderer {
-    /// Output stream (usually stdout)
-    stdout: io::Stdout,
+    /// Output sink (usually stdout); commands are queued and flushed per the flush strategy
+    out: CountingWriter<BufWriter<Box<dyn Write>>>,
+
+    /// When queued output is flushed to the sink
+    flush_strategy: FlushStrategy,
 
     /// Current cursor position (x, y)
     current_pos: Option<(u16, u16)>,
@@ -52,6 +79,28 @@ pub struct TerminalRenderer {
 
     /// Whether synchronized output is supported
     supports_synchronized: bool,
+
+    /// Whether OSC 8 hyperlinks are emitted for linked cells
+    supports_hyperlinks: bool,
+}
+
+/// Output written to the terminal for a single frame.
+///
+/// Useful for checking that rendering stays minimal: an unchanged frame should
+/// write nothing at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Total bytes written
+    pub bytes: usize,
+
+    /// Number of escape sequences written
+    pub sequences: usize,
+}
+
+/// Writer wrapper that counts what passes through it.
+struct CountingWriter<W> {
+    inner: W,
+    stats: FrameStats,
 }
 
 /// A terminal command abstraction for batching operations.
@@ -97,18 +146,63 @@ struct Run {
 //--------------------------------------------------------------------------------------------------
 
 impl TerminalRenderer {
-    /// Creates a new terminal renderer.
+    /// Creates a new terminal renderer writing to stdout.
     pub fn new() -> Self {
         Self {
-            stdout: io::stdout(),
+            supports_synchronized: Self::detect_synchronized_output(),
+            supports_hyperlinks: Self::detect_hyperlinks(),
+            ..Self::with_writer(io::stdout())
+        }
+    }
+
+    /// Creates a renderer writing to the given sink instead of stdout.
+    ///
+    /// Synchronized output is disabled so the emitted bytes don't depend on the
+    /// environment, which makes this suitable for capturing output in tests.
+    pub fn with_writer(writer: impl Write + 'static) -> Self {
+        Self {
+            out: CountingWriter::new(BufWriter::with_capacity(
+                DEFAULT_WRITE_BUFFER_SIZE,
+                Box::new(writer),
+            )),
+            flush_strategy: FlushStrategy::default(),
             current_pos: None,
             current_fg: None,
             current_bg: None,
             current_style: CellStyle::default(),
-            supports_synchronized: Self::detect_synchronized_output(),
+            supports_synchronized: false,
+            supports_hyperlinks: true,
         }
     }
 
+    /// Sets when queued output is flushed to the sink.
+    pub(crate) fn set_flush_strategy(&mut self, strategy: FlushStrategy) {
+        self.flush_strategy = strategy;
+    }
+
+    /// Sets the size in bytes of the output buffer, flushing what it holds first.
+    ///
+    /// Output beyond the buffer's size is written straight away even with
+    /// [`FlushStrategy::PerFrame`].
+    pub(crate) fn set_write_buffer_size(&mut self, size: usize) -> io::Result<()> {
+        if self.out.inner.capacity() == size {
+            return Ok(());
+        }
+        self.out.inner.flush()?;
+        let writer = std::mem::replace(
+            &mut self.out.inner,
+            BufWriter::with_capacity(0, Box::new(io::sink())),
+        );
+        let (sink, _) = writer.into_parts();
+        self.out.inner = BufWriter::with_capacity(size, sink);
+        Ok(())
+    }
+
+    /// Returns the bytes and escape sequences written by the most recent frame.
+    pub fn last_frame_stats(&self) -> FrameStats {
+        self.out.stats
+    }
+
     /// Detects if the terminal supports synchronized output mode.
     fn detect_synchronized_output() -> bool {
         // For now, we'll enable it for known terminals
@@ -121,6 +215,14 @@ impl TerminalRenderer {
         })
     }
 
+    /// Detects if hyperlinks can be emitted.
+    ///
+    /// Most terminals either support OSC 8 or silently ignore it, so links are
+    /// only left out for consoles known to print the sequence as text.
+    fn detect_hyperlinks() -> bool {
+        !std::env::var("TERM").is_ok_and(|term| matches!(term.as_str(), "dumb" | "linux"))
+    }
+
     /// Applies a list of cell updates to the terminal.
     ///
     /// ```text
@@ -138,6 +240,7 @@ impl TerminalRenderer {
     ///                                          └─────────────┘
     /// ```
     pub fn apply_updates(&mut self, updates: Vec<CellUpdate>) -> io::Result<()> {
+        self.out.reset_stats();
         if updates.is_empty() {
             return Ok(());
         }
@@ -152,40 +255,47 @@ impl TerminalRenderer {
 
     /// Applies updates without terminal optimizations (for debugging).
     pub fn apply_updates_direct(&mut self, updates: Vec<CellUpdate>) -> io::Result<()> {
+        self.out.reset_stats();
+
         // Process updates without optimization
         for update in updates {
             match update {
                 CellUpdate::Single { x, y, cell } => {
-                    self.stdout.execute(cursor::MoveTo(x, y))?;
+                    self.out.queue(cursor::MoveTo(x, y))?;
                     self.apply_cell_style(&cell)?;
-                    self.stdout.execute(Print(cell.char))?;
+                    self.out.queue(Print(cell.char))?;
                 }
             }
         }
 
-        self.stdout.execute(ResetColor)?;
-        self.stdout.execute(SetAttribute(Attribute::Reset))?;
-        self.stdout.flush()?;
+        self.set_link(None)?;
+        self.out.queue(ResetColor)?;
+        self.out.queue(SetAttribute(Attribute::Reset))?;
+        self.out.flush()?;
         Ok(())
     }
 
     /// Draws the entire buffer to terminal without optimization.
     pub fn draw_full_buffer(&mut self, buffer: &crate::buffer::ScreenBuffer) -> io::Result<()> {
         let (width, height) = buffer.dimensions();
+        self.out.reset_stats();
 
         for y in 0..height {
             for x in 0..width {
-                if let Some(cell) = buffer.get_cell(x, y) {
-                    self.stdout.execute(cursor::MoveTo(x, y))?;
+                if let Some(cell) = buffer.get_cell(x, y)
+                    && !cell.continuation
+                {
+                    self.out.queue(cursor::MoveTo(x, y))?;
                     self.apply_cell_style(cell)?;
-                    self.stdout.execute(Print(cell.char))?;
+                    self.out.queue(Print(cell.char))?;
                 }
             }
         }
 
-        self.stdout.execute(ResetColor)?;
-        self.stdout.execute(SetAttribute(Attribute::Reset))?;
-        self.stdout.flush()?;
+        self.set_link(None)?;
+        self.out.queue(ResetColor)?;
+        self.out.queue(SetAttribute(Attribute::Reset))?;
+        self.out.flush()?;
         Ok(())
     }
 
@@ -215,32 +325,32 @@ impl TerminalRenderer {
     /// Applies cell styling to terminal.
     fn apply_cell_style(&mut self, cell: &Cell) -> io::Result<()> {
         // Always reset attributes first to prevent bleeding from previous cells
-        self.stdout.execute(SetAttribute(Attribute::Reset))?;
+        self.out.queue(SetAttribute(Attribute::Reset))?;
 
         // Apply colors
         if let Some(fg) = &cell.fg {
-            self.stdout
-                .execute(SetForegroundColor(self.color_to_crossterm(*fg)))?;
+            self.out
+                .queue(SetForegroundColor(self.color_to_crossterm(*fg)))?;
         }
         if let Some(bg) = &cell.bg {
-            self.stdout
-                .execute(SetBackgroundColor(self.color_to_crossterm(*bg)))?;
+            self.out
+                .queue(SetBackgroundColor(self.color_to_crossterm(*bg)))?;
         }
 
         // Apply text styling attributes
         if cell.style.bold {
-            self.stdout.execute(SetAttribute(Attribute::Bold))?;
+            self.out.queue(SetAttribute(Attribute::Bold))?;
         }
         if cell.style.italic {
-            self.stdout.execute(SetAttribute(Attribute::Italic))?;
+            self.out.queue(SetAttribute(Attribute::Italic))?;
         }
         if cell.style.underline {
-            self.stdout.execute(SetAttribute(Attribute::Underlined))?;
+            self.out.queue(SetAttribute(Attribute::Underlined))?;
         }
         if cell.style.strikethrough {
-            self.stdout.execute(SetAttribute(Attribute::CrossedOut))?;
+            self.out.queue(SetAttribute(Attribute::CrossedOut))?;
         }
-        Ok(())
+        self.set_link(cell.style.link.as_ref())
     }
 
     /// Applies updates with synchronized output mode for atomic rendering.
@@ -262,13 +372,13 @@ impl TerminalRenderer {
     /// ```
     fn apply_updates_synchronized(&mut self, updates: Vec<CellUpdate>) -> io::Result<()> {
         // Begin synchronized update
-        self.stdout.execute(Print("\x1b[?2026h"))?;
+        self.out.queue(Print("\x1b[?2026h"))?;
 
         let result = self.apply_updates_optimized(updates);
 
         // End synchronized update
-        self.stdout.execute(Print("\x1b[?2026l"))?;
-        self.stdout.flush()?;
+        self.out.queue(Print("\x1b[?2026l"))?;
+        self.out.flush()?;
 
         result
     }
@@ -284,7 +394,7 @@ impl TerminalRenderer {
             self.apply_command(cmd)?;
         }
 
-        self.stdout.flush()?;
+        self.out.flush()?;
         Ok(())
     }
 
@@ -293,7 +403,8 @@ impl TerminalRenderer {
         match cmd {
             TerminalCommand::MoveTo(x, y) => {
                 if self.current_pos != Some((x, y)) {
-                    self.stdout.execute(cursor::MoveTo(x, y))?;
+                    self.out
+                        .write_all(cursor_move_sequence(self.current_pos, (x, y)).as_bytes())?;
                     self.current_pos = Some((x, y));
                 }
             }
@@ -301,18 +412,22 @@ impl TerminalRenderer {
                 self.set_colors(fg, bg)?;
             }
             TerminalCommand::Print(text) => {
-                self.stdout.execute(Print(&text))?;
+                self.out.queue(Print(&text))?;
                 // Update cursor position
                 if let Some((x, y)) = self.current_pos {
-                    self.current_pos = Some((x + text.len() as u16, y));
+                    self.current_pos = Some((x + display_width(&text) as u16, y));
+                }
+                if self.flush_strategy == FlushStrategy::Immediate {
+                    self.out.flush()?;
                 }
             }
             TerminalCommand::SetStyle(style) => {
                 self.set_style(style)?;
             }
             TerminalCommand::Reset => {
-                self.stdout.execute(ResetColor)?;
-                self.stdout.execute(SetAttribute(Attribute::Reset))?;
+                self.set_link(None)?;
+                self.out.queue(ResetColor)?;
+                self.out.queue(SetAttribute(Attribute::Reset))?;
                 self.current_fg = None;
                 self.current_bg = None;
                 self.current_style = CellStyle::default();
@@ -327,14 +442,14 @@ impl TerminalRenderer {
         if fg != self.current_fg {
             match fg {
                 Some(color) => {
-                    self.stdout
-                        .execute(SetForegroundColor(to_crossterm_color(color)))?;
+                    self.out
+                        .queue(SetForegroundColor(to_crossterm_color(color)))?;
                 }
                 None => {
                     // Reset to default foreground (usually white/gray)
                     // We use the terminal's default foreground explicitly
-                    self.stdout
-                        .execute(SetForegroundColor(crossterm::style::Color::Reset))?;
+                    self.out
+                        .queue(SetForegroundColor(crossterm::style::Color::Reset))?;
                 }
             }
             self.current_fg = fg;
@@ -344,14 +459,14 @@ impl TerminalRenderer {
         if bg != self.current_bg {
             match bg {
                 Some(color) => {
-                    self.stdout
-                        .execute(SetBackgroundColor(to_crossterm_color(color)))?;
+                    self.out
+                        .queue(SetBackgroundColor(to_crossterm_color(color)))?;
                 }
                 None => {
                     // Reset to default background (usually black/transparent)
                     // We use the terminal's default background explicitly
-                    self.stdout
-                        .execute(SetBackgroundColor(crossterm::style::Color::Reset))?;
+                    self.out
+                        .queue(SetBackgroundColor(crossterm::style::Color::Reset))?;
                 }
             }
             self.current_bg = bg;
@@ -364,27 +479,66 @@ impl TerminalRenderer {
     fn set_style(&mut self, style: CellStyle) -> io::Result<()> {
         if style != self.current_style {
             // Always reset attributes when changing style to ensure clean state
-            self.stdout.execute(SetAttribute(Attribute::Reset))?;
+            self.out.queue(SetAttribute(Attribute::Reset))?;
 
             // Apply new attributes if any are needed
             if style.bold {
-                self.stdout.execute(SetAttribute(Attribute::Bold))?;
+                self.out.queue(SetAttribute(Attribute::Bold))?;
             }
             if style.italic {
-                self.stdout.execute(SetAttribute(Attribute::Italic))?;
+                self.out.queue(SetAttribute(Attribute::Italic))?;
             }
             if style.underline {
-                self.stdout.execute(SetAttribute(Attribute::Underlined))?;
+                self.out.queue(SetAttribute(Attribute::Underlined))?;
             }
             if style.strikethrough {
-                self.stdout.execute(SetAttribute(Attribute::CrossedOut))?;
+                self.out.queue(SetAttribute(Attribute::CrossedOut))?;
             }
+            self.set_link(style.link.as_ref())?;
 
             self.current_style = style;
         }
         Ok(())
     }
 
+    /// Opens the hyperlink the following text belongs to, or closes the open one
+    /// for `None`. Does nothing if the link is unchanged or links aren't supported.
+    fn set_link(&mut self, link: Option<&Arc<str>>) -> io::Result<()> {
+        if !self.supports_hyperlinks || link == self.current_style.link.as_ref() {
+            return Ok(());
+        }
+        self.out
+            .write_all(hyperlink_sequence(link.map(|url| &**url)).as_bytes())?;
+        self.current_style.link = link.cloned();
+        Ok(())
+    }
+
+    /// Forgets the tracked cursor position, colors and attributes.
+    ///
+    /// Used when something outside the renderer changed the terminal state,
+    /// e.g. after the process was suspended and resumed.
+    pub(crate) fn invalidate(&mut self) {
+        self.current_pos = None;
+        self.current_fg = None;
+        self.current_bg = None;
+        self.current_style = CellStyle::default();
+    }
+
+    /// Shows the cursor at `position`, or hides it for `None`.
+    pub(crate) fn set_cursor(&mut self, position: Option<(u16, u16)>) -> io::Result<()> {
+        match position {
+            Some((x, y)) => {
+                self.out.queue(cursor::MoveTo(x, y))?;
+                self.out.queue(cursor::Show)?;
+                self.current_pos = Some((x, y));
+            }
+            None => {
+                self.out.queue(cursor::Hide)?;
+            }
+        }
+        self.out.flush()
+    }
+
     /// Resets the renderer state.
     #[allow(dead_code)]
     pub fn reset(&mut self) -> io::Result<()> {
@@ -521,8 +675,9 @@ impl Run {
     ///   Next expected: (8,10)         Actual: (9,10) ✗
     /// ```
     fn can_append(&self, x: u16, y: u16, cell: &Cell) -> bool {
-        // Must be on same line and consecutive
-        if y != self.y || x != self.x + self.cells.len() as u16 {
+        // Must be on same line and consecutive, counting wide characters as two columns
+        let run_width: u16 = self.cells.iter().map(|c| char_width(c.char) as u16).sum();
+        if y != self.y || x != self.x + run_width {
             return false;
         }
         // Must have same style
@@ -555,10 +710,165 @@ impl Run {
     }
 }
 
+impl<W: Write> CountingWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            stats: FrameStats::default(),
+        }
+    }
+
+    /// Starts counting a new frame.
+    fn reset_stats(&mut self) {
+        self.stats = FrameStats::default();
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Asks the terminal for its background color (OSC 11) and returns the raw reply.
+///
+/// Must be called in raw mode before any events are read, otherwise the reply
+/// would be consumed as key presses. Returns `None` when stdin is not a terminal
+/// or nothing arrives before the timeout.
+#[cfg(unix)]
+pub(crate) fn query_background_color(timeout: std::time::Duration) -> Option<Vec<u8>> {
+    use std::os::unix::io::AsRawFd;
+    use std::time::Instant;
+
+    let fd = io::stdin().as_raw_fd();
+    // SAFETY: isatty only inspects the descriptor
+    if unsafe { libc::isatty(fd) } != 1 {
+        return None;
+    }
+
+    let mut stdout = io::stdout();
+    stdout.write_all(b"\x1b]11;?\x1b\\").ok()?;
+    stdout.flush().ok()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut response = Vec::new();
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: pollfd is a valid, initialized struct and we pass a count of one
+        let ready = unsafe { libc::poll(&mut pollfd, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+
+        // Read straight from the descriptor so no reply bytes end up in std's stdin buffer
+        let mut buf = [0u8; 64];
+        // SAFETY: buf is valid for writes of buf.len() bytes
+        let read = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+        if read <= 0 {
+            break;
+        }
+        response.extend_from_slice(&buf[..read as usize]);
+
+        // Replies end with either BEL or ST (ESC \)
+        if response.ends_with(b"\x07") || response.ends_with(b"\x1b\\") {
+            break;
+        }
+    }
+
+    (!response.is_empty()).then_some(response)
+}
+
+/// Background color queries are not supported on this platform.
+#[cfg(not(unix))]
+pub(crate) fn query_background_color(_timeout: std::time::Duration) -> Option<Vec<u8>> {
+    None
+}
+
+/// Sets up the screen for the app: ANSI support on Windows, alternate screen,
+/// hidden cursor, mouse capture and bracketed paste. Raw mode is enabled
+/// separately by the caller.
+pub(crate) fn init_terminal(out: &mut impl Write) -> io::Result<()> {
+    use crossterm::{event, terminal};
+
+    // The renderer writes escape sequences directly, which Windows consoles
+    // only interpret once virtual terminal processing is on
+    #[cfg(windows)]
+    if !crossterm::ansi_support::supports_ansi() {
+        crate::debug_log!(warn: "console does not support ANSI escape sequences");
+    }
+
+    out.queue(terminal::EnterAlternateScreen)?;
+    out.queue(cursor::Hide)?;
+    out.queue(event::EnableMouseCapture)?;
+    out.queue(event::EnableBracketedPaste)?;
+    out.flush()
+}
+
+/// Undoes the terminal setup done by the app: bracketed paste, mouse capture,
+/// hidden cursor, alternate screen and finally raw mode.
+///
+/// Every step is attempted even if an earlier one fails, since this also runs
+/// while panicking; the first error is returned.
+pub(crate) fn restore_terminal(
+    out: &mut impl Write,
+    disable_raw_mode: impl FnOnce() -> io::Result<()>,
+) -> io::Result<()> {
+    use crossterm::{event, terminal};
+
+    let results = [
+        out.queue(event::DisableBracketedPaste).map(|_| ()),
+        out.queue(event::DisableMouseCapture).map(|_| ()),
+        out.queue(cursor::Show).map(|_| ()),
+        out.queue(terminal::LeaveAlternateScreen).map(|_| ()),
+        // Flush so every command is sent before leaving raw mode
+        out.flush(),
+        disable_raw_mode(),
+    ];
+    results.into_iter().collect()
+}
+
+/// Returns the OSC 8 sequence that starts a hyperlink to `url`, or ends the
+/// current one for `None`.
+///
+/// Control characters are dropped from the URL so it can't end the sequence early.
+fn hyperlink_sequence(url: Option<&str>) -> String {
+    let url: String = url
+        .unwrap_or_default()
+        .chars()
+        .filter(|ch| !ch.is_control())
+        .collect();
+    format!("\x1b]8;;{url}\x1b\\")
+}
+
+/// Returns the shortest escape sequence that moves the cursor from `from` to `to`.
+///
+/// Forward moves on the same row use `CUF` (`\x1b[C` / `\x1b[nC`), which is
+/// always shorter than absolute positioning. Backward moves always use absolute
+/// positioning: after printing in the last column the terminal holds the cursor
+/// in a pending-wrap state where relative moves backwards are off by one.
+///
+/// ```text
+/// from (2,0) to (5,0):  \x1b[3C      (4 bytes)
+/// from (2,0) to (3,0):  \x1b[C       (3 bytes)
+/// from (9,0) to (2,4):  \x1b[5;3H    (6 bytes)
+/// ```
+fn cursor_move_sequence(from: Option<(u16, u16)>, to: (u16, u16)) -> String {
+    match from {
+        Some((x, y)) if y == to.1 && to.0 > x => match to.0 - x {
+            1 => "\x1b[C".to_string(),
+            n => format!("\x1b[{n}C"),
+        },
+        _ => format!("\x1b[{};{}H", to.1 + 1, to.0 + 1),
+    }
+}
+
 /// Converts our Color enum to crossterm's Color type.
 fn to_crossterm_color(color: Color) -> crossterm::style::Color {
     match color {
@@ -592,6 +902,19 @@ impl Default for TerminalRenderer {
     }
 }
 
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.stats.bytes += written;
+        self.stats.sequences += buf[..written].iter().filter(|&&b| b == 0x1b).count();
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -599,8 +922,61 @@ impl Default for TerminalRenderer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::buffer::{Cell, CellStyle, CellUpdate};
+    use crate::buffer::{Cell, CellStyle, CellUpdate, DoubleBuffer};
     use crate::style::Color;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Sink that keeps everything written to it for inspection
+    #[derive(Clone, Default)]
+    struct Capture(Rc<RefCell<Vec<u8>>>);
+
+    impl Capture {
+        fn take(&self) -> Vec<u8> {
+            std::mem::take(&mut *self.0.borrow_mut())
+        }
+    }
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Sink that counts the flushes that reach it
+    #[derive(Clone, Default)]
+    struct FlushCounter(Rc<std::cell::Cell<usize>>);
+
+    impl Write for FlushCounter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.0.set(self.0.get() + 1);
+            Ok(())
+        }
+    }
+
+    /// Diffs the double buffer, writes the changes and swaps like the app does each frame
+    /// Applies updates through the batched path used with synchronized output,
+    /// without the begin/end markers around the frame
+    fn apply_batched(renderer: &mut TerminalRenderer, updates: Vec<CellUpdate>) {
+        renderer.out.reset_stats();
+        if !updates.is_empty() {
+            renderer.apply_updates_optimized(updates).unwrap();
+        }
+    }
+
+    fn draw_frame(renderer: &mut TerminalRenderer, buffers: &mut DoubleBuffer) {
+        apply_batched(renderer, buffers.diff());
+        buffers.swap();
+    }
 
     #[test]
     fn test_update_batcher_single_cell() {
@@ -612,6 +988,7 @@ mod tests {
                 fg: Some(Color::Red),
                 bg: Some(Color::Blue),
                 style: CellStyle::default(),
+                continuation: false,
             },
         }];
 
@@ -643,6 +1020,7 @@ mod tests {
                     fg: Some(Color::Green),
                     bg: None,
                     style: style.clone(),
+                    continuation: false,
                 },
             },
             CellUpdate::Single {
@@ -653,6 +1031,7 @@ mod tests {
                     fg: Some(Color::Green),
                     bg: None,
                     style: style.clone(),
+                    continuation: false,
                 },
             },
             CellUpdate::Single {
@@ -663,6 +1042,7 @@ mod tests {
                     fg: Some(Color::Green),
                     bg: None,
                     style: style.clone(),
+                    continuation: false,
                 },
             },
             CellUpdate::Single {
@@ -673,6 +1053,7 @@ mod tests {
                     fg: Some(Color::Green),
                     bg: None,
                     style: style.clone(),
+                    continuation: false,
                 },
             },
             CellUpdate::Single {
@@ -683,6 +1064,7 @@ mod tests {
                     fg: Some(Color::Green),
                     bg: None,
                     style,
+                    continuation: false,
                 },
             },
         ];
@@ -707,6 +1089,7 @@ mod tests {
                     fg: Some(Color::Red),
                     bg: None,
                     style: CellStyle::default(),
+                    continuation: false,
                 },
             },
             CellUpdate::Single {
@@ -717,6 +1100,7 @@ mod tests {
                     fg: Some(Color::Blue),
                     bg: None,
                     style: CellStyle::default(),
+                    continuation: false,
                 },
             },
         ];
@@ -788,6 +1172,7 @@ mod tests {
             fg: Some(Color::Red),
             bg: Some(Color::Blue),
             style: CellStyle::default(),
+            continuation: false,
         };
 
         let run = Run::new(5, 10, cell1.clone());
@@ -798,6 +1183,7 @@ mod tests {
             fg: Some(Color::Red),
             bg: Some(Color::Blue),
             style: CellStyle::default(),
+            continuation: false,
         };
         assert!(run.can_append(6, 10, &cell2));
 
@@ -813,6 +1199,7 @@ mod tests {
             fg: Some(Color::Green),
             bg: Some(Color::Blue),
             style: CellStyle::default(),
+            continuation: false,
         };
         assert!(!run.can_append(6, 10, &cell3));
     }
@@ -832,6 +1219,7 @@ mod tests {
                 fg: None,
                 bg: None,
                 style,
+                continuation: false,
             },
         }];
 
@@ -934,4 +1322,178 @@ mod tests {
         assert_eq!(runs[0].cells.len(), 2); // "AB"
         assert_eq!(runs[1].cells.len(), 2); // "CD"
     }
+
+    #[test]
+    fn test_unchanged_frame_writes_nothing() {
+        let capture = Capture::default();
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        let mut buffers = DoubleBuffer::new(10, 3);
+
+        buffers
+            .back_buffer_mut()
+            .write_str(0, 0, "hello", None, None);
+        draw_frame(&mut renderer, &mut buffers);
+        assert!(!capture.take().is_empty());
+
+        // Same content again: nothing to send
+        buffers.clear_back();
+        buffers
+            .back_buffer_mut()
+            .write_str(0, 0, "hello", None, None);
+        draw_frame(&mut renderer, &mut buffers);
+        assert!(capture.take().is_empty());
+        assert_eq!(renderer.last_frame_stats(), FrameStats::default());
+    }
+
+    #[test]
+    fn test_single_cell_change_writes_move_and_char() {
+        let capture = Capture::default();
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        let mut buffers = DoubleBuffer::new(10, 3);
+        draw_frame(&mut renderer, &mut buffers);
+        capture.take();
+
+        buffers.clear_back();
+        buffers.back_buffer_mut().set_cell(5, 2, Cell::new('X'));
+        draw_frame(&mut renderer, &mut buffers);
+
+        assert_eq!(capture.take(), b"\x1b[3;6HX");
+        assert_eq!(
+            renderer.last_frame_stats(),
+            FrameStats {
+                bytes: 7,
+                sequences: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_cursor_move_sequence_prefers_shortest_form() {
+        assert_eq!(cursor_move_sequence(None, (4, 2)), "\x1b[3;5H");
+        assert_eq!(cursor_move_sequence(Some((2, 0)), (3, 0)), "\x1b[C");
+        assert_eq!(cursor_move_sequence(Some((2, 0)), (12, 0)), "\x1b[10C");
+
+        // Backward and cross-row moves stay absolute
+        assert_eq!(cursor_move_sequence(Some((9, 0)), (2, 0)), "\x1b[1;3H");
+        assert_eq!(cursor_move_sequence(Some((2, 0)), (5, 1)), "\x1b[2;6H");
+    }
+
+    #[test]
+    fn test_contiguous_run_is_written_without_repositioning() {
+        let capture = Capture::default();
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+
+        // Adjacent cells, even with different styles, follow the cursor
+        let updates = vec![
+            CellUpdate::Single {
+                x: 2,
+                y: 0,
+                cell: Cell::new('A'),
+            },
+            CellUpdate::Single {
+                x: 3,
+                y: 0,
+                cell: Cell::new('B'),
+            },
+            CellUpdate::Single {
+                x: 4,
+                y: 0,
+                cell: Cell::new('C').with_fg(Color::Red),
+            },
+        ];
+        apply_batched(&mut renderer, updates);
+        let contiguous = capture.take();
+        assert_eq!(contiguous.iter().filter(|&&b| b == b'H').count(), 1);
+        assert!(contiguous.starts_with(b"\x1b[1;3HAB"));
+
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        let updates = vec![
+            CellUpdate::Single {
+                x: 2,
+                y: 0,
+                cell: Cell::new('A'),
+            },
+            CellUpdate::Single {
+                x: 5,
+                y: 0,
+                cell: Cell::new('B'),
+            },
+            CellUpdate::Single {
+                x: 6,
+                y: 3,
+                cell: Cell::new('C'),
+            },
+        ];
+        apply_batched(&mut renderer, updates);
+
+        // Gaps on a row skip forward; a new row needs absolute positioning
+        assert_eq!(capture.take(), b"\x1b[1;3HA\x1b[2CB\x1b[4;7HC");
+        assert_eq!(renderer.last_frame_stats().sequences, 3);
+    }
+
+    #[test]
+    fn test_link_text_is_wrapped_in_osc8() {
+        let node = crate::RichText::new()
+            .text("see ")
+            .link("docs", "https://example.com/docs")
+            .text("!")
+            .into();
+        let buffer = crate::components::render_to_buffer(node, 12, 1);
+
+        let capture = Capture::default();
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        apply_batched(&mut renderer, buffer.all_updates());
+        let output = String::from_utf8(capture.take()).unwrap();
+        assert!(
+            output.contains("\x1b]8;;https://example.com/docs\x1b\\docs\x1b]8;;\x1b\\"),
+            "{output:?}"
+        );
+        assert_eq!(output.matches("\x1b]8;;").count(), 2, "{output:?}");
+
+        // Without hyperlink support the text is written plainly
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        renderer.supports_hyperlinks = false;
+        apply_batched(&mut renderer, buffer.all_updates());
+        let output = String::from_utf8(capture.take()).unwrap();
+        assert!(output.contains("docs") && !output.contains("\x1b]8;;"));
+    }
+
+    #[test]
+    fn test_flush_strategy_controls_flushes_per_frame() {
+        // Three runs on separate rows
+        let updates = || {
+            (0..3)
+                .map(|y| CellUpdate::Single {
+                    x: 0,
+                    y,
+                    cell: Cell::new('x'),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let flushes = FlushCounter::default();
+        let mut renderer = TerminalRenderer::with_writer(flushes.clone());
+        apply_batched(&mut renderer, updates());
+        assert_eq!(flushes.0.get(), 1);
+
+        let flushes = FlushCounter::default();
+        let mut renderer = TerminalRenderer::with_writer(flushes.clone());
+        renderer.set_flush_strategy(FlushStrategy::Immediate);
+        apply_batched(&mut renderer, updates());
+        // One flush per run, then the end of the frame
+        assert_eq!(flushes.0.get(), 4);
+    }
+
+    #[test]
+    fn test_write_buffer_size_keeps_pending_output() {
+        let capture = Capture::default();
+        let mut renderer = TerminalRenderer::with_writer(capture.clone());
+        renderer.out.write_all(b"pending").unwrap();
+        renderer.set_write_buffer_size(4).unwrap();
+        assert_eq!(capture.take(), b"pending");
+
+        // Output larger than the buffer goes straight through before the flush
+        renderer.out.write_all(b"overflow").unwrap();
+        assert_eq!(capture.take(), b"overflow");
+    }
 }
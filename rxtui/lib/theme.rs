@@ -0,0 +1,281 @@
+use crate::style::Color;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Semantic color palette shared by every component through the [`Context`](crate::Context).
+///
+/// Components read colors by role instead of hardcoding them, so switching the
+/// theme restyles the whole application:
+///
+/// ```rust,ignore
+/// let theme = ctx.theme();
+/// node! {
+///     div(bg: (theme.background), border: (theme.border)) [
+///         text("Hello", color: (theme.text))
+///     ]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// Whether this palette is meant for a dark or light background
+    pub mode: ThemeMode,
+
+    /// Main brand color for prominent elements
+    pub primary: Color,
+
+    /// Supporting color for less prominent elements
+    pub secondary: Color,
+
+    /// Highlight color for focus rings and selections
+    pub accent: Color,
+
+    /// Application background
+    pub background: Color,
+
+    /// Background for raised surfaces like panels and dialogs
+    pub surface: Color,
+
+    /// Default text color
+    pub text: Color,
+
+    /// Text color for hints and secondary information
+    pub muted: Color,
+
+    /// Border color
+    pub border: Color,
+
+    /// Color for success states
+    pub success: Color,
+
+    /// Color for warnings
+    pub warning: Color,
+
+    /// Color for errors
+    pub error: Color,
+}
+
+/// Brightness of the terminal background a theme is designed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    /// Dark background with light text
+    #[default]
+    Dark,
+
+    /// Light background with dark text
+    Light,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ThemeMode {
+    /// Returns the opposite mode.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Dark => Self::Light,
+            Self::Light => Self::Dark,
+        }
+    }
+
+    /// Picks a mode from the terminal's reply to an OSC 11 background color query.
+    ///
+    /// Terminals that do not answer (or answer with something unparseable) get `Dark`.
+    pub fn detect(response: Option<&[u8]>) -> Self {
+        response
+            .and_then(Self::from_osc11_response)
+            .unwrap_or_default()
+    }
+
+    /// Parses an OSC 11 reply such as `ESC ] 11 ; rgb:ffff/ffff/ffff BEL`.
+    ///
+    /// Each channel may have 1-4 hex digits. The background counts as light
+    /// when its relative luminance is above one half.
+    pub fn from_osc11_response(response: &[u8]) -> Option<Self> {
+        let text = std::str::from_utf8(response).ok()?;
+        let start = text.find("rgb:")? + 4;
+        let body = text[start..].trim_end_matches(['\x07', '\x1b', '\\']);
+
+        let mut channels = body.split('/').map(|channel| {
+            let digits = channel.get(..channel.len().min(4))?;
+            let value = u32::from_str_radix(digits, 16).ok()?;
+            let max = (1u32 << (4 * digits.len())) - 1;
+            Some(value as f32 / max as f32)
+        });
+        let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+
+        let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        Some(if luminance > 0.5 {
+            Self::Light
+        } else {
+            Self::Dark
+        })
+    }
+}
+
+impl Theme {
+    /// Returns the built-in palette for the given mode.
+    pub fn for_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Dark => Self::dark(),
+            ThemeMode::Light => Self::light(),
+        }
+    }
+
+    /// Palette for terminals with a dark background.
+    pub fn dark() -> Self {
+        Self {
+            mode: ThemeMode::Dark,
+            primary: Color::Rgb(97, 175, 239),
+            secondary: Color::Rgb(198, 120, 221),
+            accent: Color::Rgb(229, 192, 123),
+            background: Color::Rgb(30, 30, 30),
+            surface: Color::Rgb(45, 45, 45),
+            text: Color::Rgb(220, 220, 220),
+            muted: Color::Rgb(128, 128, 128),
+            border: Color::Rgb(80, 80, 80),
+            success: Color::Rgb(152, 195, 121),
+            warning: Color::Rgb(229, 192, 123),
+            error: Color::Rgb(224, 108, 117),
+        }
+    }
+
+    /// Palette for terminals with a light background.
+    pub fn light() -> Self {
+        Self {
+            mode: ThemeMode::Light,
+            primary: Color::Rgb(1, 112, 196),
+            secondary: Color::Rgb(135, 60, 170),
+            accent: Color::Rgb(193, 132, 1),
+            background: Color::Rgb(250, 250, 250),
+            surface: Color::Rgb(235, 235, 235),
+            text: Color::Rgb(40, 40, 40),
+            muted: Color::Rgb(120, 120, 120),
+            border: Color::Rgb(190, 190, 190),
+            success: Color::Rgb(64, 140, 60),
+            warning: Color::Rgb(193, 132, 1),
+            error: Color::Rgb(202, 18, 67),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{Div, Node};
+    use crate::{Component, Context};
+    use std::any::Any;
+
+    struct Panel;
+
+    impl Component for Panel {
+        fn view(&self, ctx: &Context) -> Node {
+            Div::new().background(ctx.theme().background).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn panel_background(ctx: &Context) -> Option<Color> {
+        match Panel.view(ctx) {
+            Node::Div(div) => div.styles.base.and_then(|style| style.background),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_themes_produce_different_backgrounds() {
+        let dark = Context::default();
+        dark.set_theme(Theme::dark());
+        let light = Context::default();
+        light.set_theme(Theme::light());
+
+        assert_eq!(panel_background(&dark), Some(Theme::dark().background));
+        assert_eq!(panel_background(&light), Some(Theme::light().background));
+        assert_ne!(panel_background(&dark), panel_background(&light));
+    }
+
+    #[test]
+    fn test_light_response_selects_light_theme() {
+        let bel = b"\x1b]11;rgb:ffff/ffff/ffff\x07";
+        let st = b"\x1b]11;rgb:fafa/f0f0/e6e6\x1b\\";
+        assert_eq!(ThemeMode::detect(Some(bel)), ThemeMode::Light);
+        assert_eq!(ThemeMode::detect(Some(st)), ThemeMode::Light);
+        assert_eq!(
+            Theme::for_mode(ThemeMode::detect(Some(bel))),
+            Theme::light()
+        );
+    }
+
+    #[test]
+    fn test_dark_or_missing_response_selects_dark_theme() {
+        assert_eq!(
+            ThemeMode::detect(Some(b"\x1b]11;rgb:1e1e/1e1e/1e1e\x07")),
+            ThemeMode::Dark
+        );
+        assert_eq!(
+            ThemeMode::detect(Some(b"\x1b]11;rgb:0/0/0\x07")),
+            ThemeMode::Dark
+        );
+        assert_eq!(ThemeMode::detect(Some(b"garbage")), ThemeMode::Dark);
+        assert_eq!(ThemeMode::detect(None), ThemeMode::Dark);
+    }
+
+    #[test]
+    fn test_short_channels_are_scaled() {
+        assert_eq!(
+            ThemeMode::from_osc11_response(b"\x1b]11;rgb:f/f/f\x07"),
+            Some(ThemeMode::Light)
+        );
+        assert_eq!(
+            ThemeMode::from_osc11_response(b"\x1b]11;rgb:20/20/20\x07"),
+            Some(ThemeMode::Dark)
+        );
+    }
+
+    #[test]
+    fn test_toggle_theme_switches_mode() {
+        let ctx = Context::default();
+        assert_eq!(ctx.theme().mode, ThemeMode::Dark);
+
+        ctx.toggle_theme();
+        assert_eq!(ctx.theme(), Theme::light());
+        assert!(ctx.take_render_request());
+
+        ctx.toggle_theme();
+        assert_eq!(ctx.theme(), Theme::dark());
+    }
+
+    #[test]
+    fn test_switching_theme_requests_render() {
+        let ctx = Context::default();
+        assert!(!ctx.take_render_request());
+
+        ctx.child(0).set_theme(Theme::light());
+        assert!(ctx.take_render_request());
+        assert!(!ctx.take_render_request());
+        assert_eq!(panel_background(&ctx), Some(Theme::light().background));
+    }
+}
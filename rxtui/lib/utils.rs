@@ -4,7 +4,17 @@
 //! including calculating the display width of Unicode strings and characters,
 //! and text wrapping algorithms for fitting text within width constraints.
 
-use crate::style::TextWrap;
+use crate::node::TextSpan;
+use crate::style::{TextAlign, TextStyle, TextWrap};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::OsString;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::RwLock;
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 //--------------------------------------------------------------------------------------------------
@@ -12,27 +22,27 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 //--------------------------------------------------------------------------------------------------
 
 /// Debug logging macro that only compiles in debug builds.
-/// Writes timestamped messages to /tmp/radical_debug.log
+///
+/// Writes timestamped messages at debug level, or at the level named before
+/// the message as in `debug_log!(warn: "...")`. See [`debug_log_path`] for
+/// where they go and `RXTUI_LOG_LEVEL` for filtering them.
 #[cfg(debug_assertions)]
 #[macro_export]
 macro_rules! debug_log {
+    (error: $($arg:tt)*) => {
+        $crate::write_debug_log($crate::LogLevel::Error, format_args!($($arg)*))
+    };
+    (warn: $($arg:tt)*) => {
+        $crate::write_debug_log($crate::LogLevel::Warn, format_args!($($arg)*))
+    };
+    (info: $($arg:tt)*) => {
+        $crate::write_debug_log($crate::LogLevel::Info, format_args!($($arg)*))
+    };
+    (trace: $($arg:tt)*) => {
+        $crate::write_debug_log($crate::LogLevel::Trace, format_args!($($arg)*))
+    };
     ($($arg:tt)*) => {
-        {
-            use std::fs::OpenOptions;
-            use std::io::Write;
-            if let Ok(mut file) = OpenOptions::new()
-                .create(true)
-                .write(true)
-                .append(true)
-                .open("/tmp/radical_debug.log")
-            {
-                let timestamp = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_millis();
-                let _ = writeln!(file, "[{}] {}", timestamp, format!($($arg)*));
-            }
-        }
+        $crate::write_debug_log($crate::LogLevel::Debug, format_args!($($arg)*))
     };
 }
 
@@ -43,6 +53,167 @@ macro_rules! debug_log {
     ($($arg:tt)*) => {};
 }
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Name of the file `debug_log!` appends to in the temp dir unless another path is set.
+const DEFAULT_DEBUG_LOG_FILE: &str = "radical_debug.log";
+
+/// Environment variable naming the file `debug_log!` appends to.
+const DEBUG_LOG_ENV: &str = "RXTUI_LOG";
+
+/// Environment variable setting the lowest level `debug_log!` writes.
+const DEBUG_LOG_LEVEL_ENV: &str = "RXTUI_LOG_LEVEL";
+
+/// Number of columns between tab stops when a text style doesn't set one.
+pub const DEFAULT_TAB_WIDTH: u8 = 4;
+
+/// Words with fewer characters than this are broken without a hyphen.
+const MIN_HYPHENATED_WORD: usize = 5;
+
+/// Number of wrapped texts [`wrap_text_cached`] keeps per thread.
+const WRAP_CACHE_CAPACITY: usize = 256;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Severity of a `debug_log!` message, from most to least severe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    #[default]
+    Debug,
+    Trace,
+}
+
+/// Hit and miss counts of the calling thread's [`wrap_text_cached`] cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WrapCacheStats {
+    /// Calls answered from the cache
+    pub hits: u64,
+
+    /// Calls that had to wrap the text
+    pub misses: u64,
+}
+
+/// Least-recently-used cache of wrapped lines keyed by `(text hash, width, mode)`.
+#[derive(Default)]
+struct WrapCache {
+    entries: HashMap<(u64, u16, TextWrap), WrapCacheEntry>,
+
+    /// Counter stamped on entries as they are used
+    tick: u64,
+
+    stats: WrapCacheStats,
+}
+
+/// Lines wrapped from one text in [`WrapCache`].
+struct WrapCacheEntry {
+    /// Source text, compared on lookup so a hash collision is only a miss
+    text: String,
+
+    lines: Vec<String>,
+
+    /// Cache tick of the last lookup that returned this entry
+    used: u64,
+}
+
+/// Counts describing a piece of text, as shown in an editor's status line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextMetrics {
+    /// Unicode scalar values, including newlines
+    pub chars: usize,
+
+    /// User-perceived characters, with `\r\n` counted as one
+    pub graphemes: usize,
+
+    /// Whitespace-separated words
+    pub words: usize,
+
+    /// Columns taken by the widest line
+    pub display_width: usize,
+
+    /// Lines, counting the empty line after a trailing newline
+    pub lines: usize,
+}
+
+thread_local! {
+    static WRAP_CACHE: RefCell<WrapCache> = RefCell::new(WrapCache::default());
+}
+
+/// File set with [`set_debug_log_path`], if any.
+static DEBUG_LOG_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+//--------------------------------------------------------------------------------------------------
+// Functions: Debug Logging
+//--------------------------------------------------------------------------------------------------
+
+/// Sets the file the `debug_log!` macro appends to in debug builds.
+pub fn set_debug_log_path(path: impl Into<PathBuf>) {
+    *DEBUG_LOG_PATH.write().unwrap() = Some(path.into());
+}
+
+/// Returns the file the `debug_log!` macro appends to: the path set with
+/// [`set_debug_log_path`], else the `RXTUI_LOG` environment variable, else
+/// `radical_debug.log` in the platform's temp dir.
+pub fn debug_log_path() -> PathBuf {
+    let configured = DEBUG_LOG_PATH.read().unwrap().clone();
+    resolve_debug_log_path(configured, std::env::var_os(DEBUG_LOG_ENV))
+}
+
+/// Picks the debug log file from the configured path and the `RXTUI_LOG` value.
+/// The fallback goes through `temp_dir` since `/tmp` does not exist on Windows.
+fn resolve_debug_log_path(configured: Option<PathBuf>, env: Option<OsString>) -> PathBuf {
+    match (configured, env) {
+        (Some(path), _) => path,
+        (None, Some(path)) if !path.is_empty() => PathBuf::from(path),
+        _ => std::env::temp_dir().join(DEFAULT_DEBUG_LOG_FILE),
+    }
+}
+
+/// Returns the lowest level `debug_log!` writes, from the `RXTUI_LOG_LEVEL`
+/// environment variable.
+fn debug_log_level() -> LogLevel {
+    resolve_debug_log_level(std::env::var(DEBUG_LOG_LEVEL_ENV).ok().as_deref())
+}
+
+/// Picks the lowest level to write from the `RXTUI_LOG_LEVEL` value; debug when
+/// unset or unknown.
+fn resolve_debug_log_level(env: Option<&str>) -> LogLevel {
+    env.and_then(|level| level.parse().ok()).unwrap_or_default()
+}
+
+/// Returns whether a message at `level` is written when `threshold` is the
+/// lowest level enabled.
+fn is_log_level_enabled(level: LogLevel, threshold: LogLevel) -> bool {
+    level <= threshold
+}
+
+/// Appends a timestamped message to the debug log if its level is enabled.
+#[doc(hidden)]
+pub fn write_debug_log(level: LogLevel, message: std::fmt::Arguments<'_>) {
+    use std::io::Write;
+
+    if !is_log_level_enabled(level, debug_log_level()) {
+        return;
+    }
+    if let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(debug_log_path())
+    {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let _ = writeln!(file, "[{timestamp}] {level} {message}");
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions: Display Width
 //--------------------------------------------------------------------------------------------------
@@ -57,6 +228,74 @@ pub fn display_width(s: &str) -> usize {
     UnicodeWidthStr::width(s)
 }
 
+/// Returns the display width of a string with tabs expanded to the next tab stop.
+pub fn display_width_with_tabs(s: &str, tab_width: u8) -> usize {
+    display_width(&expand_tabs(s, tab_width))
+}
+
+/// Replaces each tab with spaces up to the next multiple of `tab_width` columns.
+///
+/// Columns are counted from the start of the string and restart after each
+/// newline. Strings without tabs are returned unchanged.
+pub fn expand_tabs(s: &str, tab_width: u8) -> Cow<'_, str> {
+    expand_tabs_from(s, tab_width, &mut 0)
+}
+
+/// Expands tabs starting at column `column`, leaving it at the column after `s`.
+fn expand_tabs_from<'a>(s: &'a str, tab_width: u8, column: &mut usize) -> Cow<'a, str> {
+    let tab_width = tab_width.max(1) as usize;
+    if !s.contains('\t') {
+        *column = match s.rfind('\n') {
+            Some(newline) => display_width(&s[newline + 1..]),
+            None => *column + display_width(s),
+        };
+        return Cow::Borrowed(s);
+    }
+
+    let mut expanded = String::with_capacity(s.len() + tab_width);
+    for grapheme in s.graphemes(true) {
+        match grapheme {
+            "\t" => {
+                let spaces = tab_width - *column % tab_width;
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                *column += spaces;
+            }
+            "\n" | "\r\n" => {
+                expanded.push_str(grapheme);
+                *column = 0;
+            }
+            _ => {
+                expanded.push_str(grapheme);
+                *column += display_width(grapheme);
+            }
+        }
+    }
+    Cow::Owned(expanded)
+}
+
+/// Expands tabs across consecutive spans, which share one line's tab stops.
+pub(crate) fn expand_span_tabs(spans: &[TextSpan], tab_width: u8) -> Cow<'_, [TextSpan]> {
+    if !spans.iter().any(|span| span.content.contains('\t')) {
+        return Cow::Borrowed(spans);
+    }
+
+    let mut column = 0;
+    Cow::Owned(
+        spans
+            .iter()
+            .map(|span| TextSpan {
+                content: expand_tabs_from(&span.content, tab_width, &mut column).into_owned(),
+                ..span.clone()
+            })
+            .collect(),
+    )
+}
+
+/// Returns the tab width set by a text style, or the default.
+pub(crate) fn tab_width_of(style: Option<&TextStyle>) -> u8 {
+    style.and_then(|s| s.tab_width).unwrap_or(DEFAULT_TAB_WIDTH)
+}
+
 /// Returns the display width of a character in terminal columns.
 ///
 /// Returns:
@@ -115,6 +354,139 @@ pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions: Grapheme Clusters
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the char offsets at which grapheme clusters start, plus the total char count.
+///
+/// A grapheme cluster is what a user perceives as one character, such as a flag,
+/// an emoji with a skin tone, or a ZWJ family sequence made of several code points.
+///
+/// ```text
+/// "a👨‍👩‍👧b" → [0, 1, 6, 7]
+///   a = chars 0..1, family = chars 1..6, b = chars 6..7
+/// ```
+pub fn grapheme_boundaries(s: &str) -> Vec<usize> {
+    let mut boundaries = Vec::new();
+    let mut offset = 0;
+    for grapheme in s.graphemes(true) {
+        boundaries.push(offset);
+        offset += grapheme.chars().count();
+    }
+    boundaries.push(offset);
+    boundaries
+}
+
+/// Returns the grapheme boundary before the given char offset (or 0).
+pub fn prev_grapheme_boundary(s: &str, char_pos: usize) -> usize {
+    grapheme_boundaries(s)
+        .into_iter()
+        .rev()
+        .find(|&boundary| boundary < char_pos)
+        .unwrap_or(0)
+}
+
+/// Returns the grapheme boundary after the given char offset (or the char count).
+pub fn next_grapheme_boundary(s: &str, char_pos: usize) -> usize {
+    let boundaries = grapheme_boundaries(s);
+    let end = boundaries.last().copied().unwrap_or(0);
+    boundaries
+        .into_iter()
+        .find(|&boundary| boundary > char_pos)
+        .unwrap_or(end)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions: Text Metrics
+//--------------------------------------------------------------------------------------------------
+
+/// Counts the characters, grapheme clusters, words and lines of a text, and
+/// measures the display width of its widest line.
+///
+/// ```text
+/// "日本\nabc" → chars 6, graphemes 6, words 2, display_width 4, lines 2
+/// ```
+pub fn text_metrics(s: &str) -> TextMetrics {
+    let display_width = s
+        .split('\n')
+        .map(|line| display_width(line.strip_suffix('\r').unwrap_or(line)))
+        .max()
+        .unwrap_or(0);
+
+    TextMetrics {
+        chars: s.chars().count(),
+        graphemes: s.graphemes(true).count(),
+        words: s.split_whitespace().count(),
+        display_width,
+        lines: s.matches('\n').count() + 1,
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions: Alignment
+//--------------------------------------------------------------------------------------------------
+
+/// Cuts a string down to at most `width` display columns.
+///
+/// With `ellipsis`, a truncated string ends in `…` so the cut is visible.
+/// Wide characters that would straddle the limit are dropped rather than split.
+pub fn truncate_to_width(s: &str, width: usize, ellipsis: bool) -> String {
+    if display_width(s) <= width {
+        return s.to_string();
+    }
+
+    if ellipsis && width > 0 {
+        let mut truncated = substring_by_columns(s, 0, width - 1).to_string();
+        truncated.push('…');
+        truncated
+    } else {
+        substring_by_columns(s, 0, width).to_string()
+    }
+}
+
+/// Pads a string with spaces to exactly `width` display columns.
+///
+/// Strings wider than `width` are truncated. Wide characters count as two columns,
+/// so the result always occupies `width` terminal cells.
+///
+/// ```text
+/// pad_to_width("世界", 6, Left)   → "世界  "
+/// pad_to_width("世界", 6, Center) → " 世界 "
+/// pad_to_width("世界", 6, Right)  → "  世界"
+/// ```
+pub fn pad_to_width(s: &str, width: usize, align: TextAlign) -> String {
+    align_to_width(&truncate_to_width(s, width, false), width, align)
+}
+
+/// Like [`pad_to_width`], but ends truncated strings with `…`.
+pub fn pad_to_width_ellipsis(s: &str, width: usize, align: TextAlign) -> String {
+    align_to_width(&truncate_to_width(s, width, true), width, align)
+}
+
+/// Centers a string within `width` display columns.
+///
+/// When the padding is odd, the extra space goes on the right.
+pub fn center_in(s: &str, width: usize) -> String {
+    pad_to_width(s, width, TextAlign::Center)
+}
+
+/// Pads a string that already fits within `width`.
+fn align_to_width(s: &str, width: usize, align: TextAlign) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    let (left, right) = match align {
+        TextAlign::Left => (0, padding),
+        TextAlign::Center => (padding / 2, padding - padding / 2),
+        TextAlign::Right => (padding, 0),
+    };
+
+    let mut out = String::with_capacity(s.len() + padding);
+    out.extend(std::iter::repeat_n(' ', left));
+    out.push_str(s);
+    out.extend(std::iter::repeat_n(' ', right));
+    out
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions: Text Wrapping
 //--------------------------------------------------------------------------------------------------
@@ -122,12 +494,22 @@ pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
 /// Wraps text according to the specified mode and width constraint.
 ///
 /// Returns a vector of lines that fit within the given width.
-/// Empty lines are preserved in the output.
+/// Empty lines are preserved in the output. Explicit newlines (`\n` or `\r\n`)
+/// always start a new line, in every mode, and each segment between them is
+/// wrapped on its own.
 pub fn wrap_text(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
     if width == 0 {
         return vec![];
     }
 
+    text.split('\n')
+        .map(|segment| segment.strip_suffix('\r').unwrap_or(segment))
+        .flat_map(|segment| wrap_segment(segment, width, mode))
+        .collect()
+}
+
+/// Wraps a single line of text, free of newlines, according to the mode.
+fn wrap_segment(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
     match mode {
         TextWrap::None => {
             // No wrapping - return original text as single line
@@ -143,11 +525,70 @@ pub fn wrap_text(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
         }
         TextWrap::WordBreak => {
             // Try word boundaries first, break words if necessary
-            wrap_word_break(text, width)
+            wrap_word_break(text, width, false)
+        }
+        TextWrap::Hyphenate => {
+            // Like WordBreak, but broken words end their line with a hyphen
+            wrap_word_break(text, width, true)
         }
     }
 }
 
+/// Wraps text like [`wrap_text`], reusing the lines from an earlier call with
+/// the same text, width and mode.
+///
+/// Layout wraps every text node each frame, so static paragraphs would
+/// otherwise be re-wrapped over and over. The cache is per thread and keeps
+/// the most recently used results.
+pub fn wrap_text_cached(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    let key = (hasher.finish(), width, mode);
+
+    WRAP_CACHE.with_borrow_mut(|cache| {
+        cache.tick += 1;
+        let tick = cache.tick;
+
+        if let Some(entry) = cache.entries.get_mut(&key)
+            && entry.text == text
+        {
+            entry.used = tick;
+            cache.stats.hits += 1;
+            return entry.lines.clone();
+        }
+
+        cache.stats.misses += 1;
+        let lines = wrap_text(text, width, mode);
+        if cache.entries.len() >= WRAP_CACHE_CAPACITY && !cache.entries.contains_key(&key) {
+            let oldest = cache
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                cache.entries.remove(&oldest);
+            }
+        }
+        let entry = WrapCacheEntry {
+            text: text.to_string(),
+            lines: lines.clone(),
+            used: tick,
+        };
+        cache.entries.insert(key, entry);
+        lines
+    })
+}
+
+/// Returns the hit and miss counts of this thread's [`wrap_text_cached`] cache.
+pub fn wrap_cache_stats() -> WrapCacheStats {
+    WRAP_CACHE.with_borrow(|cache| cache.stats)
+}
+
+/// Wraps text after expanding tabs, so lines break at tab-expanded positions.
+pub fn wrap_text_with_tabs(text: &str, width: u16, mode: TextWrap, tab_width: u8) -> Vec<String> {
+    wrap_text(&expand_tabs(text, tab_width), width, mode)
+}
+
 /// Wraps text at character boundaries.
 ///
 /// Breaks the text based on display width, accounting for wide characters.
@@ -322,7 +763,8 @@ fn wrap_word(text: &str, width: u16) -> Vec<String> {
 ///
 /// First attempts to break at word boundaries. If a word is longer than
 /// the line width, it breaks the word at character boundaries considering display width.
-fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
+/// With `hyphenate`, see [`break_word`] for how the broken lines are marked.
+fn wrap_word_break(text: &str, width: u16, hyphenate: bool) -> Vec<String> {
     let width = width as usize;
     let mut lines = Vec::new();
     let mut current_line = String::new();
@@ -346,16 +788,14 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
                         current_width = word_width;
                     } else {
                         // Word too long, break it
-                        for word_ch in word.chars() {
-                            let ch_width = char_width(word_ch);
-                            if current_width + ch_width > width && current_width > 0 {
-                                lines.push(current_line.clone());
-                                current_line.clear();
-                                current_width = 0;
-                            }
-                            current_line.push(word_ch);
-                            current_width += ch_width;
-                        }
+                        break_word(
+                            word.as_str(),
+                            width,
+                            hyphenate,
+                            &mut lines,
+                            &mut current_line,
+                            &mut current_width,
+                        );
                     }
                 } else if current_width + word_width <= width {
                     // Word fits on current line
@@ -372,17 +812,15 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
                         current_line.push_str(&word);
                         current_width = word_width;
                     } else {
-                        // Break the word
-                        for word_ch in word.chars() {
-                            let ch_width = char_width(word_ch);
-                            if current_width + ch_width > width && current_width > 0 {
-                                lines.push(current_line.clone());
-                                current_line.clear();
-                                current_width = 0;
-                            }
-                            current_line.push(word_ch);
-                            current_width += ch_width;
-                        }
+                        // Word too long, break it
+                        break_word(
+                            word.as_str(),
+                            width,
+                            hyphenate,
+                            &mut lines,
+                            &mut current_line,
+                            &mut current_width,
+                        );
                     }
                 }
 
@@ -427,16 +865,14 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
                 current_line.push_str(&word);
             } else {
                 // Word too long, break it
-                for word_ch in word.chars() {
-                    let ch_width = char_width(word_ch);
-                    if current_width + ch_width > width && current_width > 0 {
-                        lines.push(current_line.clone());
-                        current_line.clear();
-                        current_width = 0;
-                    }
-                    current_line.push(word_ch);
-                    current_width += ch_width;
-                }
+                break_word(
+                    word.as_str(),
+                    width,
+                    hyphenate,
+                    &mut lines,
+                    &mut current_line,
+                    &mut current_width,
+                );
             }
         } else if current_width + word_width <= width {
             // Word fits on current line
@@ -445,23 +881,21 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
             // Word doesn't fit, start new line
             lines.push(current_line.clone());
             current_line.clear();
+            current_width = 0;
 
             // Add word to new line (possibly breaking it)
             if word_width <= width {
                 current_line = word;
             } else {
-                // Break the word
-                current_width = 0;
-                for word_ch in word.chars() {
-                    let ch_width = char_width(word_ch);
-                    if current_width + ch_width > width && current_width > 0 {
-                        lines.push(current_line.clone());
-                        current_line.clear();
-                        current_width = 0;
-                    }
-                    current_line.push(word_ch);
-                    current_width += ch_width;
-                }
+                // Word too long, break it
+                break_word(
+                    word.as_str(),
+                    width,
+                    hyphenate,
+                    &mut lines,
+                    &mut current_line,
+                    &mut current_width,
+                );
             }
         }
     }
@@ -477,6 +911,73 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
     lines
 }
 
+/// Places a word too long for a line, breaking it across as many lines as needed.
+///
+/// With `hyphenate`, each line the word is broken on ends in a `-`, which counts
+/// toward the line width. Words shorter than [`MIN_HYPHENATED_WORD`] characters,
+/// lines too narrow to hold a character and a hyphen, and breaks next to a `-`
+/// already in the word are broken without one.
+fn break_word(
+    word: &str,
+    width: usize,
+    hyphenate: bool,
+    lines: &mut Vec<String>,
+    current_line: &mut String,
+    current_width: &mut usize,
+) {
+    let chars: Vec<char> = word.chars().collect();
+    let hyphenate = hyphenate && width >= 2 && chars.len() >= MIN_HYPHENATED_WORD;
+    let mut rest_width = display_width(word);
+
+    for (index, &word_ch) in chars.iter().enumerate() {
+        let ch_width = char_width(word_ch);
+        // Unless the rest of the word fits, leave room for the hyphen
+        let reserved = usize::from(hyphenate && *current_width + rest_width > width);
+        if *current_width + ch_width + reserved > width && *current_width > 0 {
+            let joined = index > 0 && (chars[index - 1] == '-' || word_ch == '-');
+            if hyphenate && !joined {
+                current_line.push('-');
+            }
+            lines.push(std::mem::take(current_line));
+            *current_width = 0;
+        }
+        current_line.push(word_ch);
+        *current_width += ch_width;
+        rest_width -= ch_width;
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl std::str::FromStr for LogLevel {
+    type Err = ();
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level.trim().to_ascii_lowercase().as_str() {
+            "error" => Ok(LogLevel::Error),
+            "warn" | "warning" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        })
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -485,6 +986,41 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
 mod tests {
     use super::*;
 
+    //----------------------------------------------------------------------------------------------
+    // Tests: Debug Logging
+    //----------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_debug_log_path_falls_back_to_temp_dir() {
+        let fallback = std::env::temp_dir().join(DEFAULT_DEBUG_LOG_FILE);
+        assert_eq!(resolve_debug_log_path(None, None), fallback);
+        assert_eq!(resolve_debug_log_path(None, Some("".into())), fallback);
+        #[cfg(windows)]
+        assert!(!fallback.starts_with("/tmp"));
+
+        assert_eq!(
+            resolve_debug_log_path(None, Some("env.log".into())),
+            PathBuf::from("env.log")
+        );
+        assert_eq!(
+            resolve_debug_log_path(Some("set.log".into()), Some("env.log".into())),
+            PathBuf::from("set.log")
+        );
+    }
+
+    #[test]
+    fn test_log_level_filters_lower_levels() {
+        assert_eq!(resolve_debug_log_level(None), LogLevel::Debug);
+        assert_eq!(resolve_debug_log_level(Some("bogus")), LogLevel::Debug);
+        assert_eq!(resolve_debug_log_level(Some(" Warning ")), LogLevel::Warn);
+
+        let threshold = resolve_debug_log_level(Some("warn"));
+        assert!(is_log_level_enabled(LogLevel::Error, threshold));
+        assert!(is_log_level_enabled(LogLevel::Warn, threshold));
+        assert!(!is_log_level_enabled(LogLevel::Info, threshold));
+        assert!(!is_log_level_enabled(LogLevel::Debug, threshold));
+    }
+
     //----------------------------------------------------------------------------------------------
     // Tests: Display Width Functions
     //----------------------------------------------------------------------------------------------
@@ -507,6 +1043,48 @@ mod tests {
         assert_eq!(display_width("Test 😀"), 7);
     }
 
+    #[test]
+    fn test_display_width_with_tabs() {
+        assert_eq!(display_width_with_tabs("\t", 4), 4);
+        assert_eq!(display_width_with_tabs("a\tb", 4), 5);
+        assert_eq!(display_width_with_tabs("abcd\tb", 4), 9);
+        assert_eq!(display_width_with_tabs("a\tb", 8), 9);
+        // Tab stops restart after a newline
+        assert_eq!(expand_tabs("ab\n\tc", 4), "ab\n    c");
+        // No tabs borrows the input
+        assert!(matches!(expand_tabs("plain", 4), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_wrap_text_with_tabs() {
+        let lines = wrap_text_with_tabs("ab\tcd\tef", 6, TextWrap::Character, 4);
+        assert_eq!(lines, vec!["ab  cd", "  ef"]);
+    }
+
+    #[test]
+    fn test_expand_span_tabs_continues_columns() {
+        let span = |content: &str| TextSpan {
+            content: content.to_string(),
+            ..Default::default()
+        };
+        let spans = vec![span("ab"), span("\tc")];
+        let expanded = expand_span_tabs(&spans, 4);
+        assert_eq!(expanded[1].content, "  c");
+    }
+
+    #[test]
+    fn test_tabs_render_at_tab_stops() {
+        use crate::node::{Node, Text};
+
+        let node: Node = Text::new("a\tb").into();
+        let lines = crate::components::render_to_buffer(node, 10, 1).lines();
+        assert_eq!(lines[0], "a   b     ");
+
+        let node: Node = Text::new("a\tb").tab_width(2).into();
+        let lines = crate::components::render_to_buffer(node, 10, 1).lines();
+        assert_eq!(lines[0], "a b       ");
+    }
+
     #[test]
     fn test_char_width() {
         assert_eq!(char_width('A'), 1);
@@ -553,6 +1131,26 @@ mod tests {
         assert_eq!(wrapped, vec![text]);
     }
 
+    #[test]
+    fn test_wrap_keeps_explicit_newlines_in_every_mode() {
+        assert_eq!(wrap_text("a\nb", 10, TextWrap::None), vec!["a", "b"]);
+        assert_eq!(
+            wrap_text("a\r\n\nb", 10, TextWrap::None),
+            vec!["a", "", "b"]
+        );
+        for mode in [
+            TextWrap::Character,
+            TextWrap::WordBreak,
+            TextWrap::Hyphenate,
+        ] {
+            assert_eq!(wrap_text("a\nb", 10, mode), vec!["a", "b"], "{mode:?}");
+        }
+
+        // Each segment still wraps on its own
+        let wrapped = wrap_text("one two three\nfour five", 8, TextWrap::Word);
+        assert_eq!(wrapped, vec!["one two ", "three", "four ", "five"]);
+    }
+
     #[test]
     fn test_wrap_character() {
         let text = "Hello World";
@@ -601,6 +1199,28 @@ mod tests {
         assert_eq!(wrapped, vec!["        _ => ", "calculate"]);
     }
 
+    #[test]
+    fn test_wrap_hyphenate_marks_broken_words() {
+        let text = "A hyphenation example";
+        let wrapped = wrap_text(text, 8, TextWrap::Hyphenate);
+        assert_eq!(wrapped, vec!["A ", "hyphena-", "tion ", "example"]);
+        assert!(wrapped.iter().all(|line| display_width(line) <= 8));
+        assert_eq!(wrapped.concat().replace('-', ""), text);
+
+        let wrapped = wrap_text("internationalization", 6, TextWrap::Hyphenate);
+        assert_eq!(wrapped, vec!["inter-", "natio-", "naliz-", "ation"]);
+        assert_eq!(wrapped.concat().replace('-', ""), "internationalization");
+    }
+
+    #[test]
+    fn test_wrap_hyphenate_skips_short_words_and_existing_hyphens() {
+        assert_eq!(wrap_text("abcd", 2, TextWrap::Hyphenate), vec!["ab", "cd"]);
+        assert_eq!(
+            wrap_text("well-known", 6, TextWrap::Hyphenate),
+            vec!["well-", "known"]
+        );
+    }
+
     #[test]
     fn test_wrap_empty_text() {
         assert_eq!(wrap_text("", 10, TextWrap::Character), vec![""]);
@@ -666,4 +1286,178 @@ mod tests {
         let wrapped = wrap_text(text, 10, TextWrap::Word);
         assert_eq!(wrapped, vec!["Hello ", "World    "]);
     }
+
+    #[test]
+    fn test_wrap_cached_reuses_identical_input() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        let before = wrap_cache_stats();
+
+        let first = wrap_text_cached(text, 12, TextWrap::Word);
+        let second = wrap_text_cached(text, 12, TextWrap::Word);
+        assert_eq!(first, second);
+        assert_eq!(first, wrap_text(text, 12, TextWrap::Word));
+
+        let after = wrap_cache_stats();
+        assert_eq!(after.misses - before.misses, 1);
+        assert_eq!(after.hits - before.hits, 1);
+
+        // A different width or mode is a separate entry
+        wrap_text_cached(text, 10, TextWrap::Word);
+        wrap_text_cached(text, 12, TextWrap::Character);
+        assert_eq!(wrap_cache_stats().misses - after.misses, 2);
+    }
+
+    #[test]
+    fn test_wrap_cache_evicts_least_recently_used() {
+        let keep = "kept because it's used";
+        wrap_text_cached(keep, 8, TextWrap::Word);
+        for i in 0..WRAP_CACHE_CAPACITY {
+            wrap_text_cached(&format!("filler {i}"), 8, TextWrap::Word);
+            wrap_text_cached(keep, 8, TextWrap::Word);
+        }
+
+        let before = wrap_cache_stats();
+        wrap_text_cached(keep, 8, TextWrap::Word);
+        wrap_text_cached("filler 0", 8, TextWrap::Word);
+        let after = wrap_cache_stats();
+        assert_eq!(after.hits - before.hits, 1);
+        assert_eq!(after.misses - before.misses, 1);
+    }
+
+    //----------------------------------------------------------------------------------------------
+    // Tests: Alignment Functions
+    //----------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_pad_to_width_ascii() {
+        assert_eq!(pad_to_width("abc", 7, TextAlign::Left), "abc    ");
+        assert_eq!(pad_to_width("abc", 7, TextAlign::Right), "    abc");
+        assert_eq!(pad_to_width("abc", 7, TextAlign::Center), "  abc  ");
+        assert_eq!(center_in("ab", 5), " ab  ");
+        assert_eq!(pad_to_width("", 3, TextAlign::Left), "   ");
+    }
+
+    #[test]
+    fn test_pad_to_width_cjk() {
+        for align in [TextAlign::Left, TextAlign::Center, TextAlign::Right] {
+            assert_eq!(display_width(&pad_to_width("世界", 7, align)), 7);
+        }
+        assert_eq!(pad_to_width("世界", 6, TextAlign::Right), "  世界");
+        assert_eq!(center_in("世界", 6), " 世界 ");
+    }
+
+    #[test]
+    fn test_pad_to_width_emoji() {
+        assert_eq!(pad_to_width("😀 ok", 8, TextAlign::Left), "😀 ok   ");
+        assert_eq!(display_width(&center_in("😀😀", 9)), 9);
+        assert_eq!(center_in("😀", 4), " 😀 ");
+    }
+
+    #[test]
+    fn test_pad_to_width_truncates() {
+        assert_eq!(pad_to_width("Hello World", 5, TextAlign::Left), "Hello");
+        assert_eq!(
+            pad_to_width_ellipsis("Hello World", 6, TextAlign::Left),
+            "Hello…"
+        );
+        assert_eq!(pad_to_width("abc", 0, TextAlign::Center), "");
+        assert_eq!(truncate_to_width("abc", 3, true), "abc");
+    }
+
+    #[test]
+    fn test_truncate_keeps_exact_width_with_wide_chars() {
+        // The second CJK character does not fit in 3 columns, so padding fills the gap
+        assert_eq!(pad_to_width("世界", 3, TextAlign::Left), "世 ");
+        assert_eq!(
+            pad_to_width_ellipsis("世界世界", 5, TextAlign::Left),
+            "世界…"
+        );
+        assert_eq!(pad_to_width_ellipsis("😀😀😀", 4, TextAlign::Right), " 😀…");
+        assert_eq!(
+            display_width(&pad_to_width_ellipsis("😀😀😀", 4, TextAlign::Left)),
+            4
+        );
+    }
+
+    //----------------------------------------------------------------------------------------------
+    // Tests: Grapheme Functions
+    //----------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_grapheme_boundaries_ascii() {
+        assert_eq!(grapheme_boundaries("abc"), vec![0, 1, 2, 3]);
+        assert_eq!(grapheme_boundaries(""), vec![0]);
+    }
+
+    #[test]
+    fn test_grapheme_boundaries_zwj_family() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(family.chars().count(), 5);
+        assert_eq!(
+            grapheme_boundaries(&format!("a{family}b")),
+            vec![0, 1, 6, 7]
+        );
+    }
+
+    #[test]
+    fn test_grapheme_boundaries_flags_and_modifiers() {
+        // Regional indicator pair and an emoji with a skin tone modifier
+        let text = "🇯🇵👍🏽";
+        assert_eq!(grapheme_boundaries(text), vec![0, 2, 4]);
+        // Combining acute accent stays with its base letter
+        assert_eq!(grapheme_boundaries("e\u{301}x"), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_prev_next_grapheme_boundary() {
+        let text = "a👨\u{200d}👩\u{200d}👧b";
+        assert_eq!(next_grapheme_boundary(text, 1), 6);
+        assert_eq!(prev_grapheme_boundary(text, 6), 1);
+        assert_eq!(prev_grapheme_boundary(text, 0), 0);
+        assert_eq!(next_grapheme_boundary(text, 7), 7);
+        // Offsets inside a cluster snap to its edges
+        assert_eq!(next_grapheme_boundary(text, 3), 6);
+        assert_eq!(prev_grapheme_boundary(text, 3), 1);
+    }
+
+    //----------------------------------------------------------------------------------------------
+    // Tests: Text Metrics
+    //----------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_text_metrics_multiline() {
+        let metrics = text_metrics("hello world\nfoo\r\n\nbar baz qux\n");
+        assert_eq!(metrics.lines, 5);
+        assert_eq!(metrics.words, 6);
+        assert_eq!(metrics.display_width, 11);
+        assert_eq!(metrics.chars, 30);
+        // The CRLF pair is a single grapheme
+        assert_eq!(metrics.graphemes, 29);
+
+        assert_eq!(
+            text_metrics(""),
+            TextMetrics {
+                lines: 1,
+                ..TextMetrics::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_text_metrics_cjk_width() {
+        let metrics = text_metrics("日本語\nab");
+        assert_eq!(metrics.display_width, 6);
+        assert_eq!(metrics.chars, 6);
+        assert_eq!(metrics.graphemes, 6);
+        assert_eq!(metrics.words, 2);
+    }
+
+    #[test]
+    fn test_text_metrics_emoji_graphemes() {
+        let metrics = text_metrics("👨\u{200d}👩\u{200d}👧 🇯🇵 👍🏽");
+        assert_eq!(metrics.chars, 11);
+        assert_eq!(metrics.graphemes, 5);
+        assert_eq!(metrics.words, 3);
+        assert_eq!(metrics.lines, 1);
+    }
 }
@@ -39,9 +39,9 @@
 //! 4. Patches are applied to update render tree
 //! 5. Render tree is drawn to terminal
 
-use crate::diff::{Patch, diff};
-use crate::render_tree::{RenderNode, RenderNodeType, RenderTree};
-use crate::utils::display_width;
+use crate::diff::{KeyedChild, Patch, PatchBackend, PatchOp, diff};
+use crate::render_tree::{LayoutMode, RenderNode, RenderNodeType, RenderTree};
+use crate::utils::{display_width, expand_span_tabs, expand_tabs, tab_width_of};
 use crate::vnode::VNode;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -97,16 +97,45 @@ impl VDom {
     /// RenderTree            RenderTree
     /// ```
     pub fn render(&mut self, vnode: VNode) {
+        self.reconcile(vnode, None);
+    }
+
+    /// Renders a new node tree like [`render`](Self::render), also passing each
+    /// change to `backend` as self-contained operations.
+    ///
+    /// The first render creates the root with a single `PatchOp::Replace` at the
+    /// empty path. Later renders send the operations for each patch in the order
+    /// they are applied, so a backend replaying them mirrors the render tree.
+    pub fn apply_patches_to<B: PatchBackend>(&mut self, vnode: VNode, backend: &mut B) {
+        self.reconcile(vnode, Some(backend));
+    }
+
+    /// Diffs `vnode` against the render tree and applies the patches, passing
+    /// each one to `backend` just before it is applied.
+    fn reconcile(&mut self, vnode: VNode, mut backend: Option<&mut dyn PatchBackend>) {
         match &self.render_tree.root {
             Some(root) => {
-                let patches = diff(root, &vnode);
-                self.apply_patches(patches);
+                for patch in diff(root, &vnode) {
+                    if let Some(backend) = backend.as_deref_mut() {
+                        for op in patch.to_ops() {
+                            backend.apply(&op);
+                        }
+                    }
+                    self.apply_patch(patch);
+                }
             }
             None => {
+                if let Some(backend) = backend {
+                    backend.apply(&PatchOp::Replace {
+                        path: Vec::new(),
+                        node: vnode.clone(),
+                    });
+                }
                 let render_node = self.create_render_node(&vnode);
                 self.render_tree.set_root(render_node);
             }
         }
+        self.render_tree.invalidate_keymaps();
         self.current_vnode = Some(vnode);
     }
 
@@ -118,6 +147,11 @@ impl VDom {
         self.render_tree.layout(width, height);
     }
 
+    /// Sets whether [`VDom::layout`] lays out sibling subtrees concurrently.
+    pub(crate) fn set_layout_mode(&mut self, mode: LayoutMode) {
+        self.render_tree.set_layout_mode(mode);
+    }
+
     /// Gets a reference to the current render tree.
     ///
     /// Used by the App to access the tree for drawing and event handling.
@@ -160,6 +194,9 @@ impl VDom {
         render_node.focused = div.focused;
         render_node.hovered = div.hovered;
         render_node.component_path = div.component_path.clone();
+        render_node.memo_key = div.memo_key;
+        render_node.key = div.key;
+        render_node.lazy_path = div.lazy_path.clone();
         render_node.refresh_state_style();
 
         let node_rc = Rc::new(RefCell::new(render_node));
@@ -168,9 +205,11 @@ impl VDom {
         for child_vnode in &div.children {
             let child_render = match child_vnode {
                 VNode::Text(text) => {
-                    let mut text_node = RenderNode::text(&text.content);
-                    text_node.width = display_width(&text.content) as u16;
+                    let content = expand_tabs(&text.content, tab_width_of(text.style.as_ref()));
+                    let mut text_node = RenderNode::text(content.as_ref());
+                    text_node.width = display_width(&content) as u16;
                     text_node.height = 1;
+                    text_node.text_source = Some(text.content.clone());
                     // Apply text-specific style
                     if let Some(ts) = &text.style {
                         text_node.text_color = ts.color;
@@ -183,10 +222,9 @@ impl VDom {
                     Rc::new(RefCell::new(text_node))
                 }
                 VNode::RichText(rich) => {
-                    let mut rich_node =
-                        RenderNode::new(RenderNodeType::RichText(rich.spans.clone()));
-                    rich_node.width = rich
-                        .spans
+                    let spans = expand_span_tabs(&rich.spans, tab_width_of(rich.style.as_ref()));
+                    let mut rich_node = RenderNode::new(RenderNodeType::RichText(spans.to_vec()));
+                    rich_node.width = spans
                         .iter()
                         .map(|span| display_width(&span.content) as u16)
                         .sum();
@@ -220,10 +258,13 @@ impl VDom {
     ///
     /// Text nodes are leaf nodes that contain string content.
     fn create_text_node(&self, text: &crate::node::Text) -> Rc<RefCell<RenderNode>> {
-        let mut render_node = RenderNode::text(&text.content);
+        // Tabs are expanded up front so width, wrapping and drawing agree
+        let content = expand_tabs(&text.content, tab_width_of(text.style.as_ref()));
+        let mut render_node = RenderNode::text(content.as_ref());
         // Set proper dimensions for text nodes
-        render_node.width = display_width(&text.content) as u16;
+        render_node.width = display_width(&content) as u16;
         render_node.height = 1;
+        render_node.text_source = Some(text.content.clone());
         // Apply text-specific style
         if let Some(ts) = &text.style {
             render_node.text_color = ts.color;
@@ -240,10 +281,10 @@ impl VDom {
     ///
     /// RichText nodes contain multiple text spans with individual styling.
     fn create_rich_text_node(&self, rich: &crate::node::RichText) -> Rc<RefCell<RenderNode>> {
-        let mut render_node = RenderNode::new(RenderNodeType::RichText(rich.spans.clone()));
+        let spans = expand_span_tabs(&rich.spans, tab_width_of(rich.style.as_ref()));
+        let mut render_node = RenderNode::new(RenderNodeType::RichText(spans.to_vec()));
         // Calculate dimensions - sum of all span widths
-        render_node.width = rich
-            .spans
+        render_node.width = spans
             .iter()
             .map(|span| display_width(&span.content) as u16)
             .sum();
@@ -268,16 +309,6 @@ impl VDom {
         Rc::new(RefCell::new(render_node))
     }
 
-    /// Applies a list of patches to update the render tree.
-    ///
-    /// Patches are applied in order to transform the current
-    /// render tree to match the new node tree.
-    fn apply_patches(&mut self, patches: Vec<Patch>) {
-        for patch in patches {
-            self.apply_patch(patch);
-        }
-    }
-
     /// Applies a single patch operation to the render tree.
     ///
     /// ## Patch Types
@@ -287,6 +318,7 @@ impl VDom {
     /// - **UpdateProps**: Update styles/dimensions
     /// - **AddChild**: Insert new child node
     /// - **RemoveChild**: Delete child node
+    /// - **ReorderChildren**: Rearrange children matched by key
     fn apply_patch(&mut self, patch: Patch) {
         match patch {
             Patch::Replace { old, new } => {
@@ -313,6 +345,7 @@ impl VDom {
             Patch::UpdateText {
                 node,
                 new_text,
+                new_source,
                 new_style,
             } => {
                 let mut node_ref = node.borrow_mut();
@@ -320,6 +353,7 @@ impl VDom {
                 node_ref.width = display_width(&new_text) as u16;
                 node_ref.height = 1;
                 node_ref.node_type = RenderNodeType::Text(new_text);
+                node_ref.text_source = Some(new_source);
 
                 // Update text style
                 node_ref.text_style = new_style.clone();
@@ -371,6 +405,11 @@ impl VDom {
                 node_ref.focused = is_focused;
                 node_ref.hovered = is_hovered;
                 node_ref.component_path = div.component_path.clone();
+                // New props may change the subtree's layout
+                node_ref.memo_key = div.memo_key;
+                node_ref.key = div.key;
+                node_ref.lazy_path = div.lazy_path.clone();
+                node_ref.memo_layout = None;
                 node_ref.refresh_state_style();
                 node_ref.mark_dirty();
             }
@@ -393,6 +432,26 @@ impl VDom {
                 // Set parent reference after inserting
                 child_render.borrow_mut().parent = Some(Rc::downgrade(&parent));
             }
+            Patch::ReorderChildren { parent, children } => {
+                let children: Vec<_> = children
+                    .into_iter()
+                    .map(|child| match child {
+                        KeyedChild::Existing(node) => node,
+                        KeyedChild::New(vnode) => {
+                            let node = self.create_render_node(&vnode);
+                            node.borrow_mut().mark_dirty();
+                            node
+                        }
+                    })
+                    .collect();
+                for child in &children {
+                    child.borrow_mut().parent = Some(Rc::downgrade(&parent));
+                }
+                let mut parent_ref = parent.borrow_mut();
+                parent_ref.children = children;
+                // Mark parent as dirty since its children changed
+                parent_ref.mark_dirty();
+            }
             Patch::RemoveChild { parent, index } => {
                 let mut parent_ref = parent.borrow_mut();
                 if index < parent_ref.children.len() {
@@ -414,3 +473,287 @@ impl Default for VDom {
         Self::new()
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{Div, Text, TextContent};
+
+    /// Root with a header of the given height above a memoized panel
+    fn tree(header_height: u16, deps: u32) -> VNode {
+        let panel = Div::new()
+            .memo(deps)
+            .width(10)
+            .height(3)
+            .child(VNode::text("cached"))
+            .child(Div::new().width(5).height(1).into());
+
+        Div::new()
+            .width(20)
+            .height(10)
+            .child(Div::new().height(header_height).into())
+            .child(panel.into())
+            .into()
+    }
+
+    fn panel(vdom: &VDom) -> Rc<RefCell<RenderNode>> {
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        root.borrow().children[1].clone()
+    }
+
+    fn render(vdom: &mut VDom, vnode: VNode) {
+        vdom.render(vnode);
+        vdom.layout(40, 20);
+    }
+
+    #[test]
+    fn test_unchanged_memo_subtree_is_not_laid_out_again() {
+        let mut vdom = VDom::new();
+        render(&mut vdom, tree(1, 7));
+
+        // Tamper with a laid out descendant; a fresh layout would reset it to 5
+        let inner = panel(&vdom).borrow().children[1].clone();
+        assert_eq!(inner.borrow().width, 5);
+        inner.borrow_mut().width = 99;
+
+        render(&mut vdom, tree(1, 7));
+        assert_eq!(inner.borrow().width, 99);
+        assert!(Rc::ptr_eq(&inner, &panel(&vdom).borrow().children[1]));
+    }
+
+    #[test]
+    fn test_changed_memo_deps_lay_out_again() {
+        let mut vdom = VDom::new();
+        render(&mut vdom, tree(1, 7));
+
+        let inner = panel(&vdom).borrow().children[1].clone();
+        inner.borrow_mut().width = 99;
+
+        render(&mut vdom, tree(1, 8));
+        assert_eq!(inner.borrow().width, 5);
+    }
+
+    #[test]
+    fn test_memo_subtree_moves_with_its_node() {
+        let mut vdom = VDom::new();
+        render(&mut vdom, tree(1, 7));
+
+        let text = panel(&vdom).borrow().children[0].clone();
+        assert_eq!(text.borrow().y, 1);
+
+        // A taller header pushes the cached subtree down without re-laying it out
+        render(&mut vdom, tree(3, 7));
+        assert_eq!(panel(&vdom).borrow().y, 3);
+        assert_eq!(text.borrow().y, 3);
+        assert_eq!(panel(&vdom).borrow().children[1].borrow().y, 4);
+    }
+
+    fn labelled(label: Text) -> VNode {
+        Div::new().width(20).height(1).child(label.into()).into()
+    }
+
+    fn label_node(vdom: &VDom) -> Rc<RefCell<RenderNode>> {
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        root.borrow().children[0].clone()
+    }
+
+    #[test]
+    fn test_shared_text_is_diffed_by_pointer() {
+        let label: Arc<str> = Arc::from("label");
+        let mut vdom = VDom::new();
+        render(&mut vdom, labelled(Text::shared(label.clone())));
+
+        // Tamper with the rendered string; only a string comparison would notice
+        let node = label_node(&vdom);
+        node.borrow_mut().node_type = RenderNodeType::Text("tampered".into());
+        let root = vdom.get_render_tree().root.clone().unwrap();
+
+        assert!(diff(&root, &labelled(Text::shared(label.clone()))).is_empty());
+        assert!(diff(&root, &labelled(Text::from_static("tampered"))).is_empty());
+
+        // Equal content in a different allocation falls back to comparing strings
+        let patches = diff(&root, &labelled(Text::shared("label")));
+        assert!(matches!(
+            patches.as_slice(),
+            [Patch::UpdateText { new_text, .. }] if new_text == "label"
+        ));
+    }
+
+    #[test]
+    fn test_shared_text_is_not_copied_across_renders() {
+        let label: Arc<str> = Arc::from("status: ok");
+        let mut vdom = VDom::new();
+        for _ in 0..100 {
+            render(&mut vdom, labelled(Text::shared(label.clone())));
+        }
+
+        // Held by the test, the current vnode and the render tree; no frame copied it
+        assert_eq!(Arc::strong_count(&label), 3);
+        let node = label_node(&vdom);
+        let source = node.borrow().text_source.clone().unwrap();
+        assert!(matches!(&source, TextContent::Shared(shared) if Arc::ptr_eq(shared, &label)));
+
+        let text = Text::from_static("static");
+        assert_eq!(text.clone().content.as_ptr(), "static".as_ptr());
+    }
+
+    /// Vertical list of focusable, scrollable items keyed by name
+    fn keyed_list(items: &[&str]) -> VNode {
+        let rows = items.iter().map(|name| {
+            Div::new()
+                .key(name)
+                .focusable(true)
+                .overflow(crate::style::Overflow::Scroll)
+                .height(2)
+                .children(
+                    (0..5)
+                        .map(|line| VNode::text(format!("{name} {line}")))
+                        .collect(),
+                )
+                .into()
+        });
+        Div::new()
+            .direction(crate::style::Direction::Vertical)
+            .width(20)
+            .height(10)
+            .children(rows.collect())
+            .into()
+    }
+
+    fn rows(vdom: &VDom) -> Vec<Rc<RefCell<RenderNode>>> {
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        root.borrow().children.clone()
+    }
+
+    #[test]
+    fn test_keyed_insert_keeps_scroll_and_focus() {
+        let mut vdom = VDom::new();
+        render(&mut vdom, keyed_list(&["b", "c"]));
+
+        let focused = rows(&vdom)[0].clone();
+        vdom.get_render_tree()
+            .set_focused_node(Some(focused.clone()));
+        focused.borrow_mut().scroll_y = 2;
+
+        render(&mut vdom, keyed_list(&["a", "b", "c"]));
+        let rows = rows(&vdom);
+        assert!(Rc::ptr_eq(&rows[1], &focused));
+        assert!(focused.borrow().focused);
+        assert_eq!(focused.borrow().scroll_y, 2);
+        assert_eq!(focused.borrow().y, 2);
+        assert!(Rc::ptr_eq(
+            &vdom.get_render_tree().get_focused_node().unwrap(),
+            &focused
+        ));
+
+        // The inserted item is new rather than the old first row relabelled
+        let inserted = rows[0].borrow();
+        assert!(!inserted.focused);
+        assert_eq!(inserted.scroll_y, 0);
+        let first_line = inserted.children[0].borrow();
+        assert!(matches!(&first_line.node_type, RenderNodeType::Text(text) if text == "a 0"));
+    }
+
+    #[test]
+    fn test_keyed_children_follow_reordering_and_removal() {
+        let mut vdom = VDom::new();
+        render(&mut vdom, keyed_list(&["a", "b", "c"]));
+        let before = rows(&vdom);
+
+        render(&mut vdom, keyed_list(&["c", "a"]));
+        let after = rows(&vdom);
+        assert_eq!(after.len(), 2);
+        assert!(Rc::ptr_eq(&after[0], &before[2]));
+        assert!(Rc::ptr_eq(&after[1], &before[0]));
+        assert_eq!(after[0].borrow().y, 0);
+    }
+
+    /// Backend that records the operations it receives
+    #[derive(Default)]
+    struct Recorder(Vec<PatchOp>);
+
+    impl PatchBackend for Recorder {
+        fn apply(&mut self, op: &PatchOp) {
+            self.0.push(op.clone());
+        }
+    }
+
+    fn lines(lines: &[&str]) -> VNode {
+        Div::new()
+            .children(lines.iter().map(|line| VNode::text(*line)).collect())
+            .into()
+    }
+
+    #[test]
+    fn test_patch_ops_describe_changes_by_path() {
+        let mut vdom = VDom::new();
+        let mut recorder = Recorder::default();
+        vdom.apply_patches_to(lines(&["one", "two"]), &mut recorder);
+        // Divs never compare equal, so match the created root by shape
+        assert!(matches!(
+            recorder.0.as_slice(),
+            [PatchOp::Replace { path, node: VNode::Div(root) }]
+                if path.is_empty() && root.children.len() == 2
+        ));
+
+        let mut recorder = Recorder::default();
+        vdom.apply_patches_to(lines(&["one", "2", "three"]), &mut recorder);
+        assert_eq!(
+            recorder.0,
+            [
+                PatchOp::UpdateText {
+                    path: vec![1],
+                    text: "2".into(),
+                    style: None,
+                },
+                PatchOp::Insert {
+                    parent: vec![],
+                    index: 2,
+                    node: VNode::text("three"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_patch_ops_break_reorders_into_moves() {
+        let mut vdom = VDom::new();
+        render(&mut vdom, keyed_list(&["a", "b", "c"]));
+
+        let mut recorder = Recorder::default();
+        vdom.apply_patches_to(keyed_list(&["c", "a", "d"]), &mut recorder);
+        let [
+            remove,
+            moved,
+            PatchOp::Insert {
+                parent,
+                index,
+                node,
+            },
+        ] = recorder.0.as_slice()
+        else {
+            panic!("unexpected operations: {:?}", recorder.0);
+        };
+        assert_eq!(
+            *remove,
+            PatchOp::Remove {
+                parent: vec![],
+                index: 1,
+            }
+        );
+        assert_eq!(
+            *moved,
+            PatchOp::Move {
+                parent: vec![],
+                from: 1,
+                to: 0,
+            }
+        );
+        assert_eq!((parent.as_slice(), *index), ([].as_slice(), 2));
+        assert!(matches!(node, VNode::Div(row) if row.key == Div::<VNode>::new().key("d").key));
+    }
+}
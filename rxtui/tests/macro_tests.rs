@@ -546,3 +546,38 @@ fn test_expression_in_dimensions() {
         _ => panic!("Expected div node"),
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Component Macro Tests
+//--------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct Probe {
+    mounted: std::cell::Cell<bool>,
+}
+
+#[component]
+impl Probe {
+    #[view]
+    fn view(&self, _ctx: &Context) -> Node {
+        node! { div [] }
+    }
+
+    fn on_mount(&self, _ctx: &Context) {
+        self.mounted.set(true);
+    }
+}
+
+#[test]
+fn test_component_macro_forwards_lifecycle_hooks() {
+    let probe = Probe {
+        mounted: std::cell::Cell::new(false),
+    };
+    let ctx = Context::default();
+
+    Component::on_mount(&probe, &ctx);
+    assert!(probe.mounted.get());
+
+    // Hooks that aren't defined fall back to doing nothing
+    Component::on_unmount(&probe, &ctx);
+}